@@ -0,0 +1,42 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 prelude
+//! 
+//! The imports every day crate starts with, in a single glob:
+//! 
+//! ```
+//! use aoc_prelude::*;
+//! 
+//! #[derive(Debug, Error)]
+//! #[error("no input")]
+//! struct Empty;
+//! 
+//! fn first_line(input: &str) -> Result<&str> {
+//!     input.lines().next().ok_or(Empty).context("reading the first line")
+//! }
+//! # assert_eq!(first_line("a\nb").unwrap(), "a");
+//! ```
+//! 
+//! [`Result`] is the `anyhow` one for binaries and tests. Day crates define
+//! their own `Result` for their error type, which takes precedence over the
+//! glob import, and [`Solution`] methods return [`SolutionResult`].
+//! 
+//! The `fs` feature, on by default, adds the readers of files and the
+//! standard input.
+//! 
+//! [`Read more`](../../../README.md)
+
+pub use std::{
+    collections::{HashMap, HashSet},
+    io::{BufRead, BufReader, Cursor, Read},
+};
+pub use anyhow::{Context, Result};
+pub use aoc_utils::{
+    error_chain, parse_grid_lines, parse_lines, parse_two_columns, split_two_columns,
+    CancelToken, Cancelled, CharClasses, ErrorKind, ExpectedAnswers, Grid, GridError, InputStats,
+    Location, MazeError, NoProgress, NormalizingReader, ParseError, Parsed, Pos, Progress, Sample, Solution,
+    SolutionError, Ticker, TwoColumns,
+};
+#[cfg(feature = "fs")]
+pub use aoc_utils::{open_buffered, InputError, InputSource};
+pub use aoc_utils::Result as SolutionResult;
+pub use thiserror::Error;
@@ -0,0 +1,58 @@
+//! A downstream day crate written against the prelude alone
+
+use aoc_prelude::*;
+
+#[derive(Debug, Error)]
+enum DayError {
+    #[error(transparent)]
+    Parse(#[from] ParseError<std::num::ParseIntError>),
+}
+
+impl From<DayError> for SolutionError {
+    fn from(e: DayError) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+/// Sums the numbers of both columns
+struct Sums;
+
+impl Solution for Sums {
+    fn day(&self) -> u8 {
+        0
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        let (left, _): TwoColumns<i64> = parse_two_columns::<i64>(input).map_err(DayError::from)?;
+        Ok(left.iter().sum::<i64>().to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        let (_, right): TwoColumns<i64> = parse_two_columns::<i64>(input).map_err(DayError::from)?;
+        Ok(right.iter().sum::<i64>().to_string())
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: "3   4\n4   3\n", answers: ["7", "7"] })
+    }
+}
+
+#[test]
+fn test_solution_from_prelude() -> Result<()> {
+    let sample = Sums.sample().context("the sample is embedded")?;
+    assert_eq!(Sums.part1(sample.input)?, sample.answers[0]);
+    assert_eq!(Sums.solve_with_progress(2, sample.input, &NoProgress)?, sample.answers[1]);
+    let e = Sums.part1("3   x\n").unwrap_err();
+    assert!(e.day_error::<DayError>().is_some());
+    Ok(())
+}
+
+#[test]
+fn test_std_imports() -> Result<()> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for line in BufReader::new(Cursor::new("a\nb\na\n")).lines() {
+        *counts.entry(line?).or_default() += 1;
+    }
+    assert_eq!(counts["a"], 2);
+    Ok(())
+}
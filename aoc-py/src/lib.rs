@@ -0,0 +1,56 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Python bindings
+//! 
+//! Exposes the solutions registered in the `aoc` runner as the `aoc_py`
+//! Python module. Both functions go through [`aoc::dispatch`], errors become
+//! Python exceptions carrying the full message of the error chain:
+//! 
+//! * unknown day or part - `ValueError`
+//! * unreadable input file - `OSError`
+//! * part not solved yet - `NotImplementedError`
+//! * any other failure - `aoc_py.SolveError`, a `RuntimeError` subclass
+//! 
+//! [`Read more`](../../../README.md)
+
+use std::path::PathBuf;
+use aoc::dispatch::{self, DispatchError};
+use aoc_utils::SolutionError;
+use pyo3::{create_exception, exceptions::{PyNotImplementedError, PyOSError, PyRuntimeError, PyValueError}, prelude::*};
+
+create_exception!(aoc_py, SolveError, PyRuntimeError, "Solving the puzzle failed");
+
+/// Python exception matching the error, with the messages of all its sources
+pub fn to_py_err(e: DispatchError) -> PyErr {
+    let message = aoc_utils::error_chain(&e);
+    match e {
+        DispatchError::UnknownDay(_) | DispatchError::InvalidPart(_) => PyValueError::new_err(message),
+        DispatchError::Input(_) => PyOSError::new_err(message),
+        DispatchError::Solution(SolutionError::NotImplemented { .. }) => PyNotImplementedError::new_err(message),
+        DispatchError::Solution(_) => SolveError::new_err(message),
+    }
+}
+
+/// Solve one part of a day for the input text
+/// 
+/// The GIL is released while solving
+#[pyfunction]
+pub fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<String> {
+    py.allow_threads(|| dispatch::solve(day, part, input)).map_err(to_py_err)
+}
+
+/// Solve one part of a day for the input in a file
+/// 
+/// The GIL is released while reading the file and solving
+#[pyfunction]
+pub fn solve_file(py: Python<'_>, day: u8, part: u8, path: PathBuf) -> PyResult<String> {
+    py.allow_threads(|| dispatch::solve_file(day, part, &path)).map_err(to_py_err)
+}
+
+/// The `aoc_py` Python module
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_file, m)?)?;
+    m.add("SolveError", m.py().get_type::<SolveError>())?;
+    Ok(())
+}
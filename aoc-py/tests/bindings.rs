@@ -0,0 +1,38 @@
+use aoc_py::{solve, solve_file, SolveError};
+use pyo3::{exceptions::{PyNotImplementedError, PyOSError, PyValueError}, Python};
+
+#[test]
+fn test_solve_sample() {
+    Python::with_gil(|py| {
+        assert_eq!(solve(py, 1, 1, day_1::SAMPLE).unwrap(), "11");
+        assert_eq!(solve(py, 5, 1, day_5::SAMPLE).unwrap(), "143");
+    });
+}
+
+#[test]
+fn test_solve_exceptions() {
+    Python::with_gil(|py| {
+        let e = solve(py, 26, 1, "").unwrap_err();
+        assert!(e.is_instance_of::<PyValueError>(py));
+        assert_eq!(e.value(py).to_string(), "day 26 is not implemented");
+
+        let e = solve(py, 1, 3, day_1::SAMPLE).unwrap_err();
+        assert!(e.is_instance_of::<PyValueError>(py));
+
+        let e = solve(py, 4, 1, day_4::SAMPLE).unwrap_err();
+        assert!(e.is_instance_of::<PyNotImplementedError>(py));
+
+        let e = solve(py, 1, 1, "3   x\n").unwrap_err();
+        assert!(e.is_instance_of::<SolveError>(py));
+        assert_eq!(e.value(py).to_string(), "input line 1, right column: failed parsing 'x' to number: invalid digit found in string");
+    });
+}
+
+#[test]
+fn test_solve_file_missing() {
+    Python::with_gil(|py| {
+        let e = solve_file(py, 1, 1, "missing.txt".into()).unwrap_err();
+        assert!(e.is_instance_of::<PyOSError>(py));
+        assert!(e.value(py).to_string().starts_with("could not read file missing.txt: "));
+    });
+}
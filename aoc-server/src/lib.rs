@@ -0,0 +1,146 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 HTTP service
+//! 
+//! Solves the puzzles registered in the `aoc` runner over HTTP:
+//! 
+//! * `POST /solve/{day}/{part}` with the raw puzzle input as the body returns
+//!   `{"answer": "...", "duration_us": ...}`
+//! * `GET /days` returns `{"days": [...]}` with every implemented day
+//! 
+//! Errors are returned as `{"error": "..."}` with a status matching the
+//! failure: 400 for a malformed request, 404 for an unknown day or part,
+//! 413 for an input over the body limit, 422 when the input cannot be solved,
+//! 501 for a part that is not solved yet and 503 when the solve timed out.
+//! 
+//! [`Read more`](../../../README.md)
+
+use std::time::Duration;
+use aoc_utils::{CancelToken, NoProgress, SolutionError};
+use axum::{
+    extract::{rejection::{PathRejection, StringRejection}, DefaultBodyLimit, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+
+/// Limits applied to every request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Largest accepted puzzle input in bytes
+    pub body_limit: usize,
+    /// Time a solve may take before it is cancelled
+    pub timeout: Duration,
+}
+
+impl Default for Config {
+    /// 1 MiB inputs, real puzzle inputs are a few tens of KiB,
+    /// and 10 second solves
+    fn default() -> Self {
+        Config { body_limit: 1024 * 1024, timeout: Duration::from_secs(10) }
+    }
+}
+
+/// Answer returned by `POST /solve/{day}/{part}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Answer {
+    /// Answer formatted as a string
+    pub answer: String,
+    /// Time spent solving, in microseconds
+    pub duration_us: u64,
+}
+
+/// Days returned by `GET /days`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Days {
+    /// Implemented days in ascending order
+    pub days: Vec<u8>,
+}
+
+/// Failed request, rendered as `{"error": "..."}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// Status code of the response
+    pub status: StatusCode,
+    /// Description of the failure
+    pub message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        ApiError { status, message: message.into() }
+    }
+}
+
+impl From<SolutionError> for ApiError {
+    fn from(e: SolutionError) -> Self {
+        let status = match e {
+            SolutionError::NotImplemented { .. } | SolutionError::NotSupported { .. } => StatusCode::NOT_IMPLEMENTED,
+            SolutionError::InvalidSelector { .. } => StatusCode::BAD_REQUEST,
+            SolutionError::Cancelled => StatusCode::SERVICE_UNAVAILABLE,
+            SolutionError::Day(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        ApiError::new(status, aoc_utils::error_chain(&e))
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        #[derive(Serialize)]
+        struct Body {
+            error: String,
+        }
+        (self.status, Json(Body { error: self.message })).into_response()
+    }
+}
+
+/// Router serving the solvers with the given limits
+/// 
+/// # Examples
+/// ```
+/// let app = aoc_server::router(aoc_server::Config::default());
+/// ```
+pub fn router(config: Config) -> Router {
+    Router::new()
+    .route("/days", get(days))
+    .route("/solve/{day}/{part}", post(solve))
+    .layer(DefaultBodyLimit::max(config.body_limit))
+    .with_state(config)
+}
+
+async fn days() -> Json<Days> {
+    Json(Days { days: aoc::solutions().iter().map(|s| s.day()).collect() })
+}
+
+async fn solve(State(config): State<Config>, path: Result<Path<(u8, u8)>, PathRejection>,
+    body: Result<String, StringRejection>) -> Result<Json<Answer>, ApiError> {
+    let Path((day, part)) = path.map_err(|e| ApiError::new(StatusCode::BAD_REQUEST, e.body_text()))?;
+    if aoc::dispatch::solution(day).is_none() {
+        return Err(ApiError::new(StatusCode::NOT_FOUND, format!("day {} is not implemented", day)));
+    }
+    if !(1..=2).contains(&part) {
+        return Err(ApiError::new(StatusCode::NOT_FOUND, format!("part must be 1 or 2, got {}", part)));
+    }
+    let input = body.map_err(|e| ApiError::new(e.status(), e.body_text()))?;
+
+    let cancel = CancelToken::new();
+    let task = tokio::task::spawn_blocking({
+        let cancel = cancel.clone();
+        move || {
+            let solution = aoc::dispatch::solution(day).expect("the day was checked before");
+            aoc::solve_part_with_cancel(solution.as_ref(), part, &input, &NoProgress, &cancel)
+        }
+    });
+    let result = match tokio::time::timeout(config.timeout, task).await {
+        Ok(joined) => joined.map_err(|e| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+        Err(_) => {
+            // days without cancellation checks keep their thread until they finish
+            cancel.cancel();
+            let message = format!("solve timed out after {} ms", config.timeout.as_millis());
+            return Err(ApiError::new(StatusCode::SERVICE_UNAVAILABLE, message));
+        },
+    }?;
+
+    Ok(Json(Answer { answer: result.answer, duration_us: result.duration.as_micros() as u64 }))
+}
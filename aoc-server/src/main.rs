@@ -0,0 +1,30 @@
+use std::{net::SocketAddr, time::Duration};
+use anyhow::{Context, Result};
+use clap::Parser;
+
+/// HTTP service solving the advent of code 2024 puzzles
+#[derive(Parser)]
+#[command(name = "aoc-server", version, about)]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: SocketAddr,
+    /// Largest accepted puzzle input in bytes
+    #[arg(long, default_value_t = aoc_server::Config::default().body_limit)]
+    body_limit: usize,
+    /// Milliseconds a solve may take before it is cancelled
+    #[arg(long, default_value_t = 10_000)]
+    timeout_ms: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let config = aoc_server::Config { body_limit: cli.body_limit, timeout: Duration::from_millis(cli.timeout_ms) };
+
+    let listener = tokio::net::TcpListener::bind(cli.addr).await
+    .with_context(|| format!("could not listen on {}", cli.addr))?;
+    eprintln!("Listening on {}", cli.addr);
+    axum::serve(listener, aoc_server::router(config)).await
+    .context("server failed")
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+use aoc_server::{router, Config};
+use axum::{body::Body, http::{Request, StatusCode}};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// Send a request to the router in-process and return the status and JSON body
+async fn send(config: Config, method: &str, uri: &str, body: impl Into<Body>) -> (StatusCode, Value) {
+    let request = Request::builder().method(method).uri(uri).body(body.into()).unwrap();
+    let response = router(config).oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_solve_success() {
+    let (status, body) = send(Config::default(), "POST", "/solve/1/2", day_1::SAMPLE).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["answer"], "31");
+    assert!(body["duration_us"].is_u64());
+}
+
+#[tokio::test]
+async fn test_days() {
+    let (status, body) = send(Config::default(), "GET", "/days", Body::empty()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["days"].as_array().unwrap()[..5], [1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn test_solve_unknown_day() {
+    let (status, body) = send(Config::default(), "POST", "/solve/26/1", "").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "day 26 is not implemented");
+}
+
+#[tokio::test]
+async fn test_solve_unknown_part() {
+    let (status, body) = send(Config::default(), "POST", "/solve/1/3", day_1::SAMPLE).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "part must be 1 or 2, got 3");
+}
+
+#[tokio::test]
+async fn test_solve_malformed_path() {
+    let (status, body) = send(Config::default(), "POST", "/solve/one/1", day_1::SAMPLE).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].is_string());
+}
+
+#[tokio::test]
+async fn test_solve_invalid_utf8() {
+    let (status, _) = send(Config::default(), "POST", "/solve/1/1", vec![b'3', 0xff]).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_solve_parse_error() {
+    let (status, body) = send(Config::default(), "POST", "/solve/1/1", "3   x\n").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"], "input line 1, right column: failed parsing 'x' to number: invalid digit found in string");
+}
+
+#[tokio::test]
+async fn test_solve_not_implemented() {
+    let (status, body) = send(Config::default(), "POST", "/solve/4/1", day_4::SAMPLE).await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+    assert_eq!(body["error"], "day 4 part 1 is not implemented");
+}
+
+#[tokio::test]
+async fn test_solve_body_limit() {
+    let config = Config { body_limit: 16, ..Config::default() };
+    let (status, _) = send(config, "POST", "/solve/1/1", day_1::SAMPLE).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_solve_timeout() {
+    // a 4000 x 4000 grid takes far longer than a millisecond
+    let row = day_4::SAMPLE.lines().next().unwrap().repeat(400) + "\n";
+    let input = row.repeat(4000);
+    let config = Config { body_limit: input.len(), timeout: Duration::from_millis(1) };
+    let (status, body) = send(config, "POST", "/solve/4/2", input).await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["error"], "solve timed out after 1 ms");
+}
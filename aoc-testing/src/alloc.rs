@@ -0,0 +1,81 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, cell::Cell};
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Global allocator forwarding to [`System`] and counting the allocations of each thread
+/// 
+/// Install it in a test binary to use [`AllocationGuard`]. The counters
+/// are per thread, so tests running in parallel do not see each other's
+/// allocations, but neither are allocations made by worker threads counted.
+/// 
+/// # Examples
+/// ```
+/// #[global_allocator]
+/// static ALLOCATOR: aoc_testing::CountingAllocator = aoc_testing::CountingAllocator;
+/// 
+/// let guard = aoc_testing::AllocationGuard::new();
+/// let numbers: Vec<u32> = (0..100).collect();
+/// assert_eq!(guard.allocations(), 1);
+/// assert_eq!(guard.bytes(), 400);
+/// # drop(numbers);
+/// ```
+pub struct CountingAllocator;
+
+fn record(size: usize) {
+    // `try_with` fails while the thread is being torn down, those allocations are not counted
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Allocations made by the current thread since the guard was created
+/// 
+/// Only counts when [`CountingAllocator`] is the global allocator,
+/// otherwise both counters stay at zero. Reallocations count as
+/// allocations of the new size.
+#[derive(Debug)]
+pub struct AllocationGuard {
+    allocations: u64,
+    bytes: u64,
+}
+
+impl AllocationGuard {
+    /// Start counting from now
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        AllocationGuard { allocations: ALLOCATIONS.get(), bytes: BYTES.get() }
+    }
+
+    /// Number of allocations and reallocations so far
+    pub fn allocations(&self) -> u64 {
+        ALLOCATIONS.get() - self.allocations
+    }
+
+    /// Bytes requested by the allocations so far, freed memory included
+    pub fn bytes(&self) -> u64 {
+        BYTES.get() - self.bytes
+    }
+}
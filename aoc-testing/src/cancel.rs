@@ -0,0 +1,36 @@
+use std::{thread, time::{Duration, Instant}};
+use aoc_utils::CancelToken;
+
+/// Run `solve` on another thread and cancel its token after `delay`
+/// 
+/// Returns the result of the solve and how long it kept running after
+/// the token was cancelled. The input has to be large enough for the solve
+/// to still be running when the delay is over.
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// 
+/// let (cancelled, latency) = aoc_testing::cancel_after(Duration::from_millis(10), |cancel| {
+///     while !cancel.is_cancelled() {
+///         std::thread::yield_now();
+///     }
+///     cancel.is_cancelled()
+/// });
+/// assert!(cancelled);
+/// assert!(latency < Duration::from_secs(1));
+/// ```
+pub fn cancel_after<T: Send>(delay: Duration, solve: impl FnOnce(&CancelToken) -> T + Send) -> (T, Duration) {
+    let token = CancelToken::new();
+    thread::scope(|scope| {
+        let handle = scope.spawn(|| {
+            let result = solve(&token);
+            (result, Instant::now())
+        });
+        thread::sleep(delay);
+        let cancelled_at = Instant::now();
+        token.cancel();
+        let (result, finished_at) = handle.join().expect("solve panicked");
+        (result, finished_at.saturating_duration_since(cancelled_at))
+    })
+}
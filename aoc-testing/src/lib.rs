@@ -0,0 +1,29 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 test helpers
+//! 
+//! Proptest strategies, seeded input generators, the fuzz regression
+//! replay, the snapshot redaction and the allocation counter shared by
+//! the tests of the crates. Every generator also
+//! computes the expected answers with a straightforward reference
+//! implementation, so the tests do not depend on the solution being checked.
+//! 
+//! The crate only depends on `aoc-utils`, day crates use it as a
+//! dev-dependency.
+//! 
+//! [`Read more`](../../../README.md)
+
+mod alloc;
+mod cancel;
+mod progress;
+mod regressions;
+mod seeded;
+mod snapshot;
+mod strategy;
+
+pub use alloc::{AllocationGuard, CountingAllocator};
+pub use cancel::cancel_after;
+pub use progress::RecordingProgress;
+pub use regressions::{regressions_dir, replay_regressions};
+pub use seeded::{crlf_bom, day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25, oracle, Generated, Rng};
+pub use snapshot::redact;
+pub use strategy::{consistent_rules_strategy, grid_strategy, report_strategy, Rules};
@@ -0,0 +1,43 @@
+use std::sync::Mutex;
+use aoc_utils::Progress;
+
+/// [`Progress`] recording every update it receives
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::Progress;
+/// 
+/// let progress = aoc_testing::RecordingProgress::default();
+/// progress.update(5, None);
+/// progress.update(10, Some(10));
+/// assert_eq!(progress.updates(), vec![(5, None), (10, Some(10))]);
+/// progress.assert_complete();
+/// ```
+#[derive(Debug, Default)]
+pub struct RecordingProgress(Mutex<Vec<(u64, Option<u64>)>>);
+
+impl RecordingProgress {
+    /// Updates received so far as `(done, total)`
+    pub fn updates(&self) -> Vec<(u64, Option<u64>)> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Assert the updates never went backwards and the last one reported 100%
+    /// 
+    /// # Panics
+    /// 
+    /// When there were no updates or any of the checks fails
+    pub fn assert_complete(&self) {
+        let updates = self.updates();
+        assert!(!updates.is_empty(), "no progress was reported");
+        assert!(updates.windows(2).all(|w| w[0].0 <= w[1].0), "progress went backwards: {:?}", updates);
+        let (done, total) = updates[updates.len() - 1];
+        assert_eq!(Some(done), total, "the last update is not 100%: {:?}", updates);
+    }
+}
+
+impl Progress for RecordingProgress {
+    fn update(&self, done: u64, total: Option<u64>) {
+        self.0.lock().unwrap().push((done, total));
+    }
+}
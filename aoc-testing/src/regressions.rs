@@ -0,0 +1,61 @@
+use std::{fs, panic::{catch_unwind, AssertUnwindSafe}, path::{Path, PathBuf}};
+
+/// Directory with the saved crashers of a fuzz target
+/// 
+/// # Examples
+/// ```
+/// let dir = aoc_testing::regressions_dir("day_1");
+/// assert!(dir.ends_with("fuzz/regressions/day_1"));
+/// ```
+pub fn regressions_dir(target: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("..")
+    .join("fuzz")
+    .join("regressions")
+    .join(target)
+}
+
+/// Run `check` on every file saved under `fuzz/regressions/<target>`
+/// 
+/// Files are replayed in name order and a panic is reported together with
+/// the file that caused it. A missing directory replays nothing.
+/// Returns the number of replayed files.
+/// 
+/// # Panics
+/// 
+/// When `check` panics on any of the files or a file cannot be read
+pub fn replay_regressions(target: &str, check: impl Fn(&[u8])) -> usize {
+    let dir = regressions_dir(target);
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return 0;
+    };
+    let mut files: Vec<PathBuf> = entries
+    .map(|entry| entry.expect("regression directory is readable").path())
+    .filter(|path| path.is_file())
+    .collect();
+    files.sort();
+
+    for file in &files {
+        let data = fs::read(file).unwrap_or_else(|e| panic!("could not read {}: {}", file.display(), e));
+        if catch_unwind(AssertUnwindSafe(|| check(&data))).is_err() {
+            panic!("fuzz regression {} panicked", file.display());
+        }
+    }
+    files.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_regressions_missing_dir() {
+        assert_eq!(replay_regressions("no_such_target", |_| panic!("nothing to replay")), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "fuzz regression")]
+    fn test_replay_regressions_negative() {
+        replay_regressions("day_1", |_| panic!("crash"));
+    }
+}
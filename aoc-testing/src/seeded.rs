@@ -0,0 +1,1754 @@
+use aoc_utils::Grid;
+
+/// Small xorshift generator, enough for reproducible test inputs
+/// 
+/// # Examples
+/// ```
+/// let mut a = aoc_testing::Rng::new(7);
+/// let mut b = aoc_testing::Rng::new(7);
+/// assert_eq!(a.next(), b.next());
+/// assert!((10..20).contains(&a.range(10, 20)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Generator starting from the seed (a zero seed is replaced by one)
+    pub fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    /// Next random number
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Random true or false
+    pub fn coin(&mut self) -> bool {
+        self.next() & 1 == 0
+    }
+
+    /// Random number in `low..high`
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next() % (high - low)
+    }
+
+    /// Shuffle the slice in place
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.range(0, i as u64 + 1) as usize;
+            values.swap(i, j);
+        }
+    }
+}
+
+/// Generated puzzle input with the answers of both parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    /// Puzzle input text
+    pub input: String,
+    /// Expected answer of the first part
+    pub part1: String,
+    /// Expected answer of the second part
+    pub part2: String,
+}
+
+/// Reference implementations used to compute the expected answers
+/// 
+/// They favour being obviously correct over being fast
+pub mod oracle {
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use aoc_utils::{Grid, Pos};
+
+    /// Day 1 total distance between the sorted lists
+    pub fn distance(left: &[i64], right: &[i64]) -> i64 {
+        let mut left = left.to_vec();
+        let mut right = right.to_vec();
+        left.sort();
+        right.sort();
+        left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum()
+    }
+
+    /// Day 1 similarity score of the lists
+    pub fn similarity(left: &[i64], right: &[i64]) -> i64 {
+        left.iter().map(|l| l * right.iter().filter(|&r| r == l).count() as i64).sum()
+    }
+
+    /// Day 2 report that is strictly increasing or decreasing by 1 to 3
+    pub fn is_safe(report: &[i32]) -> bool {
+        let increasing = report.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0])));
+        let decreasing = report.windows(2).all(|w| (1..=3).contains(&(w[0] - w[1])));
+        increasing || decreasing
+    }
+
+    /// Day 2 report that is safe after removing at most one level
+    pub fn is_safe_with_dampener(report: &[i32]) -> bool {
+        is_safe(report) || (0..report.len()).any(|skip| {
+            let shorter: Vec<i32> = report.iter().enumerate()
+            .filter(|&(i, _)| i != skip)
+            .map(|(_, &level)| level)
+            .collect();
+            is_safe(&shorter)
+        })
+    }
+
+    /// Day 4 number of `XMAS` words in any of the 8 directions
+    pub fn xmas_count(grid: &Grid<char>) -> usize {
+        const DIRECTIONS: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+        grid.iter()
+        .map(|(pos, _)| {
+            DIRECTIONS.iter()
+            .filter(|&&(dr, dc)| {
+                "XMAS".chars().enumerate().all(|(i, letter)| {
+                    grid.offset(pos, (dr * i as isize, dc * i as isize))
+                    .is_some_and(|(_, &c)| c == letter)
+                })
+            })
+            .count()
+        })
+        .sum()
+    }
+
+    /// Day 4 number of `MAS` words crossing in the shape of an X
+    pub fn x_mas_count(grid: &Grid<char>) -> usize {
+        let letter = |pos, d| grid.offset(pos, d).map(|(_, &c)| c);
+        let is_mas = |pos, a, b| matches!((letter(pos, a), letter(pos, b)), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+        grid.iter()
+        .filter(|&(pos, &c)| c == 'A' && is_mas(pos, (-1, -1), (1, 1)) && is_mas(pos, (-1, 1), (1, -1)))
+        .count()
+    }
+
+    /// Day 6 number of distinct positions the guard visits before leaving the map,
+    /// `None` when the guard walks in a loop
+    /// 
+    /// # Panics
+    /// 
+    /// When the map has no guard
+    pub fn patrol(grid: &Grid<char>) -> Option<usize> {
+        walk(grid, guard(grid), None).map(|visited| visited.len())
+    }
+
+    /// Day 6 number of empty cells where a new obstruction traps the guard in a loop
+    /// 
+    /// Only the cells of the original patrol are tried, an obstruction
+    /// anywhere else is never reached and leaves the patrol as it is
+    /// 
+    /// # Panics
+    /// 
+    /// When the map has no guard or the guard already walks in a loop
+    pub fn loop_obstructions(grid: &Grid<char>) -> usize {
+        let start = guard(grid);
+        walk(grid, start, None).expect("guard leaves the map")
+        .into_iter()
+        .filter(|&pos| grid[pos] == '.' && walk(grid, start, Some(pos)).is_none())
+        .count()
+    }
+
+    const GUARDS: [char; 4] = ['^', '>', 'v', '<'];
+
+    /// Position of the guard and the index of its facing in [`GUARDS`]
+    fn guard(grid: &Grid<char>) -> (Pos, usize) {
+        grid.iter()
+        .find_map(|(pos, c)| GUARDS.iter().position(|guard| guard == c).map(|facing| (pos, facing)))
+        .expect("map has a guard")
+    }
+
+    /// Day 7 equation whose operands make the target with the given operators
+    /// evaluated left to right, trying every combination of them
+    pub fn calibrates(target: u64, operands: &[u64], concat: bool) -> bool {
+        let operators: u32 = if concat { 3 } else { 2 };
+        let Some((&first, rest)) = operands.split_first() else {
+            return false;
+        };
+        (0..operators.pow(rest.len() as u32)).any(|mut choice: u32| {
+            let value = rest.iter().fold(first as u128, |value, &operand| {
+                let operator = choice % operators;
+                choice /= operators;
+                apply(value, operator, operand)
+            });
+            value == target as u128
+        })
+    }
+
+    /// Day 7 operator `0` (`+`), `1` (`*`) or `2` (`||`) applied to the value and the operand
+    /// 
+    /// `u128` is wide enough for the generated equations
+    pub(crate) fn apply(value: u128, operator: u32, operand: u64) -> u128 {
+        match operator {
+            0 => value + operand as u128,
+            1 => value * operand as u128,
+            _ => format!("{}{}", value, operand).parse().expect("concatenation fits u128"),
+        }
+    }
+
+    /// Day 8 pairs of antennas with the same frequency, as signed row and column positions
+    fn antenna_pairs(grid: &Grid<char>) -> Vec<((isize, isize), (isize, isize))> {
+        let antennas: Vec<(Pos, char)> = grid.iter().filter(|(_, c)| c.is_ascii_alphanumeric()).map(|(pos, &c)| (pos, c)).collect();
+        let signed = |(row, col): Pos| (row as isize, col as isize);
+        antennas.iter().enumerate()
+        .flat_map(|(i, &(a, frequency))| {
+            antennas[i + 1..].iter()
+            .filter(move |&&(_, other)| other == frequency)
+            .map(move |&(b, _)| (signed(a), signed(b)))
+        })
+        .collect()
+    }
+
+    /// Day 8 number of positions twice as far from one antenna as from another of the same frequency
+    pub fn antinodes(grid: &Grid<char>) -> usize {
+        let inside = |(row, col): (isize, isize)| row >= 0 && col >= 0 && grid.contains(row as usize, col as usize);
+        let mut found = HashSet::new();
+        for ((ar, ac), (br, bc)) in antenna_pairs(grid) {
+            found.extend([(2 * br - ar, 2 * bc - ac), (2 * ar - br, 2 * ac - bc)].into_iter().filter(|&pos| inside(pos)));
+        }
+        found.len()
+    }
+
+    /// Day 8 number of positions in line with two antennas of the same frequency,
+    /// found by checking every column (or every row of a vertical line) of each pair
+    pub fn resonant_antinodes(grid: &Grid<char>) -> usize {
+        let mut found = HashSet::new();
+        for ((ar, ac), (br, bc)) in antenna_pairs(grid) {
+            if ac == bc {
+                found.extend((0..grid.height()).map(|row| (row, ac as usize)));
+                continue;
+            }
+            for col in 0..grid.width() as isize {
+                let rise = (col - ac) * (br - ar);
+                let row = ar + rise / (bc - ac);
+                if rise % (bc - ac) == 0 && row >= 0 && (row as usize) < grid.height() {
+                    found.insert((row as usize, col as usize));
+                }
+            }
+        }
+        found.len()
+    }
+
+    /// Day 9 disk blocks of the dense disk map, the id of the file or `None` for free space
+    fn disk_blocks(map: &str) -> Vec<Option<usize>> {
+        map.trim_end().bytes().enumerate()
+        .flat_map(|(i, digit)| {
+            let block = if i % 2 == 0 { Some(i / 2) } else { None };
+            std::iter::repeat_n(block, (digit - b'0') as usize)
+        })
+        .collect()
+    }
+
+    /// Day 9 sum of the block positions times the file ids
+    fn disk_checksum(blocks: &[Option<usize>]) -> u64 {
+        blocks.iter().enumerate().map(|(pos, block)| (pos * block.unwrap_or(0)) as u64).sum()
+    }
+
+    /// Day 9 checksum after moving the last file block into the leftmost free block
+    /// until there are no gaps
+    pub fn block_compacted_checksum(map: &str) -> u64 {
+        let mut blocks = disk_blocks(map);
+        while let Some(last) = blocks.iter().rposition(Option::is_some) {
+            match blocks[..last].iter().position(Option::is_none) {
+                Some(free) => blocks.swap(free, last),
+                None => break,
+            }
+        }
+        disk_checksum(&blocks)
+    }
+
+    /// Day 9 checksum after trying once to move every whole file, highest id first,
+    /// into the leftmost free span before it that fits
+    pub fn file_compacted_checksum(map: &str) -> u64 {
+        let mut blocks = disk_blocks(map);
+        let files = map.trim_end().len().div_ceil(2);
+        for id in (0..files).rev() {
+            let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+                continue;
+            };
+            let len = blocks[start..].iter().take_while(|&&block| block == Some(id)).count();
+            let free = (0..start).find(|&free| free + len <= start && blocks[free..free + len].iter().all(Option::is_none));
+            if let Some(free) = free {
+                for i in 0..len {
+                    blocks.swap(free + i, start + i);
+                }
+            }
+        }
+        disk_checksum(&blocks)
+    }
+
+    /// Day 10 ends of every hiking trail, a path from a `0` up to a `9` one height at a time
+    fn trail_ends(grid: &Grid<char>) -> Vec<(Pos, Pos)> {
+        let height = |pos: Pos| grid[pos].to_digit(10);
+        let mut ends = Vec::new();
+        let mut paths: Vec<(Pos, Pos)> = grid.iter().filter(|(_, &c)| c == '0').map(|(pos, _)| (pos, pos)).collect();
+        while let Some((start, pos)) = paths.pop() {
+            if height(pos) == Some(9) {
+                ends.push((start, pos));
+                continue;
+            }
+            let next = height(pos).map(|h| h + 1);
+            paths.extend(grid.neighbors4(pos).filter(|&n| height(n) == next).map(|n| (start, n)));
+        }
+        ends
+    }
+
+    /// Day 10 sum over the trailheads of the `9`s reachable from them
+    pub fn trailhead_scores(grid: &Grid<char>) -> usize {
+        trail_ends(grid).into_iter().collect::<HashSet<_>>().len()
+    }
+
+    /// Day 10 number of distinct hiking trails
+    pub fn trailhead_ratings(grid: &Grid<char>) -> usize {
+        trail_ends(grid).len()
+    }
+
+    /// Day 11 stones a single stone turns into after `blinks` blinks, remembered in `memo`
+    fn stone_count(stone: u64, blinks: usize, memo: &mut HashMap<(u64, usize), u64>) -> u64 {
+        if blinks == 0 {
+            return 1;
+        }
+        if let Some(&count) = memo.get(&(stone, blinks)) {
+            return count;
+        }
+        let digits = stone.to_string();
+        let count = if stone == 0 {
+            stone_count(1, blinks - 1, memo)
+        } else if digits.len().is_multiple_of(2) {
+            let (left, right) = digits.split_at(digits.len() / 2);
+            stone_count(left.parse().unwrap(), blinks - 1, memo) + stone_count(right.parse().unwrap(), blinks - 1, memo)
+        } else {
+            stone_count(stone * 2024, blinks - 1, memo)
+        };
+        memo.insert((stone, blinks), count);
+        count
+    }
+
+    /// Day 11 number of stones after `blinks` blinks
+    pub fn stones_after(stones: &[u64], blinks: usize) -> u64 {
+        let mut memo = HashMap::new();
+        stones.iter().map(|&stone| stone_count(stone, blinks, &mut memo)).sum()
+    }
+
+    /// Day 12 region of every plot, found by lowering the labels of neighbouring
+    /// plots with the same plant until none changes
+    fn garden_labels(grid: &Grid<char>) -> Grid<usize> {
+        let mut labels = Grid::from_rows((0..grid.height()).map(|row| (0..grid.width()).map(|col| row * grid.width() + col).collect::<Vec<_>>()))
+        .expect("rows have the same length");
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (pos, &plant) in grid.iter() {
+                for next in grid.neighbors4(pos) {
+                    if grid[next] == plant && labels[next] < labels[pos] {
+                        labels[pos] = labels[next];
+                        changed = true;
+                    }
+                }
+            }
+        }
+        labels
+    }
+
+    /// Day 12 total price of the fences, the area of every region times its
+    /// perimeter or, with `bulk`, times the number of its straight sides
+    /// 
+    /// A side is counted where its fence starts: the plot to the left of a
+    /// fence above or below, or above a fence on the left or right, does not
+    /// have the same fence
+    pub fn fence_price(grid: &Grid<char>, bulk: bool) -> usize {
+        const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        let labels = garden_labels(grid);
+        let fenced = |pos: Pos, step: (isize, isize)| {
+            grid.offset(pos, step).is_none_or(|(next, _)| labels[next] != labels[pos])
+        };
+        let mut area: HashMap<usize, usize> = HashMap::new();
+        let mut fences: HashMap<usize, usize> = HashMap::new();
+        for (pos, &label) in labels.iter() {
+            *area.entry(label).or_default() += 1;
+            for step in STEPS {
+                if !fenced(pos, step) {
+                    continue;
+                }
+                let before = if step.0 == 0 { (-1, 0) } else { (0, -1) };
+                let continued = grid.offset(pos, before)
+                .is_some_and(|(prev, _)| labels[prev] == label && fenced(prev, step));
+                if !bulk || !continued {
+                    *fences.entry(label).or_default() += 1;
+                }
+            }
+        }
+        area.iter().map(|(label, area)| area * fences.get(label).copied().unwrap_or(0)).sum()
+    }
+
+    /// Day 13 fewest tokens to win the prize pressing each button at most 100 times,
+    /// trying every number of presses
+    pub fn claw_tokens(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Option<i64> {
+        (0..=100).flat_map(|presses_a| (0..=100).map(move |presses_b| (presses_a, presses_b)))
+        .filter(|&(pa, pb)| (pa * a.0 + pb * b.0, pa * a.1 + pb * b.1) == prize)
+        .map(|(pa, pb)| 3 * pa + pb)
+        .min()
+    }
+
+    /// Day 13 tokens to win the prize with Cramer's rule, for buttons that are not collinear
+    pub fn claw_tokens_exact(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Option<i64> {
+        let det = a.0 as i128 * b.1 as i128 - a.1 as i128 * b.0 as i128;
+        let presses_a = prize.0 as i128 * b.1 as i128 - prize.1 as i128 * b.0 as i128;
+        let presses_b = a.0 as i128 * prize.1 as i128 - a.1 as i128 * prize.0 as i128;
+        if presses_a % det != 0 || presses_b % det != 0 || presses_a / det < 0 || presses_b / det < 0 {
+            return None;
+        }
+        i64::try_from(3 * presses_a / det + presses_b / det).ok()
+    }
+
+    /// Day 14 robots `(x, y, vx, vy)` after `steps` steps on a `width` x `height` floor,
+    /// moving every robot one step at a time
+    fn robots_after(robots: &[(i64, i64, i64, i64)], steps: usize, width: i64, height: i64) -> Vec<(i64, i64)> {
+        let mut positions: Vec<(i64, i64)> = robots.iter().map(|&(x, y, _, _)| (x, y)).collect();
+        for _ in 0..steps {
+            for (position, &(_, _, vx, vy)) in positions.iter_mut().zip(robots) {
+                *position = ((position.0 + vx).rem_euclid(width), (position.1 + vy).rem_euclid(height));
+            }
+        }
+        positions
+    }
+
+    /// Day 14 product of the robots in every quadrant after 100 steps
+    pub fn safety_factor(robots: &[(i64, i64, i64, i64)], width: i64, height: i64) -> u64 {
+        let mut quadrants = [0u64; 4];
+        for (x, y) in robots_after(robots, 100, width, height) {
+            if x != width / 2 && y != height / 2 {
+                quadrants[usize::from(x > width / 2) + 2 * usize::from(y > height / 2)] += 1;
+            }
+        }
+        quadrants.iter().product()
+    }
+
+    /// Day 14 first step with the most robots next to another robot, trying
+    /// every step until the robots are back where they started
+    pub fn easter_egg(robots: &[(i64, i64, i64, i64)], width: i64, height: i64) -> usize {
+        let mut positions: Vec<(i64, i64)> = robots.iter().map(|&(x, y, _, _)| (x, y)).collect();
+        let mut occupied = vec![vec![false; width as usize]; height as usize];
+        let mut best = (0, 0);
+        for step in 0..(width * height) as usize {
+            for &(x, y) in &positions {
+                occupied[y as usize][x as usize] = true;
+            }
+            let taken = |x: i64, y: i64| (0..width).contains(&x) && (0..height).contains(&y) && occupied[y as usize][x as usize];
+            let neighboured = positions.iter()
+            .filter(|&&(x, y)| taken(x - 1, y) || taken(x + 1, y) || taken(x, y - 1) || taken(x, y + 1))
+            .count();
+            for &(x, y) in &positions {
+                occupied[y as usize][x as usize] = false;
+            }
+            if neighboured > best.1 {
+                best = (step, neighboured);
+            }
+            positions = positions.iter().zip(robots)
+            .map(|(&(x, y), &(_, _, vx, vy))| ((x + vx).rem_euclid(width), (y + vy).rem_euclid(height)))
+            .collect();
+        }
+        best.0
+    }
+
+    /// Day 15 sum of the GPS coordinates of the boxes after the robot follows
+    /// the `moves`, on the map made twice as wide when `wide`
+    pub fn warehouse_gps(grid: &Grid<char>, moves: &str, wide: bool) -> usize {
+        let mut cells: Vec<Vec<char>> = grid.rows()
+        .map(|row| {
+            if !wide {
+                return row.to_vec();
+            }
+            row.iter().flat_map(|&c| match c {
+                '#' => ['#', '#'],
+                'O' => ['[', ']'],
+                '@' => ['@', '.'],
+                _ => ['.', '.'],
+            })
+            .collect()
+        })
+        .collect();
+        let Some(mut robot) = cells.iter().enumerate()
+        .find_map(|(row, cells)| cells.iter().position(|&c| c == '@').map(|col| (row, col))) else {
+            return 0;
+        };
+        for c in moves.chars() {
+            let step = match c {
+                '^' => (-1, 0),
+                '>' => (0, 1),
+                'v' => (1, 0),
+                '<' => (0, -1),
+                _ => continue,
+            };
+            if can_push(&cells, robot, step) {
+                push(&mut cells, robot, step);
+                robot = (robot.0.wrapping_add_signed(step.0), robot.1.wrapping_add_signed(step.1));
+            }
+        }
+        cells.iter().enumerate()
+        .flat_map(|(row, cells)| cells.iter().enumerate().filter(|(_, &c)| c == 'O' || c == '[').map(move |(col, _)| 100 * row + col))
+        .sum()
+    }
+
+    /// Whether the robot or box half on `pos` can move a step, pushing whatever is in its way
+    fn can_push(cells: &[Vec<char>], (row, col): (usize, usize), step: (isize, isize)) -> bool {
+        let next = (row.wrapping_add_signed(step.0), col.wrapping_add_signed(step.1));
+        let vertical = step.0 != 0;
+        match cells.get(next.0).and_then(|cells| cells.get(next.1)) {
+            None | Some('#') => false,
+            Some('[') if vertical => can_push(cells, next, step) && can_push(cells, (next.0, next.1 + 1), step),
+            Some(']') if vertical => can_push(cells, next, step) && can_push(cells, (next.0, next.1 - 1), step),
+            Some('O' | '[' | ']') => can_push(cells, next, step),
+            _ => true,
+        }
+    }
+
+    /// Move the robot or box half on `pos` a step, pushing whatever is in its way first
+    fn push(cells: &mut [Vec<char>], (row, col): (usize, usize), step: (isize, isize)) {
+        let next = (row.wrapping_add_signed(step.0), col.wrapping_add_signed(step.1));
+        let vertical = step.0 != 0;
+        match cells[next.0][next.1] {
+            '[' if vertical => {
+                push(cells, next, step);
+                push(cells, (next.0, next.1 + 1), step);
+            },
+            ']' if vertical => {
+                push(cells, next, step);
+                push(cells, (next.0, next.1 - 1), step);
+            },
+            'O' | '[' | ']' => push(cells, next, step),
+            _ => (),
+        }
+        cells[next.0][next.1] = cells[row][col];
+        cells[row][col] = '.';
+    }
+
+    /// Day 16 lowest score from `S` facing east to `E` and the number of tiles on a path with that score
+    pub fn reindeer_paths(grid: &Grid<char>) -> Option<(u64, usize)> {
+        let start = grid.find(|&c| c == 'S')?;
+        let end = grid.find(|&c| c == 'E')?;
+        let ends: Vec<(Pos, usize)> = (0..4).map(|facing| (end, facing)).collect();
+        let from_start = reindeer_costs(grid, &[(start, 1)], 1);
+        let to_end = reindeer_costs(grid, &ends, -1);
+        let best = ends.iter().filter_map(|state| from_start.get(state)).min()?;
+        let tiles: HashSet<Pos> = from_start.iter()
+        .filter(|(state, cost)| to_end.get(state).is_some_and(|rest| *cost + rest == *best))
+        .map(|((pos, _), _)| *pos)
+        .collect();
+        Some((*best, tiles.len()))
+    }
+
+    /// Day 16 lowest cost of every state reachable from the `starts`, relaxed
+    /// until nothing changes, stepping backwards when `sign` is -1
+    fn reindeer_costs(grid: &Grid<char>, starts: &[(Pos, usize)], sign: isize) -> HashMap<(Pos, usize), u64> {
+        const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        let mut costs: HashMap<(Pos, usize), u64> = starts.iter().map(|&state| (state, 0)).collect();
+        let mut queue: VecDeque<(Pos, usize)> = starts.iter().copied().collect();
+        while let Some((pos, facing)) = queue.pop_front() {
+            let cost = costs[&(pos, facing)];
+            let (d_row, d_col) = STEPS[facing];
+            let forward = grid.offset(pos, (sign * d_row, sign * d_col))
+            .filter(|(_, &c)| c != '#')
+            .map(|(next, _)| ((next, facing), cost + 1));
+            let turns = [(facing + 1) % 4, (facing + 3) % 4].map(|turned| ((pos, turned), cost + 1000));
+            for (state, cost) in forward.into_iter().chain(turns) {
+                if costs.get(&state).is_none_or(|&known| cost < known) {
+                    costs.insert(state, cost);
+                    queue.push_back(state);
+                }
+            }
+        }
+        costs
+    }
+
+    /// Day 17 values the program outputs when run from the registers `a`, `b` and `c`
+    pub fn chronospatial_output(mut a: u64, mut b: u64, mut c: u64, program: &[u8]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut ip = 0;
+        while ip + 1 < program.len() {
+            let literal = u64::from(program[ip + 1]);
+            let combo = match literal {
+                4 => a,
+                5 => b,
+                6 => c,
+                _ => literal,
+            };
+            // a power of two past u64 is larger than any register
+            let divide = |a: u64| 2u64.checked_pow(u32::try_from(combo).unwrap_or(u32::MAX)).map_or(0, |power| a / power);
+            match program[ip] {
+                0 => a = divide(a),
+                1 => b ^= literal,
+                2 => b = combo % 8,
+                3 if a != 0 => {
+                    ip = literal as usize;
+                    continue;
+                },
+                3 => (),
+                4 => b ^= c,
+                5 => output.push((combo % 8) as u8),
+                6 => b = divide(a),
+                _ => c = divide(a),
+            }
+            ip += 2;
+        }
+        output
+    }
+
+    /// Day 17 lowest A making the program output itself with B and C zero,
+    /// keeping every A that outputs the last values one 3-bit digit at a time
+    pub fn quine_a(program: &[u8]) -> Option<u64> {
+        let mut candidates = vec![0u64];
+        for start in (0..program.len()).rev() {
+            candidates = candidates.iter()
+            .flat_map(|&a| (0..8).map(move |digit| a * 8 + digit))
+            .filter(|&a| chronospatial_output(a, 0, 0, program) == program[start..])
+            .collect();
+        }
+        candidates.into_iter().min()
+    }
+
+    /// Day 18 fewest steps from the top left to the bottom right corner of a
+    /// `size` x `size` memory space with the `bytes` (x, y) corrupted
+    pub fn ram_run(bytes: &[(usize, usize)], size: usize) -> Option<usize> {
+        let mut corrupted = Grid::new(size, size, false);
+        for &(x, y) in bytes.iter().filter(|&&(x, y)| x < size && y < size) {
+            corrupted[(y, x)] = true;
+        }
+        let mut steps = Grid::new(size, size, None);
+        let mut queue = VecDeque::new();
+        if size > 0 && !corrupted[(0, 0)] {
+            steps[(0, 0)] = Some(0);
+            queue.push_back((0, 0));
+        }
+        while let Some(pos) = queue.pop_front() {
+            let here = steps[pos]?;
+            if pos == (size - 1, size - 1) {
+                return Some(here);
+            }
+            for next in corrupted.neighbors4(pos) {
+                if !corrupted[next] && steps[next].is_none() {
+                    steps[next] = Some(here + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Day 18 first byte after which the exit cannot be reached, clearing the
+    /// bytes from the last one until the corners join up again
+    pub fn first_blocking(bytes: &[(usize, usize)], size: usize) -> Option<(usize, usize)> {
+        fn root(parents: &mut [usize], mut cell: usize) -> usize {
+            while parents[cell] != cell {
+                parents[cell] = parents[parents[cell]];
+                cell = parents[cell];
+            }
+            cell
+        }
+        let cell = |(x, y): (usize, usize)| (x < size && y < size).then_some(y * size + x);
+        let mut parents: Vec<usize> = (0..size * size).collect();
+        let mut falls = vec![0usize; size * size];
+        for cell in bytes.iter().filter_map(|&byte| cell(byte)) {
+            falls[cell] += 1;
+        }
+        let (start, exit) = (cell((0, 0))?, size * size - 1);
+        let join = |parents: &mut Vec<usize>, falls: &[usize], cell: usize| {
+            let (x, y) = (cell % size, cell / size);
+            for (nx, ny) in [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)] {
+                if nx < size && ny < size && falls[ny * size + nx] == 0 {
+                    let (a, b) = (root(parents, cell), root(parents, ny * size + nx));
+                    parents[a] = b;
+                }
+            }
+        };
+        let joined = |parents: &mut Vec<usize>, falls: &[usize]| {
+            falls[start] == 0 && falls[exit] == 0 && root(parents, start) == root(parents, exit)
+        };
+        for free in (0..size * size).filter(|&cell| falls[cell] == 0) {
+            join(&mut parents, &falls, free);
+        }
+        if joined(&mut parents, &falls) {
+            return None;
+        }
+        for &byte in bytes.iter().rev() {
+            let Some(cell) = cell(byte) else {
+                continue;
+            };
+            falls[cell] -= 1;
+            if falls[cell] == 0 {
+                join(&mut parents, &falls, cell);
+            }
+            if joined(&mut parents, &falls) {
+                return Some(byte);
+            }
+        }
+        None
+    }
+
+    /// Day 19 ways to arrange the `design` out of the towel `patterns`, matching every pattern at every position
+    pub fn towel_arrangements(patterns: &[&str], design: &str) -> u64 {
+        let mut ways = vec![0u64; design.len() + 1];
+        ways[design.len()] = 1;
+        for start in (0..design.len()).rev() {
+            ways[start] = patterns.iter()
+            .filter(|pattern| !pattern.is_empty() && design[start..].starts_with(*pattern))
+            .map(|pattern| ways[start + pattern.len()])
+            .sum();
+        }
+        ways[0]
+    }
+
+    /// Day 20 cheats of at most `max_cheat` steps through the walls saving at least
+    /// `min_saving` picoseconds, trying every pair of track tiles
+    pub fn race_cheats(grid: &Grid<char>, max_cheat: usize, min_saving: usize) -> usize {
+        let distances = |from: Pos| {
+            let mut steps = Grid::new(grid.height(), grid.width(), None);
+            steps[from] = Some(0);
+            let mut queue = VecDeque::from([from]);
+            while let Some(pos) = queue.pop_front() {
+                for next in grid.neighbors4(pos) {
+                    if grid[next] != '#' && steps[next].is_none() {
+                        steps[next] = Some(steps[pos].unwrap_or(0) + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            steps
+        };
+        let (Some(start), Some(end)) = (grid.find(|&tile| tile == 'S'), grid.find(|&tile| tile == 'E')) else {
+            return 0;
+        };
+        let (from_start, to_end) = (distances(start), distances(end));
+        let Some(fair) = from_start[end] else {
+            return 0;
+        };
+        let track: Vec<Pos> = grid.iter().filter(|(_, &tile)| tile != '#').map(|(pos, _)| pos).collect();
+        let mut count = 0;
+        for &from in &track {
+            for &to in &track {
+                let cheat = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+                if let (Some(ran), Some(left)) = (from_start[from], to_end[to]) {
+                    if cheat <= max_cheat && ran + cheat + left + min_saving.max(1) <= fair {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    /// Day 21 fewest presses on the human keypad typing the `code` on the door through
+    /// `robots` robots on directional keypads, trying every order of the moves
+    pub fn keypad_presses(code: &str, robots: usize) -> u64 {
+        const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+        const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+        fn position(keypad: &[&str], key: char) -> (usize, usize) {
+            keypad.iter().enumerate().find_map(|(row, keys)| keys.find(key).map(|col| (row, col))).expect("a key of the keypad")
+        }
+        /// Every order of the moves from `from` to `to` never pointing at the gap
+        fn orders(keypad: &[&str], (row, col): (usize, usize), to: (usize, usize), moves: &mut String, found: &mut Vec<String>) {
+            if keypad[row].as_bytes()[col] == b' ' {
+                return;
+            }
+            if (row, col) == to {
+                found.push(format!("{moves}A"));
+                return;
+            }
+            let steps = [(to.0 < row, '^', (row.wrapping_sub(1), col)), (to.0 > row, 'v', (row + 1, col)),
+                (to.1 < col, '<', (row, col.wrapping_sub(1))), (to.1 > col, '>', (row, col + 1))];
+            for (towards, arrow, next) in steps {
+                if towards {
+                    moves.push(arrow);
+                    orders(keypad, next, to, moves, found);
+                    moves.pop();
+                }
+            }
+        }
+        fn presses(keys: &str, keypad: &[&str], depth: usize, memo: &mut HashMap<(String, usize), u64>) -> u64 {
+            if depth == 0 {
+                return keys.len() as u64;
+            }
+            if let Some(&known) = memo.get(&(keys.to_string(), depth)) {
+                return known;
+            }
+            let mut from = position(keypad, 'A');
+            let mut total = 0;
+            for key in keys.chars() {
+                let to = position(keypad, key);
+                let mut found = Vec::new();
+                orders(keypad, from, to, &mut String::new(), &mut found);
+                total += found.iter().map(|moves| presses(moves, &DIRECTIONAL, depth - 1, memo)).min().unwrap_or(0);
+                from = to;
+            }
+            memo.insert((keys.to_string(), depth), total);
+            total
+        }
+        // the numeric keypad is only ever at the top, the depth tells the keypads apart
+        presses(code, &NUMERIC, robots + 1, &mut HashMap::new())
+    }
+
+    /// Day 22 secret number following `secret`, the mix and prune steps spelled out
+    pub fn next_secret(secret: u64) -> u64 {
+        let mix = |secret: u64, value: u64| secret ^ value;
+        let prune = |secret: u64| secret % 16777216;
+        let secret = prune(secret);
+        let secret = prune(mix(secret, secret * 64));
+        let secret = prune(mix(secret, secret / 32));
+        prune(mix(secret, secret * 2048))
+    }
+
+    /// Day 22 most bananas a sequence of four price changes earns, every buyer selling
+    /// at its first occurrence, keeping the sequences of each buyer in a map
+    pub fn monkey_bananas(secrets: &[u64]) -> u64 {
+        let mut totals: HashMap<Vec<i64>, u64> = HashMap::new();
+        for &first in secrets {
+            let mut prices = vec![first % 10];
+            let mut secret = first;
+            for _ in 0..2000 {
+                secret = next_secret(secret);
+                prices.push(secret % 10);
+            }
+            let changes: Vec<i64> = prices.windows(2).map(|pair| pair[1] as i64 - pair[0] as i64).collect();
+            let mut sold = HashSet::new();
+            for (i, sequence) in changes.windows(4).enumerate() {
+                if sold.insert(sequence.to_vec()) {
+                    *totals.entry(sequence.to_vec()).or_default() += prices[i + 4];
+                }
+            }
+        }
+        totals.values().copied().max().unwrap_or(0)
+    }
+
+    /// Day 23 triangles of connected computers with a name starting with `prefix`,
+    /// trying every computer as the third one of every connection
+    pub fn lan_triangles(connections: &[(String, String)], prefix: &str) -> usize {
+        let linked: HashSet<(&str, &str)> = connections.iter()
+        .flat_map(|(a, b)| [(a.as_str(), b.as_str()), (b.as_str(), a.as_str())])
+        .collect();
+        let names: HashSet<&str> = linked.iter().map(|&(a, _)| a).collect();
+        let mut count = 0;
+        for &(a, b) in linked.iter().filter(|(a, b)| a < b) {
+            for &c in names.iter().filter(|&&c| c > b) {
+                if linked.contains(&(a, c)) && linked.contains(&(b, c)) && [a, b, c].iter().any(|name| name.starts_with(prefix)) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Day 23 sorted names of the largest clique joined by commas, listing every maximal
+    /// clique with plain Bron–Kerbosch and keeping the smallest password on a tie
+    pub fn lan_password(connections: &[(String, String)]) -> String {
+        fn extend<'a>(links: &HashMap<&'a str, HashSet<&'a str>>, clique: &mut Vec<&'a str>, mut candidates: HashSet<&'a str>,
+            mut excluded: HashSet<&'a str>, cliques: &mut Vec<String>) {
+            if candidates.is_empty() && excluded.is_empty() {
+                let mut names = clique.clone();
+                names.sort_unstable();
+                cliques.push(names.join(","));
+            }
+            for v in candidates.clone() {
+                clique.push(v);
+                extend(links, clique, &candidates & &links[v], &excluded & &links[v], cliques);
+                clique.pop();
+                candidates.remove(v);
+                excluded.insert(v);
+            }
+        }
+        let mut links: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for (a, b) in connections {
+            links.entry(a).or_default().insert(b);
+            links.entry(b).or_default().insert(a);
+        }
+        let mut cliques = Vec::new();
+        extend(&links, &mut Vec::new(), links.keys().copied().collect(), HashSet::new(), &mut cliques);
+        cliques.into_iter().min_by(|a, b| b.split(',').count().cmp(&a.split(',').count()).then(a.cmp(b))).unwrap_or_default()
+    }
+
+    /// Day 24 number on the `z` wires, going over the gates again and again until
+    /// every output has a value, `(left, op, right, output)` for every gate
+    pub fn circuit_output(wires: &[(String, bool)], gates: &[(String, String, String, String)]) -> u64 {
+        let mut values: HashMap<&str, bool> = wires.iter().map(|(wire, value)| (wire.as_str(), *value)).collect();
+        while values.len() < wires.len() + gates.len() {
+            for (left, op, right, output) in gates {
+                if let (Some(&a), Some(&b)) = (values.get(left.as_str()), values.get(right.as_str())) {
+                    let value = match op.as_str() {
+                        "AND" => a && b,
+                        "OR" => a || b,
+                        _ => a != b,
+                    };
+                    values.insert(output, value);
+                }
+            }
+        }
+        values.iter().filter(|(wire, &value)| wire.starts_with('z') && value)
+        .map(|(wire, _)| 1 << wire[1..].parse::<u64>().expect("z and a bit number"))
+        .sum()
+    }
+
+    /// Day 25 lock and key pairs with no column adding up to more than 5
+    pub fn fitting_locks(locks: &[[u8; 5]], keys: &[[u8; 5]]) -> usize {
+        let mut pairs = 0;
+        for lock in locks {
+            for key in keys {
+                if (0..5).all(|column| lock[column] + key[column] <= 5) {
+                    pairs += 1;
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Positions visited by the guard with an optional extra obstruction, `None` for a loop
+    fn walk(grid: &Grid<char>, (mut pos, mut facing): (Pos, usize), extra: Option<Pos>) -> Option<HashSet<Pos>> {
+        const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        let mut states = HashSet::new();
+        while states.insert((pos, facing)) {
+            match grid.offset(pos, STEPS[facing]) {
+                None => return Some(states.into_iter().map(|(pos, _)| pos).collect()),
+                Some((next, &c)) if c == '#' || Some(next) == extra => facing = (facing + 1) % 4,
+                Some((next, _)) => pos = next,
+            }
+        }
+        None
+    }
+}
+
+/// The text as saved by a Windows editor, with a byte order mark and `\r\n` line endings
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc_testing::crlf_bom("1   2\n"), "\u{feff}1   2\r\n");
+/// ```
+pub fn crlf_bom(text: &str) -> String {
+    format!("\u{feff}{}", text.replace('\n', "\r\n"))
+}
+
+/// Day 1 input with `size` lines of two five digit numbers
+/// 
+/// # Examples
+/// ```
+/// let generated = aoc_testing::day1(1, 100);
+/// assert_eq!(generated.input.lines().count(), 100);
+/// assert_eq!(generated, aoc_testing::day1(1, 100));
+/// ```
+pub fn day1(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    // a small range on the right so the similarity score is not always zero
+    let base = rng.range(10000, 90000) as i64;
+    let left: Vec<i64> = (0..size).map(|_| base + rng.range(0, 500) as i64).collect();
+    let right: Vec<i64> = (0..size).map(|_| base + rng.range(0, 500) as i64).collect();
+    let input = left.iter().zip(&right).map(|(l, r)| format!("{}   {}\n", l, r)).collect();
+
+    Generated {
+        input,
+        part1: oracle::distance(&left, &right).to_string(),
+        part2: oracle::similarity(&left, &right).to_string(),
+    }
+}
+
+/// Day 2 input with `size` reports of 5 to 8 levels
+pub fn day2(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut safe = 0;
+    let mut safe_with_dampener = 0;
+    for _ in 0..size {
+        let len = rng.range(5, 9);
+        let mut level = rng.range(20, 80) as i32;
+        let ascending = rng.coin();
+        let mut report = Vec::new();
+        for _ in 0..len {
+            report.push(level);
+            // mostly safe steps, with the occasional plateau or jump
+            let step = match rng.range(0, 10) {
+                0 => 0,
+                1 => rng.range(4, 8) as i32,
+                _ => rng.range(1, 4) as i32,
+            };
+            level += if ascending { step } else { -step };
+        }
+        safe += usize::from(oracle::is_safe(&report));
+        safe_with_dampener += usize::from(oracle::is_safe_with_dampener(&report));
+        let levels: Vec<String> = report.iter().map(|level| level.to_string()).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+
+    Generated { input, part1: safe.to_string(), part2: safe_with_dampener.to_string() }
+}
+
+/// Day 3 input with `size` instructions mixed with corrupted noise
+pub fn day3(seed: u64, size: usize) -> Generated {
+    const NOISE: [&str; 8] = ["xmul(2,4]", "&mul[3,7]!", "^", "_", "+mul(32,64]", "select()", "mul ( 2 , 4 )", "why()"];
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut enabled = true;
+    let mut all = 0u64;
+    let mut only_enabled = 0u64;
+    for i in 0..size {
+        match rng.range(0, 10) {
+            0 => {
+                input.push_str("do()");
+                enabled = true;
+            },
+            1 => {
+                input.push_str("don't()");
+                enabled = false;
+            },
+            2..=3 => input.push_str(NOISE[rng.range(0, NOISE.len() as u64) as usize]),
+            _ => {
+                let (a, b) = (rng.range(1, 1000), rng.range(1, 1000));
+                input.push_str(&format!("mul({},{})", a, b));
+                all += a * b;
+                if enabled {
+                    only_enabled += a * b;
+                }
+            },
+        }
+        if i % 60 == 59 {
+            input.push('\n');
+        }
+    }
+
+    Generated { input, part1: all.to_string(), part2: only_enabled.to_string() }
+}
+
+/// Day 4 input, a `size` x `size` grid of `XMAS` letters
+pub fn day4(seed: u64, size: usize) -> Generated {
+    const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+    let mut rng = Rng::new(seed);
+    let grid = Grid::from_rows((0..size).map(|_| {
+        (0..size).map(|_| LETTERS[rng.range(0, 4) as usize]).collect::<Vec<_>>()
+    }))
+    .expect("rows have the same length");
+
+    Generated {
+        input: grid.to_string(),
+        part1: oracle::xmas_count(&grid).to_string(),
+        part2: oracle::x_mas_count(&grid).to_string(),
+    }
+}
+
+/// Day 5 input with rules over 49 pages and `size` updates
+pub fn day5(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut order: Vec<u32> = (11..60).collect();
+    rng.shuffle(&mut order);
+    let rules = crate::Rules { order };
+
+    let mut input = rules.text();
+    input.push('\n');
+    let mut ordered = 0;
+    let mut reordered = 0;
+    for _ in 0..size {
+        let len = rng.range(2, 12) as usize * 2 + 1;
+        let mut update = rules.order.clone();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+        // keep about half of the updates in the correct order
+        if rng.coin() {
+            update = rules.sorted(&update);
+        }
+        if rules.is_ordered(&update) {
+            ordered += update[len / 2];
+        } else {
+            reordered += rules.sorted(&update)[len / 2];
+        }
+        let pages: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+        input.push_str(&pages.join(","));
+        input.push('\n');
+    }
+
+    Generated { input, part1: ordered.to_string(), part2: reordered.to_string() }
+}
+
+/// Day 6 lab map, a `size` x `size` grid with scattered obstructions and a guard that leaves it
+/// 
+/// Maps where the guard walks in a loop are drawn again
+pub fn day6(seed: u64, size: usize) -> Generated {
+    const GUARDS: [char; 4] = ['^', '>', 'v', '<'];
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    loop {
+        let mut grid = Grid::from_rows((0..size).map(|_| {
+            (0..size).map(|_| if rng.range(0, 16) == 0 { '#' } else { '.' }).collect::<Vec<_>>()
+        }))
+        .expect("rows have the same length");
+        let guard = (rng.range(0, size as u64) as usize, rng.range(0, size as u64) as usize);
+        grid[guard] = GUARDS[rng.range(0, 4) as usize];
+        if let Some(visited) = oracle::patrol(&grid) {
+            return Generated {
+                input: grid.to_string(),
+                part1: visited.to_string(),
+                part2: oracle::loop_obstructions(&grid).to_string(),
+            };
+        }
+    }
+}
+
+/// Day 7 input with `size` equations of 2 to 8 operands, about half of them solvable
+pub fn day7(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut with_operators = 0;
+    let mut with_concat = 0;
+    for _ in 0..size {
+        let operands: Vec<u64> = (0..rng.range(2, 9)).map(|_| rng.range(1, 100)).collect();
+        // a target made from random operators, off by one for about half of the equations
+        let value = operands[1..].iter()
+        .fold(operands[0] as u128, |value, &operand| oracle::apply(value, rng.range(0, 3) as u32, operand));
+        let target = u64::try_from(value).expect("eight two digit operands fit u64") + rng.range(0, 2);
+        if oracle::calibrates(target, &operands, false) {
+            with_operators += target;
+        }
+        if oracle::calibrates(target, &operands, true) {
+            with_concat += target;
+        }
+        let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+        input.push_str(&format!("{}: {}\n", target, operands.join(" ")));
+    }
+
+    Generated { input, part1: with_operators.to_string(), part2: with_concat.to_string() }
+}
+
+/// Day 8 antenna map, a `size` x `size` grid with about one antenna in 25 cells
+pub fn day8(seed: u64, size: usize) -> Generated {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut rng = Rng::new(seed);
+    let grid = Grid::from_rows((0..size).map(|_| {
+        (0..size).map(|_| match rng.range(0, 25) {
+            0 => FREQUENCIES[rng.range(0, FREQUENCIES.len() as u64) as usize] as char,
+            _ => '.',
+        })
+        .collect::<Vec<_>>()
+    }))
+    .expect("rows have the same length");
+
+    Generated {
+        input: grid.to_string(),
+        part1: oracle::antinodes(&grid).to_string(),
+        part2: oracle::resonant_antinodes(&grid).to_string(),
+    }
+}
+
+/// Day 9 dense disk map with `size` files of 1 to 9 blocks and up to 9 free blocks between them
+pub fn day9(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    for file in 0..size {
+        input.push_str(&rng.range(1, 10).to_string());
+        if file + 1 < size {
+            input.push_str(&rng.range(0, 10).to_string());
+        }
+    }
+    input.push('\n');
+
+    Generated {
+        part1: oracle::block_compacted_checksum(&input).to_string(),
+        part2: oracle::file_compacted_checksum(&input).to_string(),
+        input,
+    }
+}
+
+/// Day 10 topographic map, a `size` x `size` grid of diagonal ridges with about one height in 6 random
+pub fn day10(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let grid = Grid::from_rows((0..size).map(|row| {
+        (0..size).map(|col| {
+            // heights climb from 0 to 9 and back down along the diagonals
+            let ridge = (row + col) % 18;
+            let height = match rng.range(0, 6) {
+                0 => rng.range(0, 10) as usize,
+                _ => ridge.min(18 - ridge),
+            };
+            char::from(b'0' + height as u8)
+        })
+        .collect::<Vec<_>>()
+    }))
+    .expect("rows have the same length");
+
+    Generated {
+        input: grid.to_string(),
+        part1: oracle::trailhead_scores(&grid).to_string(),
+        part2: oracle::trailhead_ratings(&grid).to_string(),
+    }
+}
+
+/// Day 11 arrangement of `size` stones engraved with numbers below 100 000
+pub fn day11(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let stones: Vec<u64> = (0..size).map(|_| rng.range(0, 100_000)).collect();
+    let input: Vec<String> = stones.iter().map(u64::to_string).collect();
+
+    Generated {
+        input: input.join(" ") + "\n",
+        part1: oracle::stones_after(&stones, 25).to_string(),
+        part2: oracle::stones_after(&stones, 75).to_string(),
+    }
+}
+
+/// Day 12 garden map, a `size` x `size` grid of six plants where most plots
+/// copy the plant above or to the left of them
+pub fn day12(seed: u64, size: usize) -> Generated {
+    const PLANTS: &[u8] = b"ABCDEF";
+    let mut rng = Rng::new(seed);
+    let mut rows: Vec<Vec<char>> = Vec::with_capacity(size);
+    for row in 0..size {
+        let mut plants = Vec::with_capacity(size);
+        for col in 0..size {
+            let plant = match rng.range(0, 3) {
+                0 if row > 0 => rows[row - 1][col],
+                1 if col > 0 => plants[col - 1],
+                _ => PLANTS[rng.range(0, PLANTS.len() as u64) as usize] as char,
+            };
+            plants.push(plant);
+        }
+        rows.push(plants);
+    }
+    let grid = Grid::from_rows(rows).expect("rows have the same length");
+
+    Generated {
+        input: grid.to_string(),
+        part1: oracle::fence_price(&grid, false).to_string(),
+        part2: oracle::fence_price(&grid, true).to_string(),
+    }
+}
+
+/// Day 13 list of `size` claw machines, about half of them with a prize the buttons reach
+pub fn day13(seed: u64, size: usize) -> Generated {
+    /// Offset of the prizes in part 2
+    const FAR: i64 = 10_000_000_000_000;
+    let mut rng = Rng::new(seed);
+    let mut machines = Vec::with_capacity(size);
+    let (mut near, mut far) = (0, 0);
+    while machines.len() < size {
+        let button = |rng: &mut Rng| (rng.range(10, 100) as i64, rng.range(10, 100) as i64);
+        let (a, b) = (button(&mut rng), button(&mut rng));
+        // collinear buttons need a different solver, the puzzle inputs do not have them
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+        let (presses_a, presses_b) = (rng.range(0, 101) as i64, rng.range(0, 101) as i64);
+        let miss = rng.range(0, 2) as i64;
+        let prize = (presses_a * a.0 + presses_b * b.0 + miss, presses_a * a.1 + presses_b * b.1);
+        near += oracle::claw_tokens(a, b, prize).unwrap_or(0);
+        far += oracle::claw_tokens_exact(a, b, (prize.0 + FAR, prize.1 + FAR)).unwrap_or(0);
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    Generated { input: machines.join("\n"), part1: near.to_string(), part2: far.to_string() }
+}
+
+/// Day 14 list of `size` robots on a 101 x 103 floor, two thirds of them
+/// meeting in a filled square at a random step
+pub fn day14(seed: u64, size: usize) -> Generated {
+    const WIDTH: i64 = 101;
+    const HEIGHT: i64 = 103;
+    let mut rng = Rng::new(seed);
+    let egg = rng.range(0, (WIDTH * HEIGHT) as u64) as i64;
+    let cluster = size * 2 / 3;
+    let side = ((cluster as f64).sqrt().ceil() as i64).clamp(1, 20);
+    let (left, top) = (rng.range(0, (WIDTH - side) as u64) as i64, rng.range(0, (HEIGHT - side) as u64) as i64);
+    let robots: Vec<(i64, i64, i64, i64)> = (0..size).map(|i| {
+        let (vx, vy) = (rng.range(0, 201) as i64 - 100, rng.range(0, 201) as i64 - 100);
+        let (x, y) = if i < cluster {
+            let (x, y) = (left + i as i64 % side, top + i as i64 / side % side);
+            ((x - vx * egg).rem_euclid(WIDTH), (y - vy * egg).rem_euclid(HEIGHT))
+        } else {
+            (rng.range(0, WIDTH as u64) as i64, rng.range(0, HEIGHT as u64) as i64)
+        };
+        (x, y, vx, vy)
+    })
+    .collect();
+    let input: String = robots.iter().map(|(x, y, vx, vy)| format!("p={x},{y} v={vx},{vy}\n")).collect();
+
+    Generated {
+        input,
+        part1: oracle::safety_factor(&robots, WIDTH, HEIGHT).to_string(),
+        part2: oracle::easter_egg(&robots, WIDTH, HEIGHT).to_string(),
+    }
+}
+
+/// Day 15 warehouse, a `size` x `size` map walled all around with a few
+/// walls and many boxes inside, and `20 * size` moves of the robot
+pub fn day15(seed: u64, size: usize) -> Generated {
+    const MOVES: [char; 4] = ['^', '>', 'v', '<'];
+    let size = size.max(3);
+    let mut rng = Rng::new(seed);
+    let mut grid = Grid::from_rows((0..size).map(|row| {
+        (0..size).map(|col| {
+            if row == 0 || col == 0 || row == size - 1 || col == size - 1 {
+                return '#';
+            }
+            match rng.range(0, 10) {
+                0 => '#',
+                1..=3 => 'O',
+                _ => '.',
+            }
+        })
+        .collect::<Vec<_>>()
+    }))
+    .expect("rows have the same length");
+    let robot = (rng.range(1, size as u64 - 1) as usize, rng.range(1, size as u64 - 1) as usize);
+    grid[robot] = '@';
+    let moves: Vec<char> = (0..20 * size).map(|_| MOVES[rng.range(0, 4) as usize]).collect();
+    let moves: String = moves.chunks(70).map(|line| line.iter().collect::<String>() + "\n").collect();
+
+    Generated {
+        input: format!("{grid}\n{moves}"),
+        part1: oracle::warehouse_gps(&grid, &moves, false).to_string(),
+        part2: oracle::warehouse_gps(&grid, &moves, true).to_string(),
+    }
+}
+
+/// Day 16 maze, a `size` x `size` grid (rounded up to odd) carved as a
+/// maze from `S` in the bottom left corner to `E` in the top right one,
+/// with about one wall in 10 knocked down to open loops
+pub fn day16(seed: u64, size: usize) -> Generated {
+    let size = size.max(5) | 1;
+    let mut rng = Rng::new(seed);
+    let mut grid = Grid::new(size, size, '#');
+    // depth first carving between the cells on odd rows and columns
+    let start = (size - 2, 1);
+    grid[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&(row, col)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(row.wrapping_sub(2), col), (row, col + 2), (row + 2, col), (row, col.wrapping_sub(2))]
+        .into_iter()
+        .filter(|&(r, c)| r < size - 1 && c < size - 1 && grid[(r, c)] == '#')
+        .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = unvisited[rng.range(0, unvisited.len() as u64) as usize];
+        grid[((row + next.0) / 2, (col + next.1) / 2)] = '.';
+        grid[next] = '.';
+        stack.push(next);
+    }
+    for row in 1..size - 1 {
+        for col in 1..size - 1 {
+            if grid[(row, col)] == '#' && rng.range(0, 10) == 0 {
+                grid[(row, col)] = '.';
+            }
+        }
+    }
+    grid[start] = 'S';
+    grid[(1, size - 2)] = 'E';
+    let (score, tiles) = oracle::reindeer_paths(&grid).expect("the maze connects every cell");
+
+    Generated {
+        input: grid.to_string(),
+        part1: score.to_string(),
+        part2: tiles.to_string(),
+    }
+}
+
+/// Day 17 computer running a program shaped like the puzzle inputs, a loop
+/// outputting the low bits of A mixed through B and C before shifting A
+/// right by 3, with `size` random 3-bit digits (at most 16) in A
+pub fn day17(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    loop {
+        let mut mix = [[1, rng.range(0, 8) as u8], [4, rng.range(0, 8) as u8]];
+        if rng.range(0, 2) == 0 {
+            mix.swap(0, 1);
+        }
+        let program: Vec<u8> = [[2, 4], [1, rng.range(0, 8) as u8], [7, 5], mix[0], mix[1], [0, 3], [5, 5], [3, 0]]
+        .concat();
+        // not every mix of the bits has a quine
+        let Some(quine) = oracle::quine_a(&program) else {
+            continue;
+        };
+        let a = (0..size.clamp(1, 16)).fold(0, |a, _| a * 8 + rng.range(0, 8));
+        let join = |values: &[u8]| values.iter().map(u8::to_string).collect::<Vec<_>>().join(",");
+
+        return Generated {
+            input: format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", join(&program)),
+            part1: join(&oracle::chronospatial_output(a, 0, 0, &program)),
+            part2: quine.to_string(),
+        };
+    }
+}
+
+/// Day 18 bytes falling on the 71 x 71 memory space of the puzzle, every
+/// cell but the start in a random order, cut `size` bytes after the first
+/// one cutting the exit off
+pub fn day18(seed: u64, size: usize) -> Generated {
+    const SIDE: usize = 71;
+    const FALLEN: usize = 1024;
+    let mut rng = Rng::new(seed);
+    loop {
+        let mut bytes: Vec<(usize, usize)> = (1..SIDE * SIDE).map(|cell| (cell % SIDE, cell / SIDE)).collect();
+        for i in (1..bytes.len()).rev() {
+            bytes.swap(i, rng.range(0, i as u64 + 1) as usize);
+        }
+        // part 1 needs a way out once the first bytes fell
+        let Some(steps) = oracle::ram_run(&bytes[..FALLEN], SIDE) else {
+            continue;
+        };
+        let blocking = oracle::first_blocking(&bytes, SIDE).expect("the exit falls last at the latest");
+        let fallen = bytes.iter().position(|&byte| byte == blocking).expect("the blocking byte fell") + 1;
+        bytes.truncate(fallen + size);
+
+        return Generated {
+            input: bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect(),
+            part1: steps.to_string(),
+            part2: format!("{},{}", blocking.0, blocking.1),
+        };
+    }
+}
+
+/// Day 19 towel patterns and `size` designs shaped like the puzzle inputs,
+/// single stripes of every color but white and a few hundred longer
+/// patterns, half of the designs joined from patterns and half random
+pub fn day19(seed: u64, size: usize) -> Generated {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let mut rng = Rng::new(seed);
+    let stripes = |rng: &mut Rng, len: usize| -> String {
+        (0..len).map(|_| COLORS[rng.range(0, 5) as usize]).collect()
+    };
+    let mut patterns: Vec<String> = COLORS[1..].iter().map(char::to_string).collect();
+    while patterns.len() < 400 {
+        let len = rng.range(2, 9) as usize;
+        let pattern = stripes(&mut rng, len);
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    rng.shuffle(&mut patterns);
+    let designs: Vec<String> = (0..size).map(|_| {
+        let len = rng.range(20, 41) as usize;
+        if rng.coin() {
+            stripes(&mut rng, len)
+        } else {
+            let mut design = String::new();
+            while design.len() < len {
+                design.push_str(&patterns[rng.range(0, patterns.len() as u64) as usize]);
+            }
+            design
+        }
+    })
+    .collect();
+    let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+    let counts: Vec<u64> = designs.iter().map(|design| oracle::towel_arrangements(&patterns, design)).collect();
+
+    Generated {
+        input: format!("{}\n\n{}", patterns.join(", "), designs.iter().map(|design| format!("{design}\n")).collect::<String>()),
+        part1: counts.iter().filter(|&&count| count > 0).count().to_string(),
+        part2: counts.iter().sum::<u64>().to_string(),
+    }
+}
+
+/// Day 20 racetrack in a `size` x `size` maze, the single way through a
+/// randomly carved maze from the bottom left to the top right corner,
+/// counting the cheats saving 100 picoseconds whatever its size
+pub fn day20(seed: u64, size: usize) -> Generated {
+    let side = size.max(5) | 1;
+    let mut rng = Rng::new(seed);
+    // depth first carving between the cells on odd rows and columns, each one keeping the cell it was carved from
+    let start = (side - 2, 1);
+    let mut parents = Grid::new(side, side, None);
+    parents[start] = Some(start);
+    let mut stack = vec![start];
+    while let Some(&(row, col)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(row.wrapping_sub(2), col), (row, col + 2), (row + 2, col), (row, col.wrapping_sub(2))]
+        .into_iter()
+        .filter(|&(r, c)| r < side - 1 && c < side - 1 && parents[(r, c)].is_none())
+        .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = unvisited[rng.range(0, unvisited.len() as u64) as usize];
+        parents[next] = Some((row, col));
+        stack.push(next);
+    }
+    let mut grid = Grid::new(side, side, '#');
+    let mut pos = (1, side - 2);
+    grid[pos] = 'E';
+    while pos != start {
+        let parent = parents[pos].expect("every cell is carved");
+        grid[((pos.0 + parent.0) / 2, (pos.1 + parent.1) / 2)] = '.';
+        grid[parent] = '.';
+        pos = parent;
+    }
+    grid[start] = 'S';
+
+    Generated {
+        input: grid.to_string(),
+        part1: oracle::race_cheats(&grid, 2, 100).to_string(),
+        part2: oracle::race_cheats(&grid, 20, 100).to_string(),
+    }
+}
+
+/// Day 21 door codes of three digits and `A` like the puzzle inputs, `size` of them
+pub fn day21(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let codes: Vec<String> = (0..size).map(|_| format!("{:03}A", rng.range(0, 1000))).collect();
+    let complexity = |robots: usize| -> u64 {
+        codes.iter().map(|code| oracle::keypad_presses(code, robots) * code[..3].parse::<u64>().expect("three digits")).sum()
+    };
+
+    Generated {
+        input: codes.iter().map(|code| format!("{code}\n")).collect(),
+        part1: complexity(2).to_string(),
+        part2: complexity(25).to_string(),
+    }
+}
+
+/// Day 22 initial secret numbers of `size` buyers
+pub fn day22(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let secrets: Vec<u64> = (0..size).map(|_| rng.range(1, 16777216)).collect();
+    let sum: u64 = secrets.iter().map(|&first| (0..2000).fold(first, |secret, _| oracle::next_secret(secret))).sum();
+
+    Generated {
+        input: secrets.iter().map(|secret| format!("{secret}\n")).collect(),
+        part1: sum.to_string(),
+        part2: oracle::monkey_bananas(&secrets).to_string(),
+    }
+}
+
+/// Day 23 connections between `size` computers with two letter names, a clique of up to
+/// 13 of them hidden between a few random connections of every computer
+pub fn day23(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut names: Vec<String> = (b'a'..=b'z').flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char))).collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(3, names.len()));
+    let party = (names.len() / 4).clamp(3, 13);
+    let mut pairs = std::collections::BTreeSet::new();
+    for i in 0..party {
+        for j in i + 1..party {
+            pairs.insert((i, j));
+        }
+    }
+    for i in 0..names.len() {
+        for _ in 0..2 {
+            let j = rng.range(0, names.len() as u64) as usize;
+            if i != j {
+                pairs.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    let mut connections: Vec<(String, String)> = pairs.into_iter().map(|(i, j)| match rng.coin() {
+        true => (names[i].clone(), names[j].clone()),
+        false => (names[j].clone(), names[i].clone()),
+    }).collect();
+    rng.shuffle(&mut connections);
+
+    Generated {
+        input: connections.iter().map(|(a, b)| format!("{a}-{b}\n")).collect(),
+        part1: oracle::lan_triangles(&connections, "t").to_string(),
+        part2: oracle::lan_password(&connections),
+    }
+}
+
+/// Day 24 ripple-carry adder of `size` bits (6 to 44) with the outputs of four pairs of
+/// gates in different bits swapped the ways that keep the circuit free of cycles
+pub fn day24(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let bits = size.clamp(6, 44);
+    let mut names = std::collections::BTreeSet::new();
+    let mut name = |rng: &mut Rng| loop {
+        let name: String = (0..3).map(|i| (b'a' + rng.range(0, if i == 0 { 23 } else { 26 }) as u8) as char).collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    // wires of bit i: sum of the inputs, carry out, AND of the inputs, AND of the sum and the carry in
+    let mut carries = vec![name(&mut rng)];
+    let mut gates = vec![
+        ("x00".to_string(), "XOR", "y00".to_string(), "z00".to_string()),
+        ("x00".to_string(), "AND", "y00".to_string(), carries[0].clone()),
+    ];
+    let mut swappable = Vec::new();
+    for i in 1..bits {
+        let (sum, and, carried) = (name(&mut rng), name(&mut rng), name(&mut rng));
+        let carry = if i + 1 == bits { format!("z{bits:02}") } else { name(&mut rng) };
+        let z = format!("z{i:02}");
+        let first = gates.len();
+        gates.extend([
+            (format!("x{i:02}"), "XOR", format!("y{i:02}"), sum.clone()),
+            (sum.clone(), "XOR", carries[i - 1].clone(), z),
+            (format!("x{i:02}"), "AND", format!("y{i:02}"), and.clone()),
+            (sum, "AND", carries[i - 1].clone(), carried.clone()),
+            (and, "OR", carried, carry.clone()),
+        ]);
+        carries.push(carry);
+        if i + 1 < bits {
+            // z with the carry out, the sum with the AND of the inputs, z with the AND of the sum
+            swappable.push([(first + 1, first + 4), (first, first + 2), (first + 1, first + 3)]);
+        }
+    }
+    rng.shuffle(&mut swappable);
+    let mut swapped = Vec::new();
+    for kinds in swappable.iter().take(4) {
+        let (a, b) = kinds[rng.range(0, 3) as usize];
+        let (left, right) = (gates[a].3.clone(), gates[b].3.clone());
+        gates[a].3 = right.clone();
+        gates[b].3 = left.clone();
+        swapped.extend([left, right]);
+    }
+    swapped.sort();
+
+    let x = rng.next() & ((1 << bits) - 1);
+    let y = rng.next() & ((1 << bits) - 1);
+    let wires: Vec<(String, bool)> = (0..bits).map(|i| (format!("x{i:02}"), x >> i & 1 == 1))
+    .chain((0..bits).map(|i| (format!("y{i:02}"), y >> i & 1 == 1)))
+    .collect();
+    let mut gates: Vec<(String, String, String, String)> = gates.into_iter().map(|(left, op, right, output)| match rng.coin() {
+        true => (left, op.to_string(), right, output),
+        false => (right, op.to_string(), left, output),
+    }).collect();
+    rng.shuffle(&mut gates);
+    let mut input: String = wires.iter().map(|(wire, value)| format!("{wire}: {}\n", u8::from(*value))).collect();
+    input.push('\n');
+    input.extend(gates.iter().map(|(left, op, right, output)| format!("{left} {op} {right} -> {output}\n")));
+
+    Generated {
+        part1: oracle::circuit_output(&wires, &gates).to_string(),
+        part2: swapped.join(","),
+        input,
+    }
+}
+
+/// Day 25 `size` lock and key schematics with random pin heights, about as many
+/// locks as keys
+pub fn day25(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let (mut locks, mut keys) = (Vec::new(), Vec::new());
+    let mut schematics = Vec::new();
+    for _ in 0..size.max(1) {
+        let heights: [u8; 5] = std::array::from_fn(|_| rng.range(0, 6) as u8);
+        let lock = rng.coin();
+        // row 0 is the filled row of a lock, row 6 the filled row of a key
+        let rows: Vec<String> = (0..7u8).map(|row| (0..5).map(|column| {
+            let pin = match lock {
+                true => row <= heights[column],
+                false => 6 - row <= heights[column],
+            };
+            if pin { '#' } else { '.' }
+        }).collect()).collect();
+        schematics.push(rows.join("\n"));
+        match lock {
+            true => locks.push(heights),
+            false => keys.push(heights),
+        }
+    }
+
+    Generated {
+        input: schematics.join("\n\n") + "\n",
+        part1: oracle::fitting_locks(&locks, &keys).to_string(),
+        part2: String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_deterministic() {
+        for generate in [day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25] {
+            assert_eq!(generate(3, 50), generate(3, 50));
+            assert_ne!(generate(3, 50), generate(4, 50));
+        }
+    }
+
+    #[test]
+    fn test_oracle_is_safe() {
+        assert!(oracle::is_safe(&[7, 6, 4, 2, 1]));
+        assert!(!oracle::is_safe(&[1, 3, 2, 4, 5]));
+        assert!(oracle::is_safe_with_dampener(&[1, 3, 2, 4, 5]));
+        assert!(!oracle::is_safe_with_dampener(&[1, 2, 7, 8, 9]));
+    }
+
+    #[test]
+    fn test_oracle_samples() -> Result<(), aoc_utils::GridError> {
+        assert_eq!(oracle::distance(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 11);
+        assert_eq!(oracle::similarity(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 31);
+        let grid: Grid<char> = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+        XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n".parse()?;
+        assert_eq!(oracle::xmas_count(&grid), 18);
+        assert_eq!(oracle::x_mas_count(&grid), 9);
+        let grid: Grid<char> = "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+        ..........\n.#..^.....\n........#.\n#.........\n......#...\n".parse()?;
+        assert_eq!(oracle::patrol(&grid), Some(41));
+        assert_eq!(oracle::loop_obstructions(&grid), 6);
+        assert_eq!(oracle::patrol(&".#.\n#^#\n.#.\n".parse()?), None);
+        assert!(oracle::calibrates(3267, &[81, 40, 27], false));
+        assert!(!oracle::calibrates(156, &[15, 6], false));
+        assert!(oracle::calibrates(156, &[15, 6], true));
+        assert!(oracle::calibrates(7290, &[6, 8, 6, 15], true));
+        let grid: Grid<char> = "............\n........0...\n.....0......\n.......0....\n....0.......\n......A.....\n\
+        ............\n............\n........A...\n.........A..\n............\n............\n".parse()?;
+        assert_eq!(oracle::antinodes(&grid), 14);
+        assert_eq!(oracle::resonant_antinodes(&grid), 34);
+        assert_eq!(oracle::block_compacted_checksum("12345\n"), 60);
+        assert_eq!(oracle::block_compacted_checksum("2333133121414131402\n"), 1928);
+        assert_eq!(oracle::file_compacted_checksum("2333133121414131402\n"), 2858);
+        let grid: Grid<char> = "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732\n".parse()?;
+        assert_eq!(oracle::trailhead_scores(&grid), 36);
+        assert_eq!(oracle::trailhead_ratings(&grid), 81);
+        assert_eq!(oracle::stones_after(&[125, 17], 6), 22);
+        assert_eq!(oracle::stones_after(&[125, 17], 25), 55312);
+        let grid: Grid<char> = "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\n\
+        VVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE\n".parse()?;
+        assert_eq!(oracle::fence_price(&grid, false), 1930);
+        assert_eq!(oracle::fence_price(&grid, true), 1206);
+        let grid: Grid<char> = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n".parse()?;
+        assert_eq!(oracle::fence_price(&grid, true), 368);
+        assert_eq!(oracle::claw_tokens((94, 34), (22, 67), (8400, 5400)), Some(280));
+        let robots = [(0, 4, 3, -3), (6, 3, -1, -3), (10, 3, -1, 2), (2, 0, 2, -1), (0, 0, 1, 3), (3, 0, -2, -2),
+            (7, 6, -1, -3), (3, 0, -1, -2), (9, 3, 2, 3), (7, 3, -1, 2), (2, 4, 2, -3), (9, 5, -3, -3)];
+        assert_eq!(oracle::safety_factor(&robots, 11, 7), 12);
+        let grid: Grid<char> = "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########\n".parse()?;
+        assert_eq!(oracle::warehouse_gps(&grid, "<^^>>>vv<v>>v<<", false), 2028);
+        let grid: Grid<char> = "#######\n#...#.#\n#.....#\n#..OO@#\n#..O..#\n#.....#\n#######\n".parse()?;
+        assert_eq!(oracle::warehouse_gps(&grid, "<vv<<^^<<^^", true), 105 + 207 + 306);
+        let grid: Grid<char> = "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n\
+        #.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n\
+        #.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############\n".parse()?;
+        assert_eq!(oracle::reindeer_paths(&grid), Some((7036, 45)));
+        assert_eq!(oracle::reindeer_paths(&"#####\n#E.S#\n#####\n".parse()?), Some((2002, 3)));
+        assert_eq!(oracle::chronospatial_output(729, 0, 0, &[0, 1, 5, 4, 3, 0]), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert_eq!(oracle::chronospatial_output(10, 0, 0, &[5, 0, 5, 1, 5, 4]), [0, 1, 2]);
+        assert_eq!(oracle::quine_a(&[0, 3, 5, 4, 3, 0]), Some(117440));
+        let bytes = [(5, 4), (4, 2), (4, 5), (3, 0), (2, 1), (6, 3), (2, 4), (1, 5), (0, 6), (3, 3), (2, 6), (5, 1), (1, 2),
+            (5, 5), (2, 5), (6, 5), (1, 4), (0, 4), (6, 4), (1, 1), (6, 1), (1, 0), (0, 5), (1, 6), (2, 0)];
+        assert_eq!(oracle::ram_run(&bytes[..12], 7), Some(22));
+        assert_eq!(oracle::first_blocking(&bytes, 7), Some((6, 1)));
+        let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        assert_eq!(oracle::towel_arrangements(&patterns, "rrbgbr"), 6);
+        assert_eq!(oracle::towel_arrangements(&patterns, "ubwu"), 0);
+        let grid: Grid<char> = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n\
+        #######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n\
+        #.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############\n".parse()?;
+        assert_eq!(oracle::race_cheats(&grid, 2, 1), 44);
+        assert_eq!(oracle::race_cheats(&grid, 2, 64), 1);
+        assert_eq!(oracle::race_cheats(&grid, 20, 50), 285);
+        assert_eq!(oracle::keypad_presses("029A", 0), 12);
+        assert_eq!(oracle::keypad_presses("029A", 2), 68);
+        assert_eq!(oracle::keypad_presses("379A", 2), 64);
+        assert_eq!(oracle::next_secret(123), 15887950);
+        assert_eq!(oracle::monkey_bananas(&[1, 2, 3, 2024]), 23);
+        let connections: Vec<(String, String)> = ["ka-co", "ta-co", "de-co", "ta-ka", "de-ta", "ka-de", "tc-td", "de-cg"].iter()
+        .map(|pair| (pair[..2].to_string(), pair[3..].to_string())).collect();
+        assert_eq!(oracle::lan_triangles(&connections, "t"), 3);
+        assert_eq!(oracle::lan_password(&connections), "co,de,ka,ta");
+        let wires = [("x00".to_string(), true), ("y00".to_string(), true)];
+        let gates = [("x00", "AND", "y00", "z01"), ("x00", "XOR", "y00", "z00")]
+        .map(|(left, op, right, output)| (left.to_string(), op.to_string(), right.to_string(), output.to_string()));
+        assert_eq!(oracle::circuit_output(&wires, &gates), 2);
+        assert_eq!(oracle::fitting_locks(&[[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]], &[[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]), 3);
+        assert_eq!(oracle::claw_tokens((26, 66), (67, 21), (12748, 12176)), None);
+        assert_eq!(oracle::claw_tokens_exact((94, 34), (22, 67), (8400, 5400)), Some(280));
+        assert_eq!(oracle::claw_tokens_exact((26, 66), (67, 21), (10000000012748, 10000000012176)), Some(459236326669));
+        Ok(())
+    }
+}
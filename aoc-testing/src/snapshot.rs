@@ -0,0 +1,80 @@
+use std::sync::LazyLock;
+use regex::Regex;
+
+static DURATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b\d+(\.\d+)?(ns|us|µs|ms|s)\b").expect("valid duration pattern")
+});
+static DURATION_FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#""duration_us": \d+"#).expect("valid duration field pattern")
+});
+static BYTES: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b\d+(\.\d+)? (B|KiB|MiB|GiB|TiB)\b").expect("valid byte count pattern")
+});
+static ALLOCATION_FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#""(allocated|peak)_bytes": \d+"#).expect("valid allocation field pattern")
+});
+
+/// Replace the parts of a rendered output that change between runs
+/// 
+/// Durations such as `1.23ms` and the `duration_us` JSON fields become
+/// `[duration]`, memory figures such as `1.5 KiB` and the `allocated_bytes`
+/// and `peak_bytes` JSON fields become `[bytes]`, paths inside the temporary
+/// directory become `[temp]/...` with forward slashes. Apply it before
+/// handing the output to insta.
+/// 
+/// # Examples
+/// ```
+/// let path = std::env::temp_dir().join("input.txt");
+/// let output = format!("  1     1  11      1.23ms  -\nread {}\n", path.display());
+/// assert_eq!(aoc_testing::redact(&output), "  1     1  11      [duration]  -\nread [temp]/input.txt\n");
+/// ```
+pub fn redact(output: &str) -> String {
+    let temp = std::env::temp_dir();
+    let temp = temp.to_string_lossy();
+    let temp = temp.trim_end_matches(['/', '\\']);
+    let output = output.split(temp)
+    .enumerate()
+    .map(|(i, part)| match i {
+        0 => part.to_string(),
+        _ => {
+            // the path goes on until the next whitespace or quote
+            let end = part.find(|c: char| c.is_whitespace() || c == '"').unwrap_or(part.len());
+            format!("[temp]{}{}", part[..end].replace('\\', "/"), &part[end..])
+        },
+    })
+    .collect::<String>();
+    let output = DURATION_FIELD.replace_all(&output, r#""duration_us": "[duration]""#);
+    let output = ALLOCATION_FIELD.replace_all(&output, r#""${1}_bytes": "[bytes]""#);
+    let output = BYTES.replace_all(&output, "[bytes]");
+    DURATION.replace_all(&output, "[duration]").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_durations() {
+        assert_eq!(redact("999us 1.50ms 3.00s 12ns"), "[duration] [duration] [duration] [duration]");
+        assert_eq!(redact("{\"duration_us\": 1234}"), "{\"duration_us\": \"[duration]\"}");
+    }
+
+    #[test]
+    fn test_redact_allocations() {
+        assert_eq!(redact("  2.0 KiB  512 B  1.5 MiB"), "  [bytes]  [bytes]  [bytes]");
+        assert_eq!(redact("{\"allocated_bytes\": 3584, \"peak_bytes\": 2048, \"input_bytes\": 36}"),
+        "{\"allocated_bytes\": \"[bytes]\", \"peak_bytes\": \"[bytes]\", \"input_bytes\": 36}");
+    }
+
+    #[test]
+    fn test_redact_keeps_answers() {
+        assert_eq!(redact("Part 1: 143\nLines: 5"), "Part 1: 143\nLines: 5");
+    }
+
+    #[test]
+    fn test_redact_temp_paths() {
+        let path = std::env::temp_dir().join(".tmpAbc").join("input.txt");
+        let output = format!("could not read file {}: not found\n", path.display());
+        assert_eq!(redact(&output), "could not read file [temp]/.tmpAbc/input.txt: not found\n");
+    }
+}
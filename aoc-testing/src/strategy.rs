@@ -0,0 +1,114 @@
+use std::ops::{Range, RangeInclusive};
+use aoc_utils::Grid;
+use proptest::{collection::{vec, SizeRange}, prelude::*, sample::{select, subsequence}};
+
+/// Report of levels as used by day 2
+/// 
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// 
+/// proptest!(|(report in aoc_testing::report_strategy(5..=8, 1..=99))| {
+///     prop_assert!((5..=8).contains(&report.len()));
+/// });
+/// ```
+pub fn report_strategy(len: impl Into<SizeRange>, range: RangeInclusive<i32>) -> impl Strategy<Value = Vec<i32>> {
+    vec(range, len)
+}
+
+/// Grid with the given number of rows and columns filled from `alphabet`
+/// 
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// 
+/// proptest!(|(grid in aoc_testing::grid_strategy(1..4, 1..4, &['X', 'M', 'A', 'S']))| {
+///     prop_assert!(grid.height() < 4 && grid.width() < 4);
+/// });
+/// ```
+pub fn grid_strategy(rows: Range<usize>, cols: Range<usize>, alphabet: &'static [char]) -> impl Strategy<Value = Grid<char>> {
+    (rows, cols)
+    .prop_flat_map(move |(rows, cols)| vec(vec(select(alphabet), cols), rows))
+    .prop_map(|rows| Grid::from_rows(rows).expect("rows have the same length"))
+}
+
+/// Page ordering rules agreeing with a single order of the pages
+/// 
+/// There is a rule for every pair of pages, the way day 5 inputs are built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    /// Pages in the order the rules describe
+    pub order: Vec<u32>,
+}
+
+impl Rules {
+    /// Every `X|Y` rule, one per line
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for (i, before) in self.order.iter().enumerate() {
+            for after in &self.order[i + 1..] {
+                text.push_str(&format!("{}|{}\n", before, after));
+            }
+        }
+        text
+    }
+
+    /// Position of the page in the order
+    pub fn rank(&self, page: u32) -> Option<usize> {
+        self.order.iter().position(|&p| p == page)
+    }
+
+    /// Whether the update lists its pages in the order of the rules
+    pub fn is_ordered(&self, update: &[u32]) -> bool {
+        update.windows(2).all(|w| self.rank(w[0]) < self.rank(w[1]))
+    }
+
+    /// The update sorted by the rules
+    pub fn sorted(&self, update: &[u32]) -> Vec<u32> {
+        let mut sorted = update.to_vec();
+        sorted.sort_by_key(|&page| self.rank(page));
+        sorted
+    }
+}
+
+/// Consistent rules over the given number of distinct two digit pages
+/// 
+/// # Examples
+/// ```
+/// use proptest::prelude::*;
+/// 
+/// proptest!(|(rules in aoc_testing::consistent_rules_strategy(2..10))| {
+///     prop_assert!(rules.is_ordered(&rules.order));
+/// });
+/// ```
+pub fn consistent_rules_strategy(pages: Range<usize>) -> impl Strategy<Value = Rules> {
+    pages
+    .prop_flat_map(|pages| subsequence((10..100).collect::<Vec<u32>>(), pages).prop_shuffle())
+    .prop_map(|order| Rules { order })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_report_strategy_bounds(report in report_strategy(3..6, -5..=5)) {
+            prop_assert!((3..6).contains(&report.len()));
+            prop_assert!(report.iter().all(|level| (-5..=5).contains(level)));
+        }
+
+        #[test]
+        fn test_grid_strategy_alphabet(grid in grid_strategy(0..5, 1..5, &['a', 'b'])) {
+            prop_assert!(grid.iter().all(|(_, &c)| c == 'a' || c == 'b'));
+        }
+
+        #[test]
+        fn test_consistent_rules_sorted(rules in consistent_rules_strategy(1..20), seed in any::<u64>()) {
+            let mut rng = crate::Rng::new(seed);
+            let update: Vec<u32> = rules.order.iter().copied().filter(|_| rng.coin()).collect();
+            prop_assert!(rules.is_ordered(&rules.sorted(&update)));
+            prop_assert_eq!(rules.text().lines().count(), rules.order.len() * (rules.order.len() - 1) / 2);
+        }
+    }
+}
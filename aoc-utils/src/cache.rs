@@ -0,0 +1,130 @@
+
+/// Answer computed for one part of a day and a specific input
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CachedAnswer {
+    /// Day of the challenge
+    pub day: u8,
+    /// Part of the challenge (1 or 2)
+    pub part: u8,
+    /// [`input_hash`] of the puzzle input the answer was computed for
+    pub input_hash: String,
+    /// Answer formatted as a string
+    pub answer: String,
+}
+
+/// Previously computed answers keyed by day, part and input hash
+/// 
+/// Answers are kept sorted by their key, so the serialized cache
+/// does not depend on the order the days were solved in
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{input_hash, AnswerCache, CachedAnswer};
+/// 
+/// let hash = input_hash("3   4\n4   3\n");
+/// let mut cache = AnswerCache::default();
+/// cache.insert(CachedAnswer { day: 1, part: 1, input_hash: hash.clone(), answer: "2".to_string() });
+/// assert_eq!(cache.get(1, 1, &hash), Some("2"));
+/// assert_eq!(cache.get(1, 1, &input_hash("3   4\n")), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnswerCache {
+    /// Cached answers
+    pub answers: Vec<CachedAnswer>,
+}
+
+impl AnswerCache {
+    /// Cached answer for the part and input, if there is one
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        self.position(day, part, input_hash)
+        .ok()
+        .map(|i| self.answers[i].answer.as_str())
+    }
+
+    /// Store an answer, returning the one it replaced
+    pub fn insert(&mut self, answer: CachedAnswer) -> Option<String> {
+        match self.position(answer.day, answer.part, &answer.input_hash) {
+            Ok(i) => Some(std::mem::replace(&mut self.answers[i], answer).answer),
+            Err(i) => {
+                self.answers.insert(i, answer);
+                None
+            },
+        }
+    }
+
+    fn position(&self, day: u8, part: u8, input_hash: &str) -> Result<usize, usize> {
+        self.answers.binary_search_by(|a| (a.day, a.part, a.input_hash.as_str()).cmp(&(day, part, input_hash)))
+    }
+}
+
+/// Hash identifying a puzzle input
+/// 
+/// 64 bit FNV-1a written as 16 hex digits. Unlike the standard library
+/// hashers it is stable between Rust releases, so cached answers stay valid.
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc_utils::input_hash(""), "cbf29ce484222325");
+/// assert_ne!(aoc_utils::input_hash("1   2\n"), aoc_utils::input_hash("1   3\n"));
+/// ```
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(day: u8, part: u8, input: &str, answer: &str) -> CachedAnswer {
+        CachedAnswer { day, part, input_hash: input_hash(input), answer: answer.to_string() }
+    }
+
+    #[test]
+    fn test_insert_replaces() {
+        let mut cache = AnswerCache::default();
+        assert_eq!(cache.insert(answer(1, 1, "a", "11")), None);
+        assert_eq!(cache.insert(answer(1, 1, "a", "12")), Some("11".to_string()));
+        assert_eq!(cache.answers.len(), 1);
+        assert_eq!(cache.get(1, 1, &input_hash("a")), Some("12"));
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut cache = AnswerCache::default();
+        cache.insert(answer(2, 1, "a", "2"));
+        cache.insert(answer(1, 2, "a", "31"));
+        cache.insert(answer(1, 1, "a", "11"));
+        let keys: Vec<(u8, u8)> = cache.answers.iter().map(|a| (a.day, a.part)).collect();
+        assert_eq!(keys, vec![(1, 1), (1, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_get_other_input() {
+        let mut cache = AnswerCache::default();
+        cache.insert(answer(1, 1, "3   4\n", "1"));
+        assert_eq!(cache.get(1, 1, &input_hash("3   5\n")), None);
+        assert_eq!(cache.get(1, 2, &input_hash("3   4\n")), None);
+    }
+
+    #[test]
+    fn test_input_hash() {
+        // published FNV-1a 64 test vector
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> anyhow::Result<()> {
+        let mut cache = AnswerCache::default();
+        cache.insert(answer(1, 1, "a", "11"));
+        let json = serde_json::to_string(&cache)?;
+        assert_eq!(json, r#"{"answers":[{"day":1,"part":1,"input_hash":"af63dc4c8601ec8c","answer":"11"}]}"#);
+        assert_eq!(serde_json::from_str::<AnswerCache>(&json)?, cache);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+use thiserror::Error;
+
+/// Error returned by a solve that was stopped with a [`CancelToken`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("solve was cancelled")]
+pub struct Cancelled;
+
+/// Shared flag asking a running solve to stop
+/// 
+/// Clones share the same flag, so the token can be handed to a solve
+/// and cancelled from another thread or a signal handler. Solvers check it
+/// with [`CancelToken::check`] or, inside hot loops, [`CancelToken::poll`].
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{CancelToken, Cancelled};
+/// 
+/// let token = CancelToken::new();
+/// let handle = token.clone();
+/// assert_eq!(token.check(), Ok(()));
+/// handle.cancel();
+/// assert_eq!(token.check(), Err(Cancelled));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Number of items [`CancelToken::poll`] lets through between two checks
+    pub const INTERVAL: usize = 1024;
+
+    /// Token that is not cancelled yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every solve holding a clone of the token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`CancelToken::cancel`] was called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// [`Cancelled`] once the token is cancelled
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(()),
+        }
+    }
+
+    /// Same as [`CancelToken::check`], but only looks at the flag
+    /// for every [`CancelToken::INTERVAL`]th item
+    pub fn poll(&self, item: usize) -> Result<(), Cancelled> {
+        match item % Self::INTERVAL {
+            0 => self.check(),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_interval() {
+        let token = CancelToken::new();
+        token.cancel();
+        assert_eq!(token.poll(1), Ok(()));
+        assert_eq!(token.poll(CancelToken::INTERVAL - 1), Ok(()));
+        assert_eq!(token.poll(0), Err(Cancelled));
+        assert_eq!(token.poll(CancelToken::INTERVAL * 3), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_cancel_from_thread() {
+        let token = CancelToken::new();
+        let handle = token.clone();
+        std::thread::spawn(move || handle.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+}
@@ -0,0 +1,106 @@
+use std::io::{BufRead, Cursor};
+use crate::{InputError, NormalizingReader};
+
+/// Text source behind [`InputSource::Clipboard`](crate::InputSource::Clipboard)
+/// 
+/// [`SystemClipboard`] reads the clipboard of the desktop session,
+/// tests implement it with a fixed text so they need no display server.
+pub trait Clipboard {
+    /// Text on the clipboard, `None` when it holds no text
+    fn text(&mut self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Clipboard of the desktop session, read with arboard
+pub struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn text(&mut self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+        match arboard::Clipboard::new()?.get_text() {
+            Ok(text) => Ok(Some(text)),
+            Err(arboard::Error::ContentNotAvailable) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Buffered reader over the clipboard text
+/// 
+/// Line endings are normalized like in files, a clipboard holding
+/// only whitespace counts as empty.
+/// 
+/// # Examples
+/// ```
+/// use std::io::Read;
+/// use aoc_utils::{clipboard_reader, Clipboard};
+/// 
+/// struct Copied(&'static str);
+/// 
+/// impl Clipboard for Copied {
+///     fn text(&mut self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+///         Ok(Some(self.0.to_string()))
+///     }
+/// }
+/// 
+/// let mut text = String::new();
+/// clipboard_reader(&mut Copied("3   4\r\n4   3\r\n")).unwrap().read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "3   4\n4   3\n");
+/// ```
+pub fn clipboard_reader(clipboard: &mut dyn Clipboard) -> Result<Box<dyn BufRead>, InputError> {
+    let text = clipboard.text()
+    .map_err(|source| InputError::Clipboard { source })?
+    .filter(|text| !text.trim().is_empty())
+    .ok_or(InputError::EmptyClipboard)?;
+    Ok(Box::new(NormalizingReader::new(Cursor::new(text.into_bytes()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use crate::error_chain;
+    use super::*;
+
+    enum Fake {
+        Text(&'static str),
+        NoText,
+        Unavailable,
+    }
+
+    impl Clipboard for Fake {
+        fn text(&mut self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+            match self {
+                Fake::Text(text) => Ok(Some(text.to_string())),
+                Fake::NoText => Ok(None),
+                Fake::Unavailable => Err("no display server".into()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_clipboard_reader_crlf() -> anyhow::Result<()> {
+        let mut text = String::new();
+        clipboard_reader(&mut Fake::Text("\u{feff}1   2\r\n3   4\r\n"))?.read_to_string(&mut text)?;
+        assert_eq!(text, "1   2\n3   4\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_clipboard_reader_empty() {
+        for mut clipboard in [Fake::NoText, Fake::Text(""), Fake::Text(" \r\n")] {
+            let e = clipboard_reader(&mut clipboard).err().unwrap();
+            assert!(matches!(e, InputError::EmptyClipboard));
+            assert_eq!(e.to_string(), "the clipboard is empty, copy the puzzle input first");
+        }
+    }
+
+    #[test]
+    fn test_clipboard_reader_unavailable() {
+        let e = clipboard_reader(&mut Fake::Unavailable).err().unwrap();
+        assert!(matches!(e, InputError::Clipboard { .. }));
+        assert_eq!(error_chain(&e), "could not read the clipboard: no display server");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(crate::InputSource::Clipboard.to_string(), "clipboard");
+    }
+}
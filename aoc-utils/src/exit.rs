@@ -0,0 +1,170 @@
+use std::{error::Error, fmt, num::ParseIntError, process::ExitCode};
+use crate::{Cancelled, GridError, ParseError, SolutionError};
+
+/// Category of a failure, deciding the exit code of the runner and the day binaries
+/// 
+/// Scripts can tell the failures apart by the exit code, `0` is a success:
+/// 
+/// | Kind | Code |
+/// |------|------|
+/// | [`ErrorKind::Other`] | 1 |
+/// | [`ErrorKind::InputNotFound`] | 2 |
+/// | [`ErrorKind::Parse`] | 3 |
+/// | [`ErrorKind::Solver`] | 4 |
+/// | [`ErrorKind::Timeout`] | 5 |
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{ErrorKind, ParseError};
+/// 
+/// let e = ParseError::<std::convert::Infallible>::Columns { line: 1, expected: 2, found: 1 };
+/// let kind = ErrorKind::of(&e, |_| None);
+/// assert_eq!((kind, kind.code(), kind.to_string()), (ErrorKind::Parse, 3, "parse".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ErrorKind {
+    /// Any failure without a category of its own, such as a bad argument
+    Other,
+    /// The puzzle input does not exist
+    InputNotFound,
+    /// The puzzle input is malformed
+    Parse,
+    /// Solving a well formed input failed, e.g. an answer overflowed
+    Solver,
+    /// The solve timed out or was cancelled
+    Timeout,
+}
+
+impl ErrorKind {
+    /// Exit code of the process failing with this kind
+    pub fn code(self) -> u8 {
+        match self {
+            ErrorKind::Other => 1,
+            ErrorKind::InputNotFound => 2,
+            ErrorKind::Parse => 3,
+            ErrorKind::Solver => 4,
+            ErrorKind::Timeout => 5,
+        }
+    }
+
+    /// Kind of a failed I/O operation while reading the input
+    /// 
+    /// A missing file is [`ErrorKind::InputNotFound`] and text that is
+    /// not valid UTF-8 is [`ErrorKind::Parse`]
+    pub fn io(e: &std::io::Error) -> ErrorKind {
+        match e.kind() {
+            std::io::ErrorKind::NotFound => ErrorKind::InputNotFound,
+            std::io::ErrorKind::InvalidData => ErrorKind::Parse,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Kind of the first error in the chain of `e` that has one, [`ErrorKind::Other`] when none does
+    /// 
+    /// `day` categorizes the day specific errors, including the ones
+    /// wrapped in [`SolutionError::Day`], the shared error types of
+    /// this crate are categorized here
+    pub fn of(e: &(dyn Error + 'static), day: impl Fn(&(dyn Error + 'static)) -> Option<ErrorKind>) -> ErrorKind {
+        let mut source = Some(e);
+        while let Some(e) = source {
+            let kind = match e.downcast_ref::<SolutionError>() {
+                Some(SolutionError::Day(e)) => day(e.as_ref()).or_else(|| shared(e.as_ref())),
+                Some(e) => e.kind(),
+                None => day(e).or_else(|| shared(e)),
+            };
+            if let Some(kind) = kind {
+                return kind;
+            }
+            source = e.source();
+        }
+        ErrorKind::Other
+    }
+}
+
+/// Kind of the error types shared by the days
+fn shared(e: &(dyn Error + 'static)) -> Option<ErrorKind> {
+    if e.is::<Cancelled>() {
+        Some(ErrorKind::Timeout)
+    } else if e.is::<ParseError>() || e.is::<ParseError<ParseIntError>>() || e.is::<GridError>() {
+        Some(ErrorKind::Parse)
+    } else {
+        #[cfg(feature = "fs")]
+        if let Some(e) = e.downcast_ref::<crate::InputError>() {
+            return Some(e.kind());
+        }
+        e.downcast_ref::<std::io::Error>().map(ErrorKind::io)
+    }
+}
+
+impl SolutionError {
+    /// Category of the error, `None` for [`SolutionError::Day`] whose day error decides it
+    pub fn kind(&self) -> Option<ErrorKind> {
+        match self {
+            SolutionError::Cancelled => Some(ErrorKind::Timeout),
+            SolutionError::NotImplemented { .. } | SolutionError::NotSupported { .. }
+            | SolutionError::InvalidSelector { .. } => Some(ErrorKind::Other),
+            SolutionError::Day(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Other => write!(f, "other"),
+            ErrorKind::InputNotFound => write!(f, "input_not_found"),
+            ErrorKind::Parse => write!(f, "parse"),
+            ErrorKind::Solver => write!(f, "solver"),
+            ErrorKind::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+impl From<ErrorKind> for ExitCode {
+    fn from(kind: ErrorKind) -> Self {
+        ExitCode::from(kind.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes() {
+        let kinds = [ErrorKind::Other, ErrorKind::InputNotFound, ErrorKind::Parse, ErrorKind::Solver, ErrorKind::Timeout];
+        assert_eq!(kinds.map(ErrorKind::code), [1, 2, 3, 4, 5]);
+        assert_eq!(kinds.map(|kind| kind.to_string()), ["other", "input_not_found", "parse", "solver", "timeout"]);
+    }
+
+    #[test]
+    fn test_of_shared() {
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert_eq!(ErrorKind::of(&missing, |_| None), ErrorKind::InputNotFound);
+        assert_eq!(ErrorKind::of(&SolutionError::Cancelled, |_| None), ErrorKind::Timeout);
+        let grid = GridError::Ragged { row: 1, expected: 2, found: 1 };
+        assert_eq!(ErrorKind::of(&SolutionError::Day(Box::new(grid)), |_| None), ErrorKind::Parse);
+        assert_eq!(ErrorKind::of(&std::fmt::Error, |_| None), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_of_day() {
+        let e = SolutionError::Day(Box::new(std::fmt::Error));
+        let day = |e: &(dyn Error + 'static)| e.is::<std::fmt::Error>().then_some(ErrorKind::Solver);
+        assert_eq!(ErrorKind::of(&e, day), ErrorKind::Solver);
+        assert_eq!(ErrorKind::of(&SolutionError::NotImplemented { day: 1, part: 2 }, day), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_of_source() {
+        // the parse error is the source of the day error
+        let source = "x".parse::<i32>().unwrap_err();
+        let e: ParseError<ParseIntError> = ParseError::Token { line: 1, column: 1, token: "x".to_string(), source };
+        #[derive(Debug, thiserror::Error)]
+        #[error("failed")]
+        struct Wrapper(#[source] ParseError<ParseIntError>);
+        assert_eq!(ErrorKind::of(&Wrapper(e), |_| None), ErrorKind::Parse);
+    }
+}
@@ -0,0 +1,671 @@
+use std::{fmt, ops::{Index, IndexMut}, str::FromStr};
+use thiserror::Error;
+use crate::Location;
+
+/// Position in a [`Grid`] as `(row, column)`
+pub type Pos = (usize, usize);
+
+/// Errors returned when building a [`Grid`]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GridError {
+    /// A row has a different length than the first one
+    #[error("row {row} has {found} cells, expected {expected}")]
+    Ragged {
+        /// Index of the offending row
+        row: usize,
+        /// Length of the first row
+        expected: usize,
+        /// Length of the offending row
+        found: usize,
+    },
+}
+
+impl GridError {
+    /// Line of the input holding the offending row
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            GridError::Ragged { row, .. } => Some(Location::Line(row + 1)),
+        }
+    }
+}
+
+/// Errors returned by [`Grid::parse_maze`]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MazeError {
+    /// The rows of the maze have different lengths
+    #[error(transparent)]
+    Grid(#[from] GridError),
+    /// Tile other than `.`, `#`, `S` and `E`
+    #[error("unexpected {tile} on line {line}, column {column}")]
+    UnknownTile {
+        /// Line of the tile, starting at 1
+        line: usize,
+        /// Column of the tile, starting at 1
+        column: usize,
+        /// The unexpected tile
+        tile: char,
+    },
+    /// The maze has no start or no end
+    #[error("the maze has no {tile}")]
+    MissingTile {
+        /// `S` or `E`
+        tile: char,
+    },
+    /// Second start or end
+    #[error("second {tile} on line {line}, column {column}")]
+    ExtraTile {
+        /// Line of the tile, starting at 1
+        line: usize,
+        /// Column of the tile, starting at 1
+        column: usize,
+        /// `S` or `E`
+        tile: char,
+    },
+}
+
+/// Rectangular 2-D grid stored row by row
+/// 
+/// Positions are `(row, column)` pairs starting at the top left corner.
+/// Accessors come in a bounds-checked flavour returning [`Option`]
+/// ([`get`](Grid::get), [`get_mut`](Grid::get_mut)) and an unchecked one
+/// (indexing with `grid[(row, col)]`, which panics when out of bounds, and
+/// the `unsafe` [`get_unchecked`](Grid::get_unchecked)).
+/// 
+/// With the `serde` feature the grid is serialized as a list of rows and
+/// ragged rows are rejected when deserializing.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::Grid;
+/// 
+/// let grid: Grid<char> = "XMA\nSAM".parse().unwrap();
+/// assert_eq!((grid.height(), grid.width()), (2, 3));
+/// assert_eq!(grid.get(1, 2), Some(&'M'));
+/// assert_eq!(grid.get(2, 0), None);
+/// assert_eq!(grid[(0, 1)], 'M');
+/// assert_eq!(grid.find(|&c| c == 'S'), Some((1, 0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Vec<Vec<T>>", try_from = "Vec<Vec<T>>",
+    bound(serialize = "T: Clone + serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given size with every cell set to `value`
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Build a grid from its rows
+    /// 
+    /// Every row must have the same length as the first one
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::{Grid, GridError};
+    /// 
+    /// let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(grid[(1, 0)], 3);
+    /// 
+    /// let ragged = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(ragged, Err(GridError::Ragged { row: 1, expected: 2, found: 1 }));
+    /// ```
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Self, GridError>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (row, values) in rows.into_iter().enumerate() {
+            let before = cells.len();
+            cells.extend(values);
+            let found = cells.len() - before;
+            if row == 0 {
+                width = found;
+            } else if found != width {
+                return Err(GridError::Ragged { row, expected: width, found });
+            }
+            height += 1;
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Build a grid from text, converting each character with `f`
+    /// 
+    /// Every line becomes a row, a trailing line break is ignored
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid = Grid::parse_with("12\n34\n", |c| c.to_digit(10).unwrap()).unwrap();
+    /// assert_eq!(grid[(1, 1)], 4);
+    /// ```
+    pub fn parse_with(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Grid::from_rows(s.lines().map(|line| line.chars().map(&mut f).collect::<Vec<_>>()))
+    }
+
+    /// Number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the grid has no cells
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Whether the position lies inside the grid
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// Cell at the position, `None` when out of bounds
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.contains(row, col) {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Mutable cell at the position, `None` when out of bounds
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Cell at the position without bounds checking
+    /// 
+    /// # Safety
+    /// 
+    /// `row` must be less than [`height`](Grid::height) and `col` less
+    /// than [`width`](Grid::width)
+    pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
+        self.cells.get_unchecked(row * self.width + col)
+    }
+
+    /// Mutable cell at the position without bounds checking
+    /// 
+    /// # Safety
+    /// 
+    /// `row` must be less than [`height`](Grid::height) and `col` less
+    /// than [`width`](Grid::width)
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
+        self.cells.get_unchecked_mut(row * self.width + col)
+    }
+
+    /// Cell at the position moved by `(d_row, d_col)`, `None` when out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// assert_eq!(grid.offset((0, 0), (1, 1)), Some(((1, 1), &'d')));
+    /// assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    /// ```
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<(Pos, &T)> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        self.get(row, col).map(|value| ((row, col), value))
+    }
+
+    /// Iterate over the rows as slices
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // chunks panics on a zero size, an empty row never has cells anyway
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Iterate over the columns, each column from top to bottom
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |col| (0..self.height).map(move |row| &self[(row, col)]))
+    }
+
+    /// Iterate over the diagonals going down and to the right
+    /// 
+    /// Diagonals start from the bottom left corner and end in the top right one
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let diagonals: Vec<String> = grid.diagonals().map(|d| d.collect()).collect();
+    /// assert_eq!(diagonals, vec!["c", "ad", "b"]);
+    /// ```
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let starts = (0..self.height).rev().map(|row| (row, 0))
+        .chain((1..self.width).map(|col| (0, col)));
+        starts.map(move |(row, col)| {
+            (0..).map(move |i| (row + i, col + i))
+            .take_while(move |&(r, c)| self.contains(r, c))
+            .map(move |(r, c)| &self[(r, c)])
+        })
+    }
+
+    /// Iterate over the diagonals going down and to the left
+    /// 
+    /// Anti-diagonals start from the top left corner and end in the bottom right one
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "ab\ncd".parse().unwrap();
+    /// let diagonals: Vec<String> = grid.anti_diagonals().map(|d| d.collect()).collect();
+    /// assert_eq!(diagonals, vec!["a", "bc", "d"]);
+    /// ```
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        let last_col = self.width.saturating_sub(1);
+        let starts = (0..self.width).map(|col| (0, col))
+        .chain((1..self.height).map(move |row| (row, last_col)));
+        starts.map(move |(row, col)| {
+            (0..=col).map(move |i| (row + i, col - i))
+            .take_while(move |&(r, c)| self.contains(r, c))
+            .map(move |(r, c)| &self[(r, c)])
+        })
+    }
+
+    /// Iterate over every position and cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.cells.iter().enumerate().map(move |(i, value)| ((i / self.width, i % self.width), value))
+    }
+
+    /// Position of the first cell (row by row) matching the predicate
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, value)| predicate(value)).map(|(pos, _)| pos)
+    }
+
+    /// In-bounds positions above, right, below and left of `pos`
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid = Grid::new(3, 3, 0);
+    /// assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    /// assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    /// ```
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+        OFFSETS.into_iter().filter_map(move |d| self.offset(pos, d).map(|(p, _)| p))
+    }
+
+    /// In-bounds positions around `pos`, diagonals included
+    /// 
+    /// Positions are returned clockwise starting from the one above
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid = Grid::new(3, 3, 0);
+    /// assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 1), (1, 0)]);
+    /// assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    /// ```
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+        OFFSETS.into_iter().filter_map(move |d| self.offset(pos, d).map(|(p, _)| p))
+    }
+
+    /// Convert every cell with `f`, keeping the shape
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Grid mirrored along the main diagonal, rows become columns
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    /// ```
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.width, self.height, |row, col| (col, row))
+    }
+
+    /// Grid rotated by 90 degrees clockwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+    /// ```
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.width, self.height, |row, col| (self.height - 1 - col, row))
+    }
+
+    /// Grid rotated by 90 degrees counterclockwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::Grid;
+    /// 
+    /// let grid: Grid<char> = "abc\ndef".parse().unwrap();
+    /// assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    /// ```
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.width, self.height, |row, col| (col, self.width - 1 - row))
+    }
+
+    /// New grid of the given size where each cell `(row, col)` is copied
+    /// from `source(row, col)` of this grid
+    fn rebuild(&self, height: usize, width: usize, source: impl Fn(usize, usize) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(self[source(row, col)].clone());
+            }
+        }
+        Grid { width, height, cells }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(self.contains(row, col), "position ({}, {}) is outside of the {}x{} grid", row, col, self.height, self.width);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(self.contains(row, col), "position ({}, {}) is outside of the {}x{} grid", row, col, self.height, self.width);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl Grid<bool> {
+    /// Parse a maze of walls `#` and open tiles `.` with one start `S` and one end `E`
+    /// 
+    /// Returns the grid, `true` where there is a wall, with the positions
+    /// of the start and the end, which are both open tiles
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::{Grid, MazeError};
+    /// 
+    /// let (walls, start, end) = Grid::parse_maze("#####\n#S.E#\n#####\n").unwrap();
+    /// assert!(walls[(0, 2)] && !walls[start]);
+    /// assert_eq!((start, end), ((1, 1), (1, 3)));
+    /// 
+    /// assert_eq!(Grid::parse_maze("S.\n.."), Err(MazeError::MissingTile { tile: 'E' }));
+    /// ```
+    pub fn parse_maze(text: &str) -> Result<(Self, Pos, Pos), MazeError> {
+        let (mut start, mut end) = (None, None);
+        // rows stop at the first failure, which is returned once the grid is built
+        let mut failure = None;
+        let rows = text.lines().enumerate().map_while(|(row, line)| {
+            let mut tiles = Vec::with_capacity(line.len());
+            for (col, tile) in line.chars().enumerate() {
+                let (line, column) = (row + 1, col + 1);
+                let found = match tile {
+                    '.' | '#' => {
+                        tiles.push(tile == '#');
+                        continue;
+                    },
+                    'S' => &mut start,
+                    'E' => &mut end,
+                    _ => {
+                        failure = Some(MazeError::UnknownTile { line, column, tile });
+                        return None;
+                    },
+                };
+                if found.is_some() {
+                    failure = Some(MazeError::ExtraTile { line, column, tile });
+                    return None;
+                }
+                *found = Some((row, col));
+                tiles.push(false);
+            }
+            Some(tiles)
+        });
+        let walls = Grid::from_rows(rows)?;
+        if let Some(e) = failure {
+            return Err(e);
+        }
+        let start = start.ok_or(MazeError::MissingTile { tile: 'S' })?;
+        let end = end.ok_or(MazeError::MissingTile { tile: 'E' })?;
+        Ok((walls, start, end))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| c)
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = GridError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        let mut cells = grid.cells.into_iter();
+        (0..grid.height).map(|_| cells.by_ref().take(grid.width).collect()).collect()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    fn strings<'a, I: Iterator<Item = &'a char>>(lines: impl Iterator<Item = I>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_from_str_success() {
+        let g = grid("abc\ndef\n");
+        assert_eq!((g.height(), g.width()), (2, 3));
+        assert_eq!(g[(1, 2)], 'f');
+        assert_eq!(g.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_from_str_negative() {
+        assert_eq!("abc\nde".parse::<Grid<char>>(), Err(GridError::Ragged { row: 1, expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn test_empty() {
+        let g = grid("");
+        assert!(g.is_empty());
+        assert_eq!((g.height(), g.width()), (0, 0));
+        assert_eq!(g.rows().count(), 0);
+        assert_eq!(g.columns().count(), 0);
+        assert_eq!(g.diagonals().count(), 0);
+        assert_eq!(g.anti_diagonals().count(), 0);
+        assert_eq!(g.get(0, 0), None);
+    }
+
+    #[test]
+    fn test_get_success() {
+        let mut g = grid("ab\ncd");
+        assert_eq!(g.get(0, 1), Some(&'b'));
+        *g.get_mut(1, 0).unwrap() = 'x';
+        g[(1, 1)] = 'y';
+        assert_eq!(g.to_string(), "ab\nxy\n");
+        assert_eq!(unsafe { *g.get_unchecked(1, 1) }, 'y');
+        unsafe { *g.get_unchecked_mut(0, 0) = 'z' };
+        assert_eq!(g[(0, 0)], 'z');
+    }
+
+    #[test]
+    fn test_get_negative() {
+        let mut g = grid("ab\ncd");
+        assert_eq!(g.get(2, 0), None);
+        assert_eq!(g.get(0, 2), None);
+        assert_eq!(g.get_mut(5, 5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 2x2 grid")]
+    fn test_index_negative() {
+        // (0, 2) would be (1, 0) without the column check
+        let _ = grid("ab\ncd")[(0, 2)];
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let g = grid("abc\ndef");
+        let rows: Vec<String> = g.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(strings(g.columns()), vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let g = grid("abc\ndef");
+        assert_eq!(strings(g.diagonals()), vec!["d", "ae", "bf", "c"]);
+        assert_eq!(strings(g.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_single_row() {
+        let g = grid("abcd");
+        assert_eq!((g.height(), g.width()), (1, 4));
+        assert_eq!(strings(g.columns()), vec!["a", "b", "c", "d"]);
+        assert_eq!(strings(g.diagonals()), vec!["a", "b", "c", "d"]);
+        assert_eq!(strings(g.anti_diagonals()), vec!["a", "b", "c", "d"]);
+        assert_eq!(g.neighbors4((0, 1)).collect::<Vec<_>>(), vec![(0, 2), (0, 0)]);
+        assert_eq!(g.neighbors8((0, 3)).collect::<Vec<_>>(), vec![(0, 2)]);
+        assert_eq!(g.transpose().to_string(), "a\nb\nc\nd\n");
+        assert_eq!(g.rotate_clockwise().to_string(), "a\nb\nc\nd\n");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "d\nc\nb\na\n");
+    }
+
+    #[test]
+    fn test_single_column() {
+        let g = grid("a\nb\nc");
+        assert_eq!((g.height(), g.width()), (3, 1));
+        let rows: Vec<String> = g.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["a", "b", "c"]);
+        assert_eq!(strings(g.columns()), vec!["abc"]);
+        assert_eq!(strings(g.diagonals()), vec!["c", "b", "a"]);
+        assert_eq!(strings(g.anti_diagonals()), vec!["a", "b", "c"]);
+        assert_eq!(g.neighbors4((1, 0)).collect::<Vec<_>>(), vec![(0, 0), (2, 0)]);
+        assert_eq!(g.transpose().to_string(), "abc\n");
+        assert_eq!(g.rotate_clockwise().to_string(), "cba\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let g = Grid::new(3, 4, 0u8);
+        assert_eq!(g.neighbors4((1, 1)).collect::<Vec<_>>(), vec![(0, 1), (1, 2), (2, 1), (1, 0)]);
+        assert_eq!(g.neighbors8((2, 3)).collect::<Vec<_>>(), vec![(1, 3), (2, 2), (1, 2)]);
+        assert_eq!(g.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_find() {
+        let g = grid("..#\n#..");
+        assert_eq!(g.find(|&c| c == '#'), Some((0, 2)));
+        assert_eq!(g.find(|&c| c == '@'), None);
+        assert_eq!(g.iter().filter(|(_, &c)| c == '#').map(|(p, _)| p).collect::<Vec<_>>(), vec![(0, 2), (1, 0)]);
+    }
+
+    #[test]
+    fn test_rotate_round_trip() {
+        let g = grid("abc\ndef");
+        let full_turn = g.rotate_clockwise().rotate_clockwise().rotate_clockwise().rotate_clockwise();
+        assert_eq!(full_turn, g);
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+        assert_eq!(g.transpose().transpose(), g);
+    }
+
+    #[test]
+    fn test_map_and_parse_with() {
+        let g = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(g.map(|v| v * 10)[(1, 0)], 30);
+    }
+
+    #[test]
+    fn test_rows_conversion() {
+        let rows: Vec<Vec<char>> = grid("ab\ncd").into();
+        assert_eq!(rows, vec![vec!['a', 'b'], vec!['c', 'd']]);
+        assert_eq!(Grid::try_from(rows), Ok(grid("ab\ncd")));
+        // rows without cells keep the height
+        let empty_rows = Grid::<u8>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!(Vec::from(empty_rows), vec![Vec::<u8>::new(), vec![]]);
+    }
+
+    #[test]
+    fn test_parse_maze_negative() {
+        assert_eq!(Grid::parse_maze("S.\n.x\n.E\n"), Err(MazeError::UnknownTile { line: 2, column: 2, tile: 'x' }));
+        assert_eq!(Grid::parse_maze("SE\nE.\n"), Err(MazeError::ExtraTile { line: 2, column: 1, tile: 'E' }));
+        assert_eq!(Grid::parse_maze(".E\n"), Err(MazeError::MissingTile { tile: 'S' }));
+        assert!(matches!(Grid::parse_maze("SE\n.\n"), Err(MazeError::Grid(GridError::Ragged { row: 1, .. }))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> anyhow::Result<()> {
+        let g = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap())?;
+        let json = serde_json::to_string(&g)?;
+        assert_eq!(json, "[[1,2],[3,4]]");
+        assert_eq!(serde_json::from_str::<Grid<u32>>(&json)?, g);
+        let e = serde_json::from_str::<Grid<u32>>("[[1,2],[3]]").unwrap_err();
+        assert_eq!(e.to_string(), "row 1 has 1 cells, expected 2");
+        Ok(())
+    }
+}
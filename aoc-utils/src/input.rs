@@ -0,0 +1,389 @@
+use std::{convert::Infallible, fmt, fs::File, io::{self, BufRead, BufReader, Cursor, Read}, path::{Path, PathBuf}, str::FromStr};
+use thiserror::Error;
+use crate::{ErrorKind, NormalizingReader};
+
+/// Errors returned when reading an [`InputSource`]
+#[derive(Debug, Error)]
+pub enum InputError {
+    /// The input could not be opened or read
+    #[error("could not read {input}")]
+    Io {
+        /// Description of the input, see [`InputSource`]'s `Display`
+        input: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The input is compressed with a format this build cannot read
+    #[error("{input} is {format} compressed, enable the {feature} feature of aoc-utils to read it")]
+    Unsupported {
+        /// Description of the input, see [`InputSource`]'s `Display`
+        input: String,
+        /// Detected compression format
+        format: Compression,
+        /// Feature adding support for the format
+        feature: &'static str,
+    },
+    /// The compressed input could not be decoded
+    #[error("corrupt {format} data in {input}")]
+    Corrupt {
+        /// Description of the input, see [`InputSource`]'s `Display`
+        input: String,
+        /// Detected compression format
+        format: Compression,
+        /// Underlying decoding error
+        source: std::io::Error,
+    },
+    /// The clipboard could not be read, e.g. without a display server
+    #[cfg(feature = "clipboard")]
+    #[error("could not read the clipboard")]
+    Clipboard {
+        /// Underlying clipboard error
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The clipboard holds no text
+    #[cfg(feature = "clipboard")]
+    #[error("the clipboard is empty, copy the puzzle input first")]
+    EmptyClipboard,
+}
+
+impl InputError {
+    /// Category of the error, see [`ErrorKind`]
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            InputError::Io { source, .. } => ErrorKind::io(source),
+            InputError::Corrupt { .. } => ErrorKind::Parse,
+            #[cfg(feature = "clipboard")]
+            InputError::EmptyClipboard => ErrorKind::InputNotFound,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Compression formats recognised by [`open_buffered`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// gzip, `.gz` files
+    Gzip,
+    /// Zstandard, `.zst` files
+    Zstd,
+}
+
+impl Compression {
+    /// Detect the format from the file extension or the first bytes of the file
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::path::Path;
+    /// use aoc_utils::Compression;
+    /// 
+    /// assert_eq!(Compression::detect(Path::new("input.txt.gz"), b""), Some(Compression::Gzip));
+    /// assert_eq!(Compression::detect(Path::new("input"), &[0x28, 0xb5, 0x2f, 0xfd]), Some(Compression::Zstd));
+    /// assert_eq!(Compression::detect(Path::new("input.txt"), b"3   4"), None);
+    /// ```
+    pub fn detect(path: &Path, magic: &[u8]) -> Option<Compression> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gz") => return Some(Compression::Gzip),
+            Some("zst" | "zstd") => return Some(Compression::Zstd),
+            _ => {},
+        }
+        match magic {
+            [0x1f, 0x8b, ..] => Some(Compression::Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compression::Gzip => write!(f, "gzip"),
+            Compression::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// Where the puzzle input comes from
+/// 
+/// Parsing a string gives [`InputSource::Stdin`] for `-` and
+/// [`InputSource::Path`] for anything else, so the type can be used
+/// directly as a command line argument.
+/// 
+/// # Examples
+/// ```
+/// use std::io::BufRead;
+/// use aoc_utils::InputSource;
+/// 
+/// assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+/// assert_eq!("data/input.txt".parse::<InputSource>().unwrap(),
+///     InputSource::Path("data/input.txt".into()));
+/// 
+/// let source = InputSource::Literal("3   4\n4   3\n".to_string());
+/// assert_eq!(source.reader().unwrap().lines().count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// File on disk
+    Path(PathBuf),
+    /// Standard input of the process
+    Stdin,
+    /// Input given as text
+    Literal(String),
+    /// Text on the system clipboard, see [`crate::clipboard_reader`]
+    #[cfg(feature = "clipboard")]
+    Clipboard,
+}
+
+impl InputSource {
+    /// Buffered reader over the input
+    /// 
+    /// Line endings and a leading byte order mark are normalized,
+    /// see [`NormalizingReader`]
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Path(path) => open_buffered(path),
+            InputSource::Stdin => Ok(Box::new(NormalizingReader::new(std::io::stdin().lock()))),
+            InputSource::Literal(text) => Ok(Box::new(NormalizingReader::new(Cursor::new(text.clone().into_bytes())))),
+            #[cfg(feature = "clipboard")]
+            InputSource::Clipboard => crate::clipboard_reader(&mut crate::SystemClipboard),
+        }
+    }
+
+    /// Whole input as text, normalized like [`InputSource::reader`]
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        let mut text = String::new();
+        self.reader()?
+        .read_to_string(&mut text)
+        .map_err(|source| InputError::Io { input: self.to_string(), source })?;
+        Ok(text)
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        InputSource::Path(path.to_path_buf())
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "file {}", path.display()),
+            InputSource::Stdin => write!(f, "standard input"),
+            InputSource::Literal(_) => write!(f, "literal input"),
+            #[cfg(feature = "clipboard")]
+            InputSource::Clipboard => write!(f, "clipboard"),
+        }
+    }
+}
+
+/// Open a file for buffered reading
+/// 
+/// gzip and zstd compressed files, recognised by [`Compression::detect`],
+/// are decompressed on the fly when the `flate2` and `zstd` features are enabled.
+/// The content is passed through a [`NormalizingReader`], use
+/// [`open_buffered_raw`] to read the bytes exactly as they are.
+/// 
+/// # Examples
+/// ```
+/// let result = aoc_utils::open_buffered(std::path::Path::new("missing.txt"));
+/// assert!(result.is_err_and(|e| e.to_string() == "could not read file missing.txt"));
+/// ```
+pub fn open_buffered(path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    Ok(Box::new(NormalizingReader::new(open_buffered_raw(path)?)))
+}
+
+/// Open a file for buffered reading without normalizing line endings
+/// 
+/// Compressed files are still decompressed like in [`open_buffered`]
+pub fn open_buffered_raw(path: &Path) -> Result<Box<dyn BufRead>, InputError> {
+    let input = InputSource::from(path).to_string();
+    let mut reader = File::open(path)
+    .map(BufReader::new)
+    .map_err(|source| InputError::Io { input: input.clone(), source })?;
+    let magic = reader.fill_buf()
+    .map_err(|source| InputError::Io { input: input.clone(), source })?;
+
+    let Some(format) = Compression::detect(path, magic) else {
+        return Ok(Box::new(reader));
+    };
+    let mut decoder = decoder(reader, format, &input)?;
+    // decode the first block right away so a corrupt header fails on open
+    let mut first = vec![0; 8 * 1024];
+    let read = decoder.read(&mut first)
+    .map_err(|source| InputError::Corrupt { input: input.clone(), format, source })?;
+    first.truncate(read);
+    let inner = Box::new(Cursor::new(first).chain(decoder));
+    Ok(Box::new(BufReader::new(Decoded { inner, input, format })))
+}
+
+/// Reader decompressing the file with the given format
+fn decoder(reader: BufReader<File>, format: Compression, input: &str) -> Result<Box<dyn Read>, InputError> {
+    match format {
+        #[cfg(feature = "flate2")]
+        Compression::Gzip => Ok(Box::new(flate2::bufread::MultiGzDecoder::new(reader))),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => zstd::Decoder::with_buffer(reader)
+        .map(|decoder| Box::new(decoder) as Box<dyn Read>)
+        .map_err(|source| InputError::Corrupt { input: input.to_string(), format, source }),
+        #[allow(unreachable_patterns)]
+        _ => {
+            drop(reader);
+            let feature = match format {
+                Compression::Gzip => "flate2",
+                Compression::Zstd => "zstd",
+            };
+            Err(InputError::Unsupported { input: input.to_string(), format, feature })
+        },
+    }
+}
+
+/// Decompressing reader naming the file in its errors
+/// 
+/// Decoding errors only show up while reading, by then the callers
+/// just report a failed line, so the error message carries the file
+struct Decoded {
+    inner: Box<dyn Read>,
+    input: String,
+    format: Compression,
+}
+
+impl Read for Decoded {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf).map_err(|e| {
+            io::Error::new(e.kind(), format!("corrupt {} data in {}: {}", self.format, self.input, e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::prelude::*;
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-".parse::<InputSource>(), Ok(InputSource::Stdin));
+        assert_eq!("./-".parse::<InputSource>(), Ok(InputSource::Path(PathBuf::from("./-"))));
+        assert_eq!("".parse::<InputSource>(), Ok(InputSource::Path(PathBuf::new())));
+    }
+
+    #[test]
+    fn test_read_to_string_literal() -> Result<(), InputError> {
+        let source = InputSource::Literal("a\nb\n".to_string());
+        assert_eq!(source.read_to_string()?, "a\nb\n");
+        assert_eq!(source.reader()?.lines().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_to_string_path() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str("1   2\n")?;
+        assert_eq!(InputSource::Path(file.path().to_path_buf()).read_to_string()?, "1   2\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_to_string_negative() {
+        let result = InputSource::Path(PathBuf::from("missing.txt")).read_to_string();
+        assert!(matches!(result, Err(InputError::Io { ref input, .. }) if input == "file missing.txt"));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InputNotFound);
+    }
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn test_open_buffered_gzip() -> anyhow::Result<()> {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"3   4\n4   3\n")?;
+        let compressed = encoder.finish()?;
+
+        // detected by the extension and by the magic bytes
+        for name in ["input.txt.gz", "input.txt"] {
+            let file = assert_fs::NamedTempFile::new(name)?;
+            file.write_binary(&compressed)?;
+            assert_eq!(InputSource::Path(file.path().to_path_buf()).read_to_string()?, "3   4\n4   3\n");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "zstd")]
+    fn test_open_buffered_zstd() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt.zst")?;
+        file.write_binary(&zstd::encode_all(&b"3   4\n4   3\n"[..], 0)?)?;
+        assert_eq!(InputSource::Path(file.path().to_path_buf()).read_to_string()?, "3   4\n4   3\n");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn test_open_buffered_corrupt_variant() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.gz")?;
+        file.write_str("3   4\n")?;
+        let e = open_buffered(file.path()).err().unwrap();
+        assert!(matches!(e, InputError::Corrupt { format: Compression::Gzip, .. }));
+        assert_eq!(e.kind(), ErrorKind::Parse);
+        assert_eq!(e.to_string(), format!("corrupt gzip data in file {}", file.path().display()));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn test_open_buffered_truncated() -> anyhow::Result<()> {
+        use std::io::Write;
+        let text = "12345   67890\n".repeat(10_000);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::none());
+        encoder.write_all(text.as_bytes())?;
+        let compressed = encoder.finish()?;
+        let file = assert_fs::NamedTempFile::new("input.gz")?;
+        file.write_binary(&compressed[..compressed.len() / 2])?;
+
+        let e = InputSource::Path(file.path().to_path_buf()).read_to_string().unwrap_err();
+        let source = std::error::Error::source(&e).unwrap().to_string();
+        assert!(source.starts_with(&format!("corrupt gzip data in file {}", file.path().display())), "{}", source);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(not(feature = "zstd"))]
+    fn test_open_buffered_unsupported_variant() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.zst")?;
+        file.write_binary(&[0x28, 0xb5, 0x2f, 0xfd])?;
+        let e = open_buffered(file.path()).err().unwrap();
+        assert!(matches!(e, InputError::Unsupported { format: Compression::Zstd, feature: "zstd", .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_to_string_crlf_bom() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str("\u{feff}1   2\r\n3   4\r\n")?;
+        let path = file.path().to_path_buf();
+        assert_eq!(InputSource::Path(path.clone()).read_to_string()?, "1   2\n3   4\n");
+        assert_eq!(InputSource::Literal("\u{feff}1\r\n".to_string()).read_to_string()?, "1\n");
+
+        let mut raw = String::new();
+        open_buffered_raw(&path)?.read_to_string(&mut raw)?;
+        assert_eq!(raw, "\u{feff}1   2\r\n3   4\r\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(InputSource::Stdin.to_string(), "standard input");
+        assert_eq!(InputSource::Literal("x".to_string()).to_string(), "literal input");
+    }
+}
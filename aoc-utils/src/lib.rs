@@ -0,0 +1,39 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 shared utilities
+//! 
+//! Types and helpers shared between the daily challenge crates
+//! and the `aoc` runner
+//! 
+//! [`Read more`](../../../README.md)
+
+mod cache;
+mod cancel;
+mod exit;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod grid;
+#[cfg(feature = "fs")]
+mod input;
+mod location;
+mod normalize;
+mod parse;
+mod progress;
+mod result;
+mod solution;
+mod stats;
+
+pub use cache::{input_hash, AnswerCache, CachedAnswer};
+pub use cancel::{CancelToken, Cancelled};
+pub use exit::ErrorKind;
+#[cfg(feature = "clipboard")]
+pub use clipboard::{clipboard_reader, Clipboard, SystemClipboard};
+pub use grid::{Grid, GridError, MazeError, Pos};
+#[cfg(feature = "fs")]
+pub use input::{open_buffered, open_buffered_raw, Compression, InputError, InputSource};
+pub use location::Location;
+pub use normalize::NormalizingReader;
+pub use parse::{parse_grid_lines, parse_lines, parse_two_columns, split_two_columns, ParseError, TwoColumns};
+pub use progress::{NoProgress, Progress, Ticker};
+pub use result::{AllocStats, SolveResult};
+pub use solution::{error_chain, ExpectedAnswers, Parsed, Result, Sample, Solution, SolutionError};
+pub use stats::{CharClasses, InputStats};
@@ -0,0 +1,94 @@
+use std::ops::Range;
+
+/// Place in the puzzle input an error points to
+/// 
+/// Errors with position data expose it through a `location` method, the
+/// runner turns it into a byte range with [`Location::find`] to show the
+/// offending part of the input.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::Location;
+/// 
+/// let input = "3   4\n4   x\n";
+/// assert_eq!(Location::Line(2).find(input), Some(6..11));
+/// assert_eq!(Location::Field { line: 2, field: 2 }.find(input), Some(10..11));
+/// assert_eq!(Location::Token("x".to_string()).find(input), Some(10..11));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// A whole line, numbered from 1
+    Line(usize),
+    /// A whitespace separated field of a line, both numbered from 1
+    Field {
+        /// Line number
+        line: usize,
+        /// Field number within the line
+        field: usize,
+    },
+    /// The first occurrence of a text, for errors that only keep the offending token
+    Token(String),
+}
+
+impl Location {
+    /// Byte range of the location in the input, `None` when it is not there
+    pub fn find(&self, input: &str) -> Option<Range<usize>> {
+        match self {
+            Location::Line(line) => line_range(input, *line),
+            Location::Field { line, field } => {
+                let range = line_range(input, *line)?;
+                let text = &input[range.clone()];
+                let (start, token) = text.split_whitespace()
+                .nth(field.checked_sub(1)?)
+                .map(|token| (token.as_ptr() as usize - text.as_ptr() as usize, token))?;
+                Some(range.start + start..range.start + start + token.len())
+            },
+            Location::Token(token) if token.is_empty() => None,
+            Location::Token(token) => input.find(token.as_str()).map(|start| start..start + token.len()),
+        }
+    }
+}
+
+/// Byte range of a line without its line break
+fn line_range(input: &str, line: usize) -> Option<Range<usize>> {
+    let mut start = 0;
+    for (i, text) in input.split_inclusive('\n').enumerate() {
+        if i + 1 == line {
+            let text = text.trim_end_matches(['\n', '\r']);
+            return Some(start..start + text.len());
+        }
+        start += text.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_line() {
+        let input = "47|53\n97\n\n75,47\n";
+        assert_eq!(Location::Line(1).find(input), Some(0..5));
+        assert_eq!(Location::Line(3).find(input), Some(9..9));
+        assert_eq!(Location::Line(4).find(input), Some(10..15));
+        assert_eq!(Location::Line(0).find(input), None);
+        assert_eq!(Location::Line(5).find(input), None);
+        assert_eq!(Location::Line(1).find("a\r\nb"), Some(0..1));
+    }
+
+    #[test]
+    fn test_find_field() {
+        let input = "1 2 3\n  4   five 6\n";
+        assert_eq!(Location::Field { line: 2, field: 2 }.find(input), Some(12..16));
+        assert_eq!(Location::Field { line: 2, field: 4 }.find(input), None);
+        assert_eq!(Location::Field { line: 1, field: 0 }.find(input), None);
+    }
+
+    #[test]
+    fn test_find_token() {
+        assert_eq!(Location::Token("five".to_string()).find("4 five 6"), Some(2..6));
+        assert_eq!(Location::Token("seven".to_string()).find("4 five 6"), None);
+        assert_eq!(Location::Token(String::new()).find("4 five 6"), None);
+    }
+}
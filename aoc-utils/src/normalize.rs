@@ -0,0 +1,172 @@
+use std::io::{self, BufRead, Read};
+
+/// UTF-8 byte order mark
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// Reader stripping a leading byte order mark and turning `\r\n` into `\n`
+/// 
+/// Inputs saved on Windows otherwise leave a `\r` glued to the last token
+/// of every line and the BOM glued to the first one. A lone `\r` that is not
+/// followed by `\n` is kept as it is.
+/// 
+/// # Examples
+/// ```
+/// use std::io::Read;
+/// use aoc_utils::NormalizingReader;
+/// 
+/// let mut text = String::new();
+/// NormalizingReader::new("\u{feff}3   4\r\n4   3\r\n".as_bytes()).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "3   4\n4   3\n");
+/// ```
+pub struct NormalizingReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    start: bool,
+    pending_cr: bool,
+}
+
+impl<R: BufRead> NormalizingReader<R> {
+    /// Wrap a reader
+    pub fn new(inner: R) -> Self {
+        NormalizingReader { inner, buf: Vec::new(), pos: 0, start: true, pending_cr: false }
+    }
+
+    /// Unwrap the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the first bytes, which might be split between several chunks of the inner reader
+    fn read_head(&mut self) -> io::Result<Vec<u8>> {
+        let mut head = Vec::with_capacity(BOM.len());
+        while head.len() < BOM.len() {
+            let chunk = self.inner.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            let n = chunk.len().min(BOM.len() - head.len());
+            head.extend_from_slice(&chunk[..n]);
+            self.inner.consume(n);
+        }
+        Ok(head)
+    }
+}
+
+/// Append the bytes to the buffer, dropping the `\r` of every `\r\n`
+/// 
+/// A trailing `\r` is held back in `pending_cr` until the next byte is known
+fn push_normalized(buf: &mut Vec<u8>, pending_cr: &mut bool, bytes: &[u8]) {
+    for &byte in bytes {
+        if *pending_cr {
+            *pending_cr = false;
+            if byte != b'\n' {
+                buf.push(b'\r');
+            }
+        }
+        match byte {
+            b'\r' => *pending_cr = true,
+            _ => buf.push(byte),
+        }
+    }
+}
+
+impl<R: BufRead> BufRead for NormalizingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos < self.buf.len() {
+            return Ok(&self.buf[self.pos..]);
+        }
+        self.buf.clear();
+        self.pos = 0;
+
+        if self.start {
+            self.start = false;
+            let head = self.read_head()?;
+            let head = head.strip_prefix(BOM).unwrap_or(&head);
+            push_normalized(&mut self.buf, &mut self.pending_cr, head);
+        }
+        // a chunk with only a `\r` produces no output yet, so keep reading
+        while self.buf.is_empty() {
+            let chunk = self.inner.fill_buf()?;
+            if chunk.is_empty() {
+                if self.pending_cr {
+                    self.pending_cr = false;
+                    self.buf.push(b'\r');
+                }
+                break;
+            }
+            let n = chunk.len();
+            push_normalized(&mut self.buf, &mut self.pending_cr, chunk);
+            self.inner.consume(n);
+        }
+        Ok(&self.buf)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buf.len());
+    }
+}
+
+impl<R: BufRead> Read for NormalizingReader<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(out.len());
+        out[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufReader;
+    use super::*;
+
+    fn normalize(bytes: &[u8], capacity: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        NormalizingReader::new(BufReader::with_capacity(capacity, bytes))
+        .read_to_end(&mut out)
+        .unwrap();
+        out
+    }
+
+    #[test]
+    fn test_normalize_crlf() {
+        assert_eq!(normalize(b"1 2\r\n3 4\r\n", 64), b"1 2\n3 4\n");
+        assert_eq!(normalize(b"1 2\r\n3 4", 64), b"1 2\n3 4");
+    }
+
+    #[test]
+    fn test_normalize_bom() {
+        assert_eq!(normalize(b"\xef\xbb\xbf1\n", 64), b"1\n");
+        assert_eq!(normalize(b"\xef\xbb\xbf", 64), b"");
+        // only a leading BOM is removed
+        assert_eq!(normalize(b"1\xef\xbb\xbf", 64), b"1\xef\xbb\xbf");
+    }
+
+    #[test]
+    fn test_normalize_lone_cr() {
+        assert_eq!(normalize(b"a\rb\r", 64), b"a\rb\r");
+        assert_eq!(normalize(b"a\r\r\nb", 64), b"a\r\nb");
+    }
+
+    #[test]
+    fn test_normalize_split_chunks() {
+        // one byte chunks split both the BOM and every `\r\n`
+        let input = b"\xef\xbb\xbf7 6\r\n1 2\r\n\r\n";
+        assert_eq!(normalize(input, 1), b"7 6\n1 2\n\n");
+        assert_eq!(normalize(input, 2), b"7 6\n1 2\n\n");
+    }
+
+    #[test]
+    fn test_normalize_empty() {
+        assert_eq!(normalize(b"", 64), b"");
+        assert_eq!(normalize(b"\r", 1), b"\r");
+    }
+
+    #[test]
+    fn test_normalize_lines() {
+        let lines: Vec<String> = NormalizingReader::new(&b"a\r\nb\r\n"[..]).lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, vec!["a", "b"]);
+    }
+}
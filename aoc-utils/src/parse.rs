@@ -0,0 +1,270 @@
+use std::{convert::Infallible, io::BufRead, str::FromStr};
+use thiserror::Error;
+use crate::Location;
+
+/// Errors returned by the parsing helpers
+/// 
+/// Line and column numbers start at 1 and count the lines of the input
+/// and the whitespace separated fields of a line respectively.
+/// `E` is the error of the value being parsed, helpers that only split
+/// the input use the default [`Infallible`].
+#[derive(Debug, Error)]
+pub enum ParseError<E = Infallible> {
+    /// A line could not be read from the input
+    #[error("failed reading line {line}")]
+    ReadLine {
+        /// Line number
+        line: usize,
+        /// Underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+    /// A field could not be parsed
+    #[error("failed parsing {token} on line {line}, column {column}")]
+    Token {
+        /// Line number
+        line: usize,
+        /// Field number within the line
+        column: usize,
+        /// Field that failed to parse
+        token: String,
+        /// Underlying parse error
+        #[source]
+        source: E,
+    },
+    /// A line has the wrong number of fields
+    #[error("expected {expected} columns on line {line}, found {found}")]
+    Columns {
+        /// Line number
+        line: usize,
+        /// Number of fields required
+        expected: usize,
+        /// Number of fields found
+        found: usize,
+    },
+}
+
+impl<E> ParseError<E> {
+    /// Line or field of the input the error points to
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::{Location, ParseError};
+    /// 
+    /// let e: ParseError = ParseError::Columns { line: 3, expected: 2, found: 1 };
+    /// assert_eq!(e.location(), Some(Location::Line(3)));
+    /// ```
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            ParseError::ReadLine { line, .. } | ParseError::Columns { line, .. } => Some(Location::Line(*line)),
+            ParseError::Token { line, column, .. } => Some(Location::Field { line: *line, field: *column }),
+        }
+    }
+}
+
+/// Left and right column values returned by [`parse_two_columns`]
+pub type TwoColumns<T> = (Vec<T>, Vec<T>);
+
+/// Non-blank lines of the text with their line numbers
+fn numbered_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+    .enumerate()
+    .map(|(i, line)| (i + 1, line))
+    .filter(|(_, line)| !line.trim().is_empty())
+}
+
+/// Parse a single field, attaching its position to the error
+fn parse_token<T: FromStr>(token: &str, line: usize, column: usize) -> Result<T, ParseError<T::Err>> {
+    token.parse::<T>()
+    .map_err(|source| ParseError::Token { line, column, token: token.to_string(), source })
+}
+
+/// Parse every line of a reader into a value
+/// 
+/// Surrounding whitespace is trimmed and blank lines are skipped
+/// 
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// 
+/// let numbers: Vec<u32> = aoc_utils::parse_lines(Cursor::new("1\n 22 \n\n333\n")).unwrap();
+/// assert_eq!(numbers, vec![1, 22, 333]);
+/// ```
+pub fn parse_lines<T: FromStr, B: BufRead>(reader: B) -> Result<Vec<T>, ParseError<T::Err>> {
+    let mut values = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.map_err(|source| ParseError::ReadLine { line: line_number, source })?;
+        let token = line.trim();
+        if !token.is_empty() {
+            values.push(parse_token(token, line_number, 1)?);
+        }
+    }
+    Ok(values)
+}
+
+/// Split every line of the text into a left and a right column
+/// 
+/// Columns may be separated by any amount of spaces or tabs,
+/// blank lines are skipped and every other line must have exactly two fields
+/// 
+/// # Examples
+/// ```
+/// let (left, right) = aoc_utils::split_two_columns("3   4\n4\t3\n").unwrap();
+/// assert_eq!(left, vec!["3", "4"]);
+/// assert_eq!(right, vec!["4", "3"]);
+/// ```
+pub fn split_two_columns(text: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for (line, content) in numbered_lines(text) {
+        let fields: Vec<&str> = content.split_whitespace().collect();
+        match fields[..] {
+            [l, r] => {
+                left.push(l);
+                right.push(r);
+            },
+            _ => return Err(ParseError::Columns { line, expected: 2, found: fields.len() }),
+        }
+    }
+    Ok((left, right))
+}
+
+/// Parse every line of the text into a left and a right column value
+/// 
+/// Same layout rules as [`split_two_columns`]
+/// 
+/// # Examples
+/// ```
+/// let (left, right) = aoc_utils::parse_two_columns::<i32>("3   4\n4   3\n").unwrap();
+/// assert_eq!(left, vec![3, 4]);
+/// assert_eq!(right, vec![4, 3]);
+/// ```
+pub fn parse_two_columns<T: FromStr>(text: &str) -> Result<TwoColumns<T>, ParseError<T::Err>> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for (line, content) in numbered_lines(text) {
+        let fields: Vec<&str> = content.split_whitespace().collect();
+        match fields[..] {
+            [l, r] => {
+                left.push(parse_token(l, line, 1)?);
+                right.push(parse_token(r, line, 2)?);
+            },
+            _ => return Err(ParseError::Columns { line, expected: 2, found: fields.len() }),
+        }
+    }
+    Ok((left, right))
+}
+
+/// Parse every line of the text into a row of whitespace separated values
+/// 
+/// Blank lines are skipped, rows may have different lengths
+/// 
+/// # Examples
+/// ```
+/// let rows = aoc_utils::parse_grid_lines::<i32>("7 6 4\n\n1  2\t7 8\n").unwrap();
+/// assert_eq!(rows, vec![vec![7, 6, 4], vec![1, 2, 7, 8]]);
+/// ```
+pub fn parse_grid_lines<T: FromStr>(text: &str) -> Result<Vec<Vec<T>>, ParseError<T::Err>> {
+    numbered_lines(text)
+    .map(|(line, content)| {
+        content.split_whitespace()
+        .enumerate()
+        .map(|(i, token)| parse_token(token, line, i + 1))
+        .collect()
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::ParseIntError};
+    use super::*;
+
+    #[test]
+    fn test_parse_lines_success() -> Result<(), ParseError<ParseIntError>> {
+        let values: Vec<i64> = parse_lines(Cursor::new("-1\n\t2\n3   \n"))?;
+        assert_eq!(values, vec![-1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lines_blank_lines() -> Result<(), ParseError<ParseIntError>> {
+        let values: Vec<i64> = parse_lines(Cursor::new("\n1\n   \n\n2"))?;
+        assert_eq!(values, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lines_negative() {
+        let result = parse_lines::<i32, _>(Cursor::new("1\n\n2x\n"));
+        assert!(matches!(result, Err(ParseError::Token { line: 3, column: 1, ref token, .. }) if token == "2x"));
+        assert_eq!(result.unwrap_err().to_string(), "failed parsing 2x on line 3, column 1");
+    }
+
+    #[test]
+    fn test_parse_lines_read_line_variant() {
+        let invalid_utf8: &[u8] = &[b'1', b'\n', 0xff, b'\n'];
+        let result = parse_lines::<i32, _>(invalid_utf8);
+        assert!(matches!(result, Err(ParseError::ReadLine { line: 2, .. })));
+    }
+
+    #[test]
+    fn test_split_two_columns_separators() -> Result<(), ParseError> {
+        let (left, right) = split_two_columns("1   2\n3\t4\n5 6\n  7    8  \n")?;
+        assert_eq!(left, vec!["1", "3", "5", "7"]);
+        assert_eq!(right, vec!["2", "4", "6", "8"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_two_columns_blank_lines() -> Result<(), ParseError> {
+        let (left, right) = split_two_columns("\n1   2\n \n3   4\n\n")?;
+        assert_eq!((left.len(), right.len()), (2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_two_columns_negative() {
+        let result = split_two_columns("1   2\n3\n");
+        assert!(matches!(result, Err(ParseError::Columns { line: 2, expected: 2, found: 1 })));
+        let result = split_two_columns("1 2 3");
+        assert!(matches!(result, Err(ParseError::Columns { line: 1, expected: 2, found: 3 })));
+    }
+
+    #[test]
+    fn test_parse_two_columns_success() -> Result<(), ParseError<ParseIntError>> {
+        let (left, right) = parse_two_columns::<i32>("10\t9\n\n-3   4\n")?;
+        assert_eq!(left, vec![10, -3]);
+        assert_eq!(right, vec![9, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_two_columns_negative() {
+        let result = parse_two_columns::<i32>("1   2\n\n3   x4\n");
+        assert!(matches!(result, Err(ParseError::Token { line: 3, column: 2, ref token, .. }) if token == "x4"));
+        let result = parse_two_columns::<i32>("1   2   3\n");
+        assert!(matches!(result, Err(ParseError::Columns { line: 1, found: 3, .. })));
+    }
+
+    #[test]
+    fn test_parse_grid_lines_separators() -> Result<(), ParseError<ParseIntError>> {
+        let rows = parse_grid_lines::<u8>("1 2 3\n4\t5\n  6   7  8 9\n")?;
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7, 8, 9]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_grid_lines_blank_lines() -> Result<(), ParseError<ParseIntError>> {
+        assert_eq!(parse_grid_lines::<u8>("\n\n1 2\n\n")?, vec![vec![1, 2]]);
+        assert!(parse_grid_lines::<u8>("")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_grid_lines_negative() {
+        let result = parse_grid_lines::<u8>("1 2\n3 4 300\n");
+        assert!(matches!(result, Err(ParseError::Token { line: 2, column: 3, ref token, .. }) if token == "300"));
+    }
+}
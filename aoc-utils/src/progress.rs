@@ -0,0 +1,157 @@
+use std::sync::{atomic::{AtomicU64, Ordering}, Mutex};
+
+/// Receiver of progress updates from a long running solve
+/// 
+/// `done` only grows between calls, `total` is `None` while the amount
+/// of work is not known. Implementations must be cheap, but solvers only
+/// call them at coarse intervals anyway (see [`Ticker`]).
+/// 
+/// # Examples
+/// ```
+/// use std::sync::Mutex;
+/// use aoc_utils::Progress;
+/// 
+/// struct Last(Mutex<u64>);
+/// 
+/// impl Progress for Last {
+///     fn update(&self, done: u64, _total: Option<u64>) {
+///         *self.0.lock().unwrap() = done;
+///     }
+/// }
+/// 
+/// let last = Last(Mutex::new(0));
+/// last.update(3, Some(10));
+/// assert_eq!(*last.0.lock().unwrap(), 3);
+/// ```
+pub trait Progress: Sync {
+    /// Report that `done` out of `total` units of work are finished
+    fn update(&self, done: u64, total: Option<u64>);
+}
+
+/// [`Progress`] ignoring every update
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn update(&self, _done: u64, _total: Option<u64>) {}
+}
+
+/// Number of updates reported over the whole solve when the total is known
+const UPDATES: u64 = 100;
+
+/// Interval between updates when the total is not known
+const UNKNOWN_TOTAL_INTERVAL: u64 = 1024;
+
+/// Counter forwarding to a [`Progress`] at coarse intervals
+/// 
+/// Solvers call [`Ticker::tick`] for every unit of work, which may happen
+/// from several threads, and [`Ticker::finish`] once they are done.
+/// Reported values never decrease, and without a [`Progress`] it does nothing.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{NoProgress, Ticker};
+/// 
+/// let ticker = Ticker::new(Some(&NoProgress), Some(1000));
+/// for _ in 0..1000 {
+///     ticker.tick(1);
+/// }
+/// ticker.finish();
+/// assert_eq!(ticker.done(), 1000);
+/// ```
+pub struct Ticker<'a> {
+    progress: Option<&'a dyn Progress>,
+    total: Option<u64>,
+    interval: u64,
+    done: AtomicU64,
+    reported: Mutex<u64>,
+}
+
+impl<'a> Ticker<'a> {
+    /// Ticker reporting to `progress`, if there is one
+    pub fn new(progress: Option<&'a dyn Progress>, total: Option<u64>) -> Self {
+        let interval = total.map_or(UNKNOWN_TOTAL_INTERVAL, |total| total / UPDATES).max(1);
+        Ticker { progress, total, interval, done: AtomicU64::new(0), reported: Mutex::new(0) }
+    }
+
+    /// Record `amount` more units of finished work
+    pub fn tick(&self, amount: u64) {
+        let Some(progress) = self.progress else {
+            return;
+        };
+        let before = self.done.fetch_add(amount, Ordering::Relaxed);
+        let done = before + amount;
+        if before / self.interval != done / self.interval {
+            self.report(progress, done, self.total);
+        }
+    }
+
+    /// Units of work recorded so far
+    pub fn done(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    /// Report the work as complete
+    /// 
+    /// The final update has `done` equal to the total, so progress bars reach 100%
+    pub fn finish(&self) {
+        if let Some(progress) = self.progress {
+            let done = self.total.unwrap_or_else(|| self.done()).max(self.done());
+            self.report(progress, done, Some(done));
+        }
+    }
+
+    fn report(&self, progress: &dyn Progress, done: u64, total: Option<u64>) {
+        let mut reported = self.reported.lock().unwrap_or_else(|e| e.into_inner());
+        if done >= *reported {
+            *reported = done;
+            progress.update(done, total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<(u64, Option<u64>)>>);
+
+    impl Progress for Recorder {
+        fn update(&self, done: u64, total: Option<u64>) {
+            self.0.lock().unwrap().push((done, total));
+        }
+    }
+
+    #[test]
+    fn test_ticker_coarse_updates() {
+        let recorder = Recorder::default();
+        let ticker = Ticker::new(Some(&recorder), Some(10_000));
+        for _ in 0..10_000 {
+            ticker.tick(1);
+        }
+        ticker.finish();
+        let updates = recorder.0.into_inner().unwrap();
+        assert_eq!(updates.len(), 101);
+        assert_eq!(updates[0], (100, Some(10_000)));
+        assert_eq!(updates.last(), Some(&(10_000, Some(10_000))));
+    }
+
+    #[test]
+    fn test_ticker_unknown_total() {
+        let recorder = Recorder::default();
+        let ticker = Ticker::new(Some(&recorder), None);
+        ticker.tick(3000);
+        ticker.finish();
+        let updates = recorder.0.into_inner().unwrap();
+        assert_eq!(updates, vec![(3000, None), (3000, Some(3000))]);
+    }
+
+    #[test]
+    fn test_ticker_without_progress() {
+        let ticker = Ticker::new(None, Some(10));
+        ticker.tick(5);
+        ticker.finish();
+        assert_eq!(ticker.done(), 0);
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Outcome of solving one part of a day
+/// 
+/// Serialized field names and their order are part of the runner
+/// output format, the duration is written as integer microseconds
+/// under the `duration_us` name. Needs the `serde` feature.
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc_utils::SolveResult;
+/// 
+/// let result = SolveResult {
+///     day: 1,
+///     part: 2,
+///     answer: "31".to_string(),
+///     duration: Duration::from_micros(15),
+///     input_bytes: 42,
+///     allocations: None,
+///     timed_out: false,
+/// };
+/// assert_eq!(result.duration_us(), 15);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveResult {
+    /// Day of the challenge
+    pub day: u8,
+    /// Part of the challenge (1 or 2)
+    pub part: u8,
+    /// Answer formatted as a string
+    pub answer: String,
+    /// Time taken to solve the part
+    #[cfg_attr(feature = "serde", serde(rename = "duration_us", serialize_with = "serialize_micros", deserialize_with = "deserialize_micros"))]
+    pub duration: Duration,
+    /// Size of the puzzle input in bytes
+    pub input_bytes: u64,
+    /// Memory allocated while solving, when the runner tracks it
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub allocations: Option<AllocStats>,
+    /// The solve was stopped by the runner timeout, the answer is [`SolveResult::TIMEOUT`]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "std::ops::Not::not"))]
+    pub timed_out: bool,
+}
+
+/// Heap usage measured during a single solve
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllocStats {
+    /// Total bytes allocated, including memory that was freed again
+    pub allocated_bytes: u64,
+    /// Highest number of bytes alive at the same time
+    pub peak_bytes: u64,
+}
+
+impl SolveResult {
+    /// Answer reported for a part stopped by the runner timeout
+    pub const TIMEOUT: &str = "TIMEOUT";
+
+    /// Part stopped after running for `duration`, see `aoc run --timeout`
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc_utils::SolveResult;
+    /// 
+    /// let result = SolveResult::timeout(6, 2, Duration::from_secs(30), 17000);
+    /// assert!(result.timed_out);
+    /// assert_eq!(result.answer, "TIMEOUT");
+    /// ```
+    pub fn timeout(day: u8, part: u8, duration: Duration, input_bytes: u64) -> Self {
+        SolveResult {
+            day,
+            part,
+            answer: Self::TIMEOUT.to_string(),
+            duration,
+            input_bytes,
+            allocations: None,
+            timed_out: true,
+        }
+    }
+
+    /// Solving time in whole microseconds
+    pub fn duration_us(&self) -> u64 {
+        u64::try_from(self.duration.as_micros()).unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_micros<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(u64::try_from(duration.as_micros()).unwrap_or(u64::MAX))
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_micros<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_micros)
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_round_trip() -> anyhow::Result<()> {
+        let result = SolveResult {
+            day: 4,
+            part: 2,
+            answer: "9".to_string(),
+            duration: Duration::from_micros(1250),
+            input_bytes: 110,
+            allocations: Some(AllocStats { allocated_bytes: 4096, peak_bytes: 1024 }),
+            timed_out: false,
+        };
+        let json = serde_json::to_string(&result)?;
+        assert_eq!(json, concat!(
+            r#"{"day":4,"part":2,"answer":"9","duration_us":1250,"input_bytes":110,"#,
+            r#""allocations":{"allocated_bytes":4096,"peak_bytes":1024}}"#,
+        ));
+        assert_eq!(serde_json::from_str::<SolveResult>(&json)?, result);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_round_trip_timeout() -> anyhow::Result<()> {
+        let result = SolveResult::timeout(6, 1, Duration::from_secs(2), 17);
+        let json = serde_json::to_string(&result)?;
+        assert_eq!(json, r#"{"day":6,"part":1,"answer":"TIMEOUT","duration_us":2000000,"input_bytes":17,"timed_out":true}"#);
+        assert_eq!(serde_json::from_str::<SolveResult>(&json)?, result);
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use thiserror::Error;
+use crate::{CancelToken, Cancelled, InputStats, Progress};
+
+/// Result type returned by [`Solution`] implementations
+pub type Result<T, E = SolutionError> = std::result::Result<T, E>;
+
+/// Common interface implemented by every day
+/// 
+/// Each day crate provides a zero-sized struct implementing this trait
+/// so the runner (and any other tooling) can solve all the days uniformly.
+/// Both parts take the full puzzle input text and return the answer
+/// formatted as a string. Solutions are `Sync` so the runner can solve
+/// on a worker thread while it keeps track of the timeout.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution, SolutionError};
+/// 
+/// struct Example;
+/// 
+/// impl Solution for Example {
+///     fn day(&self) -> u8 {
+///         0
+///     }
+/// 
+///     fn part1(&self, input: &str) -> Result<String> {
+///         Ok(input.lines().count().to_string())
+///     }
+/// 
+///     fn part2(&self, _input: &str) -> Result<String> {
+///         Err(SolutionError::NotImplemented { day: 0, part: 2 })
+///     }
+/// }
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(Example.part1("a\nb\nc")?, "3");
+///     assert!(Example.part2("a").is_err());
+///     Ok(())
+/// }
+/// ```
+pub trait Solution: Sync {
+    /// Day of the challenge (1 - 25)
+    fn day(&self) -> u8;
+
+    /// Solve the first part of the challenge for the provided input
+    fn part1(&self, input: &str) -> Result<String>;
+
+    /// Solve the second part of the challenge for the provided input
+    fn part2(&self, input: &str) -> Result<String>;
+
+    /// Solve one part (1 or 2) while reporting progress
+    /// 
+    /// The default calls [`Solution::part1`] or [`Solution::part2`] and reports
+    /// completion once the answer is known, days with slow solvers override it
+    /// to report along the way
+    fn solve_with_progress(&self, part: u8, input: &str, progress: &dyn Progress) -> Result<String> {
+        self.solve_with_cancel(part, input, progress, &CancelToken::new())
+    }
+
+    /// Solve one part (1 or 2) while reporting progress, stopping once `cancel` is cancelled
+    /// 
+    /// The default only checks the token before solving, days with slow
+    /// solvers override it to check along the way and report progress.
+    /// A cancelled solve returns [`SolutionError::Cancelled`].
+    fn solve_with_cancel(&self, part: u8, input: &str, progress: &dyn Progress, 
+        cancel: &CancelToken) -> Result<String> {
+        cancel.check()?;
+        let answer = match part {
+            1 => self.part1(input),
+            _ => self.part2(input),
+        }?;
+        progress.update(1, Some(1));
+        Ok(answer)
+    }
+
+    /// Solve both parts, returning the answers of part 1 and part 2
+    /// 
+    /// The default calls [`Solution::part1`] and [`Solution::part2`], days
+    /// override it to parse the input once for both parts or to share the
+    /// rest of the work between them. Used by `aoc run --part both`.
+    fn solve_both(&self, input: &str) -> Result<(String, String)> {
+        Ok((self.part1(input)?, self.part2(input)?))
+    }
+
+    /// Published example of the challenge, `None` when it is not embedded
+    fn sample(&self) -> Option<Sample> {
+        None
+    }
+
+    /// Solution set up to solve [`Solution::sample`], `None` when this one solves it
+    /// 
+    /// Days whose answers depend on more than the input, such as the size
+    /// of the grid, return themselves with the parameters of the sample
+    fn sample_solution(&self) -> Option<Box<dyn Solution>> {
+        None
+    }
+
+    /// Input generated from `seed` with its expected answers, `size` sets how large it is
+    /// 
+    /// The same size and seed always produce the same input. `None` when
+    /// the day has no generator, see `aoc gen`.
+    fn generate(&self, _size: usize, _seed: u64) -> Option<(String, ExpectedAnswers)> {
+        None
+    }
+
+    /// Facts about the input shown by `aoc stats`
+    /// 
+    /// The default reports the [`InputStats::generic`] counts, days override
+    /// it to also check that the input looks like their format
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input)
+    }
+
+    /// Why the answer comes out the way it does for the part of the input picked by `selector`
+    /// 
+    /// What the selector picks depends on the day, such as the line number
+    /// of a report, see `aoc explain`. The default returns
+    /// [`SolutionError::NotSupported`].
+    fn explain(&self, _input: &str, _selector: &str) -> Result<String> {
+        Err(SolutionError::NotSupported { day: self.day(), hook: "explain" })
+    }
+
+    /// Input parsed into the structure both parts are solved from
+    /// 
+    /// Lets `aoc bench` time parsing and solving separately. The default
+    /// returns [`SolutionError::NotSupported`].
+    fn parse(&self, _input: &str) -> Result<Box<dyn Parsed>> {
+        Err(SolutionError::NotSupported { day: self.day(), hook: "parse" })
+    }
+}
+
+/// Input returned by [`Solution::parse`], solved without parsing it again
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Parsed, Result};
+/// 
+/// struct Lines(Vec<String>);
+/// 
+/// impl Parsed for Lines {
+///     fn solve(&self, part: u8) -> Result<String> {
+///         Ok((self.0.len() * usize::from(part)).to_string())
+///     }
+/// }
+/// 
+/// let lines = Lines(vec!["a".to_string(), "b".to_string()]);
+/// assert_eq!(lines.solve(2).unwrap(), "4");
+/// ```
+pub trait Parsed {
+    /// Solve one part (1 or 2) of the parsed input
+    fn solve(&self, part: u8) -> Result<String>;
+}
+
+/// Expected answers of the first and the second part of a generated input
+pub type ExpectedAnswers = [String; 2];
+
+/// Example input from the puzzle description with the answers of both parts
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::Sample;
+/// 
+/// let sample = Sample { input: "3   4\n4   3\n", answers: ["2", "24"] };
+/// assert_eq!(sample.input.lines().count(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// Example puzzle input
+    pub input: &'static str,
+    /// Expected answers of the first and the second part, empty when the sample has none
+    pub answers: [&'static str; 2],
+}
+
+/// Errors reported by [`Solution`] implementations
+/// 
+/// Day crates convert their own error enums into [`SolutionError::Day`]
+/// so the original error can still be recovered with `downcast_ref`
+#[derive(Debug, Error)]
+pub enum SolutionError {
+    /// The requested part has not been solved yet
+    #[error("day {day} part {part} is not implemented")]
+    NotImplemented {
+        /// Day of the challenge
+        day: u8,
+        /// Part of the challenge (1 or 2)
+        part: u8,
+    },
+    /// The day does not implement an optional hook of [`Solution`]
+    #[error("day {day} does not support {hook}")]
+    NotSupported {
+        /// Day of the challenge
+        day: u8,
+        /// Name of the hook, such as `explain`
+        hook: &'static str,
+    },
+    /// The selector passed to [`Solution::explain`] does not pick anything in the input
+    #[error("invalid selector `{selector}`, expected {expected}")]
+    InvalidSelector {
+        /// Given selector
+        selector: String,
+        /// What the day accepts, such as `a report line from 1 to 6`
+        expected: String,
+    },
+    /// The solve was stopped with a [`CancelToken`]
+    #[error("solve was cancelled")]
+    Cancelled,
+    /// Solving failed with a day specific error
+    #[error(transparent)]
+    Day(Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+impl From<Cancelled> for SolutionError {
+    fn from(_: Cancelled) -> Self {
+        SolutionError::Cancelled
+    }
+}
+
+impl SolutionError {
+    /// Day specific error of the given type, if this is one
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc_utils::SolutionError;
+    /// 
+    /// let e = SolutionError::Day(Box::new(std::fmt::Error));
+    /// assert!(e.day_error::<std::fmt::Error>().is_some());
+    /// assert!(SolutionError::NotImplemented { day: 1, part: 2 }.day_error::<std::fmt::Error>().is_none());
+    /// ```
+    pub fn day_error<E: std::error::Error + 'static>(&self) -> Option<&E> {
+        match self {
+            SolutionError::Day(e) => e.downcast_ref::<E>(),
+            _ => None,
+        }
+    }
+}
+
+/// Error message followed by the messages of all its sources, separated by `: `
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{error_chain, ParseError};
+/// 
+/// let source = "x".parse::<i32>().unwrap_err();
+/// let e: ParseError<_> = ParseError::Token { line: 2, column: 1, token: "x".to_string(), source };
+/// assert_eq!(error_chain(&e), "failed parsing x on line 2, column 1: invalid digit found in string");
+/// ```
+pub fn error_chain(e: &dyn std::error::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(e) = source {
+        message.push_str(": ");
+        message.push_str(&e.to_string());
+        source = e.source();
+    }
+    message
+}
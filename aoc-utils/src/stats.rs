@@ -0,0 +1,165 @@
+use std::{collections::HashMap, fmt};
+
+/// Number of characters of each class in an input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharClasses {
+    /// ASCII digits
+    pub digits: usize,
+    /// Alphabetic characters
+    pub letters: usize,
+    /// Spaces, tabs and line breaks
+    pub whitespace: usize,
+    /// Everything else, such as punctuation
+    pub other: usize,
+}
+
+/// Basic facts about a puzzle input, reported by `aoc stats`
+/// 
+/// Tokens are the runs of letters and digits, the numeric tokens give the
+/// minimum and maximum values. Days fill [`InputStats::problem`] when the
+/// input does not look like their format.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::InputStats;
+/// 
+/// let stats = InputStats::generic("3   4\n4   3\n2   5\n");
+/// assert_eq!((stats.lines, stats.bytes), (3, 18));
+/// assert_eq!((stats.min, stats.max), (Some(2), Some(5)));
+/// assert_eq!(stats.tokens[0], ("3".to_string(), 2));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputStats {
+    /// Number of lines
+    pub lines: usize,
+    /// Size in bytes
+    pub bytes: usize,
+    /// Characters by class
+    pub chars: CharClasses,
+    /// Number of numeric tokens
+    pub numbers: usize,
+    /// Smallest numeric token
+    pub min: Option<i64>,
+    /// Largest numeric token
+    pub max: Option<i64>,
+    /// Most common tokens with their counts, most common first
+    pub tokens: Vec<(String, usize)>,
+    /// Why the input does not look like the format of the day
+    pub problem: Option<String>,
+}
+
+impl InputStats {
+    /// Number of tokens kept in [`InputStats::tokens`]
+    pub const TOP_TOKENS: usize = 5;
+
+    /// Counts that do not depend on the day, without a format check
+    pub fn generic(input: &str) -> Self {
+        let mut chars = CharClasses::default();
+        for c in input.chars() {
+            match c {
+                c if c.is_ascii_digit() => chars.digits += 1,
+                c if c.is_alphabetic() => chars.letters += 1,
+                c if c.is_whitespace() => chars.whitespace += 1,
+                _ => chars.other += 1,
+            }
+        }
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for token in input.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()) {
+            *counts.entry(token).or_default() += 1;
+        }
+        let numbers: Vec<i64> = counts.iter()
+        .filter_map(|(token, &count)| token.parse().ok().map(|n| (n, count)))
+        .flat_map(|(n, count)| std::iter::repeat_n(n, count))
+        .collect();
+        let mut tokens: Vec<(String, usize)> = counts.into_iter()
+        .map(|(token, count)| (token.to_string(), count))
+        .collect();
+        tokens.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tokens.truncate(Self::TOP_TOKENS);
+
+        InputStats {
+            lines: input.lines().count(),
+            bytes: input.len(),
+            chars,
+            numbers: numbers.len(),
+            min: numbers.iter().copied().min(),
+            max: numbers.iter().copied().max(),
+            tokens,
+            problem: None,
+        }
+    }
+
+    /// The stats with the result of a format check of the day
+    pub fn with_problem(self, problem: Option<String>) -> Self {
+        InputStats { problem, ..self }
+    }
+}
+
+impl fmt::Display for InputStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CharClasses { digits, letters, whitespace, other } = self.chars;
+        writeln!(f, "Lines: {}", self.lines)?;
+        writeln!(f, "Bytes: {}", self.bytes)?;
+        writeln!(f, "Characters: {} digits, {} letters, {} whitespace, {} other", digits, letters, whitespace, other)?;
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => writeln!(f, "Numbers: {} from {} to {}", self.numbers, min, max)?,
+            _ => writeln!(f, "Numbers: none")?,
+        }
+        let tokens: Vec<String> = self.tokens.iter()
+        .map(|(token, count)| format!("{} ({})", token, count))
+        .collect();
+        writeln!(f, "Common tokens: {}", tokens.join(", "))?;
+        match &self.problem {
+            Some(problem) => writeln!(f, "Format: {}", problem),
+            None => writeln!(f, "Format: ok"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_counts() {
+        let stats = InputStats::generic("mul(2,4)do()\nmul(12,4)\n");
+        assert_eq!(stats.lines, 2);
+        assert_eq!(stats.bytes, 23);
+        assert_eq!(stats.chars, CharClasses { digits: 5, letters: 8, whitespace: 2, other: 8 });
+        assert_eq!(stats.numbers, 4);
+        assert_eq!((stats.min, stats.max), (Some(2), Some(12)));
+        assert_eq!(stats.tokens, vec![
+            ("4".to_string(), 2), ("mul".to_string(), 2), ("12".to_string(), 1), ("2".to_string(), 1), ("do".to_string(), 1),
+        ]);
+        assert_eq!(stats.problem, None);
+    }
+
+    #[test]
+    fn test_generic_empty() {
+        let stats = InputStats::generic("");
+        assert_eq!(stats, InputStats::default());
+        assert!(stats.to_string().contains("Numbers: none\n"));
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = InputStats::generic("1|2\n").with_problem(Some("no updates".to_string()));
+        assert_eq!(stats.to_string(), "Lines: 1\nBytes: 4\n\
+        Characters: 2 digits, 0 letters, 1 whitespace, 1 other\n\
+        Numbers: 2 from 1 to 2\nCommon tokens: 1 (1), 2 (1)\nFormat: no updates\n");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> anyhow::Result<()> {
+        let stats = InputStats::generic("3   4\n4   3\n").with_problem(Some("odd".to_string()));
+        let json = serde_json::to_value(&stats)?;
+        assert_eq!(json["chars"]["whitespace"], 8);
+        assert_eq!(json["tokens"][0], serde_json::json!(["3", 2]));
+        assert_eq!(serde_json::from_value::<InputStats>(json)?, stats);
+        Ok(())
+    }
+}
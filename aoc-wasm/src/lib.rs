@@ -0,0 +1,77 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 WebAssembly bindings
+//! 
+//! Exposes the solutions to JavaScript through [`solve`]. The day crates are
+//! built without their `fs` feature, so the input is always passed as text.
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_utils::{error_chain, Solution, SolutionError};
+use wasm_bindgen::prelude::*;
+
+/// Registry of the days available in the browser
+/// 
+/// Returns the solutions ordered by day
+/// 
+/// # Examples
+/// ```
+/// let days: Vec<u8> = aoc_wasm::solutions().iter().map(|s| s.day()).collect();
+/// assert_eq!(&days[..5], &[1, 2, 3, 4, 5]);
+/// ```
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6),
+        Box::new(day_7::Day7),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14::default()),
+        Box::new(day_15::Day15),
+        Box::new(day_16::Day16),
+        Box::new(day_17::Day17),
+        Box::new(day_18::Day18::default()),
+        Box::new(day_19::Day19),
+        Box::new(day_20::Day20::default()),
+        Box::new(day_21::Day21),
+        Box::new(day_22::Day22),
+        Box::new(day_23::Day23),
+        Box::new(day_24::Day24),
+        Box::new(day_25::Day25),
+    ]
+}
+
+/// Solve one part of a day for the input text
+/// 
+/// Unknown days and parts, and failed solves, are thrown to JavaScript
+/// as a string with the messages of the whole error chain
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc_wasm::solve_text(1, 2, day_1::SAMPLE).unwrap(), "31");
+/// assert_eq!(aoc_wasm::solve_text(26, 1, "").unwrap_err(), "day 26 is not implemented");
+/// ```
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, JsValue> {
+    solve_text(day, part, input).map_err(|message| JsValue::from_str(&message))
+}
+
+/// [`solve`] with the error message as a Rust string, usable outside of JavaScript
+pub fn solve_text(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let solution = solutions().into_iter()
+    .find(|s| s.day() == day)
+    .ok_or_else(|| format!("day {} is not implemented", day))?;
+    let answer: Result<String, SolutionError> = match part {
+        1 => solution.part1(input),
+        2 => solution.part2(input),
+        _ => return Err(format!("part must be 1 or 2, got {}", part)),
+    };
+    answer.map_err(|e| error_chain(&e))
+}
@@ -0,0 +1,148 @@
+//! Solves the embedded samples through the JavaScript entry point,
+//! run with `wasm-pack test --node`
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Solve both parts of the sample, skipping the parts that are not solved yet
+fn assert_sample(day: u8, input: &str, answers: [&str; 2]) {
+    for (part, expected) in [1, 2].into_iter().zip(answers) {
+        match aoc_wasm::solve(day, part, input) {
+            Ok(answer) => assert_eq!(answer, expected, "day {} part {}", day, part),
+            Err(e) => {
+                let message = e.as_string().unwrap_or_default();
+                assert!(message.ends_with("is not implemented"), "day {} part {}: {}", day, part, message);
+            },
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_day_1_sample() {
+    assert_sample(1, day_1::SAMPLE, day_1::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_2_sample() {
+    assert_sample(2, day_2::SAMPLE, day_2::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_3_sample() {
+    assert_sample(3, day_3::SAMPLE, day_3::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_4_sample() {
+    assert_sample(4, day_4::SAMPLE, day_4::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_5_sample() {
+    assert_sample(5, day_5::SAMPLE, day_5::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_6_sample() {
+    assert_sample(6, day_6::SAMPLE, day_6::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_7_sample() {
+    assert_sample(7, day_7::SAMPLE, day_7::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_8_sample() {
+    assert_sample(8, day_8::SAMPLE, day_8::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_9_sample() {
+    assert_sample(9, day_9::SAMPLE, day_9::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_10_sample() {
+    assert_sample(10, day_10::SAMPLE, day_10::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_11_sample() {
+    assert_sample(11, day_11::SAMPLE, day_11::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_12_sample() {
+    assert_sample(12, day_12::SAMPLE, day_12::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_13_sample() {
+    assert_sample(13, day_13::SAMPLE, day_13::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_14_sample() {
+    assert_sample(14, day_14::SAMPLE, day_14::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_15_sample() {
+    assert_sample(15, day_15::SAMPLE, day_15::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_16_sample() {
+    assert_sample(16, day_16::SAMPLE, day_16::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_17_sample() {
+    assert_sample(17, day_17::SAMPLE, day_17::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_18_sample() {
+    assert_sample(18, day_18::SAMPLE, day_18::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_19_sample() {
+    assert_sample(19, day_19::SAMPLE, day_19::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_20_sample() {
+    assert_sample(20, day_20::SAMPLE, day_20::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_21_sample() {
+    assert_sample(21, day_21::SAMPLE, day_21::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_22_sample() {
+    assert_sample(22, day_22::SAMPLE, day_22::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_23_sample() {
+    assert_sample(23, day_23::SAMPLE, day_23::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_24_sample() {
+    assert_sample(24, day_24::SAMPLE, day_24::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_day_25_sample() {
+    assert_sample(25, day_25::SAMPLE, day_25::SAMPLE_ANSWERS);
+}
+
+#[wasm_bindgen_test]
+fn test_unknown_day() {
+    let e = aoc_wasm::solve(26, 1, "").unwrap_err();
+    assert_eq!(e.as_string().as_deref(), Some("day 26 is not implemented"));
+}
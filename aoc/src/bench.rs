@@ -0,0 +1,383 @@
+//! Repeated solving timed by `aoc bench`
+//! 
+//! Each iteration parses the input once with [`Solution::parse`] and solves
+//! both parts from the parsed input, so parsing and solving are timed
+//! separately. Days without a `parse` hook are timed as whole parts.
+
+use std::{fmt, time::{Duration, Instant}};
+use anyhow::{Context, Result};
+use aoc_utils::{Solution, SolutionError};
+use serde::Serialize;
+use crate::{report::Format, table::{format_duration, layout, Cell, Column}};
+
+/// Timed iterations when `--iterations` is not given
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// Untimed iterations run first to warm up caches and the allocator
+pub const DEFAULT_WARMUP: usize = 2;
+
+/// CSV header, in the order the columns are written
+pub const CSV_HEADER: &str = "day,stage,iterations,min_us,median_us,p95_us";
+
+/// Summary of the timings of a stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Fastest timing
+    pub min: Duration,
+    /// Middle timing, the mean of the two middle ones for an even count
+    pub median: Duration,
+    /// Nearest-rank 95th percentile
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Statistics of the timings, `None` when there are none
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc::bench::Stats;
+    /// 
+    /// let timings: Vec<Duration> = [4, 1, 3, 2].into_iter().map(Duration::from_micros).collect();
+    /// let stats = Stats::new(&timings).unwrap();
+    /// assert_eq!(stats.min, Duration::from_micros(1));
+    /// assert_eq!(stats.median, Duration::from_nanos(2500));
+    /// assert_eq!(stats.p95, Duration::from_micros(4));
+    /// ```
+    pub fn new(timings: &[Duration]) -> Option<Self> {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let min = *sorted.first()?;
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        Some(Stats { min, median, p95 })
+    }
+}
+
+/// Part of the work a timing covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Parsing the input
+    Parse,
+    /// Solving a part, from the parsed input when the day can parse it
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// Timings of one stage of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// Day benchmarked
+    pub day: u8,
+    /// Stage timed
+    pub stage: Stage,
+    /// Number of timed iterations
+    pub iterations: usize,
+    /// Statistics of the timed iterations
+    pub stats: Stats,
+}
+
+/// Solve the input `warmup + iterations` times and summarize the timed iterations
+/// 
+/// Parts the day has not solved yet are left out. `iterations` must be
+/// at least 1.
+/// 
+/// # Examples
+/// ```
+/// let timings = aoc::bench::run(&day_1::Day1, day_1::SAMPLE, 3, 1).unwrap();
+/// let stages: Vec<String> = timings.iter().map(|t| t.stage.to_string()).collect();
+/// assert_eq!(stages, ["parse", "part 1", "part 2"]);
+/// assert!(timings.iter().all(|t| t.iterations == 3));
+/// ```
+pub fn run(solution: &dyn Solution, input: &str, iterations: usize, warmup: usize) -> aoc_utils::Result<Vec<Timing>> {
+    assert!(iterations > 0, "at least one iteration is needed");
+    let parses = !matches!(solution.parse(input), Err(SolutionError::NotSupported { .. }));
+    let mut parse = Vec::with_capacity(iterations);
+    let mut parts: [Option<Vec<Duration>>; 2] = [Some(Vec::with_capacity(iterations)), Some(Vec::with_capacity(iterations))];
+
+    for iteration in 0..warmup + iterations {
+        let timed = iteration >= warmup;
+        let parsed = match parses {
+            true => {
+                let start = Instant::now();
+                let parsed = solution.parse(input)?;
+                if timed {
+                    parse.push(start.elapsed());
+                }
+                Some(parsed)
+            },
+            false => None,
+        };
+        for (part, timings) in (1..=2).zip(parts.iter_mut()) {
+            let Some(durations) = timings else { continue };
+            let start = Instant::now();
+            let answer = match &parsed {
+                Some(parsed) => parsed.solve(part),
+                None if part == 1 => solution.part1(input),
+                None => solution.part2(input),
+            };
+            let elapsed = start.elapsed();
+            match answer {
+                Ok(_) if timed => durations.push(elapsed),
+                Ok(_) => (),
+                Err(SolutionError::NotImplemented { .. }) => *timings = None,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    let day = solution.day();
+    let stages = [(Stage::Parse, Some(parse))].into_iter()
+    .chain((1..=2).zip(parts).map(|(part, timings)| (Stage::Part(part), timings)));
+    let timings = stages
+    .filter_map(|(stage, durations)| Some(Timing { day, stage, iterations, stats: Stats::new(&durations?)? }))
+    .collect();
+    Ok(timings)
+}
+
+/// Flat row of a timing in the JSON output, durations in microseconds
+#[derive(Serialize)]
+struct Row {
+    day: u8,
+    stage: String,
+    iterations: usize,
+    min_us: u64,
+    median_us: u64,
+    p95_us: u64,
+}
+
+impl From<&Timing> for Row {
+    fn from(timing: &Timing) -> Self {
+        let micros = |duration: Duration| duration.as_micros() as u64;
+        Row {
+            day: timing.day,
+            stage: timing.stage.to_string(),
+            iterations: timing.iterations,
+            min_us: micros(timing.stats.min),
+            median_us: micros(timing.stats.median),
+            p95_us: micros(timing.stats.p95),
+        }
+    }
+}
+
+/// Render the timings in the requested format, `color` only applies to the table
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc::{bench::{render, Stage, Stats, Timing}, report::Format};
+/// 
+/// let stats = Stats { min: Duration::from_micros(5), median: Duration::from_micros(6), p95: Duration::from_micros(9) };
+/// let timings = vec![Timing { day: 1, stage: Stage::Part(1), iterations: 20, stats }];
+/// assert_eq!(render(&timings, Format::Csv, false).unwrap(), concat!(
+///     "day,stage,iterations,min_us,median_us,p95_us\n",
+///     "1,part 1,20,5,6,9\n",
+/// ));
+/// ```
+pub fn render(timings: &[Timing], format: Format, color: bool) -> Result<String> {
+    let mut out = String::new();
+    match format {
+        Format::Table if timings.is_empty() => (),
+        Format::Table => {
+            let time = |stat: fn(&Stats) -> Duration| timings.iter().map(move |t| Cell::plain(format_duration(stat(&t.stats))));
+            let columns = [
+                Column::new("Day", true, timings.iter().map(|t| Cell::plain(t.day.to_string()))),
+                Column::new("Stage", false, timings.iter().map(|t| Cell::plain(t.stage.to_string()))),
+                Column::new("Min", true, time(|s| s.min)),
+                Column::new("Median", true, time(|s| s.median)),
+                Column::new("P95", true, time(|s| s.p95)),
+            ];
+            out = layout(&columns, timings.len(), color);
+        },
+        Format::Json => {
+            let rows: Vec<Row> = timings.iter().map(Row::from).collect();
+            out = serde_json::to_string_pretty(&rows)
+            .with_context(|| "failed serializing timings to JSON")?;
+            out.push('\n');
+        },
+        Format::Csv => {
+            out.push_str(CSV_HEADER);
+            out.push('\n');
+            for t in timings {
+                out.push_str(&format!("{},{},{},{},{},{}\n", t.day, t.stage, t.iterations, t.stats.min.as_micros(),
+                t.stats.median.as_micros(), t.stats.p95.as_micros()));
+            }
+        },
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+    use aoc_utils::Parsed;
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats_odd() {
+        let stats = Stats::new(&micros(&[9, 3, 7, 1, 5])).unwrap();
+        assert_eq!(stats, Stats {
+            min: Duration::from_micros(1), median: Duration::from_micros(5), p95: Duration::from_micros(9),
+        });
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let stats = Stats::new(&micros(&[10, 40, 20, 30])).unwrap();
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(25));
+        assert_eq!(stats.p95, Duration::from_micros(40));
+    }
+
+    #[test]
+    fn test_stats_p95_nearest_rank() {
+        // the 95th of 100 sorted timings, not the largest
+        let timings: Vec<u64> = (1..=100).rev().collect();
+        let stats = Stats::new(&micros(&timings)).unwrap();
+        assert_eq!(stats.p95, Duration::from_micros(95));
+        assert_eq!(stats.median, Duration::from_nanos(50_500));
+        // with 20 timings the rank is 19
+        let stats = Stats::new(&micros(&(1..=20).collect::<Vec<_>>())).unwrap();
+        assert_eq!(stats.p95, Duration::from_micros(19));
+    }
+
+    #[test]
+    fn test_stats_single_and_empty() {
+        let stats = Stats::new(&micros(&[7])).unwrap();
+        assert_eq!((stats.min, stats.median, stats.p95), (Duration::from_micros(7), Duration::from_micros(7), Duration::from_micros(7)));
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    /// Line counter that counts its calls, with or without a `parse` hook
+    struct Counted {
+        parse_hook: bool,
+        parses: AtomicUsize,
+        solves: Arc<AtomicUsize>,
+    }
+
+    impl Counted {
+        fn new(parse_hook: bool) -> Self {
+            Counted { parse_hook, parses: AtomicUsize::new(0), solves: Arc::new(AtomicUsize::new(0)) }
+        }
+    }
+
+    struct Lines(Arc<AtomicUsize>, usize);
+
+    impl Parsed for Lines {
+        fn solve(&self, part: u8) -> aoc_utils::Result<String> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            match part {
+                1 => Ok(self.1.to_string()),
+                _ => Err(SolutionError::NotImplemented { day: 99, part }),
+            }
+        }
+    }
+
+    impl Solution for Counted {
+        fn day(&self) -> u8 {
+            99
+        }
+
+        fn part1(&self, input: &str) -> aoc_utils::Result<String> {
+            self.solves.fetch_add(1, Ordering::Relaxed);
+            Ok(input.lines().count().to_string())
+        }
+
+        fn part2(&self, _input: &str) -> aoc_utils::Result<String> {
+            self.solves.fetch_add(1, Ordering::Relaxed);
+            Err(SolutionError::NotImplemented { day: 99, part: 2 })
+        }
+
+        fn parse(&self, input: &str) -> aoc_utils::Result<Box<dyn Parsed>> {
+            if !self.parse_hook {
+                return Err(SolutionError::NotSupported { day: 99, hook: "parse" });
+            }
+            self.parses.fetch_add(1, Ordering::Relaxed);
+            Ok(Box::new(Lines(self.solves.clone(), input.lines().count())))
+        }
+    }
+
+    #[test]
+    fn test_run_honors_iterations() -> anyhow::Result<()> {
+        let solution = Counted::new(true);
+        let timings = run(&solution, "a\nb\n", 20, 3)?;
+        let stages: Vec<Stage> = timings.iter().map(|t| t.stage).collect();
+        assert_eq!(stages, [Stage::Parse, Stage::Part(1)]);
+        assert!(timings.iter().all(|t| t.iterations == 20 && t.day == 99));
+        // one parse to check for the hook, then one per iteration
+        assert_eq!(solution.parses.load(Ordering::Relaxed), 1 + 23);
+        // part 2 is not tried again once it is not implemented
+        assert_eq!(solution.solves.load(Ordering::Relaxed), 23 + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_without_parse_hook() -> anyhow::Result<()> {
+        let solution = Counted::new(false);
+        let timings = run(&solution, "a\nb\n", 5, 0)?;
+        let stages: Vec<Stage> = timings.iter().map(|t| t.stage).collect();
+        assert_eq!(stages, [Stage::Part(1)]);
+        assert_eq!(timings[0].iterations, 5);
+        assert_eq!(solution.parses.load(Ordering::Relaxed), 0);
+        assert_eq!(solution.solves.load(Ordering::Relaxed), 5 + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_error() {
+        let e = run(&day_2::Day2, "1 2 x\n", 3, 1).unwrap_err();
+        assert!(matches!(e, SolutionError::Day(_)), "{}", e);
+    }
+
+    #[test]
+    fn test_render() -> anyhow::Result<()> {
+        let stats = Stats { min: Duration::from_micros(42), median: Duration::from_nanos(1_500_000), p95: Duration::from_millis(2500) };
+        let timings = vec![
+            Timing { day: 5, stage: Stage::Parse, iterations: 20, stats },
+            Timing { day: 5, stage: Stage::Part(1), iterations: 20, stats },
+        ];
+        assert_eq!(render(&timings, Format::Table, false)?, concat!(
+            "Day  Stage    Min  Median    P95\n",
+            "---  ------  ----  ------  -----\n",
+            "  5  parse   42us  1.50ms  2.50s\n",
+            "  5  part 1  42us  1.50ms  2.50s\n",
+        ));
+        assert_eq!(render(&timings, Format::Json, false)?, r#"[
+  {
+    "day": 5,
+    "stage": "parse",
+    "iterations": 20,
+    "min_us": 42,
+    "median_us": 1500,
+    "p95_us": 2500000
+  },
+  {
+    "day": 5,
+    "stage": "part 1",
+    "iterations": 20,
+    "min_us": 42,
+    "median_us": 1500,
+    "p95_us": 2500000
+  }
+]
+"#);
+        assert_eq!(render(&[], Format::Table, false)?, "");
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+//! HTTP access to the Advent of Code website
+//! 
+//! Requests go through the [`HttpClient`] trait so tests can
+//! replace the real website with canned responses
+
+use anyhow::{Context, Result};
+
+/// User agent sent with every request, as asked by the Advent of Code maintainers
+pub const USER_AGENT: &str = "github.com/MantasG6/advent-of-code-2024 by mantasgricius6@email.com";
+
+/// Status code and body returned by the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// Response body
+    pub body: String,
+}
+
+impl Response {
+    /// Whether the status code is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Minimal HTTP client used to talk to the Advent of Code website
+/// 
+/// Non-success status codes are returned as a [`Response`],
+/// only transport failures are reported as errors
+pub trait HttpClient {
+    /// Send a `GET` request authenticated with the session cookie
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+
+    /// Send a `POST` request with a form encoded body authenticated with the session cookie
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// [`HttpClient`] backed by [`ureq`]
+#[derive(Debug, Default)]
+pub struct UreqClient;
+
+impl UreqClient {
+    fn into_response(result: std::result::Result<ureq::Response, ureq::Error>, url: &str) -> Result<Response> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e).with_context(|| format!("request to {} failed", url)),
+        };
+        let status = response.status();
+        let body = response.into_string()
+        .with_context(|| format!("failed reading response from {}", url))?;
+        Ok(Response { status, body })
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let result = ureq::get(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call();
+        Self::into_response(result, url)
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let result = ureq::post(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(form);
+        Self::into_response(result, url)
+    }
+}
@@ -0,0 +1,145 @@
+//! Errors pointing into the puzzle input, rendered with miette
+//! 
+//! The day crates only expose a [`Location`] on their error types,
+//! [`location`] walks the error chain to find one and [`render`] shows
+//! the offending line of the input with the location underlined
+
+use std::{error::Error, num::ParseIntError};
+use aoc_utils::{error_chain, GridError, Location, ParseError};
+use miette::{Diagnostic, GraphicalReportHandler, GraphicalTheme, NamedSource, SourceSpan};
+
+/// How errors pointing into the input are printed, see `--diagnostics`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Style {
+    /// Unicode frames, colored on a terminal
+    #[default]
+    Fancy,
+    /// ASCII frames without colors
+    Plain,
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("{message}")]
+struct InputDiagnostic {
+    message: String,
+    #[source_code]
+    input: NamedSource<String>,
+    #[label("here")]
+    span: SourceSpan,
+}
+
+/// Location of the first error in the chain that has one
+pub fn location(e: &(dyn Error + 'static)) -> Option<Location> {
+    let mut source = Some(e);
+    while let Some(e) = source {
+        let location = if let Some(e) = e.downcast_ref::<aoc_utils::SolutionError>() {
+            e.day_error::<day_1::Day1Error>().and_then(day_1::Day1Error::location)
+            .or_else(|| e.day_error::<day_2::Day2Error>().and_then(day_2::Day2Error::location))
+            .or_else(|| e.day_error::<day_3::Day3Error>().and_then(day_3::Day3Error::location))
+            .or_else(|| e.day_error::<day_4::Day4Error>().and_then(day_4::Day4Error::location))
+            .or_else(|| e.day_error::<day_5::Day5Error>().and_then(day_5::Day5Error::location))
+            .or_else(|| e.day_error::<day_6::Day6Error>().and_then(day_6::Day6Error::location))
+            .or_else(|| e.day_error::<day_7::Day7Error>().and_then(day_7::Day7Error::location))
+            .or_else(|| e.day_error::<day_8::Day8Error>().and_then(day_8::Day8Error::location))
+            .or_else(|| e.day_error::<day_9::Day9Error>().and_then(day_9::Day9Error::location))
+            .or_else(|| e.day_error::<day_10::Day10Error>().and_then(day_10::Day10Error::location))
+            .or_else(|| e.day_error::<day_11::Day11Error>().and_then(day_11::Day11Error::location))
+            .or_else(|| e.day_error::<day_12::Day12Error>().and_then(day_12::Day12Error::location))
+            .or_else(|| e.day_error::<day_13::Day13Error>().and_then(day_13::Day13Error::location))
+            .or_else(|| e.day_error::<day_14::Day14Error>().and_then(day_14::Day14Error::location))
+            .or_else(|| e.day_error::<day_15::Day15Error>().and_then(day_15::Day15Error::location))
+            .or_else(|| e.day_error::<day_16::Day16Error>().and_then(day_16::Day16Error::location))
+            .or_else(|| e.day_error::<day_17::Day17Error>().and_then(day_17::Day17Error::location))
+            .or_else(|| e.day_error::<day_18::Day18Error>().and_then(day_18::Day18Error::location))
+            .or_else(|| e.day_error::<day_19::Day19Error>().and_then(day_19::Day19Error::location))
+            .or_else(|| e.day_error::<day_20::Day20Error>().and_then(day_20::Day20Error::location))
+            .or_else(|| e.day_error::<day_21::Day21Error>().and_then(day_21::Day21Error::location))
+            .or_else(|| e.day_error::<day_22::Day22Error>().and_then(day_22::Day22Error::location))
+            .or_else(|| e.day_error::<day_23::Day23Error>().and_then(day_23::Day23Error::location))
+            .or_else(|| e.day_error::<day_24::Day24Error>().and_then(day_24::Day24Error::location))
+            .or_else(|| e.day_error::<day_25::Day25Error>().and_then(day_25::Day25Error::location))
+        } else if let Some(e) = e.downcast_ref::<ParseError>() {
+            e.location()
+        } else if let Some(e) = e.downcast_ref::<ParseError<ParseIntError>>() {
+            e.location()
+        } else {
+            e.downcast_ref::<GridError>().and_then(GridError::location)
+        };
+        if location.is_some() {
+            return location;
+        }
+        source = e.source();
+    }
+    None
+}
+
+/// Error with the part of the input it points to, `None` when it has no location
+/// 
+/// `name` labels the input in the snippet, such as the path of the file
+/// 
+/// # Examples
+/// ```
+/// use aoc::diagnostics::{render, Style};
+/// use aoc_utils::Solution;
+/// 
+/// let input = "47|53\n97|x\n\n47,53\n";
+/// let e = day_5::Day5.part1(input).unwrap_err();
+/// let rendered = render(&e, input, "input.txt", Style::Plain).unwrap();
+/// assert!(rendered.contains("[input.txt:2:1]"));
+/// assert!(rendered.contains("97|x"));
+/// ```
+pub fn render(e: &(dyn Error + 'static), input: &str, name: &str, style: Style) -> Option<String> {
+    let range = location(e)?.find(input)?;
+    let diagnostic = InputDiagnostic {
+        message: error_chain(e),
+        input: NamedSource::new(name, input.to_string()),
+        span: range.into(),
+    };
+    let theme = match style {
+        Style::Fancy => GraphicalTheme::default(),
+        Style::Plain => GraphicalTheme::none(),
+    };
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(theme)
+    .render_report(&mut out, &diagnostic)
+    .ok()?;
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::Solution;
+    use super::*;
+
+    #[test]
+    fn test_render_malformed_rule() {
+        let input = day_5::SAMPLE.replacen("97|13", "97|1x", 1);
+        let e = day_5::Day5.part1(&input).unwrap_err();
+        let rendered = render(&e, &input, "input.txt", Style::Plain).unwrap();
+        assert!(rendered.contains("malformed rule 97|1x on line 2"), "{}", rendered);
+        assert!(rendered.contains("[input.txt:2:1]"), "{}", rendered);
+        assert!(rendered.contains("2 | 97|1x"), "{}", rendered);
+        assert!(rendered.contains("^^|^^"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_render_field() {
+        let input = "3   4\n4   x\n";
+        let e = day_1::Day1.part1(input).unwrap_err();
+        assert_eq!(location(&e), Some(Location::Field { line: 2, field: 2 }));
+        let rendered = render(&e, input, "input.txt", Style::Plain).unwrap();
+        assert!(rendered.contains("[input.txt:2:5]"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_render_without_location() {
+        let e = day_4::Day4.part1(day_4::SAMPLE).unwrap_err();
+        assert_eq!(render(&e, day_4::SAMPLE, "input.txt", Style::Plain), None);
+    }
+
+    #[test]
+    fn test_location_of_ragged_grid() {
+        let e = day_4::Day4.part2("XMAS\nXM\n").unwrap_err();
+        assert_eq!(location(&e), Some(Location::Line(2)));
+    }
+}
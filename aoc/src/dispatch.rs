@@ -0,0 +1,123 @@
+//! Solving a day by its number, the entry point of the language bindings
+//! 
+//! The `aoc-py` Python module calls these functions and turns every
+//! [`DispatchError`] variant into a matching Python exception
+
+use std::path::Path;
+use aoc_utils::{InputError, InputSource, Solution, SolutionError};
+#[cfg(test)]
+use aoc_utils::error_chain;
+use thiserror::Error;
+
+/// Errors returned when solving a day by its number
+#[derive(Debug, Error)]
+pub enum DispatchError {
+    /// No solution is registered for the day
+    #[error("day {0} is not implemented")]
+    UnknownDay(u8),
+    /// The part is neither 1 nor 2
+    #[error("part must be 1 or 2, got {0}")]
+    InvalidPart(u8),
+    /// The input file could not be read
+    #[error(transparent)]
+    Input(#[from] InputError),
+    /// Solving the part failed
+    #[error(transparent)]
+    Solution(#[from] SolutionError),
+}
+
+/// Registered solution of a day
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc::dispatch::solution(2).map(|s| s.day()), Some(2));
+/// assert!(aoc::dispatch::solution(26).is_none());
+/// ```
+pub fn solution(day: u8) -> Option<Box<dyn Solution>> {
+    crate::solutions().into_iter().find(|s| s.day() == day)
+}
+
+/// Solve one part of a day for the input text
+/// 
+/// # Examples
+/// ```
+/// let answer = aoc::dispatch::solve(1, 2, day_1::SAMPLE).unwrap();
+/// assert_eq!(answer, "31");
+/// ```
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, DispatchError> {
+    let solution = solution(day).ok_or(DispatchError::UnknownDay(day))?;
+    match part {
+        1 => Ok(solution.part1(input)?),
+        2 => Ok(solution.part2(input)?),
+        _ => Err(DispatchError::InvalidPart(part)),
+    }
+}
+
+/// Solve one part of a day for the input in a file
+/// 
+/// The file is read like `aoc run --input`, compressed files included
+pub fn solve_file(day: u8, part: u8, path: &Path) -> Result<String, DispatchError> {
+    // check the arguments before reading a possibly large file
+    solution(day).ok_or(DispatchError::UnknownDay(day))?;
+    if !(1..=2).contains(&part) {
+        return Err(DispatchError::InvalidPart(part));
+    }
+    let input = InputSource::Path(path.to_path_buf()).read_to_string()?;
+    solve(day, part, &input)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::prelude::*;
+    use super::*;
+
+    #[test]
+    fn test_solve_every_sample() {
+        for solution in crate::solutions() {
+            let sample = solution.sample().expect("every day embeds its sample");
+            let sample_solution = solution.sample_solution();
+            // parts without a sample answer are not solved
+            for (part, expected) in [1, 2].into_iter().zip(sample.answers).filter(|(_, expected)| !expected.is_empty()) {
+                let answer = match &sample_solution {
+                    // the dispatched solution has the parameters of the puzzle input
+                    Some(solver) => crate::solve_part(solver.as_ref(), part, sample.input)
+                    .map(|result| result.answer)
+                    .map_err(DispatchError::from),
+                    None => solve(solution.day(), part, sample.input),
+                };
+                match answer {
+                    Ok(answer) => assert_eq!(answer, expected),
+                    Err(e) => assert!(matches!(e, DispatchError::Solution(SolutionError::NotImplemented { .. })), "{}", e),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_unknown_day() {
+        let e = solve(26, 1, "").unwrap_err();
+        assert!(matches!(e, DispatchError::UnknownDay(26)));
+        assert_eq!(error_chain(&e), "day 26 is not implemented");
+    }
+
+    #[test]
+    fn test_solve_invalid_part() {
+        assert!(matches!(solve(1, 3, day_1::SAMPLE), Err(DispatchError::InvalidPart(3))));
+        assert!(matches!(solve_file(1, 0, Path::new("missing.txt")), Err(DispatchError::InvalidPart(0))));
+    }
+
+    #[test]
+    fn test_solve_file() -> anyhow::Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(day_3::SAMPLE)?;
+        assert_eq!(solve_file(3, 2, file.path())?, "48");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_file_missing() {
+        let e = solve_file(1, 1, Path::new("missing.txt")).unwrap_err();
+        assert!(matches!(e, DispatchError::Input(InputError::Io { .. })));
+        assert!(error_chain(&e).starts_with("could not read file missing.txt: "), "{}", error_chain(&e));
+    }
+}
@@ -0,0 +1,112 @@
+//! Exit codes of the runner for scripts, see [`ErrorKind`]
+//! 
+//! Errors returned by a command are categorized with [`kind`], which
+//! knows the error types of every day. Commands that keep going after
+//! a part fails return [`Reported`] once they are done.
+
+use std::error::Error;
+use aoc_utils::ErrorKind;
+use crate::dispatch::DispatchError;
+
+/// Failures that were already printed while solving, the runner only exits with their category
+#[derive(Debug, thiserror::Error)]
+#[error("{count} parts failed, the first with a {kind} error")]
+pub struct Reported {
+    /// Category of the first failure
+    pub kind: ErrorKind,
+    /// Number of the failed parts
+    pub count: usize,
+}
+
+impl Reported {
+    /// Add a failure to the ones reported so far
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc::exit::Reported;
+    /// use aoc_utils::ErrorKind;
+    /// 
+    /// let mut reported = None;
+    /// Reported::add(&mut reported, ErrorKind::Parse);
+    /// Reported::add(&mut reported, ErrorKind::Timeout);
+    /// let reported = reported.unwrap();
+    /// assert_eq!((reported.kind, reported.count), (ErrorKind::Parse, 2));
+    /// ```
+    pub fn add(reported: &mut Option<Reported>, kind: ErrorKind) {
+        match reported {
+            Some(reported) => reported.count += 1,
+            None => *reported = Some(Reported { kind, count: 1 }),
+        }
+    }
+}
+
+/// Category of the first error in the chain that has one
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{ErrorKind, Solution};
+/// 
+/// let e = day_5::Day5.part1("47|53\n97|x\n\n47,53\n").unwrap_err();
+/// assert_eq!(aoc::exit::kind(&e), ErrorKind::Parse);
+/// ```
+pub fn kind(e: &(dyn Error + 'static)) -> ErrorKind {
+    ErrorKind::of(e, |e| {
+        if let Some(e) = e.downcast_ref::<DispatchError>() {
+            return Some(match e {
+                DispatchError::Input(e) => e.kind(),
+                DispatchError::Solution(e) => kind(e),
+                DispatchError::UnknownDay(_) | DispatchError::InvalidPart(_) => ErrorKind::Other,
+            });
+        }
+        e.downcast_ref().map(|reported: &Reported| reported.kind)
+        .or_else(|| e.downcast_ref().map(day_1::Day1Error::kind))
+        .or_else(|| e.downcast_ref().map(day_2::Day2Error::kind))
+        .or_else(|| e.downcast_ref().map(day_3::Day3Error::kind))
+        .or_else(|| e.downcast_ref().map(day_4::Day4Error::kind))
+        .or_else(|| e.downcast_ref().map(day_5::Day5Error::kind))
+        .or_else(|| e.downcast_ref().map(day_6::Day6Error::kind))
+        .or_else(|| e.downcast_ref().map(day_7::Day7Error::kind))
+        .or_else(|| e.downcast_ref().map(day_8::Day8Error::kind))
+        .or_else(|| e.downcast_ref().map(day_9::Day9Error::kind))
+        .or_else(|| e.downcast_ref().map(day_10::Day10Error::kind))
+        .or_else(|| e.downcast_ref().map(day_11::Day11Error::kind))
+        .or_else(|| e.downcast_ref().map(day_12::Day12Error::kind))
+        .or_else(|| e.downcast_ref().map(day_13::Day13Error::kind))
+        .or_else(|| e.downcast_ref().map(day_14::Day14Error::kind))
+        .or_else(|| e.downcast_ref().map(day_15::Day15Error::kind))
+        .or_else(|| e.downcast_ref().map(day_16::Day16Error::kind))
+        .or_else(|| e.downcast_ref().map(day_17::Day17Error::kind))
+        .or_else(|| e.downcast_ref().map(day_18::Day18Error::kind))
+        .or_else(|| e.downcast_ref().map(day_19::Day19Error::kind))
+        .or_else(|| e.downcast_ref().map(day_20::Day20Error::kind))
+        .or_else(|| e.downcast_ref().map(day_21::Day21Error::kind))
+        .or_else(|| e.downcast_ref().map(day_22::Day22Error::kind))
+        .or_else(|| e.downcast_ref().map(day_23::Day23Error::kind))
+        .or_else(|| e.downcast_ref().map(day_24::Day24Error::kind))
+        .or_else(|| e.downcast_ref().map(day_25::Day25Error::kind))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{Solution, SolutionError};
+
+    #[test]
+    fn test_kind_days() {
+        let overflow = day_1::Day1.part2("9223372036854775807   9223372036854775807\n2   9223372036854775807\n");
+        assert_eq!(kind(&overflow.unwrap_err()), ErrorKind::Solver);
+        assert_eq!(kind(&day_4::Day4.part2("XMAS\nXM\n").unwrap_err()), ErrorKind::Parse);
+        assert_eq!(kind(&SolutionError::NotImplemented { day: 4, part: 1 }), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_kind_wrapped() {
+        let e = anyhow::Error::new(SolutionError::Cancelled).context("interrupted while solving day 1 part 1");
+        assert_eq!(kind(e.as_ref()), ErrorKind::Timeout);
+        let e = anyhow::Error::new(Reported { kind: ErrorKind::Solver, count: 1 });
+        assert_eq!(kind(e.as_ref()), ErrorKind::Solver);
+        assert_eq!(kind(&DispatchError::Solution(SolutionError::Cancelled)), ErrorKind::Timeout);
+        assert_eq!(kind(&DispatchError::UnknownDay(26)), ErrorKind::Other);
+    }
+}
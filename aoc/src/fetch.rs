@@ -0,0 +1,150 @@
+//! Puzzle input downloading
+//! 
+//! Inputs are cached next to each day crate and never downloaded twice
+
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use crate::client::HttpClient;
+
+/// Environment variable holding the Advent of Code session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Read the session cookie from the [`SESSION_VAR`] environment variable
+/// 
+/// Fails with a message explaining how to provide the cookie
+/// when the variable is missing or empty
+pub fn session_from_env() -> Result<String> {
+    match std::env::var(SESSION_VAR) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => bail!("{} environment variable is not set, copy the `session` cookie \
+        from adventofcode.com into it", SESSION_VAR),
+    }
+}
+
+/// URL of the puzzle input for a given day
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc::fetch::input_url(5), "https://adventofcode.com/2024/day/5/input");
+/// ```
+pub fn input_url(day: u8) -> String {
+    format!("https://adventofcode.com/2024/day/{}/input", day)
+}
+
+/// Download the puzzle input unless it is already cached
+/// 
+/// The input is stored at [`crate::input_path`] relative to `root`.
+/// When the file already exists no request is made at all.
+/// Returns the path of the cached input.
+pub fn fetch_input(client: &dyn HttpClient, session: Option<&str>, day: u8, root: &Path) -> Result<PathBuf> {
+    let path = root.join(crate::input_path(day));
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let session = session
+    .with_context(|| format!("cannot download day {} input: {} environment variable is not set", day, SESSION_VAR))?;
+
+    let url = input_url(day);
+    let response = client.get(&url, session)?;
+    if !response.is_success() {
+        bail!("server returned {} for day {} input: {}", response.status, day,
+        response.body.lines().next().unwrap_or_default());
+    }
+
+    let dir = path.parent().with_context(|| format!("invalid input path {}", path.display()))?;
+    std::fs::create_dir_all(dir)
+    .with_context(|| format!("could not create directory {}", dir.display()))?;
+    std::fs::write(&path, response.body)
+    .with_context(|| format!("could not write file {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::cell::RefCell;
+    use assert_fs::prelude::*;
+    use anyhow::Result;
+    use crate::client::{HttpClient, Response};
+    use super::*;
+
+    /// Fake server returning a canned response and recording the requests
+    pub(crate) struct FakeClient {
+        pub(crate) response: Response,
+        pub(crate) requests: RefCell<Vec<String>>,
+    }
+
+    impl FakeClient {
+        pub(crate) fn new(status: u16, body: &str) -> Self {
+            FakeClient {
+                response: Response { status, body: body.to_string() },
+                requests: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for FakeClient {
+        fn get(&self, url: &str, session: &str) -> Result<Response> {
+            self.requests.borrow_mut().push(format!("GET {} {}", url, session));
+            Ok(self.response.clone())
+        }
+
+        fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            self.requests.borrow_mut().push(format!("POST {} {} {}", url, session, form.join("&")));
+            Ok(self.response.clone())
+        }
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_and_caches() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(200, "1   2\n3   4\n");
+
+        let path = fetch_input(&client, Some("abc"), 1, root.path())?;
+
+        root.child("day-1/data/input.txt").assert("1   2\n3   4\n");
+        assert_eq!(path, root.path().join("day-1/data/input.txt"));
+        assert_eq!(*client.requests.borrow(), vec!["GET https://adventofcode.com/2024/day/1/input abc"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_uses_cache() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        root.child("day-2/data/input.txt").write_str("cached")?;
+        let client = FakeClient::new(200, "fresh");
+
+        fetch_input(&client, None, 2, root.path())?;
+
+        root.child("day-2/data/input.txt").assert("cached");
+        assert!(client.requests.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_missing_session() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(200, "input");
+
+        let result = fetch_input(&client, None, 3, root.path());
+
+        assert!(result.is_err_and(|e| e.to_string().contains("AOC_SESSION environment variable is not set")));
+        assert!(client.requests.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_input_client_error() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n");
+
+        let result = fetch_input(&client, Some("expired"), 4, root.path());
+
+        assert!(result.is_err_and(|e| e.to_string()
+        .eq("server returned 400 for day 4 input: Puzzle inputs differ by user.  Please log in to get your puzzle input.")));
+        assert!(!root.child("day-4/data/input.txt").path().exists());
+        Ok(())
+    }
+}
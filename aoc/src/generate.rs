@@ -0,0 +1,82 @@
+//! Seeded puzzle inputs for benchmarks and tests
+//! 
+//! `aoc gen` asks the [`Solution`](aoc_utils::Solution) of a day for an
+//! input of the requested size and prints the answers it should be solved to
+
+use std::fmt;
+use anyhow::{Context, Result};
+use aoc_utils::ExpectedAnswers;
+
+/// Input generated for a day with its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    /// Day of the challenge
+    pub day: u8,
+    /// Puzzle input text
+    pub input: String,
+    /// Expected answers of both parts
+    pub answers: ExpectedAnswers,
+}
+
+impl fmt::Display for Generated {
+    /// One `day=N part=P answer=A` line per part
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in [1, 2].into_iter().zip(&self.answers) {
+            writeln!(f, "day={} part={} answer={}", self.day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generate the input of `day` from `seed`
+/// 
+/// # Examples
+/// ```
+/// let generated = aoc::generate::generate(1, 10, 42).unwrap();
+/// assert_eq!(generated.input.lines().count(), 10);
+/// assert!(generated.to_string().starts_with("day=1 part=1 answer="));
+/// ```
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<Generated> {
+    let solution = crate::dispatch::solution(day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+    let (input, answers) = solution.generate(size, seed)
+    .with_context(|| format!("day {} has no input generator", day))?;
+    Ok(Generated { day, input, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_reproducible() -> Result<()> {
+        for solution in crate::solutions() {
+            let day = solution.day();
+            assert_eq!(generate(day, 200, 7)?, generate(day, 200, 7)?, "day {}", day);
+            assert_ne!(generate(day, 200, 7)?.input, generate(day, 200, 8)?.input, "day {}", day);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_answers_match_solutions() -> Result<()> {
+        for solution in crate::solutions() {
+            let generated = generate(solution.day(), 200, 2024)?;
+            let answers = [solution.part1(&generated.input), solution.part2(&generated.input)];
+            for (part, (answer, expected)) in (1..).zip(answers.into_iter().zip(&generated.answers)) {
+                match answer {
+                    Ok(answer) => assert_eq!(&answer, expected, "day {} part {}", generated.day, part),
+                    Err(aoc_utils::SolutionError::NotImplemented { .. }) => {},
+                    Err(e) => panic!("day {} part {}: {}", generated.day, part, e),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_unknown_day() {
+        let result = generate(26, 10, 1);
+        assert!(result.is_err_and(|e| e.to_string() == "day 26 is not implemented"));
+    }
+}
@@ -0,0 +1,475 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 runner
+//! 
+//! Collects the solutions of every day so they can be run together
+//! 
+//! [`Read more`](../../../README.md)
+
+use std::{sync::mpsc::{self, RecvTimeoutError}, time::{Duration, Instant}};
+use aoc_utils::{CancelToken, InputSource, NoProgress, Progress, Solution, SolutionError, SolveResult};
+
+pub mod bench;
+pub mod client;
+pub mod diagnostics;
+pub mod dispatch;
+pub mod exit;
+pub mod fetch;
+pub mod generate;
+pub mod metrics;
+pub mod progress;
+pub mod repl;
+pub mod report;
+pub mod sample;
+pub mod scaffold;
+pub mod submit;
+pub mod table;
+pub mod verify;
+
+/// Registry of all the implemented days
+/// 
+/// Returns the solutions ordered by day
+/// 
+/// # Examples
+/// ```
+/// let days: Vec<u8> = aoc::solutions().iter().map(|s| s.day()).collect();
+/// assert_eq!(&days[..5], &[1, 2, 3, 4, 5]);
+/// ```
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(day_1::Day1),
+        Box::new(day_2::Day2),
+        Box::new(day_3::Day3),
+        Box::new(day_4::Day4),
+        Box::new(day_5::Day5),
+        Box::new(day_6::Day6),
+        Box::new(day_7::Day7),
+        Box::new(day_8::Day8),
+        Box::new(day_9::Day9),
+        Box::new(day_10::Day10),
+        Box::new(day_11::Day11),
+        Box::new(day_12::Day12),
+        Box::new(day_13::Day13),
+        Box::new(day_14::Day14::default()),
+        Box::new(day_15::Day15),
+        Box::new(day_16::Day16),
+        Box::new(day_17::Day17),
+        Box::new(day_18::Day18::default()),
+        Box::new(day_19::Day19),
+        Box::new(day_20::Day20::default()),
+        Box::new(day_21::Day21),
+        Box::new(day_22::Day22),
+        Box::new(day_23::Day23),
+        Box::new(day_24::Day24),
+        Box::new(day_25::Day25),
+    ]
+}
+
+/// Puzzle parameters the inputs do not state, set with the `aoc run` options
+/// 
+/// The defaults are the ones of the puzzle inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::Args)]
+pub struct Parameters {
+    /// Width of the day 14 floor
+    #[arg(long, default_value_t = day_14::FLOOR.0, value_parser = clap::value_parser!(i64).range(1..))]
+    pub width: i64,
+    /// Height of the day 14 floor
+    #[arg(long, default_value_t = day_14::FLOOR.1, value_parser = clap::value_parser!(i64).range(1..))]
+    pub height: i64,
+    /// Side of the day 18 memory space
+    #[arg(long, default_value_t = day_18::SPACE.0)]
+    pub size: usize,
+    /// Bytes fallen on the day 18 memory space before part 1
+    #[arg(long, default_value_t = day_18::SPACE.1)]
+    pub fallen: usize,
+    /// Picoseconds a day 20 cheat has to save to be counted
+    #[arg(long, default_value_t = day_20::MIN_SAVING)]
+    pub min_saving: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        let (width, height) = day_14::FLOOR;
+        let (size, fallen) = day_18::SPACE;
+        Parameters { width, height, size, fallen, min_saving: day_20::MIN_SAVING }
+    }
+}
+
+/// Registry of all the implemented days, solving with the given [`Parameters`]
+/// 
+/// # Examples
+/// ```
+/// let parameters = aoc::Parameters { width: 11, height: 7, ..Default::default() };
+/// let solutions = aoc::solutions_with(&parameters);
+/// assert_eq!(solutions[13].part1(day_14::SAMPLE).unwrap(), "12");
+/// let solutions = aoc::solutions_with(&aoc::Parameters { size: 7, fallen: 12, ..Default::default() });
+/// assert_eq!(solutions[17].part1(day_18::SAMPLE).unwrap(), "22");
+/// let solutions = aoc::solutions_with(&aoc::Parameters { min_saving: 50, ..Default::default() });
+/// assert_eq!(solutions[19].part2(day_20::SAMPLE).unwrap(), "285");
+/// ```
+pub fn solutions_with(parameters: &Parameters) -> Vec<Box<dyn Solution>> {
+    let mut solutions = solutions();
+    for solution in &mut solutions {
+        match solution.day() {
+            14 => *solution = Box::new(day_14::Day14 { floor: (parameters.width, parameters.height) }),
+            18 => *solution = Box::new(day_18::Day18 { space: (parameters.size, parameters.fallen) }),
+            20 => *solution = Box::new(day_20::Day20 { min_saving: parameters.min_saving }),
+            _ => {},
+        }
+    }
+    solutions
+}
+
+/// Path of the puzzle input for a given day
+/// 
+/// Inputs are kept in the `data` directory of each day crate
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc::input_path(3), std::path::Path::new("day-3/data/input.txt"));
+/// ```
+pub fn input_path(day: u8) -> std::path::PathBuf {
+    std::path::Path::new(&format!("day-{}", day)).join("data").join("input.txt")
+}
+
+/// Set the number of threads used by the days solved in parallel
+/// 
+/// Only available when built with the `parallel` feature,
+/// passing `None` keeps the rayon default of one thread per core
+/// 
+/// # Examples
+/// ```
+/// assert!(aoc::configure_threads(None).is_ok());
+/// ```
+pub fn configure_threads(threads: Option<usize>) -> anyhow::Result<()> {
+    let Some(threads) = threads else {
+        return Ok(());
+    };
+    #[cfg(feature = "parallel")]
+    {
+        use anyhow::Context;
+        rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .with_context(|| format!("failed starting {} threads", threads))
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        anyhow::bail!("cannot use {} threads: aoc was built without the parallel feature", threads)
+    }
+}
+
+/// Input read from the system clipboard, used by `aoc run --clip`
+/// 
+/// Fails when aoc was built without the `clipboard` feature
+pub fn clipboard_input() -> anyhow::Result<InputSource> {
+    #[cfg(feature = "clipboard")]
+    {
+        Ok(InputSource::Clipboard)
+    }
+    #[cfg(not(feature = "clipboard"))]
+    {
+        anyhow::bail!("cannot read the clipboard: aoc was built without the clipboard feature")
+    }
+}
+
+/// Solve one part of a day and measure how long it took
+/// 
+/// Memory usage is recorded as well when the `metrics` feature is enabled
+/// 
+/// # Examples
+/// ```
+/// let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+/// let result = aoc::solve_part(&day_1::Day1, 2, input).unwrap();
+/// assert_eq!(result.answer, "31");
+/// assert_eq!(result.input_bytes, 36);
+/// ```
+pub fn solve_part(solution: &dyn Solution, part: u8, input: &str) -> aoc_utils::Result<SolveResult> {
+    solve_part_with_progress(solution, part, input, &NoProgress)
+}
+
+/// [`solve_part`] reporting the progress of the solve to `progress`
+/// 
+/// # Examples
+/// ```
+/// let result = aoc::solve_part_with_progress(&day_5::Day5, 1, day_5::SAMPLE, &aoc_utils::NoProgress).unwrap();
+/// assert_eq!(result.answer, "143");
+/// ```
+pub fn solve_part_with_progress(solution: &dyn Solution, part: u8, input: &str,
+    progress: &dyn Progress) -> aoc_utils::Result<SolveResult> {
+    solve_part_with_cancel(solution, part, input, progress, &CancelToken::new())
+}
+
+/// [`solve_part_with_progress`] stopping early once `cancel` is cancelled
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{CancelToken, NoProgress, SolutionError};
+/// 
+/// let cancel = CancelToken::new();
+/// cancel.cancel();
+/// let result = aoc::solve_part_with_cancel(&day_4::Day4, 2, day_4::SAMPLE, &NoProgress, &cancel);
+/// assert!(matches!(result, Err(SolutionError::Cancelled)));
+/// ```
+pub fn solve_part_with_cancel(solution: &dyn Solution, part: u8, input: &str,
+    progress: &dyn Progress, cancel: &CancelToken) -> aoc_utils::Result<SolveResult> {
+    let ((answer, duration), allocations) = metrics::measure(|| {
+        let start = Instant::now();
+        let answer = solution.solve_with_cancel(part, input, progress, cancel);
+        (answer, start.elapsed())
+    });
+    let answer = answer?;
+
+    Ok(SolveResult {
+        day: solution.day(),
+        part,
+        answer,
+        duration,
+        input_bytes: input.len() as u64,
+        allocations,
+        timed_out: false,
+    })
+}
+
+/// Parts solved by `aoc run --part`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Parts {
+    /// Part 1 only
+    #[value(name = "1")]
+    One,
+    /// Part 2 only
+    #[value(name = "2")]
+    Two,
+    /// Both parts with a single [`Solution::solve_both`] call
+    Both,
+}
+
+/// Solve both parts of a day with [`Solution::solve_both`] and measure how long it took
+/// 
+/// The parts are solved together, so both results carry the time and
+/// memory usage of the whole call.
+/// 
+/// # Examples
+/// ```
+/// let [part1, part2] = aoc::solve_both(&day_3::Day3, day_3::SAMPLE).unwrap();
+/// assert_eq!((part1.answer.as_str(), part2.answer.as_str()), ("161", "48"));
+/// assert_eq!(part1.duration, part2.duration);
+/// ```
+pub fn solve_both(solution: &dyn Solution, input: &str) -> aoc_utils::Result<[SolveResult; 2]> {
+    let ((answers, duration), allocations) = metrics::measure(|| {
+        let start = Instant::now();
+        let answers = solution.solve_both(input);
+        (answers, start.elapsed())
+    });
+    let (part1, part2) = answers?;
+
+    let result = |part, answer| SolveResult {
+        day: solution.day(),
+        part,
+        answer,
+        duration,
+        input_bytes: input.len() as u64,
+        allocations,
+        timed_out: false,
+    };
+    Ok([result(1, part1), result(2, part2)])
+}
+
+/// How often [`solve_part_with_timeout`] checks `cancel` while it waits for the solve
+const TIMEOUT_POLL: Duration = Duration::from_millis(20);
+
+/// [`solve_part_with_cancel`] giving up once the solve takes longer than `timeout`
+/// 
+/// The part is solved on a worker thread. Once the timeout passes the worker
+/// is cancelled and the part is reported as [`SolveResult::timeout`]. The
+/// worker is waited for, so days that only check the token before solving
+/// finish their solve before this returns.
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc_utils::{CancelToken, NoProgress};
+/// 
+/// let cancel = CancelToken::new();
+/// let result = aoc::solve_part_with_timeout(&day_1::Day1, 1, day_1::SAMPLE, &NoProgress, &cancel,
+///     Duration::from_secs(10)).unwrap();
+/// assert_eq!(result.answer, "11");
+/// assert!(!result.timed_out);
+/// ```
+pub fn solve_part_with_timeout(solution: &dyn Solution, part: u8, input: &str, progress: &dyn Progress,
+    cancel: &CancelToken, timeout: Duration) -> aoc_utils::Result<SolveResult> {
+    let deadline = Instant::now() + timeout;
+    let worker_cancel = CancelToken::new();
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        scope.spawn({
+            let worker_cancel = &worker_cancel;
+            move || {
+                // the receiver is gone once the part timed out
+                let _ = sender.send(solve_part_with_cancel(solution, part, input, progress, worker_cancel));
+            }
+        });
+        loop {
+            let wait = deadline.saturating_duration_since(Instant::now()).min(TIMEOUT_POLL);
+            match receiver.recv_timeout(wait) {
+                Ok(result) => return result,
+                Err(RecvTimeoutError::Disconnected) => panic!("solving day {} part {} panicked", solution.day(), part),
+                Err(RecvTimeoutError::Timeout) => {},
+            }
+            if cancel.is_cancelled() {
+                worker_cancel.cancel();
+                return Err(SolutionError::Cancelled);
+            }
+            if Instant::now() >= deadline {
+                worker_cancel.cancel();
+                return Ok(SolveResult::timeout(solution.day(), part, timeout, input.len() as u64));
+            }
+        }
+    })
+}
+
+/// Parse a `--timeout` value such as `500ms`, `30s` or `2m`
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// 
+/// assert_eq!(aoc::parse_timeout("30s"), Ok(Duration::from_secs(30)));
+/// assert!(aoc::parse_timeout("30").is_err());
+/// ```
+pub fn parse_timeout(text: &str) -> Result<Duration, String> {
+    let split = text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+    let (amount, unit) = text.split_at(split);
+    let amount: u64 = amount.parse()
+    .map_err(|_| format!("invalid timeout `{}`, expected a number followed by ms, s or m", text))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "s" => Ok(Duration::from_secs(amount)),
+        "m" => Ok(Duration::from_secs(amount * 60)),
+        _ => Err(format!("invalid timeout unit in `{}`, expected ms, s or m", text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part() -> aoc_utils::Result<()> {
+        let result = solve_part(&day_3::Day3, 1, day_3::SAMPLE)?;
+        assert_eq!((result.day, result.part, result.answer.as_str()), (3, 1, "161"));
+        assert_eq!(result.input_bytes, day_3::SAMPLE.len() as u64);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn test_solve_part_allocations() -> aoc_utils::Result<()> {
+        let first = solve_part(&day_1::Day1, 1, day_1::SAMPLE)?.allocations.expect("allocations recorded");
+        assert!(first.allocated_bytes > 0 && first.peak_bytes > 0);
+        let second = solve_part(&day_1::Day1, 2, day_1::SAMPLE)?.allocations.expect("allocations recorded");
+        assert!(second.allocated_bytes > 0 && second.peak_bytes > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_part_not_implemented() {
+        let result = solve_part(&day_4::Day4, 1, "XMAS");
+        assert!(matches!(result, Err(aoc_utils::SolutionError::NotImplemented { day: 4, part: 1 })));
+    }
+
+    #[test]
+    fn test_solve_both() -> aoc_utils::Result<()> {
+        let [part1, part2] = solve_both(&day_1::Day1, day_1::SAMPLE)?;
+        assert_eq!((part1.day, part1.part, part1.answer.as_str()), (1, 1, "11"));
+        assert_eq!((part2.day, part2.part, part2.answer.as_str()), (1, 2, "31"));
+        assert_eq!((part1.duration, part1.input_bytes), (part2.duration, part2.input_bytes));
+        let result = solve_both(&day_5::Day5, day_5::SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: 5, part: 2 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solutions_ordered_by_day() {
+        let days: Vec<u8> = solutions().iter().map(|s| s.day()).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(&days[..5], &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "parallel"))]
+    fn test_configure_threads_negative() {
+        let result = configure_threads(Some(2));
+        assert!(result.is_err_and(|e| e.to_string().contains("without the parallel feature")));
+    }
+
+    /// Day that never finishes on its own, only when cancelled
+    struct Endless;
+
+    impl Solution for Endless {
+        fn day(&self) -> u8 {
+            99
+        }
+
+        fn part1(&self, input: &str) -> aoc_utils::Result<String> {
+            self.solve_with_cancel(1, input, &NoProgress, &CancelToken::new())
+        }
+
+        fn part2(&self, input: &str) -> aoc_utils::Result<String> {
+            self.solve_with_cancel(2, input, &NoProgress, &CancelToken::new())
+        }
+
+        fn solve_with_cancel(&self, _part: u8, _input: &str, _progress: &dyn Progress,
+            cancel: &CancelToken) -> aoc_utils::Result<String> {
+            loop {
+                cancel.check()?;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_part_with_timeout() -> aoc_utils::Result<()> {
+        let start = Instant::now();
+        let result = solve_part_with_timeout(&Endless, 1, "input", &NoProgress, &CancelToken::new(),
+            Duration::from_millis(50))?;
+        assert_eq!(result, SolveResult::timeout(99, 1, Duration::from_millis(50), 5));
+        assert!(start.elapsed() < Duration::from_secs(2), "took {:?} to stop", start.elapsed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_after_timeout() -> aoc_utils::Result<()> {
+        let solutions: Vec<Box<dyn Solution>> = vec![Box::new(Endless), Box::new(day_1::Day1)];
+        let mut answers = Vec::new();
+        for solution in &solutions {
+            for part in [1, 2] {
+                let result = solve_part_with_timeout(solution.as_ref(), part, day_1::SAMPLE, &NoProgress,
+                    &CancelToken::new(), Duration::from_millis(50))?;
+                answers.push(result.answer);
+            }
+        }
+        assert_eq!(answers, ["TIMEOUT", "TIMEOUT", "11", "31"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_part_with_timeout_cancelled() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let result = solve_part_with_timeout(&Endless, 2, "", &NoProgress, &cancel, Duration::from_secs(60));
+        assert!(matches!(result, Err(SolutionError::Cancelled)));
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_timeout("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_timeout("s").is_err());
+        assert!(parse_timeout("3h").is_err_and(|e| e.contains("expected ms, s or m")));
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(input_path(12), std::path::Path::new("day-12/data/input.txt"));
+    }
+}
@@ -0,0 +1,500 @@
+use std::process::ExitCode;
+use anyhow::{Context, Result};
+use aoc::{client::UreqClient, diagnostics::Style, exit::Reported, report::Format, Parameters, Parts};
+use aoc_utils::{input_hash, CachedAnswer, CancelToken, ErrorKind, InputSource, NoProgress, SolutionError};
+use clap::{Parser, Subcommand};
+
+/// Advent of code 2024 runner
+#[derive(Parser)]
+#[command(name = "aoc", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How errors pointing into the puzzle input are shown
+    #[arg(long, global = true, value_enum, default_value_t)]
+    diagnostics: Style,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzles using the inputs from `day-N/data/input.txt`
+    Run {
+        /// Day to solve
+        #[arg(long, conflicts_with = "all", required_unless_present = "all")]
+        day: Option<u8>,
+        /// Solve every implemented day
+        #[arg(long)]
+        all: bool,
+        /// Download the input first if it is not cached yet
+        #[arg(long, conflicts_with = "input")]
+        fetch: bool,
+        /// Solve the embedded puzzle examples and check the expected answers
+        #[arg(long, conflicts_with_all = ["fetch", "input"])]
+        sample: bool,
+        /// Read the input from this file instead, `-` reads the standard input
+        #[arg(long, conflicts_with = "all")]
+        input: Option<InputSource>,
+        /// Read the input from the system clipboard (needs the `clipboard` feature)
+        #[arg(long, conflicts_with_all = ["all", "fetch", "sample", "input"])]
+        clip: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Number of threads for the days solved in parallel (needs the `parallel` feature)
+        #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+        threads: Option<u16>,
+        /// Stop a part once it runs longer than this, e.g. `500ms`, `30s` or `2m`
+        #[arg(long, value_parser = aoc::parse_timeout)]
+        timeout: Option<std::time::Duration>,
+        /// Solve only one part, or both in one pass sharing the work between them,
+        /// instead of each part on its own
+        #[arg(long, value_enum, conflicts_with_all = ["sample", "timeout"])]
+        part: Option<Parts>,
+        #[command(flatten)]
+        parameters: Parameters,
+    },
+    /// Download the puzzle input into `day-N/data/input.txt`
+    Fetch {
+        /// Day to download
+        #[arg(long)]
+        day: u8,
+    },
+    /// Generate a seeded input and print the answers it should be solved to
+    Gen {
+        /// Day to generate the input for
+        #[arg(long)]
+        day: u8,
+        /// Size of the input, such as the number of lines or the side of a grid
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// Seed of the generator, the same seed gives the same input
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Write the input to this file instead of the standard output
+        #[arg(long)]
+        out: Option<std::path::PathBuf>,
+    },
+    /// Print the example input embedded in a day, its expected answers go to the standard error
+    Sample {
+        /// Day to print the sample of
+        #[arg(long)]
+        day: u8,
+    },
+    /// Print basic facts about an input and check it looks like the day's format
+    Stats {
+        /// Day the input is for
+        #[arg(long)]
+        day: u8,
+        /// Input to inspect instead of `day-N/data/input.txt`, `-` reads the standard input
+        #[arg(long)]
+        input: Option<InputSource>,
+    },
+    /// Explain the answer for the part of the input picked by the selector
+    Explain {
+        /// Day the input is for
+        #[arg(long)]
+        day: u8,
+        /// Input to explain instead of `day-N/data/input.txt`, `-` reads the standard input
+        #[arg(long)]
+        input: Option<InputSource>,
+        /// Part of the input to explain, such as a line number, depends on the day
+        selector: String,
+    },
+    /// Solve a day repeatedly and print the spread of the parse and part timings
+    Bench {
+        /// Day to benchmark
+        #[arg(long)]
+        day: u8,
+        /// Input to solve instead of `day-N/data/input.txt`, `-` reads the standard input
+        #[arg(long)]
+        input: Option<InputSource>,
+        /// Number of timed iterations
+        #[arg(long, default_value_t = aoc::bench::DEFAULT_ITERATIONS, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+        iterations: usize,
+        /// Number of untimed iterations run first
+        #[arg(long, default_value_t = aoc::bench::DEFAULT_WARMUP)]
+        warmup: usize,
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Start an interactive prompt to select a day, load inputs and solve parts
+    Repl,
+    /// Create the `day-N` crate and register it in the runner
+    NewDay {
+        /// Day to create
+        day: u8,
+    },
+    /// Solve every day again and compare the answers with the cached ones
+    Verify {
+        /// Accept the new answers and write them to the cache
+        #[arg(long)]
+        update: bool,
+    },
+    /// Solve a puzzle part and submit the answer
+    Submit {
+        /// Day to submit
+        #[arg(long)]
+        day: u8,
+        /// Part to submit (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+}
+
+fn fetch(day: u8) -> Result<std::path::PathBuf> {
+    let session = aoc::fetch::session_from_env().ok();
+    aoc::fetch::fetch_input(&UreqClient, session.as_deref(), day, std::path::Path::new("."))
+}
+
+/// Name of the input shown above the snippet of an error
+fn input_name(source: &InputSource) -> String {
+    match source {
+        InputSource::Path(path) => path.display().to_string(),
+        source => source.to_string(),
+    }
+}
+
+/// Print why the parts of a day failed, with the offending part of the input when the error points into it
+fn report_failure(day: u8, parts: &str, e: &SolutionError, input: &str, source: &InputSource, style: Style) {
+    match aoc::diagnostics::render(e, input, &input_name(source), style) {
+        Some(rendered) => eprint!("Day {} {}:\n{}", day, parts, rendered),
+        None => eprintln!("Day {} {}: {}", day, parts, e),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run(day: Option<u8>, fetch_missing: bool, input: Option<InputSource>, format: Format, style: Style,
+    timeout: Option<std::time::Duration>, parts: Option<Parts>, parameters: &Parameters, cancel: &CancelToken) -> Result<()> {
+    let solutions = aoc::solutions_with(parameters);
+    let selected: Vec<_> = solutions.iter()
+    .filter(|s| day.is_none_or(|d| d == s.day()))
+    .collect();
+    if selected.is_empty() {
+        anyhow::bail!("day {} is not implemented", day.unwrap_or_default());
+    }
+
+    let mut results = Vec::new();
+    let mut answers = Vec::new();
+    let mut checks = Vec::new();
+    let mut failed = None;
+    for solution in selected {
+        let day = solution.day();
+        if format == Format::Table {
+            checks.extend(aoc::sample::check(solution.as_ref()).unwrap_or_default());
+        }
+        if fetch_missing {
+            fetch(day)?;
+        }
+        let source = input.clone()
+        .unwrap_or_else(|| InputSource::Path(aoc::input_path(day)));
+        let input = source.read_to_string()?;
+        let hash = input_hash(&input);
+
+        if parts == Some(Parts::Both) {
+            match aoc::solve_both(solution.as_ref(), &input) {
+                Ok(solved) => {
+                    for result in solved {
+                        answers.push(CachedAnswer { day, part: result.part, input_hash: hash.clone(), answer: result.answer.clone() });
+                        results.push(result);
+                    }
+                    continue;
+                },
+                // days with an unsolved part are solved part by part
+                Err(SolutionError::NotImplemented { .. }) => {},
+                Err(e) => {
+                    report_failure(day, "parts 1 and 2", &e, &input, &source, style);
+                    Reported::add(&mut failed, aoc::exit::kind(&e));
+                    continue;
+                },
+            }
+        }
+        let selected_parts: &[u8] = match parts {
+            Some(Parts::One) => &[1],
+            Some(Parts::Two) => &[2],
+            Some(Parts::Both) | None => &[1, 2],
+        };
+        for &part in selected_parts {
+            let progress = aoc::progress::for_stderr(day, part);
+            let solved = match timeout {
+                Some(timeout) => aoc::solve_part_with_timeout(solution.as_ref(), part, &input, progress.as_ref(),
+                    cancel, timeout),
+                None => aoc::solve_part_with_cancel(solution.as_ref(), part, &input, progress.as_ref(), cancel),
+            };
+            match solved {
+                Ok(result) if result.timed_out => {
+                    eprintln!("Day {} part {}: timed out after {:?}", day, part, result.duration);
+                    Reported::add(&mut failed, ErrorKind::Timeout);
+                    results.push(result);
+                },
+                Ok(result) => {
+                    answers.push(CachedAnswer { day, part, input_hash: hash.clone(), answer: result.answer.clone() });
+                    results.push(result);
+                },
+                Err(e @ SolutionError::Cancelled) => {
+                    return Err(e).with_context(|| format!("interrupted while solving day {} part {}", day, part));
+                },
+                // unsolved parts are listed without failing the run
+                Err(e @ SolutionError::NotImplemented { .. }) => report_failure(day, &format!("part {}", part), &e, &input, &source, style),
+                Err(e) => {
+                    report_failure(day, &format!("part {}", part), &e, &input, &source, style);
+                    Reported::add(&mut failed, aoc::exit::kind(&e));
+                },
+            }
+        }
+    }
+
+    match format {
+        Format::Table => print!("{}", aoc::table::render(&results, &checks, aoc::table::use_color())),
+        _ => print!("{}", aoc::report::render(&results, format)?),
+    }
+    if let Err(e) = record_answers(answers) {
+        eprintln!("could not update the answer cache: {:#}", e);
+    }
+    failed.map_or(Ok(()), |failed| Err(failed.into()))
+}
+
+fn record_answers(answers: Vec<CachedAnswer>) -> Result<()> {
+    let path = std::path::Path::new(aoc::verify::CACHE_PATH);
+    let mut cache = aoc::verify::load(path)?;
+    for answer in answers {
+        cache.insert(answer);
+    }
+    aoc::verify::save(path, &cache)
+}
+
+fn verify(update: bool, style: Style, cancel: &CancelToken) -> Result<()> {
+    let path = std::path::Path::new(aoc::verify::CACHE_PATH);
+    let mut cache = aoc::verify::load(path)?;
+
+    let mut answers = Vec::new();
+    let mut failed = None;
+    for solution in aoc::solutions() {
+        let day = solution.day();
+        let input_path = aoc::input_path(day);
+        if !input_path.exists() {
+            eprintln!("Day {}: no input at {}", day, input_path.display());
+            continue;
+        }
+        let source = InputSource::Path(input_path);
+        let input = source.read_to_string()?;
+        let hash = input_hash(&input);
+        for part in [1, 2] {
+            match aoc::solve_part_with_cancel(solution.as_ref(), part, &input, &NoProgress, cancel) {
+                Ok(result) => answers.push(CachedAnswer { day, part, input_hash: hash.clone(), answer: result.answer }),
+                Err(SolutionError::NotImplemented { .. }) => {},
+                Err(e @ SolutionError::Cancelled) => {
+                    return Err(e).with_context(|| format!("interrupted while solving day {} part {}", day, part));
+                },
+                Err(e) => {
+                    report_failure(day, &format!("part {}", part), &e, &input, &source, style);
+                    Reported::add(&mut failed, aoc::exit::kind(&e));
+                },
+            }
+        }
+    }
+
+    let mut drifted = 0;
+    for answer in &answers {
+        let verified = aoc::verify::compare(&cache, answer);
+        drifted += usize::from(matches!(verified.status, aoc::verify::Status::Drift { .. }));
+        println!("{}", verified);
+    }
+
+    if update {
+        for answer in answers {
+            cache.insert(answer);
+        }
+        return aoc::verify::save(path, &cache);
+    }
+    if drifted > 0 {
+        anyhow::bail!("{} answers differ from the cache, rerun with --update to accept them", drifted);
+    }
+    failed.map_or(Ok(()), |failed| Err(failed.into()))
+}
+
+fn run_samples(day: Option<u8>) -> Result<()> {
+    let solutions = aoc::solutions();
+    let selected: Vec<_> = solutions.iter()
+    .filter(|s| day.is_none_or(|d| d == s.day()))
+    .collect();
+    if selected.is_empty() {
+        anyhow::bail!("day {} is not implemented", day.unwrap_or_default());
+    }
+
+    let mut checks = Vec::new();
+    for solution in selected {
+        match aoc::sample::check(solution.as_ref()) {
+            Some(day_checks) => checks.extend(day_checks),
+            None => eprintln!("Day {}: no sample embedded", solution.day()),
+        }
+    }
+    for check in &checks {
+        println!("{}", check);
+    }
+    aoc::sample::verdict(&checks)
+}
+
+/// Write the generated input to `out` or the standard output, the answers go to
+/// the standard output or, when it holds the input, to the standard error
+fn generate(day: u8, size: usize, seed: u64, out: Option<std::path::PathBuf>) -> Result<()> {
+    let generated = aoc::generate::generate(day, size, seed)?;
+    match out {
+        Some(path) => {
+            std::fs::write(&path, &generated.input)
+            .with_context(|| format!("could not write file {}", path.display()))?;
+            print!("{}", generated);
+        },
+        None => {
+            print!("{}", generated.input);
+            eprint!("{}", generated);
+        },
+    }
+    Ok(())
+}
+
+fn show_sample(day: u8) -> Result<()> {
+    let sample = aoc::sample::embedded(day)?;
+    print!("{}", sample.sample.input);
+    if !sample.sample.input.ends_with('\n') {
+        println!();
+    }
+    eprint!("{}", sample);
+    Ok(())
+}
+
+fn stats(day: u8, input: Option<InputSource>) -> Result<()> {
+    let solution = aoc::dispatch::solution(day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+    let source = input.unwrap_or_else(|| InputSource::Path(aoc::input_path(day)));
+    let stats = solution.describe(&source.read_to_string()?);
+    print!("{}", stats);
+    if let Some(problem) = &stats.problem {
+        eprintln!("warning: {} does not look like a day {} input: {}", source, day, problem);
+    }
+    Ok(())
+}
+
+fn explain(day: u8, input: Option<InputSource>, selector: &str, style: Style) -> Result<()> {
+    let solution = aoc::dispatch::solution(day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+    let source = input.unwrap_or_else(|| InputSource::Path(aoc::input_path(day)));
+    let input = source.read_to_string()?;
+    let explanation = solution.explain(&input, selector).map_err(|e| {
+        match aoc::diagnostics::render(&e, &input, &input_name(&source), style) {
+            Some(rendered) => anyhow::anyhow!(rendered),
+            None => e.into(),
+        }
+    })?;
+    println!("{}", explanation);
+    Ok(())
+}
+
+fn bench(day: u8, input: Option<InputSource>, iterations: usize, warmup: usize, format: Format) -> Result<()> {
+    let solution = aoc::dispatch::solution(day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+    let source = input.unwrap_or_else(|| InputSource::Path(aoc::input_path(day)));
+    let timings = aoc::bench::run(solution.as_ref(), &source.read_to_string()?, iterations, warmup)?;
+    print!("{}", aoc::bench::render(&timings, format, format == Format::Table && aoc::table::use_color())?);
+    Ok(())
+}
+
+fn submit(day: u8, part: u8) -> Result<()> {
+    let solutions = aoc::solutions();
+    let solution = solutions.iter()
+    .find(|s| s.day() == day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+
+    let session = aoc::fetch::session_from_env()?;
+    let input_path = fetch(day)?;
+    let input = std::fs::read_to_string(&input_path)
+    .with_context(|| format!("could not read file {}", input_path.display()))?;
+    let answer = match part {
+        1 => solution.part1(&input)?,
+        _ => solution.part2(&input)?,
+    };
+
+    let verdict = aoc::submit::submit_answer(&UreqClient, &session, day, part, &answer,
+        std::path::Path::new("."), std::time::SystemTime::now())?;
+    println!("day={} part={} answer={} {}", day, part, answer, verdict);
+    Ok(())
+}
+
+/// Cancel the running solve on the first Ctrl-C and exit on the second
+fn cancel_on_ctrlc() -> Result<CancelToken> {
+    let cancel = CancelToken::new();
+    let handle = cancel.clone();
+    ctrlc::set_handler(move || {
+        if handle.is_cancelled() {
+            std::process::exit(130);
+        }
+        handle.cancel();
+    })
+    .context("could not install the Ctrl-C handler")?;
+    Ok(cancel)
+}
+
+fn execute(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::Run { day, all: _, fetch, sample, input, clip, format, threads, timeout, part, parameters } => {
+            aoc::configure_threads(threads.map(usize::from))?;
+            let input = match clip {
+                true => Some(aoc::clipboard_input()?),
+                false => input,
+            };
+            match sample {
+                true => run_samples(day),
+                false => run(day, fetch, input, format, cli.diagnostics, timeout, part, &parameters, &cancel_on_ctrlc()?),
+            }
+        }
+        Command::Fetch { day } => {
+            let path = fetch(day)?;
+            println!("Day {} input: {}", day, path.display());
+            Ok(())
+        }
+        Command::Gen { day, size, seed, out } => generate(day, size, seed, out),
+        Command::Sample { day } => show_sample(day),
+        Command::Stats { day, input } => stats(day, input),
+        Command::Explain { day, input, selector } => explain(day, input, &selector, cli.diagnostics),
+        Command::Bench { day, input, iterations, warmup, format } => bench(day, input, iterations, warmup, format),
+        Command::Repl => aoc::repl::run(),
+        Command::NewDay { day } => {
+            for path in aoc::scaffold::new_day(std::path::Path::new("."), day)? {
+                println!("{}", path.display());
+            }
+            Ok(())
+        }
+        Command::Verify { update } => verify(update, cli.diagnostics, &cancel_on_ctrlc()?),
+        Command::Submit { day, part } => submit(day, part),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            // clap exits with 2 on usage errors, which is taken by a missing input
+            return match e.exit_code() {
+                0 => ExitCode::SUCCESS,
+                _ => ErrorKind::Other.into(),
+            };
+        },
+    };
+    let json = matches!(cli.command, Command::Run { format: Format::Json, .. } | Command::Bench { format: Format::Json, .. });
+
+    match execute(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            let kind = aoc::exit::kind(e.as_ref());
+            // failed parts were printed while solving
+            if !e.is::<Reported>() {
+                eprintln!("Error: {:?}", e);
+            }
+            if json {
+                eprintln!("error_kind={}", kind);
+            }
+            kind.into()
+        },
+    }
+}
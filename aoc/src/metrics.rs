@@ -0,0 +1,186 @@
+//! Allocation counting for the runner
+//! 
+//! With the `metrics` feature enabled [`CountingAllocator`] becomes the
+//! global allocator and [`measure`] reports how much memory a closure used.
+//! Without the feature nothing is counted and [`measure`] returns `None`.
+//! 
+//! The counters are process wide, so allocations made by other threads
+//! while a part is being solved are included in its figures.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+use aoc_utils::AllocStats;
+
+/// Allocator forwarding to [`System`] while keeping allocation counters
+#[derive(Debug, Default)]
+pub struct CountingAllocator {
+    allocated: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+    baseline: AtomicU64,
+}
+
+impl CountingAllocator {
+    /// Allocator with all the counters at zero
+    pub const fn new() -> Self {
+        CountingAllocator {
+            allocated: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+            baseline: AtomicU64::new(0),
+        }
+    }
+
+    /// Start a new measurement
+    /// 
+    /// Memory that is already alive is not counted towards the next peak
+    pub fn reset(&self) {
+        let live = self.live.load(Ordering::Relaxed);
+        self.allocated.store(0, Ordering::Relaxed);
+        self.baseline.store(live, Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+    }
+
+    /// Counters since the last [`reset`](CountingAllocator::reset)
+    pub fn stats(&self) -> AllocStats {
+        AllocStats {
+            allocated_bytes: self.allocated.load(Ordering::Relaxed),
+            peak_bytes: self.peak.load(Ordering::Relaxed)
+            .saturating_sub(self.baseline.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn record_alloc(&self, size: usize) {
+        let size = size as u64;
+        self.allocated.fetch_add(size, Ordering::Relaxed);
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.live.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record_dealloc(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "metrics")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator::new();
+
+/// Run `f` and report the memory it allocated
+/// 
+/// Returns `None` for the stats when the `metrics` feature is disabled
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "metrics")]
+    {
+        GLOBAL.reset();
+        let value = f();
+        (value, Some(GLOBAL.stats()))
+    }
+    #[cfg(not(feature = "metrics"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Allocate and free `size` bytes through the given allocator
+    fn churn(allocator: &CountingAllocator, size: usize) {
+        let layout = Layout::from_size_align(size, 8).unwrap();
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            assert!(!ptr.is_null());
+            allocator.dealloc(ptr, layout);
+        }
+    }
+
+    #[test]
+    fn test_counting_allocator_counts() {
+        let allocator = CountingAllocator::new();
+        churn(&allocator, 64);
+        churn(&allocator, 32);
+        let stats = allocator.stats();
+        assert!(stats.allocated_bytes > 0);
+        assert!(stats.peak_bytes > 0);
+        assert!(stats.peak_bytes <= stats.allocated_bytes);
+    }
+
+    #[test]
+    fn test_counting_allocator_reset() {
+        let allocator = CountingAllocator::new();
+        churn(&allocator, 128);
+        allocator.reset();
+        assert_eq!(allocator.stats(), AllocStats::default());
+
+        churn(&allocator, 16);
+        let stats = allocator.stats();
+        assert!(stats.allocated_bytes > 0);
+        assert!(stats.allocated_bytes < 128);
+    }
+
+    #[test]
+    fn test_counting_allocator_realloc() {
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(8, 8).unwrap();
+        unsafe {
+            let ptr = allocator.alloc(layout);
+            let ptr = allocator.realloc(ptr, layout, 256);
+            assert!(allocator.stats().peak_bytes >= 256);
+            allocator.dealloc(ptr, Layout::from_size_align(256, 8).unwrap());
+        }
+        allocator.reset();
+        assert_eq!(allocator.stats().peak_bytes, 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "metrics"))]
+    fn test_measure_disabled() {
+        let (value, stats) = measure(|| vec![1u8; 1024].len());
+        assert_eq!(value, 1024);
+        assert!(stats.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn test_measure_enabled() {
+        let (value, stats) = measure(|| vec![1u8; 1024].len());
+        assert_eq!(value, 1024);
+        let stats = stats.expect("metrics feature records allocations");
+        assert!(stats.allocated_bytes > 0);
+        assert!(stats.peak_bytes > 0);
+    }
+}
@@ -0,0 +1,72 @@
+//! Progress bar shown while the runner solves a part
+//! 
+//! The bar is only drawn when stderr is a terminal, so piped output
+//! and reports stay clean
+
+use std::io::IsTerminal;
+use aoc_utils::{NoProgress, Progress};
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// [`Progress`] drawing an indicatif progress bar on stderr
+/// 
+/// The bar is cleared again when it is dropped
+pub struct TerminalProgress {
+    bar: ProgressBar,
+}
+
+impl TerminalProgress {
+    /// Progress bar labelled with the day and part being solved
+    pub fn new(day: u8, part: u8) -> Self {
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(ProgressStyle::with_template("{prefix} {wide_bar} {percent:>3}%")
+        .unwrap_or_else(|_| ProgressStyle::default_bar()));
+        bar.set_prefix(format!("Day {} part {}", day, part));
+        TerminalProgress { bar }
+    }
+}
+
+impl Progress for TerminalProgress {
+    fn update(&self, done: u64, total: Option<u64>) {
+        if let Some(total) = total {
+            self.bar.set_length(total);
+        }
+        self.bar.set_position(done);
+    }
+}
+
+impl Drop for TerminalProgress {
+    fn drop(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+/// Progress for solving a part, a bar when stderr is a terminal and nothing otherwise
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::Progress;
+/// 
+/// let progress = aoc::progress::for_stderr(1, 2);
+/// progress.update(1, Some(1));
+/// ```
+pub fn for_stderr(day: u8, part: u8) -> Box<dyn Progress> {
+    if std::io::stderr().is_terminal() {
+        Box::new(TerminalProgress::new(day, part))
+    } else {
+        Box::new(NoProgress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_progress_update() {
+        let progress = TerminalProgress::new(4, 2);
+        progress.update(10, None);
+        assert_eq!(progress.bar.position(), 10);
+        progress.update(50, Some(100));
+        assert_eq!((progress.bar.position(), progress.bar.length()), (50, Some(100)));
+    }
+}
@@ -0,0 +1,362 @@
+//! Interactive prompt started by `aoc repl`
+//! 
+//! Every line is parsed into a [`ReplCommand`] and applied to a [`Session`]
+//! holding the selected day, part and loaded input. Lines piped to the
+//! standard input are read one by one, so the prompt can be scripted.
+
+use std::{path::PathBuf, time::Duration};
+use anyhow::{bail, Context};
+use aoc_utils::{InputSource, Solution};
+use rustyline::{error::ReadlineError, DefaultEditor};
+use thiserror::Error;
+use crate::table::format_duration;
+
+/// Commands understood by the prompt, printed by `help` and after unknown commands
+pub const HELP: &str = "\
+Commands:
+  day N        select the day to solve
+  load PATH    read the puzzle input from a file
+  sample       load the embedded sample of the day
+  part N       solve part N of the loaded input
+  explain SEL  explain the answer for a selector, such as a line number
+  bench N      solve the current part N times and report min/avg
+  help         show this help
+  quit         leave the prompt
+";
+
+/// Errors returned when a line is not a valid command
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CommandError {
+    /// The first word is not a known command
+    #[error("unknown command `{0}`")]
+    Unknown(String),
+    /// The command needs an argument that was not given
+    #[error("`{command}` needs {argument}")]
+    MissingArgument {
+        /// Name of the command
+        command: &'static str,
+        /// Description of the missing argument
+        argument: &'static str,
+    },
+    /// The argument of the command is not a valid number
+    #[error("`{command}` needs a number, got `{value}`")]
+    InvalidNumber {
+        /// Name of the command
+        command: &'static str,
+        /// Given argument
+        value: String,
+    },
+}
+
+/// Command typed at the prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplCommand {
+    /// Select a day
+    Day(u8),
+    /// Read the input from a file
+    Load(PathBuf),
+    /// Load the sample embedded in the selected day
+    Sample,
+    /// Solve a part and make it the current one
+    Part(u8),
+    /// Explain the answer for a selector
+    Explain(String),
+    /// Solve the current part repeatedly
+    Bench(usize),
+    /// Print the commands
+    Help,
+    /// Leave the prompt
+    Quit,
+}
+
+impl ReplCommand {
+    /// Parse a line typed at the prompt, `None` for a blank line
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc::repl::{CommandError, ReplCommand};
+    /// 
+    /// assert_eq!(ReplCommand::parse("day 5"), Ok(Some(ReplCommand::Day(5))));
+    /// assert_eq!(ReplCommand::parse("  "), Ok(None));
+    /// assert_eq!(ReplCommand::parse("solve"), Err(CommandError::Unknown("solve".to_string())));
+    /// ```
+    pub fn parse(line: &str) -> Result<Option<ReplCommand>, CommandError> {
+        let line = line.trim();
+        let (name, argument) = match line.split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, Some(argument.trim())),
+            None => (line, None),
+        };
+        let command = match name {
+            "" => return Ok(None),
+            "day" => ReplCommand::Day(number("day", "a day number", argument)?),
+            "load" => ReplCommand::Load(PathBuf::from(required("load", "a path", argument)?)),
+            "sample" => ReplCommand::Sample,
+            "part" => ReplCommand::Part(number("part", "a part number", argument)?),
+            "explain" => ReplCommand::Explain(required("explain", "a selector", argument)?.to_string()),
+            "bench" => ReplCommand::Bench(number("bench", "a number of runs", argument)?),
+            "help" | "?" => ReplCommand::Help,
+            "quit" | "exit" => ReplCommand::Quit,
+            name => return Err(CommandError::Unknown(name.to_string())),
+        };
+        Ok(Some(command))
+    }
+}
+
+fn required<'a>(command: &'static str, argument: &'static str, value: Option<&'a str>) -> Result<&'a str, CommandError> {
+    value.ok_or(CommandError::MissingArgument { command, argument })
+}
+
+fn number<T: std::str::FromStr>(command: &'static str, argument: &'static str, value: Option<&str>) -> Result<T, CommandError> {
+    let value = required(command, argument, value)?;
+    value.parse().map_err(|_| CommandError::InvalidNumber { command, value: value.to_string() })
+}
+
+/// State of the prompt between commands
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Session {
+    /// Selected day
+    pub day: Option<u8>,
+    /// Part solved last, used by `bench`
+    pub part: Option<u8>,
+    /// Where the input was loaded from and its text
+    pub input: Option<(InputSource, String)>,
+    /// Whether the input is the embedded sample, solved with the parameters of the sample
+    pub sample: bool,
+}
+
+impl Session {
+    /// Text shown before every line, with the selected day
+    pub fn prompt(&self) -> String {
+        match self.day {
+            Some(day) => format!("day {}> ", day),
+            None => "aoc> ".to_string(),
+        }
+    }
+
+    /// Apply a command and return the text to print
+    /// 
+    /// A failed command leaves the session unchanged
+    /// 
+    /// # Examples
+    /// ```
+    /// use aoc::repl::{ReplCommand, Session};
+    /// 
+    /// let mut session = Session::default();
+    /// session.execute(ReplCommand::Day(1)).unwrap();
+    /// session.execute(ReplCommand::Sample).unwrap();
+    /// assert_eq!(session.execute(ReplCommand::Part(2)).unwrap(), "Part 2: 31");
+    /// ```
+    pub fn execute(&mut self, command: ReplCommand) -> anyhow::Result<String> {
+        match command {
+            ReplCommand::Day(day) => {
+                crate::dispatch::solution(day).with_context(|| format!("day {} is not implemented", day))?;
+                if self.day != Some(day) {
+                    *self = Session { day: Some(day), ..Session::default() };
+                }
+                Ok(format!("Selected day {}", day))
+            },
+            ReplCommand::Load(path) => {
+                let source = InputSource::Path(path);
+                let text = source.read_to_string()?;
+                let message = format!("Loaded {} lines from {}", text.lines().count(), source);
+                self.input = Some((source, text));
+                self.sample = false;
+                Ok(message)
+            },
+            ReplCommand::Sample => {
+                let solution = self.solution()?;
+                let sample = solution.sample().with_context(|| format!("day {} has no sample", solution.day()))?;
+                let source = InputSource::Literal(sample.input.to_string());
+                self.input = Some((source, sample.input.to_string()));
+                self.sample = true;
+                Ok(format!("Loaded the sample of day {}, expecting {} and {}", solution.day(), sample.answers[0], sample.answers[1]))
+            },
+            ReplCommand::Part(part) => {
+                if !(1..=2).contains(&part) {
+                    bail!("part must be 1 or 2, got {}", part);
+                }
+                let result = crate::solve_part(self.solution()?.as_ref(), part, self.input()?)?;
+                self.part = Some(part);
+                Ok(format!("Part {}: {}", part, result.answer))
+            },
+            ReplCommand::Explain(selector) => Ok(self.solution()?.explain(self.input()?, &selector)?),
+            ReplCommand::Bench(runs) => {
+                if runs == 0 {
+                    bail!("bench needs at least one run");
+                }
+                let part = self.part.context("no part solved yet, use `part N` first")?;
+                let solution = self.solution()?;
+                let input = self.input()?;
+                let mut durations = Vec::with_capacity(runs);
+                for _ in 0..runs {
+                    durations.push(crate::solve_part(solution.as_ref(), part, input)?.duration);
+                }
+                let (min, avg) = min_avg(&durations);
+                Ok(format!("Part {}: {} runs, min {}, avg {}", part, runs, format_duration(min), format_duration(avg)))
+            },
+            ReplCommand::Help => Ok(HELP.trim_end().to_string()),
+            ReplCommand::Quit => Ok(String::new()),
+        }
+    }
+
+    fn solution(&self) -> anyhow::Result<Box<dyn Solution>> {
+        let day = self.day.context("no day selected, use `day N` first")?;
+        let solution = crate::dispatch::solution(day).with_context(|| format!("day {} is not implemented", day))?;
+        match self.sample {
+            true => Ok(solution.sample_solution().unwrap_or(solution)),
+            false => Ok(solution),
+        }
+    }
+
+    fn input(&self) -> anyhow::Result<&str> {
+        self.input.as_ref()
+        .map(|(_, text)| text.as_str())
+        .context("no input loaded, use `load PATH` or `sample` first")
+    }
+}
+
+/// Shortest and average duration, the durations must not be empty
+fn min_avg(durations: &[Duration]) -> (Duration, Duration) {
+    let min = durations.iter().min().copied().unwrap_or_default();
+    let total: Duration = durations.iter().sum();
+    (min, total / durations.len() as u32)
+}
+
+/// Read commands until `quit` or the end of the input
+/// 
+/// Errors of single commands are printed and the prompt goes on,
+/// only a failing terminal ends it
+pub fn run() -> anyhow::Result<()> {
+    let mut editor = DefaultEditor::new().context("could not start the prompt")?;
+    let mut session = Session::default();
+    loop {
+        let line = match editor.readline(&session.prompt()) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => return Ok(()),
+            Err(e) => return Err(e).context("could not read the command"),
+        };
+        let _ = editor.add_history_entry(line.as_str());
+        match ReplCommand::parse(&line) {
+            Ok(None) => {},
+            Ok(Some(ReplCommand::Quit)) => return Ok(()),
+            Ok(Some(command)) => match session.execute(command) {
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("error: {:#}", e),
+            },
+            Err(e) => println!("{}\n{}", e, HELP.trim_end()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        let parsed: Vec<_> = ["day 5", "load ./data/input.txt", "sample", "part 2", "explain 12", "bench 10", "help", "quit"]
+        .into_iter()
+        .map(|line| ReplCommand::parse(line).unwrap().unwrap())
+        .collect();
+        assert_eq!(parsed, vec![
+            ReplCommand::Day(5),
+            ReplCommand::Load(PathBuf::from("./data/input.txt")),
+            ReplCommand::Sample,
+            ReplCommand::Part(2),
+            ReplCommand::Explain("12".to_string()),
+            ReplCommand::Bench(10),
+            ReplCommand::Help,
+            ReplCommand::Quit,
+        ]);
+    }
+
+    #[test]
+    fn test_parse_whitespace() {
+        assert_eq!(ReplCommand::parse("  day   3 \n"), Ok(Some(ReplCommand::Day(3))));
+        assert_eq!(ReplCommand::parse("load my input.txt"), Ok(Some(ReplCommand::Load(PathBuf::from("my input.txt")))));
+        assert_eq!(ReplCommand::parse(""), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_negative() {
+        assert_eq!(ReplCommand::parse("solve 1"), Err(CommandError::Unknown("solve".to_string())));
+        assert_eq!(ReplCommand::parse("day"), Err(CommandError::MissingArgument { command: "day", argument: "a day number" }));
+        assert_eq!(ReplCommand::parse("part two").unwrap_err().to_string(), "`part` needs a number, got `two`");
+        assert_eq!(ReplCommand::parse("day 300").unwrap_err().to_string(), "`day` needs a number, got `300`");
+    }
+
+    #[test]
+    fn test_session_transitions() -> anyhow::Result<()> {
+        let mut session = Session::default();
+        assert_eq!(session.prompt(), "aoc> ");
+        session.execute(ReplCommand::Day(1))?;
+        assert_eq!(session.prompt(), "day 1> ");
+        session.execute(ReplCommand::Sample)?;
+        assert!(session.input.is_some());
+        assert_eq!(session.execute(ReplCommand::Part(1))?, "Part 1: 11");
+        assert_eq!(session.part, Some(1));
+        assert!(session.execute(ReplCommand::Bench(3))?.starts_with("Part 1: 3 runs, min "));
+
+        // selecting the same day keeps the input, another day clears it
+        session.execute(ReplCommand::Day(1))?;
+        assert!(session.input.is_some());
+        session.execute(ReplCommand::Day(2))?;
+        assert_eq!(session, Session { day: Some(2), ..Session::default() });
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_negative() -> anyhow::Result<()> {
+        let mut session = Session::default();
+        assert_eq!(format!("{:#}", session.execute(ReplCommand::Part(1)).unwrap_err()), "no day selected, use `day N` first");
+        assert_eq!(format!("{:#}", session.execute(ReplCommand::Day(26)).unwrap_err()), "day 26 is not implemented");
+        assert_eq!(session, Session::default());
+
+        session.execute(ReplCommand::Day(3))?;
+        assert!(session.execute(ReplCommand::Part(1)).unwrap_err().to_string().starts_with("no input loaded"));
+        assert!(session.execute(ReplCommand::Bench(2)).unwrap_err().to_string().starts_with("no part solved yet"));
+        session.execute(ReplCommand::Sample)?;
+        assert_eq!(session.execute(ReplCommand::Part(3)).unwrap_err().to_string(), "part must be 1 or 2, got 3");
+        assert_eq!(session.execute(ReplCommand::Explain("all".to_string()))?.lines().last(), Some("Enabled total: 48"));
+        session.execute(ReplCommand::Day(1))?;
+        session.execute(ReplCommand::Sample)?;
+        assert_eq!(session.execute(ReplCommand::Explain("1".to_string())).unwrap_err().to_string(), "day 1 does not support explain");
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_load() -> anyhow::Result<()> {
+        use assert_fs::prelude::*;
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str("3   4\r\n4   3\r\n")?;
+        let mut session = Session { day: Some(1), ..Session::default() };
+        let message = session.execute(ReplCommand::Load(file.path().to_path_buf()))?;
+        assert_eq!(message, format!("Loaded 2 lines from file {}", file.path().display()));
+        assert_eq!(session.input()?, "3   4\n4   3\n");
+        assert_eq!(session.execute(ReplCommand::Part(2))?, "Part 2: 7");
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_sample_parameters() -> anyhow::Result<()> {
+        use assert_fs::prelude::*;
+        let mut session = Session::default();
+        session.execute(ReplCommand::Day(14))?;
+        session.execute(ReplCommand::Sample)?;
+        assert!(session.sample);
+        assert_eq!(session.execute(ReplCommand::Part(1))?, "Part 1: 12");
+        // a loaded input moves on the floor of the puzzle
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(day_14::SAMPLE)?;
+        session.execute(ReplCommand::Load(file.path().to_path_buf()))?;
+        assert!(!session.sample);
+        assert_eq!(session.execute(ReplCommand::Part(1))?, "Part 1: 21");
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_avg() {
+        let durations = [Duration::from_micros(30), Duration::from_micros(10), Duration::from_micros(20)];
+        assert_eq!(min_avg(&durations), (Duration::from_micros(10), Duration::from_micros(20)));
+    }
+}
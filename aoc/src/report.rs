@@ -0,0 +1,198 @@
+//! Rendering of the collected [`SolveResult`]s
+//! 
+//! The CSV columns and JSON field names are stable so scripts can rely on them
+
+use aoc_utils::SolveResult;
+use anyhow::{Context, Result};
+
+/// CSV header, in the order the columns are written
+pub const CSV_HEADER: &str = "day,part,answer,duration_us,input_bytes";
+
+/// Output format of the runner
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Aligned table, colored on a terminal
+    #[default]
+    Table,
+    /// JSON array of results
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Human readable byte count, e.g. `512 B` or `1.5 MiB`
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Render the results in the requested format
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc::report::{render, Format};
+/// use aoc_utils::SolveResult;
+/// 
+/// let results = vec![SolveResult {
+///     day: 1, part: 1, answer: "11".to_string(),
+///     duration: Duration::from_micros(7), input_bytes: 36, allocations: None, timed_out: false,
+/// }];
+/// let csv = render(&results, Format::Csv).unwrap();
+/// assert_eq!(csv, "day,part,answer,duration_us,input_bytes\n1,1,11,7,36\n");
+/// ```
+pub fn render(results: &[SolveResult], format: Format) -> Result<String> {
+    let mut out = String::new();
+    match format {
+        Format::Table => out = crate::table::render(results, &[], false),
+        Format::Json => {
+            out = serde_json::to_string_pretty(results)
+            .with_context(|| "failed serializing results to JSON")?;
+            out.push('\n');
+        },
+        Format::Csv => {
+            out.push_str(CSV_HEADER);
+            out.push('\n');
+            for r in results {
+                out.push_str(&format!("{},{},{},{},{}\n", r.day, r.part, csv_field(&r.answer),
+                r.duration_us(), r.input_bytes));
+            }
+        },
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use anyhow::Result;
+    use aoc_utils::AllocStats;
+    use super::*;
+
+    fn results() -> Vec<SolveResult> {
+        vec![
+            SolveResult {
+                day: 1,
+                part: 1,
+                answer: "2430334".to_string(),
+                duration: Duration::from_nanos(1_234_567),
+                input_bytes: 14000,
+                allocations: None,
+                timed_out: false,
+            },
+            SolveResult {
+                day: 17,
+                part: 1,
+                answer: "4,6,3,5,6,3,5,2,1,0".to_string(),
+                duration: Duration::from_micros(42),
+                input_bytes: 98,
+                allocations: Some(AllocStats { allocated_bytes: 3584, peak_bytes: 2048 }),
+                timed_out: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_table() -> Result<()> {
+        assert_eq!(render(&results(), Format::Table)?, concat!(
+            "Day  Part  Answer                 Time     Peak  Allocated  Sample\n",
+            "---  ----  -------------------  ------  -------  ---------  ------\n",
+            "  1     1  2430334              1.23ms        -          -  -\n",
+            " 17     1  4,6,3,5,6,3,5,2,1,0    42us  2.0 KiB    3.5 KiB  -\n",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_json() -> Result<()> {
+        assert_eq!(render(&results(), Format::Json)?, r#"[
+  {
+    "day": 1,
+    "part": 1,
+    "answer": "2430334",
+    "duration_us": 1234,
+    "input_bytes": 14000
+  },
+  {
+    "day": 17,
+    "part": 1,
+    "answer": "4,6,3,5,6,3,5,2,1,0",
+    "duration_us": 42,
+    "input_bytes": 98,
+    "allocations": {
+      "allocated_bytes": 3584,
+      "peak_bytes": 2048
+    }
+  }
+]
+"#);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_csv() -> Result<()> {
+        assert_eq!(render(&results(), Format::Csv)?,
+        "day,part,answer,duration_us,input_bytes\n\
+        1,1,2430334,1234,14000\n\
+        17,1,\"4,6,3,5,6,3,5,2,1,0\",42,98\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_timeout() -> Result<()> {
+        let results = vec![SolveResult::timeout(6, 2, Duration::from_secs(30), 17000)];
+        assert_eq!(render(&results, Format::Json)?, r#"[
+  {
+    "day": 6,
+    "part": 2,
+    "answer": "TIMEOUT",
+    "duration_us": 30000000,
+    "input_bytes": 17000,
+    "timed_out": true
+  }
+]
+"#);
+        assert_eq!(render(&results, Format::Csv)?, "day,part,answer,duration_us,input_bytes\n6,2,TIMEOUT,30000000,17000\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_empty() -> Result<()> {
+        assert_eq!(render(&[], Format::Csv)?, "day,part,answer,duration_us,input_bytes\n");
+        assert_eq!(render(&[], Format::Json)?, "[]\n");
+        assert_eq!(render(&[], Format::Table)?, "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_csv_field_quotes() {
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("plain"), "plain");
+    }
+}
@@ -0,0 +1,209 @@
+//! Checks of the solutions against the published examples
+//! 
+//! `aoc run --sample` solves the [`Sample`] embedded in every day and
+//! compares the answers with the expected ones, `aoc sample` prints it
+
+use std::fmt;
+use anyhow::Context;
+use aoc_utils::{Sample, Solution, SolutionError};
+
+/// Result of checking one part against its sample answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer matches the expected one
+    Pass,
+    /// The answer differs from the expected one
+    Fail {
+        /// Expected answer
+        expected: String,
+        /// Computed answer
+        answer: String,
+    },
+    /// Solving the sample returned an error
+    Error(String),
+    /// The part is not implemented yet
+    Skip,
+    /// The sample has no answer for the part, so it is not solved
+    Unsupported,
+}
+
+/// Sample check of one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampleCheck {
+    /// Day of the challenge
+    pub day: u8,
+    /// Part of the challenge (1 or 2)
+    pub part: u8,
+    /// Result of the check
+    pub outcome: Outcome,
+}
+
+impl SampleCheck {
+    /// Whether the check failed, skipped parts do not count as failures
+    pub fn failed(&self) -> bool {
+        matches!(self.outcome, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+
+impl fmt::Display for SampleCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "PASS"),
+            Outcome::Fail { expected, answer } => write!(f, "FAIL (expected {}, got {})", expected, answer),
+            Outcome::Error(e) => write!(f, "FAIL ({})", e),
+            Outcome::Skip => write!(f, "SKIP (not implemented)"),
+            Outcome::Unsupported => write!(f, "SKIP (no sample answer)"),
+        }
+    }
+}
+
+/// Solve both parts of the sample and compare them with the expected answers
+/// 
+/// Returns `None` when the day has no embedded sample. The sample is
+/// solved by [`Solution::sample_solution`] when the day has one.
+/// 
+/// # Examples
+/// ```
+/// let checks = aoc::sample::check(&day_1::Day1).unwrap();
+/// assert_eq!(checks[0].to_string(), "Day 1 part 1: PASS");
+/// assert!(checks.iter().all(|check| !check.failed()));
+/// ```
+pub fn check(solution: &dyn Solution) -> Option<Vec<SampleCheck>> {
+    let Sample { input, answers } = solution.sample()?;
+    let sample_solution = solution.sample_solution();
+    let solver = sample_solution.as_deref().unwrap_or(solution);
+    let checks = [1, 2].into_iter().zip(answers)
+    .map(|(part, expected)| {
+        let answer = match part {
+            1 => solver.part1(input),
+            _ => solver.part2(input),
+        };
+        let outcome = match answer {
+            Err(SolutionError::NotImplemented { .. }) => Outcome::Skip,
+            _ if expected.is_empty() => Outcome::Unsupported,
+            Ok(answer) if answer == expected => Outcome::Pass,
+            Ok(answer) => Outcome::Fail { expected: expected.to_string(), answer },
+            Err(e) => Outcome::Error(e.to_string()),
+        };
+        SampleCheck { day: solution.day(), part, outcome }
+    })
+    .collect();
+    Some(checks)
+}
+
+/// Sample embedded in a day, printed by `aoc sample`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaySample {
+    /// Day of the challenge
+    pub day: u8,
+    /// Example input and its expected answers
+    pub sample: Sample,
+}
+
+impl fmt::Display for DaySample {
+    /// One `day=N part=P answer=A` line per part, like `aoc gen`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, answer) in [1, 2].into_iter().zip(self.sample.answers) {
+            writeln!(f, "day={} part={} answer={}", self.day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sample embedded in `day`
+/// 
+/// # Examples
+/// ```
+/// let sample = aoc::sample::embedded(1).unwrap();
+/// assert_eq!(sample.sample.input, day_1::SAMPLE);
+/// assert_eq!(sample.to_string(), "day=1 part=1 answer=11\nday=1 part=2 answer=31\n");
+/// ```
+pub fn embedded(day: u8) -> anyhow::Result<DaySample> {
+    let solution = crate::dispatch::solution(day)
+    .with_context(|| format!("day {} is not implemented", day))?;
+    let sample = solution.sample()
+    .with_context(|| format!("day {} has no embedded sample", day))?;
+    Ok(DaySample { day, sample })
+}
+
+/// Error when any of the checks failed
+/// 
+/// The runner returns it from `main`, so failing samples give a non-zero exit code
+pub fn verdict(checks: &[SampleCheck]) -> anyhow::Result<()> {
+    let failed = checks.iter().filter(|check| check.failed()).count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} sample checks failed", failed, checks.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::Result;
+    use super::*;
+
+    /// Counts the lines, with a configurable expectation for the sample
+    struct LineCount(&'static str);
+
+    impl Solution for LineCount {
+        fn day(&self) -> u8 {
+            7
+        }
+
+        fn part1(&self, input: &str) -> Result<String> {
+            Ok(input.lines().count().to_string())
+        }
+
+        fn part2(&self, _input: &str) -> Result<String> {
+            Err(SolutionError::NotImplemented { day: 7, part: 2 })
+        }
+
+        fn sample(&self) -> Option<Sample> {
+            Some(Sample { input: "a\nb\n", answers: [self.0, "0"] })
+        }
+    }
+
+    #[test]
+    fn test_check_pass() {
+        let checks = check(&LineCount("2")).unwrap();
+        assert_eq!(checks[0], SampleCheck { day: 7, part: 1, outcome: Outcome::Pass });
+        assert_eq!(checks[1].outcome, Outcome::Skip);
+        assert!(verdict(&checks).is_ok());
+    }
+
+    #[test]
+    fn test_check_fail() {
+        let checks = check(&LineCount("3")).unwrap();
+        assert!(checks[0].failed());
+        assert_eq!(checks[0].to_string(), "Day 7 part 1: FAIL (expected 3, got 2)");
+        assert_eq!(checks[1].to_string(), "Day 7 part 2: SKIP (not implemented)");
+        let e = verdict(&checks).unwrap_err();
+        assert_eq!(e.to_string(), "1 of 2 sample checks failed");
+    }
+
+    #[test]
+    fn test_check_unsupported() {
+        let checks = check(&LineCount("")).unwrap();
+        assert_eq!(checks[0].outcome, Outcome::Unsupported);
+        assert_eq!(checks[0].to_string(), "Day 7 part 1: SKIP (no sample answer)");
+        assert!(verdict(&checks).is_ok());
+    }
+
+    #[test]
+    fn test_embedded() -> anyhow::Result<()> {
+        let sample = embedded(3)?;
+        assert_eq!(sample.sample, Sample { input: day_3::SAMPLE, answers: day_3::SAMPLE_ANSWERS });
+        assert_eq!(sample.to_string(), "day=3 part=1 answer=161\nday=3 part=2 answer=48\n");
+        assert_eq!(embedded(26).unwrap_err().to_string(), "day 26 is not implemented");
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_every_day() {
+        for solution in crate::solutions() {
+            let checks = check(solution.as_ref()).expect("every day embeds its sample");
+            assert!(checks.iter().all(|check| !check.failed()), "{:?}", checks);
+        }
+    }
+}
@@ -0,0 +1,405 @@
+//! Generator for new day crates
+//! 
+//! `aoc new-day N` creates `day-N/` from the templates below, adds it to
+//! the workspace members and registers it in the runner's [`solutions`](crate::solutions)
+//! and, when the workspace has one, in the `aoc-wasm` module
+
+use std::{fs, path::{Path, PathBuf}};
+use anyhow::{bail, Context, Result};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day-{{DAY}}"
+version = "0.1.0"
+edition = "2021"
+authors = ["Mantas Gricius <mantasgricius6@email.com>"]
+license = "MIT OR Apache-2.0"
+description = "Advent of code challenges in RUST"
+readme = "README.md"
+homepage = "https://github.com/MantasG6/advent-of-code-2024"
+repository = "https://github.com/MantasG6/advent-of-code-2024"
+keywords = ["adventofcode"]
+categories = ["command-line-utilities"]
+
+[dependencies]
+aoc-prelude = { path = "../aoc-prelude", default-features = false }
+thiserror = "2.0"
+
+[features]
+default = ["fs"]
+# Read puzzle inputs from files, off for targets without a filesystem
+fs = ["aoc-prelude/fs"]
+
+[dev-dependencies]
+assert_fs = "1.1.1"
+
+[[bin]]
+name = "day-{{DAY}}"
+path = "src/main.rs"
+required-features = ["fs"]
+"#;
+
+const LIB_RS: &str = r#"#![warn(missing_docs)]
+//! Advent of code 2024 Day {{DAY}} Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day {{DAY}} functions
+#[derive(Debug, Error)]
+pub enum Day{{DAY}}Error {
+    /// A line of the input has an unexpected format
+    #[error("malformed input on line {line}")]
+    Malformed {
+        /// Line number (starting from 1)
+        line: usize,
+    },
+}
+
+impl From<Day{{DAY}}Error> for SolutionError {
+    fn from(e: Day{{DAY}}Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+/// Result type returned by the day {{DAY}} functions
+pub type Result<T, E = Day{{DAY}}Error> = std::result::Result<T, E>;
+
+/// Puzzle input, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_{{DAY}}::Result<()> {
+///     let input = day_{{DAY}}::Day{{DAY}}Input::try_from("a\nb\n")?;
+///     assert_eq!(input.lines.len(), 2);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Day{{DAY}}Input {
+    /// Lines of the puzzle input
+    pub lines: Vec<String>,
+}
+
+impl TryFrom<&str> for Day{{DAY}}Input {
+    type Error = Day{{DAY}}Error;
+
+    fn try_from(input: &str) -> Result<Self> {
+        Ok(Day{{DAY}}Input { lines: input.lines().map(String::from).collect() })
+    }
+}
+
+/// Solve the first part of the challenge
+pub fn part1(_input: &Day{{DAY}}Input) -> SolutionResult<u64> {
+    Err(SolutionError::NotImplemented { day: {{DAY}}, part: 1 })
+}
+
+/// Solve the second part of the challenge
+pub fn part2(_input: &Day{{DAY}}Input) -> SolutionResult<u64> {
+    Err(SolutionError::NotImplemented { day: {{DAY}}, part: 2 })
+}
+
+/// Day {{DAY}} solution used by the `aoc` runner
+pub struct Day{{DAY}};
+
+impl Solution for Day{{DAY}} {
+    fn day(&self) -> u8 {
+        {{DAY}}
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day{{DAY}}Input::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day{{DAY}}Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day{{DAY}}Input::try_from(input)?;
+        Ok((part1(&input)?.to_string(), part2(&input)?.to_string()))
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day{{DAY}}Input::try_from(input)?))
+    }
+}
+
+impl Parsed for Day{{DAY}}Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_prelude::{Solution, SolutionError};
+    use crate::Day{{DAY}};
+
+    const SAMPLE: &str = "";
+
+    #[test]
+    fn test_solution_part1_sample() {
+        let result = Day{{DAY}}.part1(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: {{DAY}}, part: 1 })));
+    }
+
+    #[test]
+    fn test_solution_part2_sample() {
+        let result = Day{{DAY}}.part2(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: {{DAY}}, part: 2 })));
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use aoc_prelude::*;
+
+fn main() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+
+    let content = input.read_to_string()?;
+
+    println!("Part 1: {}", day_{{DAY}}::Day{{DAY}}.part1(&content)?);
+    println!("Part 2: {}", day_{{DAY}}::Day{{DAY}}.part2(&content)?);
+
+    Ok(())
+}
+"#;
+
+const README_MD: &str = r#"[For more info visit Advent of Code 2024 site](https://adventofcode.com/2024)
+## Day {{DAY}}
+
+To begin, [get your puzzle input](./data/input.txt).
+"#;
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{DAY}}", &day.to_string())
+}
+
+/// Day number of a `day-N` workspace entry such as `"day-12",`
+fn member_day(line: &str) -> Option<u8> {
+    line.trim()
+    .trim_end_matches(',')
+    .trim_matches('"')
+    .strip_prefix("day-")?
+    .parse()
+    .ok()
+}
+
+/// Insert `line` into `lines[start..end]` before the first day crate with a
+/// higher number, or after the last day crate when there is none
+fn insert_ordered(lines: &mut Vec<String>, start: usize, end: usize, day: u8, line: String,
+    day_of: impl Fn(&str) -> Option<u8>) {
+    let days: Vec<(usize, u8)> = (start..end)
+    .filter_map(|i| day_of(&lines[i]).map(|d| (i, d)))
+    .collect();
+    let at = days.iter()
+    .find(|&&(_, d)| d > day)
+    .map(|&(i, _)| i)
+    .or_else(|| days.last().map(|&(i, _)| i + 1))
+    .unwrap_or(end);
+    lines.insert(at, line);
+}
+
+/// Add `day-N` to the `members` list of the workspace manifest
+fn add_workspace_member(text: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let start = lines.iter()
+    .position(|l| l.trim_start().starts_with("members = ["))
+    .context("workspace manifest has no members list")?;
+    let end = start + lines[start..].iter()
+    .position(|l| l.trim() == "]")
+    .context("workspace members list is not closed")?;
+    insert_ordered(&mut lines, start + 1, end, day, format!("    \"day-{}\",", day), member_day);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the `day-N` path dependency to the runner manifest
+fn add_runner_dependency(text: &str, day: u8) -> Result<String> {
+    add_dependency(text, day, "")
+}
+
+/// Add the `day-N` path dependency without filesystem access to the `aoc-wasm` manifest
+fn add_wasm_dependency(text: &str, day: u8) -> Result<String> {
+    add_dependency(text, day, ", default-features = false")
+}
+
+/// Add the `day-N` path dependency with the `options` following its path
+fn add_dependency(text: &str, day: u8, options: &str) -> Result<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let start = lines.iter()
+    .position(|l| l.trim() == "[dependencies]")
+    .context("runner manifest has no [dependencies] section")?;
+    let end = start + 1 + lines[start + 1..].iter()
+    .position(|l| l.trim().is_empty() || l.starts_with('['))
+    .unwrap_or(lines.len() - start - 1);
+    let day_of = |l: &str| member_day(l.split('=').next().unwrap_or_default());
+    insert_ordered(&mut lines, start + 1, end, day,
+    format!("day-{day} = {{ path = \"../day-{day}\"{options} }}"), day_of);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add `Box::new(day_N::DayN)` to the [`solutions`](crate::solutions) registry
+fn add_registry_entry(text: &str, day: u8) -> Result<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let start = lines.iter()
+    .position(|l| l.starts_with("pub fn solutions()"))
+    .context("runner has no solutions registry")?;
+    let end = start + lines[start..].iter()
+    .position(|l| l.trim() == "]")
+    .context("solutions registry is not closed")?;
+    let day_of = |l: &str| l.trim().strip_prefix("Box::new(day_")?.split(':').next()?.parse().ok();
+    insert_ordered(&mut lines, start + 1, end, day, format!("        Box::new(day_{day}::Day{day}),"), day_of);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Text edit registering a new day in one of the workspace files
+type ManifestEdit = fn(&str, u8) -> Result<String>;
+
+/// Read a file, apply `edit` and write the result back
+fn edit_file(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text = fs::read_to_string(path)
+    .with_context(|| format!("could not read file {}", path.display()))?;
+    let text = edit(&text)
+    .with_context(|| format!("could not update {}", path.display()))?;
+    fs::write(path, text)
+    .with_context(|| format!("could not write file {}", path.display()))
+}
+
+/// Create the `day-N` crate in the workspace at `root`
+/// 
+/// Refuses to overwrite an existing directory. Returns every file that was
+/// created or edited, in the order it was touched.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("day {} is outside of the advent calendar (1 - 25)", day);
+    }
+    let dir = root.join(format!("day-{}", day));
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+
+    let mut touched = Vec::new();
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("README.md", README_MD),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("data/.gitkeep", ""),
+    ];
+    for (name, template) in files {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+            .with_context(|| format!("could not create directory {}", parent.display()))?;
+        }
+        fs::write(&path, render(template, day))
+        .with_context(|| format!("could not write file {}", path.display()))?;
+        touched.push(path);
+    }
+
+    let mut edits: Vec<(PathBuf, ManifestEdit)> = vec![
+        (root.join("Cargo.toml"), add_workspace_member),
+        (root.join("aoc/Cargo.toml"), add_runner_dependency),
+        (root.join("aoc/src/lib.rs"), add_registry_entry),
+    ];
+    if root.join("aoc-wasm").is_dir() {
+        edits.push((root.join("aoc-wasm/Cargo.toml"), add_wasm_dependency));
+        edits.push((root.join("aoc-wasm/src/lib.rs"), add_registry_entry));
+    }
+    for (path, edit) in edits {
+        edit_file(&path, |text| edit(text, day))?;
+        touched.push(path);
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use assert_fs::prelude::*;
+    use super::*;
+
+    const WORKSPACE: &str = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"day-1\",\n    \"day-10\",\n]\n";
+    const RUNNER_TOML: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\nanyhow = \"1.0\"\n\
+    day-1 = { path = \"../day-1\" }\nureq = \"2.12\"\n\n[dev-dependencies]\n";
+    const RUNNER_LIB: &str = "pub fn solutions() -> Vec<Box<dyn Solution>> {\n    vec![\n\
+    \x20       Box::new(day_1::Day1),\n        Box::new(day_10::Day10),\n    ]\n}\n";
+
+    #[test]
+    fn test_add_workspace_member() -> Result<()> {
+        assert_eq!(add_workspace_member(WORKSPACE, 6)?,
+        "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"day-1\",\n    \"day-6\",\n    \"day-10\",\n]\n");
+        assert!(add_workspace_member(WORKSPACE, 12)?.contains("\"day-10\",\n    \"day-12\",\n]"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_runner_dependency() -> Result<()> {
+        assert_eq!(add_runner_dependency(RUNNER_TOML, 6)?, "[package]\nname = \"aoc\"\n\n[dependencies]\nanyhow = \"1.0\"\n\
+        day-1 = { path = \"../day-1\" }\nday-6 = { path = \"../day-6\" }\nureq = \"2.12\"\n\n[dev-dependencies]\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_wasm_dependency() -> Result<()> {
+        assert!(add_wasm_dependency(RUNNER_TOML, 6)?
+        .contains("day-1 = { path = \"../day-1\" }\nday-6 = { path = \"../day-6\", default-features = false }\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_registry_entry() -> Result<()> {
+        assert_eq!(add_registry_entry(RUNNER_LIB, 6)?, "pub fn solutions() -> Vec<Box<dyn Solution>> {\n    vec![\n\
+        \x20       Box::new(day_1::Day1),\n        Box::new(day_6::Day6),\n        Box::new(day_10::Day10),\n    ]\n}\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day_negative() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        root.child("day-1/Cargo.toml").write_str("")?;
+        let result = new_day(root.path(), 1);
+        assert!(result.is_err_and(|e| e.to_string().ends_with("day-1 already exists")));
+        assert!(new_day(root.path(), 26).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_new_day_compiles() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        root.child("Cargo.toml").write_str("[workspace]\nresolver = \"2\"\nmembers = [\n]\n")?;
+        root.child("aoc/Cargo.toml").write_str(RUNNER_TOML)?;
+        root.child("aoc/src/lib.rs").write_str(RUNNER_LIB)?;
+        // the generated crate depends on the real shared crates
+        for shared in ["aoc-prelude", "aoc-utils"] {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(shared);
+            std::os::unix::fs::symlink(path.canonicalize()?, root.path().join(shared))?;
+        }
+
+        let touched = new_day(root.path(), 7)?;
+        assert_eq!(touched.len(), 8);
+        assert!(root.path().join("day-7/data").is_dir());
+        root.child("Cargo.toml").assert("[workspace]\nresolver = \"2\"\nmembers = [\n    \"day-7\",\n]\n");
+        assert!(new_day(root.path(), 7).is_err());
+
+        let target = Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/scaffold");
+        let status = Command::new(env!("CARGO"))
+        .args(["check", "--offline", "--quiet", "--all-targets", "-p", "day-7"])
+        .current_dir(root.path())
+        .env("CARGO_TARGET_DIR", target)
+        .status()?;
+        assert!(status.success());
+        Ok(())
+    }
+}
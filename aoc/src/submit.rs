@@ -0,0 +1,381 @@
+//! Answer submission
+//! 
+//! Answers are posted to the website and the response page is turned into a
+//! [`Verdict`]. The cooldown requested by the website is stored next to the
+//! cached input so answers are never resubmitted too early.
+
+use std::{fmt::Display, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use anyhow::{bail, Context, Result};
+use crate::client::HttpClient;
+
+/// Wait time assumed when the website asks to wait without saying for how long
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// Direction hint given for a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    /// The submitted answer is too high
+    TooHigh,
+    /// The submitted answer is too low
+    TooLow,
+}
+
+/// Outcome of an answer submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer was accepted
+    Correct,
+    /// The answer was rejected
+    Incorrect {
+        /// Too high / too low hint when the website gives one
+        hint: Option<Hint>,
+        /// Time to wait before the next submission
+        wait: Duration,
+    },
+    /// The previous answer was submitted too recently
+    TooRecent {
+        /// Time left to wait before the next submission
+        wait: Duration,
+    },
+    /// The part is already solved or not unlocked yet
+    WrongLevel,
+    /// Response text that could not be recognized
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Time to wait before submitting again, if any
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            Verdict::Incorrect { wait, .. } | Verdict::TooRecent { wait } => Some(*wait),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "verdict=correct"),
+            Verdict::Incorrect { hint, wait } => {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => "too_high",
+                    Some(Hint::TooLow) => "too_low",
+                    None => "none",
+                };
+                write!(f, "verdict=incorrect hint={} wait={}s", hint, wait.as_secs())
+            },
+            Verdict::TooRecent { wait } => write!(f, "verdict=too_recent wait={}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "verdict=wrong_level"),
+            Verdict::Unknown(text) => write!(f, "verdict=unknown message={:?}", text),
+        }
+    }
+}
+
+/// URL answers for a given day are posted to
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(aoc::submit::submit_url(5), "https://adventofcode.com/2024/day/5/answer");
+/// ```
+pub fn submit_url(day: u8) -> String {
+    format!("https://adventofcode.com/2024/day/{}/answer", day)
+}
+
+/// File keeping the submission cooldowns of a day, next to its cached input
+pub fn cooldown_path(day: u8) -> PathBuf {
+    crate::input_path(day).with_file_name("submissions.txt")
+}
+
+/// Extract the plain text of the `<article>` element in the response page
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..].find("</article>").map(|e| start + e).unwrap_or(html.len());
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a `1m 37s` style duration from the `You have ... left to wait` sentence
+fn parse_left_to_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for token in text[start..end].split_whitespace() {
+        let (num, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let num: u64 = num.parse().ok()?;
+        secs += match unit {
+            "h" => num * 3600,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Parse the `please wait one minute` / `please wait 5 minutes` sentence
+fn parse_please_wait(text: &str) -> Option<Duration> {
+    let start = text.find("wait ")? + "wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        num => num.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(amount * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(amount)),
+        _ => None,
+    }
+}
+
+/// Turn the response page of a submission into a [`Verdict`]
+/// 
+/// # Examples
+/// ```
+/// use aoc::submit::{parse_verdict, Verdict};
+/// 
+/// let html = "<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>";
+/// assert_eq!(parse_verdict(html), Verdict::Correct);
+/// ```
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = parse_please_wait(&text).unwrap_or(DEFAULT_WAIT);
+        Verdict::Incorrect { hint, wait }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooRecent { wait: parse_left_to_wait(&text).unwrap_or(DEFAULT_WAIT) }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown(text)
+    }
+}
+
+/// Read the time before which the given part must not be submitted
+fn read_cooldown(path: &Path, part: u8) -> Result<Option<u64>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(path)
+    .with_context(|| format!("could not read file {}", path.display()))?;
+    for line in contents.lines() {
+        let Some((p, not_before)) = line.split_once(' ') else {
+            continue;
+        };
+        if p == part.to_string() {
+            let not_before = not_before.parse::<u64>()
+            .with_context(|| format!("failed parsing {} to number in {}", not_before, path.display()))?;
+            return Ok(Some(not_before));
+        }
+    }
+    Ok(None)
+}
+
+/// Store the time before which the given part must not be submitted
+fn write_cooldown(path: &Path, part: u8, not_before: u64) -> Result<()> {
+    let contents = if path.exists() {
+        std::fs::read_to_string(path)
+        .with_context(|| format!("could not read file {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let mut lines: Vec<String> = contents.lines()
+    .filter(|line| !line.starts_with(&format!("{} ", part)))
+    .map(|line| line.to_string())
+    .collect();
+    lines.push(format!("{} {}", part, not_before));
+    lines.sort();
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+        .with_context(|| format!("could not create directory {}", dir.display()))?;
+    }
+    std::fs::write(path, lines.join("\n") + "\n")
+    .with_context(|| format!("could not write file {}", path.display()))
+}
+
+/// Submit an answer for a day part
+/// 
+/// Refuses to submit while the cooldown stored under `root` from a previous
+/// submission is still running. The cooldown returned by the website is
+/// persisted for the next call.
+pub fn submit_answer(client: &dyn HttpClient, session: &str, day: u8, part: u8,
+    answer: &str, root: &Path, now: SystemTime) -> Result<Verdict> {
+    if !(1..=2).contains(&part) {
+        bail!("part must be 1 or 2, got {}", part);
+    }
+    let now = now.duration_since(UNIX_EPOCH)
+    .with_context(|| "system clock is before 1970")?.as_secs();
+
+    let path = root.join(cooldown_path(day));
+    if let Some(not_before) = read_cooldown(&path, part)? {
+        if now < not_before {
+            bail!("day {} part {} was submitted too recently, wait {}s before trying again",
+            day, part, not_before - now);
+        }
+    }
+
+    let level = part.to_string();
+    let response = client.post_form(&submit_url(day), session, &[("level", &level), ("answer", answer)])?;
+    if !response.is_success() {
+        bail!("server returned {} for day {} part {} answer: {}", response.status, day, part,
+        response.body.lines().next().unwrap_or_default());
+    }
+
+    let verdict = parse_verdict(&response.body);
+    if let Some(wait) = verdict.wait() {
+        write_cooldown(&path, part, now + wait.as_secs())?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use assert_fs::prelude::*;
+    use anyhow::Result;
+    use crate::fetch::tests::FakeClient;
+    use super::*;
+
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+    closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to Part Two]</a></p></article>\n</main>";
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+    make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, \
+    or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  \
+    Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    const TOO_LOW: &str = "<main>\n<article><p>That's not the right answer; your answer is too low.  \
+    Please wait 5 minutes before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    const TOO_RECENT: &str = "<main>\n<article><p>You gave an answer too recently; you have to wait after submitting an \
+    answer before trying again.  You have 1m 37s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    const WRONG_LEVEL: &str = "<main>\n<article><p>You don't seem to be solving the right level.  Did you already complete it? \
+    <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_parse_verdict_correct() {
+        assert_eq!(parse_verdict(CORRECT), Verdict::Correct);
+    }
+
+    #[test]
+    fn test_parse_verdict_too_high() {
+        assert_eq!(parse_verdict(TOO_HIGH),
+        Verdict::Incorrect { hint: Some(Hint::TooHigh), wait: Duration::from_secs(60) });
+    }
+
+    #[test]
+    fn test_parse_verdict_too_low() {
+        assert_eq!(parse_verdict(TOO_LOW),
+        Verdict::Incorrect { hint: Some(Hint::TooLow), wait: Duration::from_secs(300) });
+    }
+
+    #[test]
+    fn test_parse_verdict_too_recent() {
+        assert_eq!(parse_verdict(TOO_RECENT), Verdict::TooRecent { wait: Duration::from_secs(97) });
+    }
+
+    #[test]
+    fn test_parse_verdict_wrong_level() {
+        assert_eq!(parse_verdict(WRONG_LEVEL), Verdict::WrongLevel);
+    }
+
+    #[test]
+    fn test_parse_verdict_unknown() {
+        assert_eq!(parse_verdict("<article><p>Something else</p></article>"),
+        Verdict::Unknown("Something else".to_string()));
+    }
+
+    #[test]
+    fn test_verdict_display() {
+        let verdict = Verdict::Incorrect { hint: Some(Hint::TooHigh), wait: Duration::from_secs(60) };
+        assert_eq!(verdict.to_string(), "verdict=incorrect hint=too_high wait=60s");
+    }
+
+    #[test]
+    fn test_submit_answer_posts_form() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(200, CORRECT);
+
+        let verdict = submit_answer(&client, "abc", 1, 2, "31", root.path(), at(1000))?;
+
+        assert_eq!(verdict, Verdict::Correct);
+        assert_eq!(*client.requests.borrow(),
+        vec!["POST https://adventofcode.com/2024/day/1/answer abc level=2&answer=31"]);
+        assert!(!root.child("day-1/data/submissions.txt").path().exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_answer_persists_cooldown() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(200, TOO_HIGH);
+
+        submit_answer(&client, "abc", 1, 1, "12", root.path(), at(1000))?;
+
+        root.child("day-1/data/submissions.txt").assert("1 1060\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_answer_refuses_during_cooldown() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        root.child("day-1/data/submissions.txt").write_str("1 1060\n")?;
+        let client = FakeClient::new(200, CORRECT);
+
+        let result = submit_answer(&client, "abc", 1, 1, "12", root.path(), at(1030));
+
+        assert!(result.is_err_and(|e| e.to_string()
+        .eq("day 1 part 1 was submitted too recently, wait 30s before trying again")));
+        assert!(client.requests.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_answer_after_cooldown() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        root.child("day-1/data/submissions.txt").write_str("1 1060\n2 5000\n")?;
+        let client = FakeClient::new(200, TOO_RECENT);
+
+        let verdict = submit_answer(&client, "abc", 1, 1, "12", root.path(), at(1060))?;
+
+        assert_eq!(verdict, Verdict::TooRecent { wait: Duration::from_secs(97) });
+        root.child("day-1/data/submissions.txt").assert("1 1157\n2 5000\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_submit_answer_invalid_part() -> Result<()> {
+        let root = assert_fs::TempDir::new()?;
+        let client = FakeClient::new(200, CORRECT);
+
+        let result = submit_answer(&client, "abc", 1, 3, "12", root.path(), at(1000));
+
+        assert!(result.is_err_and(|e| e.to_string().eq("part must be 1 or 2, got 3")));
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+//! Aligned summary table printed by `aoc run`
+//! 
+//! Column widths follow the longest cell. Colors are only used when the
+//! standard output is a terminal and `NO_COLOR` is not set, see
+//! <https://no-color.org>
+
+use std::{io::IsTerminal, time::Duration};
+use aoc_utils::SolveResult;
+use crate::{report::format_bytes, sample::{Outcome, SampleCheck}};
+
+/// Answers longer than this are cut and end with `…`
+pub const MAX_ANSWER_WIDTH: usize = 32;
+
+const BOLD: &str = "\x1b[1m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether the table printed to the standard output should be colored
+pub fn use_color() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !no_color && std::io::stdout().is_terminal()
+}
+
+/// Human readable solving time, e.g. `42us`, `1.23ms` or `2.50s`
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    match micros {
+        0..1_000 => format!("{}us", micros),
+        1_000..1_000_000 => format!("{:.2}ms", duration.as_secs_f64() * 1e3),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+/// The answer cut to [`MAX_ANSWER_WIDTH`] characters
+fn truncate(answer: &str) -> String {
+    if answer.chars().count() <= MAX_ANSWER_WIDTH {
+        return answer.to_string();
+    }
+    let mut cut: String = answer.chars().take(MAX_ANSWER_WIDTH - 1).collect();
+    cut.push('…');
+    cut
+}
+
+/// Answer of a part, `TIMEOUT` in red when the runner stopped it
+fn answer(result: &SolveResult) -> Cell {
+    match result.timed_out {
+        true => Cell { text: SolveResult::TIMEOUT.to_string(), color: Some(RED) },
+        false => Cell::plain(truncate(&result.answer)),
+    }
+}
+
+/// Sample verification mark of a part with its color, `-` when it was not checked
+fn sample_mark(checks: &[SampleCheck], day: u8, part: u8) -> Cell {
+    let outcome = checks.iter()
+    .find(|check| check.day == day && check.part == part)
+    .map(|check| &check.outcome);
+    match outcome {
+        Some(Outcome::Pass) => Cell { text: "✓".to_string(), color: Some(GREEN) },
+        Some(Outcome::Fail { .. } | Outcome::Error(_)) => Cell { text: "✗".to_string(), color: Some(RED) },
+        Some(Outcome::Skip | Outcome::Unsupported) | None => Cell::plain("-"),
+    }
+}
+
+pub(crate) struct Cell {
+    text: String,
+    color: Option<&'static str>,
+}
+
+impl Cell {
+    pub(crate) fn plain(text: impl Into<String>) -> Self {
+        Cell { text: text.into(), color: None }
+    }
+}
+
+pub(crate) struct Column {
+    title: &'static str,
+    right: bool,
+    cells: Vec<Cell>,
+}
+
+impl Column {
+    pub(crate) fn new(title: &'static str, right: bool, cells: impl Iterator<Item = Cell>) -> Self {
+        Column { title, right, cells: cells.collect() }
+    }
+
+    fn width(&self) -> usize {
+        self.cells.iter()
+        .map(|cell| cell.text.chars().count())
+        .chain([self.title.len()])
+        .max()
+        .unwrap_or_default()
+    }
+}
+
+/// Pad the text to the width, then color it so the escape codes do not count
+fn pad(text: &str, width: usize, right: bool, color: Option<&str>) -> String {
+    let padded = match right {
+        true => format!("{:>width$}", text),
+        false => format!("{:<width$}", text),
+    };
+    match color {
+        Some(color) => format!("{}{}{}", color, padded, RESET),
+        None => padded,
+    }
+}
+
+/// Render the results as a table with day, part, answer, time and sample columns
+/// 
+/// The sample column shows `✓` or `✗` for the parts found in `checks` and
+/// `-` for the others. Parts stopped by the timeout show `TIMEOUT` as
+/// their answer. Peak and allocated memory columns are added when
+/// any result has allocation statistics.
+/// 
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc_utils::SolveResult;
+/// 
+/// let results = vec![SolveResult {
+///     day: 1, part: 1, answer: "11".to_string(),
+///     duration: Duration::from_micros(7), input_bytes: 36, allocations: None, timed_out: false,
+/// }];
+/// assert_eq!(aoc::table::render(&results, &[], false), concat!(
+///     "Day  Part  Answer  Time  Sample\n",
+///     "---  ----  ------  ----  ------\n",
+///     "  1     1  11       7us  -\n",
+/// ));
+/// ```
+pub fn render(results: &[SolveResult], checks: &[SampleCheck], color: bool) -> String {
+    if results.is_empty() {
+        return String::new();
+    }
+
+    let mut columns = vec![
+        Column::new("Day", true, results.iter().map(|r| Cell::plain(r.day.to_string()))),
+        Column::new("Part", true, results.iter().map(|r| Cell::plain(r.part.to_string()))),
+        Column::new("Answer", false, results.iter().map(answer)),
+        Column::new("Time", true, results.iter().map(|r| Cell::plain(format_duration(r.duration)))),
+    ];
+    if results.iter().any(|r| r.allocations.is_some()) {
+        let bytes = |value: Option<u64>| Cell::plain(value.map(format_bytes).unwrap_or_else(|| "-".to_string()));
+        columns.push(Column::new("Peak", true, results.iter().map(|r| bytes(r.allocations.map(|a| a.peak_bytes)))));
+        columns.push(Column::new("Allocated", true, results.iter().map(|r| bytes(r.allocations.map(|a| a.allocated_bytes)))));
+    }
+    columns.push(Column::new("Sample", false, results.iter().map(|r| sample_mark(checks, r.day, r.part))));
+    layout(&columns, results.len(), color)
+}
+
+/// Align the columns of `rows` cells under their bold titles
+pub(crate) fn layout(columns: &[Column], rows: usize, color: bool) -> String {
+    let widths: Vec<usize> = columns.iter().map(Column::width).collect();
+    let header: Vec<String> = columns.iter().zip(&widths)
+    .map(|(column, &width)| pad(column.title, width, column.right, color.then_some(BOLD)))
+    .collect();
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    let mut lines = vec![header.join("  "), separator.join("  ")];
+    for row in 0..rows {
+        let cells: Vec<String> = columns.iter().zip(&widths)
+        .map(|(column, &width)| {
+            let cell = &column.cells[row];
+            pad(&cell.text, width, column.right, cell.color.filter(|_| color))
+        })
+        .collect();
+        lines.push(cells.join("  "));
+    }
+
+    lines.iter()
+    .map(|line| format!("{}\n", line.trim_end()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::AllocStats;
+    use super::*;
+
+    fn result(day: u8, part: u8, answer: &str, duration: Duration) -> SolveResult {
+        SolveResult { day, part, answer: answer.to_string(), duration, input_bytes: 100, allocations: None, timed_out: false }
+    }
+
+    fn checks() -> Vec<SampleCheck> {
+        vec![
+            SampleCheck { day: 1, part: 1, outcome: Outcome::Pass },
+            SampleCheck { day: 1, part: 2, outcome: Outcome::Fail { expected: "31".to_string(), answer: "30".to_string() } },
+            SampleCheck { day: 4, part: 1, outcome: Outcome::Skip },
+        ]
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let results = vec![
+            result(1, 1, "2430334", Duration::from_nanos(1_234_567)),
+            result(1, 2, "28786472", Duration::from_micros(42)),
+            result(12, 1, "7", Duration::from_millis(2500)),
+        ];
+        assert_eq!(render(&results, &checks(), false), concat!(
+            "Day  Part  Answer      Time  Sample\n",
+            "---  ----  --------  ------  ------\n",
+            "  1     1  2430334   1.23ms  ✓\n",
+            "  1     2  28786472    42us  ✗\n",
+            " 12     1  7          2.50s  -\n",
+        ));
+    }
+
+    #[test]
+    fn test_render_truncates_long_answers() {
+        let answer = "4,6,3,5,6,3,5,2,1,0,".repeat(3);
+        let results = vec![result(17, 1, &answer, Duration::from_micros(5))];
+        let table = render(&results, &[], false);
+        let row = table.lines().nth(2).unwrap();
+        assert_eq!(row, " 17     1  4,6,3,5,6,3,5,2,1,0,4,6,3,5,6,3…   5us  -");
+        assert_eq!(table.lines().nth(1).unwrap(), "---  ----  --------------------------------  ----  ------");
+    }
+
+    #[test]
+    fn test_render_memory_columns() {
+        let mut results = vec![result(1, 1, "11", Duration::from_micros(7))];
+        results[0].allocations = Some(AllocStats { allocated_bytes: 3584, peak_bytes: 2048 });
+        assert_eq!(render(&results, &[], false), concat!(
+            "Day  Part  Answer  Time     Peak  Allocated  Sample\n",
+            "---  ----  ------  ----  -------  ---------  ------\n",
+            "  1     1  11       7us  2.0 KiB    3.5 KiB  -\n",
+        ));
+    }
+
+    #[test]
+    fn test_render_colored() {
+        let results = vec![result(1, 1, "11", Duration::from_micros(7))];
+        let table = render(&results, &checks(), true);
+        assert!(table.starts_with("\x1b[1mDay\x1b[0m  \x1b[1mPart\x1b[0m"));
+        assert!(table.ends_with("\x1b[32m✓     \x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_render_timeout() {
+        let results = vec![
+            SolveResult::timeout(6, 2, Duration::from_secs(30), 100),
+            result(7, 1, "3749", Duration::from_micros(980)),
+        ];
+        assert_eq!(render(&results, &[], false), concat!(
+            "Day  Part  Answer     Time  Sample\n",
+            "---  ----  -------  ------  ------\n",
+            "  6     2  TIMEOUT  30.00s  -\n",
+            "  7     1  3749      980us  -\n",
+        ));
+        assert!(render(&results, &[], true).contains("\x1b[31mTIMEOUT\x1b[0m"));
+    }
+
+    #[test]
+    fn test_render_empty() {
+        assert_eq!(render(&[], &[], false), "");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(999)), "999us");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.00s");
+    }
+}
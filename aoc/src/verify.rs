@@ -0,0 +1,154 @@
+//! Cache of computed answers and the checks behind `aoc verify`
+//! 
+//! Every `aoc run` records its answers in [`CACHE_PATH`], keyed by day,
+//! part and the hash of the input. `aoc verify` solves the days again and
+//! reports any answer that no longer matches the cached one.
+
+use std::{fmt, fs, path::Path};
+use anyhow::{Context, Result};
+use aoc_utils::{AnswerCache, CachedAnswer};
+
+/// Location of the answer cache, relative to the workspace root
+pub const CACHE_PATH: &str = ".aoc-cache/answers.json";
+
+/// Read the answer cache, a missing file gives an empty cache
+/// 
+/// # Examples
+/// ```
+/// let cache = aoc::verify::load(std::path::Path::new("missing/answers.json")).unwrap();
+/// assert!(cache.answers.is_empty());
+/// ```
+pub fn load(path: &Path) -> Result<AnswerCache> {
+    if !path.exists() {
+        return Ok(AnswerCache::default());
+    }
+    let text = fs::read_to_string(path)
+    .with_context(|| format!("could not read file {}", path.display()))?;
+    serde_json::from_str(&text)
+    .with_context(|| format!("malformed answer cache {}", path.display()))
+}
+
+/// Write the answer cache, creating its directory if needed
+pub fn save(path: &Path, cache: &AnswerCache) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+        .with_context(|| format!("could not create directory {}", dir.display()))?;
+    }
+    let text = serde_json::to_string_pretty(cache)?;
+    fs::write(path, text + "\n")
+    .with_context(|| format!("could not write file {}", path.display()))
+}
+
+/// How a computed answer compares with the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Same answer as the cached one
+    Unchanged,
+    /// Nothing cached for this input yet
+    New,
+    /// The answer differs from the cached one
+    Drift {
+        /// Answer found in the cache
+        cached: String,
+    },
+}
+
+/// Computed answer of one part compared with the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verified {
+    /// Day of the challenge
+    pub day: u8,
+    /// Part of the challenge (1 or 2)
+    pub part: u8,
+    /// Computed answer
+    pub answer: String,
+    /// Comparison with the cache
+    pub status: Status,
+}
+
+impl fmt::Display for Verified {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match &self.status {
+            Status::Unchanged => write!(f, "ok ({})", self.answer),
+            Status::New => write!(f, "new ({})", self.answer),
+            Status::Drift { cached } => write!(f, "DRIFT (cached {}, got {})", cached, self.answer),
+        }
+    }
+}
+
+/// Compare a computed answer with the cache
+/// 
+/// # Examples
+/// ```
+/// use aoc::verify::{compare, Status};
+/// use aoc_utils::{AnswerCache, CachedAnswer};
+/// 
+/// let mut cache = AnswerCache::default();
+/// cache.insert(CachedAnswer { day: 1, part: 1, input_hash: "ab".to_string(), answer: "11".to_string() });
+/// assert_eq!(compare(&cache, &cache.answers[0]).status, Status::Unchanged);
+/// ```
+pub fn compare(cache: &AnswerCache, answer: &CachedAnswer) -> Verified {
+    let status = match cache.get(answer.day, answer.part, &answer.input_hash) {
+        None => Status::New,
+        Some(cached) if cached == answer.answer => Status::Unchanged,
+        Some(cached) => Status::Drift { cached: cached.to_string() },
+    };
+    Verified { day: answer.day, part: answer.part, answer: answer.answer.clone(), status }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::prelude::*;
+    use aoc_utils::input_hash;
+    use super::*;
+
+    fn answer(input: &str, answer: &str) -> CachedAnswer {
+        CachedAnswer { day: 1, part: 1, input_hash: input_hash(input), answer: answer.to_string() }
+    }
+
+    #[test]
+    fn test_save_load_roundtrip() -> Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        let path = dir.child(".aoc-cache/answers.json");
+        let mut cache = AnswerCache::default();
+        cache.insert(answer("3   4\n", "1"));
+        save(path.path(), &cache)?;
+        assert_eq!(load(path.path())?, cache);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_negative() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("answers.json")?;
+        file.write_str("{\"answers\": 3}")?;
+        let e = load(file.path()).unwrap_err();
+        assert!(e.to_string().starts_with("malformed answer cache"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_drift() {
+        let mut cache = AnswerCache::default();
+        cache.insert(answer("3   4\n", "1"));
+        let verified = compare(&cache, &answer("3   4\n", "2"));
+        assert_eq!(verified.status, Status::Drift { cached: "1".to_string() });
+        assert_eq!(verified.to_string(), "Day 1 part 1: DRIFT (cached 1, got 2)");
+    }
+
+    #[test]
+    fn test_compare_input_changed() -> Result<()> {
+        let dir = assert_fs::TempDir::new()?;
+        let input = dir.child("input.txt");
+        input.write_str("3   4\n")?;
+        let mut cache = AnswerCache::default();
+        cache.insert(answer(&fs::read_to_string(input.path())?, "1"));
+
+        // a different input invalidates the cached answer instead of drifting
+        input.write_str("3   5\n")?;
+        let verified = compare(&cache, &answer(&fs::read_to_string(input.path())?, "2"));
+        assert_eq!(verified.status, Status::New);
+        assert_eq!(verified.to_string(), "Day 1 part 1: new (2)");
+        Ok(())
+    }
+}
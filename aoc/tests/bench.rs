@@ -0,0 +1,48 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc bench --day <day> --input -` with the extra arguments and the input piped to stdin
+fn bench(day: u8, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["bench", "--day", &day.to_string(), "--input", "-"])
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+#[test]
+fn test_bench_table() {
+    let output = bench(5, &["--iterations", "4"], day_5::SAMPLE);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stages: Vec<&str> = stdout.lines().skip(2).map(|line| &line[5..11]).collect();
+    assert_eq!(stages, ["parse ", "part 1"], "{}", stdout);
+}
+
+#[test]
+fn test_bench_json() -> anyhow::Result<()> {
+    let output = bench(1, &["--iterations", "7", "--warmup", "0", "--format", "json"], day_1::SAMPLE);
+    assert!(output.status.success());
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let rows = rows.as_array().unwrap();
+    assert_eq!(rows.len(), 3);
+    for (row, stage) in rows.iter().zip(["parse", "part 1", "part 2"]) {
+        assert_eq!(row["day"], 1);
+        assert_eq!(row["stage"], stage);
+        assert_eq!(row["iterations"], 7);
+        assert!(row["min_us"].as_u64() <= row["median_us"].as_u64());
+        assert!(row["median_us"].as_u64() <= row["p95_us"].as_u64());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bench_invalid_input() {
+    let output = bench(2, &[], "1 2 x\n");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
@@ -0,0 +1,35 @@
+use std::process::{Command, Output};
+
+/// Run `aoc run` with the arguments and without a display server
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .arg("run")
+    .args(args)
+    .env_remove("DISPLAY")
+    .env_remove("WAYLAND_DISPLAY")
+    .output()
+    .expect("failed to start aoc")
+}
+
+#[test]
+fn test_clip_conflicts_with_input() {
+    let output = run(&["--day", "1", "--clip", "--input", "-"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[test]
+#[cfg(not(feature = "clipboard"))]
+fn test_clip_without_feature() {
+    let output = run(&["--day", "1", "--clip"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("aoc was built without the clipboard feature"));
+}
+
+#[test]
+#[cfg(all(feature = "clipboard", target_os = "linux"))]
+fn test_clip_headless() {
+    let output = run(&["--day", "1", "--clip"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("could not read the clipboard"));
+}
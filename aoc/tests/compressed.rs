@@ -0,0 +1,57 @@
+#![cfg(all(feature = "flate2", feature = "zstd"))]
+//! Compressed inputs give the same answers as the plain file
+
+use std::{io::Write, path::Path, process::Command};
+use assert_fs::prelude::*;
+use day_1::SAMPLE;
+
+/// Day, part and answer columns printed by `aoc run --day 1 --format csv --input <path>`
+fn answers(path: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--format", "csv", "--input"])
+    .arg(path)
+    .output()
+    .expect("failed to run aoc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .skip(1)
+    .map(|line| line.split(',').take(3).collect::<Vec<_>>().join(","))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[test]
+fn test_compressed_day_1() -> anyhow::Result<()> {
+    let dir = assert_fs::TempDir::new()?;
+    let plain = dir.child("input.txt");
+    plain.write_str(SAMPLE)?;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(SAMPLE.as_bytes())?;
+    let gzip = dir.child("input.txt.gz");
+    gzip.write_binary(&encoder.finish()?)?;
+
+    let zstd = dir.child("input.txt.zst");
+    zstd.write_binary(&zstd::encode_all(SAMPLE.as_bytes(), 0)?)?;
+
+    let expected = answers(plain.path());
+    assert_eq!(expected, "1,1,11\n1,2,31");
+    assert_eq!(answers(gzip.path()), expected);
+    assert_eq!(answers(zstd.path()), expected);
+    Ok(())
+}
+
+#[test]
+fn test_corrupt_archive() -> anyhow::Result<()> {
+    let file = assert_fs::NamedTempFile::new("input.txt.zst")?;
+    file.write_str(SAMPLE)?;
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--input"])
+    .arg(file.path())
+    .output()?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(&format!("corrupt zstd data in file {}", file.path().display())), "{}", stderr);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc run --day <day> --input -` with the extra arguments and the input piped to stdin
+fn run(day: u8, input: &str, args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", &day.to_string(), "--input", "-", "--format", "csv"])
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+#[test]
+fn test_plain_diagnostics_malformed_rule() {
+    let input = day_5::SAMPLE.replacen("61|13", "61|1x", 1);
+    let output = run(5, &input, &["--diagnostics", "plain"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("Day 5 part 1:\n"), "{}", stderr);
+    assert!(stderr.contains("malformed rule 61|1x on line 6"), "{}", stderr);
+    assert!(stderr.contains("[standard input:6:1]"), "{}", stderr);
+    assert!(stderr.contains(" 6 | 61|1x\n"), "{}", stderr);
+}
+
+#[test]
+fn test_diagnostics_without_location() {
+    let output = run(4, day_4::SAMPLE, &["--diagnostics", "plain"]);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "Day 4 part 1: day 4 part 1 is not implemented\n");
+}
+
+#[test]
+fn test_diagnostics_invalid_style() {
+    let output = run(1, day_1::SAMPLE, &["--diagnostics", "pretty"]);
+    assert!(!output.status.success());
+}
@@ -0,0 +1,88 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc run --day <day> --input - --format json` and the extra arguments with the input piped to stdin
+fn run_json(day: u8, input: &str, args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", &day.to_string(), "--input", "-", "--format", "json"])
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+/// Exit code and the `error_kind=` line of the standard error
+fn failure(output: &Output) -> (Option<i32>, Option<String>) {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let kind = stderr.lines().find_map(|line| line.strip_prefix("error_kind=")).map(str::to_string);
+    (output.status.code(), kind)
+}
+
+#[test]
+fn test_exit_success() {
+    let output = run_json(1, day_1::SAMPLE, &[]);
+    assert_eq!(failure(&output), (Some(0), None));
+}
+
+#[test]
+fn test_exit_input_not_found() -> anyhow::Result<()> {
+    let dir = assert_fs::TempDir::new()?;
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--format", "json", "--input"])
+    .arg(dir.path().join("missing.txt"))
+    .output()?;
+    assert_eq!(failure(&output), (Some(2), Some("input_not_found".to_string())));
+    Ok(())
+}
+
+#[test]
+fn test_exit_parse() {
+    let output = run_json(5, "47|53\n97|x\n\n47,53\n", &[]);
+    assert_eq!(failure(&output), (Some(3), Some("parse".to_string())));
+    // the failed part is still shown with the input snippet
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Day 5 part 1:"));
+}
+
+#[test]
+fn test_exit_solver() {
+    let output = run_json(1, "9223372036854775807   9223372036854775807\n2   9223372036854775807\n", &[]);
+    assert_eq!(failure(&output), (Some(4), Some("solver".to_string())));
+    // part 1 is solved, part 2 overflows
+    assert!(String::from_utf8_lossy(&output.stdout).contains("\"answer\": \"9223372036854775805\""));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Day 1 part 2: part 2 overflowed"));
+}
+
+#[test]
+fn test_exit_timeout() {
+    let input = "12345   67890\n".repeat(100_000);
+    let output = run_json(1, &input, &["--timeout", "0ms"]);
+    assert_eq!(failure(&output), (Some(5), Some("timeout".to_string())));
+}
+
+#[test]
+fn test_exit_unsolved_part_succeeds() {
+    let output = run_json(4, day_4::SAMPLE, &[]);
+    assert_eq!(failure(&output), (Some(0), None));
+}
+
+#[test]
+fn test_exit_kind_only_in_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "26", "--input", "-", "--format", "csv"])
+    .output()
+    .expect("failed to start aoc");
+    assert_eq!(failure(&output), (Some(1), None));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error: day 26 is not implemented"));
+}
+
+#[test]
+fn test_exit_usage_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--format", "yaml"])
+    .output()
+    .expect("failed to start aoc");
+    assert_eq!(failure(&output), (Some(1), None));
+}
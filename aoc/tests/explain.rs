@@ -0,0 +1,45 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc explain --day <day> --input - <selector>` with the input piped to stdin
+fn explain(day: u8, input: &str, selector: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["explain", "--day", &day.to_string(), "--input", "-", selector])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+#[test]
+fn test_explain_day_2() {
+    let output = explain(2, day_2::SAMPLE, "4");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Report on line 4: 1 3 2 4 5\n\
+    Unsafe: 3 -> 2 turns decreasing at level 2\nSafe with the problem dampener, removing level 2 (3)\n");
+}
+
+#[test]
+fn test_explain_day_3() {
+    let output = explain(3, day_3::SAMPLE, "2");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout),
+    "Region 2: bytes 20..59, disabled by don't(): mul(5,5), mul(11,8) skipped\n");
+}
+
+#[test]
+fn test_explain_day_5() {
+    let output = explain(5, day_5::SAMPLE, "27");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Update on line 27: 61,13,29\n\
+    Breaks 29|13: 13 comes before 29\nNot correctly ordered\n");
+}
+
+#[test]
+fn test_explain_not_supported() {
+    let output = explain(1, day_1::SAMPLE, "1");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("day 1 does not support explain"));
+}
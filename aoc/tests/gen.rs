@@ -0,0 +1,39 @@
+use std::process::Command;
+use assert_fs::prelude::*;
+
+/// Run `aoc gen` for day 2 writing the input to `out`, returns the printed answers
+fn generate(out: &std::path::Path, seed: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["gen", "--day", "2", "--size", "300", "--seed", seed, "--out"])
+    .arg(out)
+    .output()
+    .expect("failed to run aoc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_gen_same_seed_same_bytes() -> anyhow::Result<()> {
+    let dir = assert_fs::TempDir::new()?;
+    let (first, second) = (dir.child("first.txt"), dir.child("second.txt"));
+    let answers = generate(first.path(), "11");
+    assert_eq!(generate(second.path(), "11"), answers);
+    assert_eq!(std::fs::read(first.path())?, std::fs::read(second.path())?);
+    assert_eq!(std::fs::read_to_string(first.path())?.lines().count(), 300);
+
+    let (expected, _) = day_2::generate(300, 11);
+    first.assert(expected);
+    Ok(())
+}
+
+#[test]
+fn test_gen_prints_answers() -> anyhow::Result<()> {
+    let dir = assert_fs::TempDir::new()?;
+    let input = dir.child("input.txt");
+    let answers = generate(input.path(), "5");
+    let lines: Vec<&str> = answers.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("day=2 part=1 answer="));
+    assert!(lines[1].starts_with("day=2 part=2 answer="));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc run --day <day> --input - --format csv --part <part>` with the input piped to stdin
+fn run_part(day: u8, part: &str, input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", &day.to_string(), "--input", "-", "--format", "csv", "--part", part])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+/// `(part, answer, duration_us)` of every CSV row
+fn rows(output: &Output) -> Vec<(String, String, String)> {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .skip(1)
+    .map(|line| {
+        let fields: Vec<&str> = line.split(',').collect();
+        (fields[1].to_string(), fields[2].to_string(), fields[3].to_string())
+    })
+    .collect()
+}
+
+#[test]
+fn test_part_both_one_solve() {
+    let rows = rows(&run_part(3, "both", day_3::SAMPLE));
+    let answers: Vec<(&str, &str)> = rows.iter().map(|(part, answer, _)| (part.as_str(), answer.as_str())).collect();
+    assert_eq!(answers, [("1", "161"), ("2", "48")]);
+    // both parts report the time of the single combined solve
+    assert_eq!(rows[0].2, rows[1].2);
+}
+
+#[test]
+fn test_part_single() {
+    let rows = rows(&run_part(1, "2", day_1::SAMPLE));
+    assert_eq!(rows, [("2".to_string(), "31".to_string(), rows[0].2.clone())]);
+}
+
+#[test]
+fn test_part_both_falls_back_for_unsolved_parts() {
+    let output = run_part(4, "both", day_4::SAMPLE);
+    let answers: Vec<String> = rows(&output).into_iter().map(|(_, answer, _)| answer).collect();
+    assert_eq!(answers, ["9"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Day 4 part 1: day 4 part 1 is not implemented"));
+}
@@ -0,0 +1,40 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+/// Run `aoc repl` with the commands piped to stdin and return its output
+fn repl(commands: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .arg("repl")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(commands.as_bytes()).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for aoc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_repl_solves_sample() {
+    let output = repl("day 5\nsample\npart 1\nbench 3\nquit\npart 2\n");
+    assert!(output.contains("Selected day 5"), "{}", output);
+    assert!(output.contains("Part 1: 143"), "{}", output);
+    assert!(output.contains("Part 1: 3 runs, min "), "{}", output);
+    // nothing runs after quit
+    assert!(!output.contains("Part 2"), "{}", output);
+}
+
+#[test]
+fn test_repl_unknown_command() {
+    let output = repl("solve\n");
+    assert!(output.contains("unknown command `solve`\nCommands:\n"), "{}", output);
+}
+
+#[test]
+fn test_repl_errors_keep_going() {
+    let output = repl("part 1\nday 1\nload missing.txt\nsample\npart 2\n");
+    assert!(output.contains("error: no day selected, use `day N` first"), "{}", output);
+    assert!(output.contains("error: could not read file missing.txt: "), "{}", output);
+    assert!(output.contains("Part 2: 31"), "{}", output);
+}
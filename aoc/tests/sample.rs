@@ -0,0 +1,64 @@
+use std::process::{Command, Output};
+
+/// Run `aoc run` with the given extra arguments
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .arg("run")
+    .args(args)
+    .output()
+    .expect("failed to run aoc")
+}
+
+#[test]
+fn test_sample_all() {
+    let output = run(&["--all", "--sample"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Day 1 part 1: PASS\nDay 1 part 2: PASS\n"));
+    assert!(stdout.contains("Day 4 part 1: SKIP (not implemented)\nDay 4 part 2: PASS\n"));
+    assert!(!stdout.contains("FAIL"));
+}
+
+#[test]
+fn test_sample_day() {
+    let output = run(&["--day", "3", "--sample"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Day 3 part 1: PASS\nDay 3 part 2: PASS\n");
+}
+
+#[test]
+fn test_sample_conflicts_with_input() {
+    let output = run(&["--day", "1", "--sample", "--input", "-"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_sample_command() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["sample", "--day", "1"])
+    .output()
+    .expect("failed to run aoc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), day_1::SAMPLE);
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "day=1 part=1 answer=11\nday=1 part=2 answer=31\n");
+}
+
+#[test]
+fn test_sample_command_ends_line() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["sample", "--day", "3"])
+    .output()
+    .expect("failed to run aoc");
+    // day 3 embeds its sample without a trailing line break
+    assert_eq!(String::from_utf8_lossy(&output.stdout), format!("{}\n", day_3::SAMPLE));
+}
+
+#[test]
+fn test_sample_command_unknown_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["sample", "--day", "26"])
+    .output()
+    .expect("failed to run aoc");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("day 26 is not implemented"));
+}
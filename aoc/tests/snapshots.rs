@@ -0,0 +1,89 @@
+//! Snapshots of the human-facing outputs of the runner
+//! 
+//! Review changes with `cargo insta review`, durations, memory figures and
+//! temporary paths are redacted with `aoc_testing::redact` first
+
+use std::process::Command;
+use aoc::sample::SampleCheck;
+use aoc_testing::redact;
+use aoc_utils::{AllocStats, SolveResult};
+
+/// Results and sample checks of every embedded sample
+fn solve_samples() -> (Vec<SolveResult>, Vec<SampleCheck>) {
+    let mut results = Vec::new();
+    let mut checks = Vec::new();
+    for solution in aoc::solutions() {
+        let sample = solution.sample().expect("every day embeds its sample");
+        let sample_solution = solution.sample_solution();
+        let solver = sample_solution.as_deref().unwrap_or(solution.as_ref());
+        for part in [1, 2] {
+            if let Ok(result) = aoc::solve_part(solver, part, sample.input) {
+                results.push(result);
+            }
+        }
+        checks.extend(aoc::sample::check(solution.as_ref()).unwrap_or_default());
+    }
+    (results, checks)
+}
+
+/// Snapshot of a runner output, the `metrics` feature adds the allocations to its own one
+fn assert_run_snapshot(name: &str, output: &str) {
+    let mut settings = insta::Settings::clone_current();
+    if cfg!(feature = "metrics") {
+        settings.set_snapshot_suffix("metrics");
+    }
+    settings.bind(|| insta::assert_snapshot!(name, output));
+}
+
+#[test]
+fn test_run_table() {
+    let (mut results, checks) = solve_samples();
+    // the widths of the time and memory columns depend on the figures
+    for result in &mut results {
+        result.duration = std::time::Duration::ZERO;
+        result.allocations = result.allocations.map(|_| AllocStats::default());
+    }
+    assert_run_snapshot("run_table", &redact(&aoc::table::render(&results, &checks, false)));
+}
+
+#[test]
+fn test_run_json() -> anyhow::Result<()> {
+    let (results, _) = solve_samples();
+    assert_run_snapshot("run_json", &redact(&aoc::report::render(&results, aoc::report::Format::Json)?));
+    Ok(())
+}
+
+#[test]
+fn test_sample_checks() {
+    let (_, checks) = solve_samples();
+    let lines: Vec<String> = checks.iter().map(SampleCheck::to_string).collect();
+    insta::assert_snapshot!(lines.join("\n"));
+}
+
+#[test]
+fn test_stats() {
+    for solution in aoc::solutions() {
+        let sample = solution.sample().expect("every day embeds its sample");
+        insta::assert_snapshot!(format!("stats_day_{}", solution.day()), solution.describe(sample.input).to_string());
+    }
+}
+
+#[test]
+fn test_repl_help() {
+    insta::assert_snapshot!(aoc::repl::HELP);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_missing_input_error() {
+    let path = std::env::temp_dir().join("aoc-snapshot-missing").join("input.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--input"])
+    .arg(&path)
+    .env_remove("RUST_BACKTRACE")
+    .env_remove("RUST_LIB_BACKTRACE")
+    .output()
+    .expect("failed to start aoc");
+    assert!(!output.status.success());
+    insta::assert_snapshot!(redact(&String::from_utf8_lossy(&output.stderr)));
+}
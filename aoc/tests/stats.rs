@@ -0,0 +1,32 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc stats --day <day> --input -` with the input piped to stdin
+fn stats(day: u8, input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["stats", "--day", &day.to_string(), "--input", "-"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+#[test]
+fn test_stats_sample() {
+    let output = stats(1, day_1::SAMPLE);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Lines: 6\nBytes: 36\n"), "{}", stdout);
+    assert!(stdout.ends_with("Format: ok\n"), "{}", stdout);
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_stats_warns_on_wrong_format() {
+    let output = stats(5, &day_5::SAMPLE.replace("\n\n", "\n"));
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "warning: standard input does not look like a day 5 input: \
+    missing the blank line between the rules and the updates on line 22\n");
+}
@@ -0,0 +1,66 @@
+use std::{io::Write, process::{Command, Output, Stdio}};
+
+/// Run `aoc run --day <day> --input - --format csv` with the input piped to stdin
+fn run_with_stdin(day: u8, sample: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", &day.to_string(), "--input", "-", "--format", "csv"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .expect("failed to start aoc");
+    child.stdin.take().unwrap().write_all(sample.as_bytes()).unwrap();
+    child.wait_with_output().expect("failed to wait for aoc")
+}
+
+/// Answers printed on stdout, one per solved part
+fn answers(output: &Output) -> Vec<String> {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .skip(1)
+    .map(|line| line.split(',').nth(2).unwrap().to_string())
+    .collect()
+}
+
+#[test]
+fn test_stdin_day_1() {
+    let output = run_with_stdin(1, day_1::SAMPLE);
+    assert_eq!(answers(&output), day_1::SAMPLE_ANSWERS);
+}
+
+#[test]
+fn test_stdin_day_2() {
+    let output = run_with_stdin(2, day_2::SAMPLE);
+    assert_eq!(answers(&output), day_2::SAMPLE_ANSWERS);
+}
+
+#[test]
+fn test_stdin_day_3() {
+    let sample = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    assert_eq!(answers(&run_with_stdin(3, sample))[0], "161");
+    let output = run_with_stdin(3, day_3::SAMPLE);
+    assert_eq!(answers(&output), day_3::SAMPLE_ANSWERS);
+}
+
+#[test]
+fn test_stdin_day_4() {
+    let output = run_with_stdin(4, day_4::SAMPLE);
+    assert_eq!(answers(&output), [day_4::SAMPLE_ANSWERS[1]]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("day 4 part 1 is not implemented"));
+}
+
+#[test]
+fn test_stdin_day_5() {
+    let output = run_with_stdin(5, day_5::SAMPLE);
+    assert_eq!(answers(&output), [day_5::SAMPLE_ANSWERS[0]]);
+}
+
+#[test]
+fn test_input_requires_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--all", "--input", "-"])
+    .output()
+    .expect("failed to start aoc");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,10 @@
+#![cfg(feature = "parallel")]
+//! Global rayon pool setup, kept in its own binary so no other
+//! test builds the pool before it
+
+#[test]
+fn test_configure_threads() -> anyhow::Result<()> {
+    aoc::configure_threads(Some(2))?;
+    assert_eq!(rayon::current_num_threads(), 2);
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use std::process::Command;
+
+#[test]
+fn test_timeout_solves_in_time() -> anyhow::Result<()> {
+    let file = assert_fs::NamedTempFile::new("input.txt")?;
+    std::fs::write(file.path(), day_1::SAMPLE)?;
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--day", "1", "--timeout", "30s", "--format", "csv", "--input"])
+    .arg(file.path())
+    .output()?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().skip(1).map(|line| line.split(',').nth(2).unwrap()).collect::<Vec<_>>(), ["11", "31"]);
+    Ok(())
+}
+
+#[test]
+fn test_timeout_invalid() -> anyhow::Result<()> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+    .args(["run", "--all", "--timeout", "30x"])
+    .output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid timeout unit in `30x`"));
+    Ok(())
+}
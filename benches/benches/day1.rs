@@ -0,0 +1,34 @@
+//! Day 1 distance and similarity score on generated inputs of up to a
+//! million lines, the functions of each answer against `solve`, which
+//! sorts once and finds both answers in one pass over the sorted lists
+//! 
+//! Run with `cargo bench -p benches --bench day1`, and with
+//! `--features parallel` for the rayon sorts and sums
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn bench_lists(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day1_lists");
+    group.sample_size(10);
+    // a real input has 1 000 lines
+    for lines in [1_000, 100_000, 1_000_000] {
+        let input = day_1::generate_input(lines, 0x2024_0001);
+        let (l1, l2) = day_1::get_lists(&input).expect("generated lists");
+        let (n1, n2) = day_1::get_number_lists::<i32>(&input).expect("generated lists");
+        group.throughput(Throughput::Elements(lines as u64));
+        group.bench_with_input(BenchmarkId::new("distance", lines), &(&l1, &l2), |b, (l1, l2)| {
+            b.iter(|| day_1::distance(black_box(l1), black_box(l2)))
+        });
+        group.bench_with_input(BenchmarkId::new("similarity_score", lines), &(&l1, &l2), |b, (l1, l2)| {
+            b.iter(|| day_1::similarity_score(black_box(l1), black_box(l2)))
+        });
+        group.bench_with_input(BenchmarkId::new("solve", lines), &(&n1, &n2), |b, (n1, n2)| {
+            b.iter(|| day_1::solve(black_box(n1), black_box(n2)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_lists);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+//! Day 19 arrangement counting, comparing every pattern against the design
+//! against following the trie of the patterns
+//! 
+//! Run with `cargo bench -p benches --bench day19`
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_19::{TowelIndex, Towels};
+
+/// Generated towels with `designs` designs and the few hundred patterns of a real input
+fn towels(designs: usize) -> Towels {
+    day_19::parse_towels(&benches::generate(19, designs)).expect("generated towels")
+}
+
+fn bench_arrangements(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day19_arrangements");
+    group.sample_size(10);
+    // a real input has about 450 patterns and 400 designs
+    for designs in [100, 400] {
+        let towels = towels(designs);
+        group.bench_with_input(BenchmarkId::new("naive", designs), &towels, |b, towels| {
+            b.iter(|| {
+                black_box(towels).designs.iter()
+                .filter_map(|design| day_19::arrangements_naive(&towels.patterns, design))
+                .sum::<u64>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("trie", designs), &towels, |b, towels| {
+            b.iter(|| {
+                let index = TowelIndex::new(&black_box(towels).patterns);
+                towels.designs.iter().filter_map(|design| index.arrangements(design)).sum::<u64>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_arrangements);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Day 7 operator searches on long equations, trying every combination
+//! against the backward search pruned by the target
+//! 
+//! Run with `cargo bench -p benches --bench day7`
+
+use std::hint::black_box;
+use aoc_testing::Rng;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_7::Equation;
+
+/// Equations with `operands` numbers like the longest lines of a real input, half of them solvable
+fn equations(count: usize, operands: usize) -> Vec<Equation> {
+    let mut rng = Rng::new(0x2024_0007);
+    (0..count).map(|_| {
+        let operands: Vec<u64> = (0..operands).map(|_| rng.range(1, 10)).collect();
+        // single digits keep any combination of the operators well inside u64
+        let target = operands[1..].iter().fold(operands[0], |value, &operand| match rng.range(0, 3) {
+            0 => value + operand,
+            1 => value * operand,
+            _ => day_7::concat(value, operand).expect("twelve digits fit u64"),
+        });
+        Equation { target: target + rng.range(0, 2), operands }
+    })
+    .collect()
+}
+
+fn bench_concat_search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day7_concat");
+    group.sample_size(10);
+    for operands in [6, 9, 12] {
+        let equations = equations(20, operands);
+        group.bench_with_input(BenchmarkId::new("naive", operands), &equations, |b, equations| {
+            b.iter(|| black_box(equations).iter().filter(|e| e.is_solvable_naive(true)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("pruned", operands), &equations, |b, equations| {
+            b.iter(|| black_box(equations).iter().filter(|e| e.is_solvable_with_concat()).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_concat_search);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+//! Day 9 whole file compaction, scanning the blocks for the free span of
+//! every file against the min-heaps of the free spans by length
+//! 
+//! Run with `cargo bench -p benches --bench day9`
+
+use std::hint::black_box;
+use aoc_testing::Rng;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_9::Disk;
+
+/// Disk with `files` files of 1 to 9 blocks and up to 9 free blocks after each of them
+fn disk(files: usize) -> Disk {
+    let mut rng = Rng::new(0x2024_0009);
+    let map: String = (0..files).map(|_| format!("{}{}", rng.range(1, 10), rng.range(0, 10))).collect();
+    day_9::parse_disk(&map).expect("only digits")
+}
+
+fn bench_compact_files(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day9_files");
+    group.sample_size(10);
+    // a real input has about 10 000 files in 95 000 blocks
+    for files in [1_000, 5_000, 10_000] {
+        let disk = disk(files);
+        group.bench_with_input(BenchmarkId::new("naive", files), &disk, |b, disk| {
+            b.iter(|| {
+                let mut disk: Disk = black_box(disk).clone();
+                day_9::compact_files_naive(&mut disk);
+                day_9::checksum(&disk)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("heaps", files), &disk, |b, disk| {
+            b.iter(|| {
+                let mut disk: Disk = black_box(disk).clone();
+                day_9::compact_files(&mut disk);
+                day_9::checksum(&disk)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_compact_files);
+criterion_main!(benches);
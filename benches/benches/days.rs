@@ -0,0 +1,55 @@
+//! Benchmarks of every day on the official sample and a large generated input
+//! 
+//! Results are grouped per day (`day1`, `day2`, ...), so a single day can be
+//! selected with `cargo bench -p benches -- day3` and compared against a
+//! baseline saved with `cargo bench -p benches -- --save-baseline before`
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Records in the generated inputs (days 4, 6, 8, 10, 12, 15, 16 and 20 use it as the grid side)
+/// 
+/// Day 9 gets fewer files since its oracle moves every file block by block,
+/// day 14 fewer robots since its oracle walks them through every step of the period,
+/// day 20 the side of a real racetrack since its oracle tries every pair of tiles
+/// and day 22 as many buyers as a real input since its oracle maps every sequence of each
+fn large_size(day: u8) -> usize {
+    match day {
+        4 | 6 | 8 | 10 | 12 | 15 | 16 => 500,
+        9 => 5_000,
+        14 => 1_000,
+        20 => 141,
+        22 => 2_000,
+        _ => 20_000,
+    }
+}
+
+fn bench_days(c: &mut Criterion) {
+    for (solution, sample) in benches::samples() {
+        let day = solution.day();
+        let large = benches::generate(day, large_size(day));
+        let sample_solution = solution.sample_solution();
+        let sample_solver = sample_solution.as_deref().unwrap_or(solution.as_ref());
+        let mut group = c.benchmark_group(format!("day{}", day));
+
+        for (name, solver, input) in [("sample", sample_solver, sample), ("large", solution.as_ref(), large.as_str())] {
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            for part in [1u8, 2] {
+                // unsolved parts have nothing worth measuring
+                let solve = |input: &str| match part {
+                    1 => solver.part1(input),
+                    _ => solver.part2(input),
+                };
+                if matches!(solve(input), Err(aoc_utils::SolutionError::NotImplemented { .. })) {
+                    continue;
+                }
+                group.bench_with_input(BenchmarkId::new(format!("part{}", part), name), input,
+                |b, input| b.iter(|| solve(black_box(input))));
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
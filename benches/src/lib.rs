@@ -0,0 +1,120 @@
+#![warn(missing_docs)]
+//! Inputs for the advent of code 2024 benchmarks
+//! 
+//! Official samples are the ones embedded in each day crate and the
+//! large inputs are generated from a fixed seed, so every run (and every
+//! saved criterion baseline) measures exactly the same bytes
+
+use aoc_utils::Solution;
+
+/// Official sample input of every day together with its solution
+/// 
+/// The solutions have the parameters of the puzzle input, the sample is
+/// solved by [`Solution::sample_solution`] when the day has one
+pub fn samples() -> Vec<(Box<dyn Solution>, &'static str)> {
+    vec![
+        (Box::new(day_1::Day1), day_1::SAMPLE),
+        (Box::new(day_2::Day2), day_2::SAMPLE),
+        (Box::new(day_3::Day3), day_3::SAMPLE),
+        (Box::new(day_4::Day4), day_4::SAMPLE),
+        (Box::new(day_5::Day5), day_5::SAMPLE),
+        (Box::new(day_6::Day6), day_6::SAMPLE),
+        (Box::new(day_7::Day7), day_7::SAMPLE),
+        (Box::new(day_8::Day8), day_8::SAMPLE),
+        (Box::new(day_9::Day9), day_9::SAMPLE),
+        (Box::new(day_10::Day10), day_10::SAMPLE),
+        (Box::new(day_11::Day11), day_11::SAMPLE),
+        (Box::new(day_12::Day12), day_12::SAMPLE),
+        (Box::new(day_13::Day13), day_13::SAMPLE),
+        (Box::new(day_14::Day14::default()), day_14::SAMPLE),
+        (Box::new(day_15::Day15), day_15::SAMPLE),
+        (Box::new(day_16::Day16), day_16::SAMPLE),
+        (Box::new(day_17::Day17), day_17::SAMPLE),
+        (Box::new(day_18::Day18::default()), day_18::SAMPLE),
+        (Box::new(day_19::Day19), day_19::SAMPLE),
+        (Box::new(day_20::Day20::default()), day_20::SAMPLE),
+        (Box::new(day_21::Day21), day_21::SAMPLE),
+        (Box::new(day_22::Day22), day_22::SAMPLE),
+        (Box::new(day_23::Day23), day_23::SAMPLE),
+        (Box::new(day_24::Day24), day_24::SAMPLE),
+        (Box::new(day_25::Day25), day_25::SAMPLE),
+    ]
+}
+
+/// Generate a large input for the given day
+/// 
+/// `size` is roughly the number of records (lines, instructions or grid rows)
+/// 
+/// # Examples
+/// ```
+/// let input = benches::generate(1, 1000);
+/// assert_eq!(input.lines().count(), 1000);
+/// assert_eq!(input, benches::generate(1, 1000));
+/// ```
+pub fn generate(day: u8, size: usize) -> String {
+    let seed = 0x2024_1201 + day as u64;
+    let generated = match day {
+        1 => aoc_testing::day1(seed, size),
+        2 => aoc_testing::day2(seed, size),
+        3 => aoc_testing::day3(seed, size),
+        4 => aoc_testing::day4(seed, size),
+        5 => aoc_testing::day5(seed, size),
+        6 => aoc_testing::day6(seed, size),
+        7 => aoc_testing::day7(seed, size),
+        8 => aoc_testing::day8(seed, size),
+        9 => aoc_testing::day9(seed, size),
+        10 => aoc_testing::day10(seed, size),
+        11 => aoc_testing::day11(seed, size),
+        12 => aoc_testing::day12(seed, size),
+        13 => aoc_testing::day13(seed, size),
+        14 => aoc_testing::day14(seed, size),
+        15 => aoc_testing::day15(seed, size),
+        16 => aoc_testing::day16(seed, size),
+        17 => aoc_testing::day17(seed, size),
+        18 => aoc_testing::day18(seed, size),
+        19 => aoc_testing::day19(seed, size),
+        20 => aoc_testing::day20(seed, size),
+        21 => aoc_testing::day21(seed, size),
+        22 => aoc_testing::day22(seed, size),
+        23 => aoc_testing::day23(seed, size),
+        24 => aoc_testing::day24(seed, size),
+        25 => aoc_testing::day25(seed, size),
+        _ => panic!("no generator for day {}", day),
+    };
+    generated.input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_cover_every_day() {
+        let days: Vec<u8> = samples().iter().map(|(solution, _)| solution.day()).collect();
+        assert_eq!(days, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25]);
+    }
+
+    #[test]
+    fn test_generate_deterministic() {
+        for day in 1..=25 {
+            assert_eq!(generate(day, 50), generate(day, 50));
+        }
+    }
+
+    #[test]
+    fn test_generate_solvable() {
+        for (solution, _) in samples() {
+            let input = generate(solution.day(), 100);
+            for answer in [solution.part1(&input), solution.part2(&input)] {
+                assert!(!matches!(answer, Err(aoc_utils::SolutionError::Day(_))),
+                "day {} failed on generated input", solution.day());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_generate_negative() {
+        generate(26, 10);
+    }
+}
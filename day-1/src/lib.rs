@@ -5,20 +5,375 @@
 //! 
 //! [`Read more`](../../../README.md)
 
-use anyhow::{Context, Error, Ok, Result};
+use std::{num::{ParseIntError, TryFromIntError}, str::FromStr};
+#[cfg(feature = "fs")]
+use std::{fs::File, path::PathBuf};
+use aoc_prelude::*;
 
-/// Find similarity score
+/// Errors returned by the day 1 functions
+#[derive(Debug, Error)]
+pub enum Day1Error {
+    /// Input file could not be read
+    #[cfg(feature = "fs")]
+    #[error("could not read file {}", path.display())]
+    Io {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The lists of a file could not be read, see [read_numbers]
+    #[cfg(feature = "fs")]
+    #[error("failed reading the lists of {}", path.display())]
+    File {
+        /// Path of the file
+        path: PathBuf,
+        /// Error at a line of the file
+        source: Box<Day1Error>,
+    },
+    /// A line could not be read from the input
+    #[error("failed reading line {line}")]
+    ReadLine {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// A line does not contain two numbers
+    #[error("split failed on {text}")]
+    Split {
+        /// Text of the line
+        text: String,
+        /// Underlying parse error, with the line number
+        source: ParseError,
+    },
+    /// A symbol is not a number
+    #[error("failed parsing {token} to number")]
+    Parse {
+        /// Symbol that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// A symbol on a numbered line is not a number
+    #[error("input line {line}, {} column: failed parsing '{token}' to number", column_name(*.column))]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column of the symbol (1 or 2)
+        column: usize,
+        /// Symbol that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// The lists do not have the same number of entries
+    #[error("left list has {left} entries but right list has {right}")]
+    Lengths {
+        /// Entries of the left list
+        left: usize,
+        /// Entries of the right list
+        right: usize,
+    },
+    /// A list expected in ascending order is not sorted
+    #[error("{} list is not sorted, entry {index} is larger than the next one", column_name(*.column))]
+    Unsorted {
+        /// Column of the list (1 or 2)
+        column: usize,
+        /// Index of the first entry out of order
+        index: usize,
+    },
+    /// Repetitions do not fit into the result type
+    #[error("failure finding repetitions for {sym}")]
+    Repetitions {
+        /// Symbol being counted
+        sym: String,
+        /// Underlying conversion error
+        source: TryFromIntError,
+    },
+    /// An answer does not fit into its integer type
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the challenge (1 or 2)
+        part: u8,
+    },
+}
+
+impl From<Day1Error> for SolutionError {
+    fn from(e: Day1Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day1Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day1Error::ReadLine { line, .. } => Some(Location::Line(*line)),
+            Day1Error::Split { source, .. } => source.location(),
+            Day1Error::Parse { token, .. } => Some(Location::Token(token.clone())),
+            Day1Error::Number { line, column, .. } => Some(Location::Field { line: *line, field: *column }),
+            #[cfg(feature = "fs")]
+            Day1Error::File { source, .. } => source.location(),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "fs")]
+            Day1Error::Io { source, .. } => ErrorKind::io(source),
+            #[cfg(feature = "fs")]
+            Day1Error::File { source, .. } => source.kind(),
+            Day1Error::ReadLine { source, .. } => ErrorKind::io(source),
+            Day1Error::Split { .. } | Day1Error::Parse { .. } | Day1Error::Number { .. }
+            | Day1Error::Lengths { .. } | Day1Error::Unsorted { .. } => ErrorKind::Parse,
+            Day1Error::Repetitions { .. } | Day1Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Name of a column of the input in the error messages
+fn column_name(column: usize) -> &'static str {
+    match column {
+        1 => "left",
+        _ => "right",
+    }
+}
+
+/// Result type returned by the day 1 functions
+pub type Result<T, E = Day1Error> = std::result::Result<T, E>;
+
+/// Number type the lists can be parsed to
 /// 
-/// Finds the similarity score required for the challenge
-/// Similarity score is calculated by going through * `list1`
-/// and calculating [repetitions] of each element in * `list2`
-/// and multiplying * `list1` element by the repetitions number.
+/// Any primitive integer up to 64 bits, signed or not. Differences,
+/// products and sums are taken in `i128`, which fits every one of
+/// them for lists of any realistic length.
+pub trait LocationId: Copy + Ord + std::hash::Hash + Send + Sync + FromStr<Err = ParseIntError> + Into<i128> {}
+
+impl<T: Copy + Ord + std::hash::Hash + Send + Sync + FromStr<Err = ParseIntError> + Into<i128>> LocationId for T {}
+
+/// Parse a symbol to a number
+fn parse_number<T: FromStr<Err = ParseIntError>>(sym: &str) -> Result<T> {
+    sym.parse::<T>()
+    .map_err(|source| Day1Error::Parse { token: sym.to_string(), source })
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Inputs parsed on this thread, the tests check that both parts share one parse
+    static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Both lists of location IDs, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let input = day_1::Day1Input::try_from("3   4\n4   3\n")?;
+///     assert_eq!(input.left, vec![3, 4]);
+///     assert_eq!(input.right, vec![4, 3]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day1Input {
+    /// Numbers of the first column
+    pub left: Vec<i64>,
+    /// Numbers of the second column
+    pub right: Vec<i64>,
+}
+
+impl Day1Input {
+    /// Parse both lists, split as `options` say
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_1::{Day1Input, ParseOptions};
+    /// 
+    /// assert!(Day1Input::parse_with("3   4   7\n", ParseOptions::strict()).is_err());
+    /// assert_eq!(Day1Input::parse_with("3   4   7\n", ParseOptions::default()).unwrap().right, [4]);
+    /// ```
+    pub fn parse_with(text: &str, options: impl Into<ParseOptions>) -> Result<Self> {
+        #[cfg(test)]
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        let (left, right) = parse_number_lists(text, options.into())?;
+        Ok(Day1Input { left, right })
+    }
+}
+
+impl TryFrom<&str> for Day1Input {
+    type Error = Day1Error;
+
+    /// Parse both lists with the default [ParseOptions]
+    fn try_from(text: &str) -> Result<Self> {
+        Day1Input::parse_with(text, ParseOptions::default())
+    }
+}
+
+/// Total distance between the lists paired up smallest to largest, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let input = day_1::Day1Input::try_from(day_1::SAMPLE)?;
+///     assert_eq!(day_1::part1(&input)?, 11);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day1Input) -> Result<i64> {
+    narrow(1, number_distance(&input.left, &input.right)?)
+}
+
+/// Similarity score of the lists, the answer of part 2
+/// 
+/// Every number of the left list is multiplied by the number
+/// of times it appears in the right list
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let input = day_1::Day1Input::try_from(day_1::SAMPLE)?;
+///     assert_eq!(day_1::part2(&input)?, 31);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day1Input) -> Result<i64> {
+    narrow(2, histogram_score(&histogram(&input.left, &input.right))?)
+}
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["11", "31"];
+
+/// Day 1 solution used by the `aoc` runner
+/// 
+/// Part 1 is the total distance between the lists and
+/// part 2 is their similarity score, see [`part1`] and [`part2`].
+/// Both parts are solved from the same [`Day1Input`].
 /// 
 /// # Examples
 /// ```
-/// use anyhow::{Ok, Result};
+/// use aoc_utils::{Result, Solution};
 /// 
 /// fn main() -> Result<()> {
+///     let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+///     assert_eq!(day_1::Day1.part1(input)?, "11");
+///     assert_eq!(day_1::Day1.part2(input)?, "31");
+///     Ok(())
+/// }
+/// ```
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day1Input::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day1Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day1Input::try_from(input)?;
+        Ok((part1(&input)?.to_string(), part2(&input)?.to_string()))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day1Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Day1Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like two columns of numbers, a third column included
+fn format_problem(input: &str) -> Option<String> {
+    match parse_number_lists::<i64>(input, ParseOptions::strict()) {
+        Ok(_) => None,
+        Err(Day1Error::Split { source, .. }) => Some(error_chain(&source)),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+/// Day 1 input with `size` lines of two numbers, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_1::generate(50, 7);
+/// assert_eq!(day_1::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day1(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+/// Day 1 input with `n` lines of two numbers, generated from `seed`
+/// 
+/// Numbers have five digits like the puzzle input, so long inputs repeat
+/// plenty of them for the similarity score. Unlike [generate] nothing is
+/// solved, a million lines take well under a second.
+/// 
+/// # Examples
+/// ```
+/// let input = day_1::generate_input(1_000, 7);
+/// assert_eq!(input.lines().count(), 1_000);
+/// assert_eq!(day_1::generate_input(1_000, 7), input);
+/// ```
+#[cfg(any(test, feature = "generate"))]
+pub fn generate_input(n: usize, seed: u64) -> String {
+    let mut rng = aoc_testing::Rng::new(seed);
+    (0..n).map(|_| format!("{}   {}\n", rng.range(10000, 100000), rng.range(10000, 100000))).collect()
+}
+
+/// Fail unless both lists have the same number of entries
+fn check_lengths<T>(list1: &[T], list2: &[T]) -> Result<()> {
+    match list1.len() == list2.len() {
+        true => Ok(()),
+        false => Err(Day1Error::Lengths { left: list1.len(), right: list2.len() }),
+    }
+}
+
+/// Find similarity score
+/// 
+/// Finds the similarity score required for the challenge
+/// Similarity score is the sum of every number of the [histogram]
+/// of both lists times its count in * `list1` and its count in * `list2`,
+/// the same as every * `list1` element times the number of times
+/// it appears in * `list2`.
+/// Numbers missing from either list add nothing to the score.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
 ///     let v1 = vec!["3", "4", "2", "1", "3", "3"];
 ///     let v2 = vec!["4", "3", "5", "3", "9", "3"];
 ///     let sc = day_1::similarity_score(&v1, &v2)?;
@@ -26,18 +381,118 @@ use anyhow::{Context, Error, Ok, Result};
 ///     Ok(())
 /// }
 /// ```
-pub fn similarity_score(list1: &Vec<&str>, list2: &Vec<&str>) -> Result<i32> {
-    let mut sc = 0;
+pub fn similarity_score(list1: &[&str], list2: &[&str]) -> Result<i64> {
+    narrow(2, similarity_score_of::<i32>(list1, list2)?)
+}
+
+/// Find similarity score of lists of any [LocationId] type
+/// 
+/// Same as [similarity_score] with the symbols parsed to `T`,
+/// the score is summed up in `i128`.
+/// 
+/// # Example
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let v1 = vec!["18446744073709551615", "7"];
+///     let v2 = vec!["18446744073709551615", "18446744073709551615"];
+///     let sc = day_1::similarity_score_of::<u64>(&v1, &v2)?;
+///     assert_eq!(sc, 2 * i128::from(u64::MAX));
+///     Ok(())
+/// }
+/// ```
+pub fn similarity_score_of<T: LocationId>(list1: &[&str], list2: &[&str]) -> Result<i128> {
+    check_lengths(list1, list2)?;
+    let parse = |list: &[&str]| list.iter().map(|sym| parse_number(sym)).collect::<Result<Vec<T>>>();
+    histogram_score(&histogram(&parse(list1)?, &parse(list2)?))
+}
+
+/// Similarity score of the [histogram] of both lists
+fn histogram_score<T: LocationId>(histogram: &HashMap<T, (usize, usize)>) -> Result<i128> {
+    #[cfg(feature = "parallel")]
+    return histogram_score_parallel(histogram);
+    #[cfg(not(feature = "parallel"))]
+    return histogram_score_serial(histogram);
+}
+
+/// Score of one number of the histogram, the number times its count in each list
+fn value_score<T: LocationId>(num: T, (left, right): (usize, usize)) -> Result<i128> {
+    let reps = |count: usize| i128::try_from(count)
+    .map_err(|source| Day1Error::Repetitions { sym: num.into().to_string(), source });
+    let (left, right) = (reps(left)?, reps(right)?);
+    num.into().checked_mul(left)
+    .and_then(|score| score.checked_mul(right))
+    .ok_or(Day1Error::Overflow { part: 2 })
+}
 
-    for sym in list1 {
-        let num = sym.parse::<i32>()
-        .with_context(|| format!("failed parsing {} to number", sym))?;
-        
-        let reps = repetitions(sym, &list2)?;
+/// Sum of the values of a part, [`Day1Error::Overflow`] instead of wrapping past the `i128` range
+fn checked_sum(part: u8, values: impl IntoIterator<Item = Result<i128>>) -> Result<i128> {
+    values.into_iter()
+    .try_fold(0i128, |sum, value| sum.checked_add(value?).ok_or(Day1Error::Overflow { part }))
+}
+
+/// Answer of a part as `i64`, [`Day1Error::Overflow`] if it does not fit
+fn narrow(part: u8, answer: i128) -> Result<i64> {
+    i64::try_from(answer).map_err(|_| Day1Error::Overflow { part })
+}
+
+/// [`histogram_score`] going through the histogram one number at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn histogram_score_serial<T: LocationId>(histogram: &HashMap<T, (usize, usize)>) -> Result<i128> {
+    checked_sum(2, histogram.iter().map(|(&num, &counts)| value_score(num, counts)))
+}
+
+/// [`histogram_score`] splitting the histogram between the rayon threads
+#[cfg(feature = "parallel")]
+fn histogram_score_parallel<T: LocationId>(histogram: &HashMap<T, (usize, usize)>) -> Result<i128> {
+    use rayon::prelude::*;
 
-        sc += num * reps;
+    histogram.par_iter()
+    .map(|(&num, &counts)| value_score(num, counts))
+    .try_reduce(|| 0, |a, b| a.checked_add(b).ok_or(Day1Error::Overflow { part: 2 }))
+}
+
+/// Count how many times each number appears in the left and in the right list
+/// 
+/// Built in one pass over each list, the right side from the
+/// [right_counts] of the right list. A number missing from one of
+/// the lists has a count of 0 on that side.
+/// 
+/// # Example
+/// ```
+/// let histogram = day_1::histogram(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+/// assert_eq!(histogram[&3], (3, 3));
+/// assert_eq!(histogram[&1], (1, 0));
+/// assert_eq!(histogram[&9], (0, 1));
+/// assert_eq!(histogram.get(&7), None);
+/// ```
+pub fn histogram<T: Copy + Eq + std::hash::Hash>(list1: &[T], list2: &[T]) -> HashMap<T, (usize, usize)> {
+    let mut histogram: HashMap<T, (usize, usize)> = right_counts(list2).into_iter()
+    .map(|(num, count)| (num, (0, count)))
+    .collect();
+    for &num in list1 {
+        histogram.entry(num).or_default().0 += 1;
     }
-    Ok(sc)
+    histogram
+}
+
+/// Count how many times each number appears in a list
+/// 
+/// Built in one pass over the list, the right side of the [histogram]
+/// of both lists
+/// 
+/// # Example
+/// ```
+/// let counts = day_1::right_counts(&[4, 3, 5, 3, 9, 3]);
+/// assert_eq!(counts[&3], 3);
+/// assert_eq!(counts[&4], 1);
+/// assert_eq!(counts.get(&2), None);
+/// ```
+pub fn right_counts<T: Copy + Eq + std::hash::Hash>(list: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for &num in list {
+        *counts.entry(num).or_default() += 1;
+    }
+    counts
 }
 
 /// Find repetitions of a symbol in a vector
@@ -47,9 +502,7 @@ pub fn similarity_score(list1: &Vec<&str>, list2: &Vec<&str>) -> Result<i32> {
 /// 
 /// # Example
 /// ```
-/// use anyhow::{Ok, Result};
-/// 
-/// fn main() -> Result<()> {
+/// fn main() -> day_1::Result<()> {
 ///     let v = vec!["3", "4", "2", "1", "3", "3"];
 ///     let s = "3";
 ///     let reps = day_1::repetitions(s, &v)?;
@@ -57,26 +510,26 @@ pub fn similarity_score(list1: &Vec<&str>, list2: &Vec<&str>) -> Result<i32> {
 ///     Ok(())
 /// }
 /// ```
-pub fn repetitions(sym: &str, list: &Vec<&str>) -> Result<i32>{
+pub fn repetitions(sym: &str, list: &[&str]) -> Result<i32>{
     let rep: i32 = list.iter()
     .filter(|&s| s.eq(&sym))
     .count()
     .try_into()
-    .with_context(|| format!("failure finding repetitions for {}", sym))?;
+    .map_err(|source| Day1Error::Repetitions { sym: sym.to_string(), source })?;
 
     Ok(rep)
 }
 
 /// Find distance between 2 vectors
 /// 
-/// Compare each number in 2 vectors and
-/// accumulate total distance
+/// Parse the symbols of both vectors, sort the numbers
+/// smallest to largest and accumulate the total distance
+/// between the numbers paired up in that order.
+/// Only the parsed copies are sorted, the vectors are left as they are.
 /// 
 /// # Example
 /// ```
-/// use anyhow::Result;
-/// 
-/// fn main() -> Result<()> {
+/// fn main() -> day_1::Result<()> {
 ///     let v1 = vec!["3", "4", "2", "1", "3", "3"];
 ///     let v2 = vec!["4", "3", "5", "3", "9", "3"];
 ///     let dist = day_1::distance(&v1, &v2)?;
@@ -84,29 +537,381 @@ pub fn repetitions(sym: &str, list: &Vec<&str>) -> Result<i32>{
 ///     Ok(())
 /// }
 /// ```
-pub fn distance(list1: &Vec<&str>, list2: &Vec<&str>) -> Result<i32> {
-    let mut dist: i32 = 0;
+pub fn distance(list1: &[&str], list2: &[&str]) -> Result<i64> {
+    narrow(1, distance_of::<i32>(list1, list2)?)
+}
 
-    let mut sorted_list1 = list1.clone();
-    sorted_list1.sort();
-    let mut sorted_list2 = list2.clone();
-    sorted_list2.sort();
+/// Find distance between lists of any [LocationId] type
+/// 
+/// Same as [distance] with the symbols parsed to `T`,
+/// the distance is summed up in `i128`.
+/// 
+/// # Example
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let v1 = vec!["18446744073709551615", "0"];
+///     let v2 = vec!["0", "0"];
+///     let dist = day_1::distance_of::<u64>(&v1, &v2)?;
+///     assert_eq!(dist, i128::from(u64::MAX));
+///     Ok(())
+/// }
+/// ```
+pub fn distance_of<T: LocationId>(list1: &[&str], list2: &[&str]) -> Result<i128> {
+    check_lengths(list1, list2)?;
+    let parse = |list: &[&str]| list.iter().map(|sym| parse_number(sym)).collect::<Result<Vec<T>>>();
+    number_distance(&parse(list1)?, &parse(list2)?)
+}
 
-    for i in 0..sorted_list1.len() {
-        let sym1 = sorted_list1.get(i)
-        .with_context(|| format!("failed reading symbol from List 1"))?;
-        let num1 = sym1.parse::<i32>()
-        .with_context(|| format!("failed parsing {} to number", sym1))?;
-        let sym2 = sorted_list2.get(i)
-        .with_context(|| format!("failed reading symbol from List 2"))?;
-        let num2 = sym2.parse::<i32>()
-        .with_context(|| format!("failed parsing {} to number", sym2))?;
+/// [`distance_of`] lists already parsed and of the same length
+fn number_distance<T: LocationId>(list1: &[T], list2: &[T]) -> Result<i128> {
+    // the same sorted copies [sorted_pairs] zips up
+    let (sorted_list1, sorted_list2) = sorted_lists(list1, list2);
+    sorted_distance(&sorted_list1, &sorted_list2)
+}
 
-        let dist_i = num1 - num2;
-        dist = dist + dist_i.abs();
+/// Find distance between lists sorted already
+/// 
+/// Same as [distance] for parsed lists, without sorting copies
+/// of them first. Lists of different lengths fail with
+/// [`Day1Error::Lengths`] and lists out of ascending order
+/// with [`Day1Error::Unsorted`].
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     assert_eq!(day_1::distance_sorted(&[1, 2, 3, 3, 3, 4], &[3, 3, 3, 4, 5, 9])?, 11);
+///     assert!(day_1::distance_sorted(&[3, 4, 2], &[3, 4, 5]).is_err());
+///     Ok(())
+/// }
+/// ```
+pub fn distance_sorted<T: LocationId>(list1: &[T], list2: &[T]) -> Result<i64> {
+    check_lengths(list1, list2)?;
+    check_sorted(list1, 1)?;
+    check_sorted(list2, 2)?;
+    narrow(1, sorted_distance(list1, list2)?)
+}
+
+/// [`Day1Error::Unsorted`] at the first entry larger than the next one
+fn check_sorted<T: Ord>(list: &[T], column: usize) -> Result<()> {
+    match list.windows(2).position(|pair| pair[0] > pair[1]) {
+        Some(index) => Err(Day1Error::Unsorted { column, index }),
+        None => Ok(()),
     }
+}
+
+/// Sum of the distances of the sorted lists paired up in order, as [sorted_pairs] pairs them
+fn sorted_distance<T: LocationId>(sorted_list1: &[T], sorted_list2: &[T]) -> Result<i128> {
+    #[cfg(feature = "parallel")]
+    return sorted_distance_parallel(sorted_list1, sorted_list2);
+    #[cfg(not(feature = "parallel"))]
+    return sorted_distance_serial(sorted_list1, sorted_list2);
+}
+
+/// [`sorted_distance`] summed up one pair at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn sorted_distance_serial<T: LocationId>(sorted_list1: &[T], sorted_list2: &[T]) -> Result<i128> {
+    checked_sum(1, sorted_list1.iter().zip(sorted_list2).map(|(&num1, &num2)| Ok(pair_distance(num1, num2))))
+}
+
+/// [`sorted_distance`] summing up the pairs on the rayon threads
+#[cfg(feature = "parallel")]
+fn sorted_distance_parallel<T: LocationId>(sorted_list1: &[T], sorted_list2: &[T]) -> Result<i128> {
+    use rayon::prelude::*;
 
-    Ok(dist)
+    sorted_list1.par_iter().zip(sorted_list2)
+    .map(|(&num1, &num2)| Ok(pair_distance(num1, num2)))
+    .try_reduce(|| 0, |a, b| a.checked_add(b).ok_or(Day1Error::Overflow { part: 1 }))
+}
+
+/// Distance between two numbers, the difference of two 64-bit numbers always fits i128
+fn pair_distance<T: LocationId>(num1: T, num2: T) -> i128 {
+    (num1.into() - num2.into()).abs()
+}
+
+/// Sorted copy of a list, split between the rayon threads with the `parallel` feature
+fn sorted<T: Copy + Ord + Send>(list: &[T]) -> Vec<T> {
+    #[cfg(feature = "parallel")]
+    return sorted_parallel(list);
+    #[cfg(not(feature = "parallel"))]
+    return sorted_serial(list);
+}
+
+/// [`sorted`] on the current thread
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn sorted_serial<T: Copy + Ord>(list: &[T]) -> Vec<T> {
+    let mut sorted = list.to_vec();
+    sorted.sort_unstable();
+    sorted
+}
+
+/// [`sorted`] on the rayon threads
+#[cfg(feature = "parallel")]
+fn sorted_parallel<T: Copy + Ord + Send>(list: &[T]) -> Vec<T> {
+    use rayon::prelude::*;
+
+    let mut sorted = list.to_vec();
+    sorted.par_sort_unstable();
+    sorted
+}
+
+/// Pair up the numbers of both lists, smallest with smallest
+/// 
+/// These are the pairs [distance] adds up, in the same order.
+/// Copies of the lists are sorted, on the rayon threads with the
+/// `parallel` feature, and the pairs stop at the end of the shorter list.
+/// 
+/// # Example
+/// ```
+/// let pairs: Vec<(i32, i32)> = day_1::sorted_pairs(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]).collect();
+/// assert_eq!(pairs, [(1, 3), (2, 3), (3, 3), (3, 4), (3, 5), (4, 9)]);
+/// ```
+pub fn sorted_pairs<T: Copy + Ord + Send + Sync>(list1: &[T], list2: &[T]) -> impl Iterator<Item = (T, T)> {
+    let (sorted_list1, sorted_list2) = sorted_lists(list1, list2);
+    sorted_list1.into_iter().zip(sorted_list2)
+}
+
+/// Sorted copies of both lists, each sorted on its own rayon thread with the `parallel` feature
+fn sorted_lists<T: Copy + Ord + Send + Sync>(list1: &[T], list2: &[T]) -> (Vec<T>, Vec<T>) {
+    #[cfg(feature = "parallel")]
+    return rayon::join(|| sorted(list1), || sorted(list2));
+    #[cfg(not(feature = "parallel"))]
+    return (sorted(list1), sorted(list2));
+}
+
+/// Find the pairs of numbers furthest apart
+/// 
+/// Up to `n` of the [sorted_pairs] the [distance] adds up, each with its
+/// absolute difference, largest difference first. Pairs of the same
+/// difference keep the order of the sorted lists.
+/// 
+/// # Example
+/// ```
+/// let top = day_1::top_differences(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3], 3);
+/// assert_eq!(top, [(4, 9, 5), (1, 3, 2), (3, 5, 2)]);
+/// ```
+pub fn top_differences<T: LocationId>(list1: &[T], list2: &[T], n: usize) -> Vec<(T, T, i128)> {
+    let mut pairs: Vec<(T, T, i128)> = sorted_pairs(list1, list2)
+    .map(|(num1, num2)| (num1, num2, pair_distance(num1, num2)))
+    .collect();
+    pairs.sort_by_key(|&(_, _, difference)| std::cmp::Reverse(difference));
+    pairs.truncate(n);
+    pairs
+}
+
+/// Left and right lists of location IDs, always of the same length
+/// 
+/// The lists are parsed and checked when the pair is built, so they
+/// cannot be swapped or mismatched later and only an overflow of the
+/// sums can still fail. The numbers are `i64` unless another
+/// [LocationId] type is given.
+/// 
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// 
+/// fn main() -> day_1::Result<()> {
+///     let pair: day_1::ListPair<i32> = day_1::ListPair::from_reader(Cursor::new("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"))?;
+///     assert_eq!(pair.len(), 6);
+///     assert_eq!(pair.distance()?, 11);
+///     assert_eq!(pair.similarity()?, 31);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListPair<T = i64> {
+    left: Vec<T>,
+    right: Vec<T>,
+}
+
+impl<T: LocationId> ListPair<T> {
+    /// Pair up two lists, [`Day1Error::Lengths`] unless they have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// let pair = day_1::ListPair::new(vec![3, 4], vec![4, 3]).unwrap();
+    /// assert_eq!(pair.left(), [3, 4]);
+    /// assert!(day_1::ListPair::new(vec![3, 4], vec![4]).is_err());
+    /// ```
+    pub fn new(left: Vec<T>, right: Vec<T>) -> Result<Self> {
+        check_lengths(&left, &right)?;
+        Ok(ListPair { left, right })
+    }
+
+    /// Read the pair line by line, see [read_lists]
+    pub fn from_reader(mut reader: impl BufRead) -> Result<Self> {
+        let (left, right) = read_lists(&mut reader)?;
+        ListPair::new(left, right)
+    }
+
+    /// Numbers of the left list, in input order
+    pub fn left(&self) -> &[T] {
+        &self.left
+    }
+
+    /// Numbers of the right list, in input order
+    pub fn right(&self) -> &[T] {
+        &self.right
+    }
+
+    /// Number of entries in each list
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Whether the lists have no entries
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Numbers of both lists paired up for the distance, see [sorted_pairs]
+    pub fn sorted_pairs(&self) -> impl Iterator<Item = (T, T)> {
+        sorted_pairs(&self.left, &self.right)
+    }
+
+    /// Pairs of numbers furthest apart, see [top_differences]
+    pub fn top_differences(&self, n: usize) -> Vec<(T, T, i128)> {
+        top_differences(&self.left, &self.right, n)
+    }
+
+    /// Total distance between the lists, see [distance]
+    pub fn distance(&self) -> Result<i64> {
+        narrow(1, number_distance(&self.left, &self.right)?)
+    }
+
+    /// Similarity score of the lists, see [similarity_score]
+    pub fn similarity(&self) -> Result<i64> {
+        narrow(2, histogram_score(&self.histogram())?)
+    }
+
+    /// Both answers in one pass, see [solve]
+    pub fn solve(&self) -> Result<Answers> {
+        solve(&self.left, &self.right)
+    }
+
+    /// Count of every number in each list, see [histogram]
+    pub fn histogram(&self) -> HashMap<T, (usize, usize)> {
+        histogram(&self.left, &self.right)
+    }
+
+    /// Pairs of equal numbers the lists can form, see [common_values]
+    pub fn common_values(&self) -> usize {
+        common_values(&self.left, &self.right)
+    }
+
+    /// Numbers of each list missing from the other, see [unmatched]
+    pub fn unmatched(&self) -> Unmatched<T> {
+        unmatched(&self.left, &self.right)
+    }
+}
+
+impl<T: LocationId> FromStr for ListPair<T> {
+    type Err = Day1Error;
+
+    /// Parse the pair from the whole text, see [get_number_lists]
+    fn from_str(text: &str) -> Result<Self> {
+        ListPair::from_reader(text.as_bytes())
+    }
+}
+
+/// Both answers of the challenge, see [solve]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Answers {
+    /// Total distance between the lists, as [distance] finds it
+    pub distance: i64,
+    /// Similarity score of the lists, as [similarity_score] finds it
+    pub similarity: i64,
+}
+
+/// Find both the distance and the similarity score
+/// 
+/// The lists are sorted once and both answers are added up in a single
+/// pass over them. The distance adds up the [sorted_pairs] as [distance]
+/// does, and every left number is scored with its count in the right
+/// list, which the pass finds walking the sorted right list alongside.
+/// 
+/// # Example
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let answers = day_1::solve(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3])?;
+///     assert_eq!(answers, day_1::Answers { distance: 11, similarity: 31 });
+///     Ok(())
+/// }
+/// ```
+pub fn solve<T: LocationId>(list1: &[T], list2: &[T]) -> Result<Answers> {
+    check_lengths(list1, list2)?;
+    let (sorted_list1, sorted_list2) = sorted_lists(list1, list2);
+    let (distance, similarity) = sorted_answers(&sorted_list1, &sorted_list2)?;
+    Ok(Answers { distance: narrow(1, distance)?, similarity: narrow(2, similarity)? })
+}
+
+/// Distance and similarity score of sorted lists of the same length, in one pass
+fn sorted_answers<T: LocationId>(sorted_list1: &[T], sorted_list2: &[T]) -> Result<(i128, i128)> {
+    let (mut distance, mut similarity) = (0i128, 0i128);
+    // start of the right numbers not below the current left number, and the count equal to it
+    let (mut right, mut count) = (0, 0);
+    for (index, &num) in sorted_list1.iter().enumerate() {
+        distance = distance.checked_add(pair_distance(num, sorted_list2[index]))
+        .ok_or(Day1Error::Overflow { part: 1 })?;
+        if index == 0 || sorted_list1[index - 1] != num {
+            right += count + sorted_list2[right + count..].partition_point(|&other| other < num);
+            count = sorted_list2[right..].partition_point(|&other| other == num);
+        }
+        similarity = similarity.checked_add(value_score(num, (1, count))?)
+        .ok_or(Day1Error::Overflow { part: 2 })?;
+    }
+    Ok((distance, similarity))
+}
+
+/// Count the pairs of equal numbers the lists can form
+/// 
+/// Every number of the [histogram] pairs up as many times as
+/// the smaller of its two counts, each entry used at most once.
+/// 
+/// # Example
+/// ```
+/// // the three 3s pair up with the three 3s and the 4 with the 4
+/// assert_eq!(day_1::common_values(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 4);
+/// ```
+pub fn common_values<T: Copy + Eq + std::hash::Hash>(list1: &[T], list2: &[T]) -> usize {
+    histogram(list1, list2).into_values()
+    .map(|(left, right)| left.min(right))
+    .sum()
+}
+
+/// Numbers of each list that never appear in the other, see [unmatched]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Unmatched<T = i64> {
+    /// Numbers of the left list missing from the right one, sorted and without duplicates
+    pub left_only: Vec<T>,
+    /// Numbers of the right list missing from the left one, sorted and without duplicates
+    pub right_only: Vec<T>,
+}
+
+/// Find the numbers that appear in only one of the lists
+/// 
+/// These are the numbers of the [histogram] with a count of 0 on one
+/// side, so they add nothing to the [similarity_score].
+/// 
+/// # Example
+/// ```
+/// let unmatched = day_1::unmatched(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+/// assert_eq!(unmatched.left_only, [1, 2]);
+/// assert_eq!(unmatched.right_only, [5, 9]);
+/// ```
+pub fn unmatched<T: Copy + Ord + std::hash::Hash>(list1: &[T], list2: &[T]) -> Unmatched<T> {
+    let mut unmatched = Unmatched { left_only: Vec::new(), right_only: Vec::new() };
+    for (num, counts) in histogram(list1, list2) {
+        match counts {
+            (_, 0) => unmatched.left_only.push(num),
+            (0, _) => unmatched.right_only.push(num),
+            _ => {},
+        }
+    }
+    unmatched.left_only.sort_unstable();
+    unmatched.right_only.sort_unstable();
+    unmatched
 }
 
 /// Reads a file from a given path
@@ -119,62 +924,387 @@ pub fn distance(list1: &Vec<&str>, list2: &Vec<&str>) -> Result<i32> {
 /// use assert_fs::prelude::*;
 /// use anyhow::Result;
 /// 
-/// fn main() -> Result<()> {
-///     let file = assert_fs::NamedTempFile::new("sample.txt")?;
-///     file.write_str("Example text")?;
-///     let result = day_1::read_file(file.path())?;
-///     println!("{}", result);
+/// fn main() -> Result<()> {
+///     let file = assert_fs::NamedTempFile::new("sample.txt")?;
+///     file.write_str("Example text")?;
+///     let result = day_1::read_file(file.path())?;
+///     println!("{}", result);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "fs")]
+pub fn read_file(path: &std::path::Path) -> Result<String> {
+    let io_error = |source| Day1Error::Io { path: path.to_path_buf(), source };
+    let file = File::open(path).map_err(io_error)?;
+    let mut contents = String::new();
+    NormalizingReader::new(BufReader::new(file))
+    .read_to_string(&mut contents)
+    .map_err(io_error)?;
+
+    Ok(contents)
+}
+
+/// Reads the lists of numbers from a file at a given path
+/// 
+/// The file is read through a buffer of a few kilobytes into [read_lists],
+/// so only the parsed lists are kept in memory and never the whole text
+/// as with [read_file]. Windows line endings and a byte order mark
+/// are taken care of in the same way. A file that cannot be opened
+/// fails with [`Day1Error::Io`], and an error at a line of the file
+/// is wrapped in [`Day1Error::File`] with the path.
+/// 
+/// # Examples
+/// ```
+/// use assert_fs::prelude::*;
+/// use anyhow::Result;
+/// 
+/// fn main() -> Result<()> {
+///     let file = assert_fs::NamedTempFile::new("input.txt")?;
+///     file.write_str("3   4\r\n4   3\r\n")?;
+///     assert_eq!(day_1::read_numbers(file.path())?, (vec![3, 4], vec![4, 3]));
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "fs")]
+pub fn read_numbers<T: LocationId>(path: &std::path::Path) -> Result<(Vec<T>, Vec<T>)> {
+    read_numbers_with(path, ParseOptions::default())
+}
+
+/// Reads the lists of numbers from a file as [read_numbers], split as `options` say
+#[cfg(feature = "fs")]
+pub fn read_numbers_with<T: LocationId>(path: &std::path::Path, options: impl Into<ParseOptions>) -> Result<(Vec<T>, Vec<T>)> {
+    let file = File::open(path).map_err(|source| Day1Error::Io { path: path.to_path_buf(), source })?;
+    read_lists_with(&mut NormalizingReader::new(BufReader::new(file)), options)
+    .map_err(|source| Day1Error::File { path: path.to_path_buf(), source: Box::new(source) })
+}
+
+/// Creates the lists of numbers
+/// 
+/// Based on provided text (String) splits the numbers
+/// and puts them in 2 separate lists.
+/// Numbers may be separated by any amount of whitespace
+/// and blank or whitespace only lines, a trailing newline included,
+/// are skipped. Text of nothing but blank lines gives two empty lists,
+/// which are at a distance of 0. A line with fewer than two numbers
+/// fails with its text in the error, and the columns after the first
+/// two are dropped unless the [ParseOptions] are strict.
+/// The symbols borrow from the text, [get_number_lists] and
+/// [read_numbers] give parsed lists that own their numbers.
+/// 
+/// #Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let text = "123   45\n33\t45\n2  45\n";
+///     let (l1,l2) = day_1::get_lists(text)?;
+///     assert_eq!(l1, vec!["123", "33", "2"]);
+///     assert_eq!(l2, vec!["45", "45", "45"]);
+///     Ok(())
+/// }
+/// ```
+pub fn get_lists(text: &str) -> Result<(Vec<&str>, Vec<&str>)> {
+    get_lists_with(text, ParseOptions::default())
+}
+
+/// What separates the two columns of a line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any amount of spaces or tabs, as in the puzzle input
+    #[default]
+    Whitespace,
+    /// A comma, as in CSV exports
+    Comma,
+    /// A single tab, as in TSV exports
+    Tab,
+    /// Any other character
+    Char(char),
+}
+
+impl Delimiter {
+    /// Character splitting the columns, `None` for [`Delimiter::Whitespace`]
+    fn char(self) -> Option<char> {
+        match self {
+            Delimiter::Whitespace => None,
+            Delimiter::Comma => Some(','),
+            Delimiter::Tab => Some('\t'),
+            Delimiter::Char(c) => Some(c),
+        }
+    }
+}
+
+/// How the two columns are split from each line
+/// 
+/// The default reads the puzzle input, columns separated by whitespace
+/// and lenient about a line of more than two columns, which keeps its
+/// first two. A strict parse fails on such a line with its line number.
+/// A line with fewer than two columns fails either way.
+/// 
+/// # Examples
+/// ```
+/// use day_1::ParseOptions;
+/// 
+/// fn main() -> day_1::Result<()> {
+///     let text = "3   4\n4   3   7\n";
+///     assert_eq!(day_1::get_number_lists::<i32>(text)?, (vec![3, 4], vec![4, 3]));
+///     let e = day_1::get_number_lists_with::<i32>(text, ParseOptions::strict()).unwrap_err();
+///     assert_eq!(e.location(), Some(aoc_utils::Location::Line(2)));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// What separates the two columns
+    pub delimiter: Delimiter,
+    /// Whether a line with more than two columns fails, instead of
+    /// keeping the first two and dropping the rest
+    pub strict: bool,
+}
+
+impl ParseOptions {
+    /// Whitespace separated columns, failing on a line of more than two
+    pub fn strict() -> Self {
+        ParseOptions { strict: true, ..ParseOptions::default() }
+    }
+}
+
+impl From<Delimiter> for ParseOptions {
+    /// Columns split by `delimiter`, as lenient as the default
+    fn from(delimiter: Delimiter) -> Self {
+        ParseOptions { delimiter, ..ParseOptions::default() }
+    }
+}
+
+/// Creates the lists of numbers from columns split as `options` say
+/// 
+/// Like [get_lists], which is the case of the default [ParseOptions].
+/// A [Delimiter] alone splits as leniently as the default.
+/// Spaces around a delimiter are trimmed, quoted fields are not supported.
+/// 
+/// # Examples
+/// ```
+/// use day_1::{Delimiter, ParseOptions};
+/// 
+/// fn main() -> day_1::Result<()> {
+///     let (l1, l2) = day_1::get_lists_with("3, 4\n4 ,3\n", Delimiter::Comma)?;
+///     assert_eq!(l1, vec!["3", "4"]);
+///     assert_eq!(l2, vec!["4", "3"]);
+///     let strict = ParseOptions { delimiter: Delimiter::Comma, strict: true };
+///     assert!(day_1::get_lists_with("3,4\n4,3,7\n", strict).is_err());
+///     Ok(())
+/// }
+/// ```
+pub fn get_lists_with(text: &str, options: impl Into<ParseOptions>) -> Result<(Vec<&str>, Vec<&str>)> {
+    let options = options.into();
+    let mut lists = (Vec::new(), Vec::new());
+    for (i, line) in text.lines().enumerate() {
+        if let Some((sym1, sym2)) = split_line(line, i + 1, options)? {
+            lists.0.push(sym1);
+            lists.1.push(sym2);
+        }
+    }
+    Ok(lists)
+}
+
+/// The two columns of a numbered line, `None` for a blank line
+fn split_line(line: &str, line_number: usize, options: ParseOptions) -> Result<Option<(&str, &str)>> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    let columns = match options.delimiter.char() {
+        Some(delimiter) => first_two(line.split(delimiter).map(str::trim)),
+        None => first_two(line.split_whitespace()),
+    };
+    match columns {
+        (Some(sym1), Some(sym2), rest) if rest == 0 || !options.strict => Ok(Some((sym1, sym2))),
+        (sym1, sym2, rest) => {
+            let found = usize::from(sym1.is_some()) + usize::from(sym2.is_some()) + rest;
+            let source = ParseError::Columns { line: line_number, expected: 2, found };
+            Err(Day1Error::Split { text: line.trim().to_string(), source })
+        },
+    }
+}
+
+/// First two fields and the count of the rest, without collecting them
+fn first_two<'a>(mut fields: impl Iterator<Item = &'a str>) -> (Option<&'a str>, Option<&'a str>, usize) {
+    (fields.next(), fields.next(), fields.count())
+}
+
+/// Creates the lists of numbers, parsed
+/// 
+/// Same layout as [get_lists], but every symbol is parsed while
+/// splitting, so a symbol that is not a number fails with its line number.
+/// The lists do not borrow from the text, and their numbers may be
+/// of any [LocationId] type.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_1::Result<()> {
+///     let (l1, l2) = day_1::get_number_lists::<i32>("3   4\n10\t2\n")?;
+///     assert_eq!(l1, vec![3, 10]);
+///     assert_eq!(l2, vec![4, 2]);
+///     let e = day_1::get_number_lists::<i32>("3   4\n\n10   abc\n").unwrap_err();
+///     assert_eq!(e.to_string(), "input line 3, right column: failed parsing 'abc' to number");
 ///     Ok(())
 /// }
 /// ```
-pub fn read_file(path: &std::path::Path) -> Result<String, Error> {
-    let contents = std::fs::read_to_string(path)
-    .with_context(|| format!("could not read file {}", path.display()))?;
+pub fn get_number_lists<T: LocationId>(text: &str) -> Result<(Vec<T>, Vec<T>)> {
+    get_number_lists_with(text, ParseOptions::default())
+}
 
-    Ok(contents)
+/// Creates the lists of numbers as [get_number_lists], split as `options` say
+pub fn get_number_lists_with<T: LocationId>(text: &str, options: impl Into<ParseOptions>) -> Result<(Vec<T>, Vec<T>)> {
+    parse_number_lists(text, options.into())
 }
 
-/// Creates the lists of numbers
+/// Read the lists of numbers line by line from a [BufRead]
 /// 
-/// Based on provided text (String) splits the numbers
-/// and puts them in 2 separate lists
+/// Nothing but the current line is kept in memory, so the
+/// input may be a pipe or a file too large to read at once.
+/// Layout and errors are the same as [get_number_lists].
 /// 
-/// #Examples
+/// # Examples
 /// ```
-/// use anyhow::{Ok, Result};
+/// use std::io::{BufReader, Cursor};
 /// 
-/// fn main() -> Result<()> {
-///     let text = "123   45\n33   45\n2   45\n";
-///     let (l1,l2) = day_1::get_lists(&text)?;
-///     assert!(!l1.is_empty());
-///     assert!(!l2.is_empty());
+/// fn main() -> day_1::Result<()> {
+///     let mut reader = BufReader::new(Cursor::new("3   4\n4   3\n2   5\n"));
+///     let (l1, l2) = day_1::read_lists::<i32, _>(&mut reader)?;
+///     assert_eq!(l1, vec![3, 4, 2]);
+///     assert_eq!(l2, vec![4, 3, 5]);
 ///     Ok(())
 /// }
 /// ```
-pub fn get_lists(text: &str) -> Result<(Vec<&str>, Vec<&str>), Error> {
-    let mut list1: Vec<&str> = Vec::new();
-    let mut list2: Vec<&str> = Vec::new();
-    for line in text.lines() {
-        let mut numbers = line.split("   ");
+pub fn read_lists<T: LocationId, B: BufRead>(reader: &mut B) -> Result<(Vec<T>, Vec<T>)> {
+    read_lists_with(reader, ParseOptions::default())
+}
+
+/// Read the lists of numbers as [read_lists], split as `options` say
+pub fn read_lists_with<T: LocationId, B: BufRead>(reader: &mut B, options: impl Into<ParseOptions>) -> Result<(Vec<T>, Vec<T>)> {
+    read_number_lists(reader, options.into())
+}
+
+/// [read_lists] parsing the symbols to any number type
+fn read_number_lists<T: FromStr<Err = ParseIntError>, B: BufRead>(reader: &mut B, options: ParseOptions) -> Result<(Vec<T>, Vec<T>)> {
+    let mut lists = (Vec::new(), Vec::new());
+    // one buffer for all the lines, the line ending is left to the split
+    let mut line = String::new();
+    for line_number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => push_line(&line, line_number, options, &mut lists)?,
+            Err(source) => return Err(Day1Error::ReadLine { line: line_number, source }),
+        }
+    }
+    Ok(lists)
+}
 
-        let number1 = numbers.next()
-        .with_context(|| format!("split failed"))?;
-        
-        let number2 = numbers.next()
-        .with_context(|| format!("split failed"))?;
+/// [read_number_lists] of text already in memory, without copying its lines
+fn parse_number_lists<T: FromStr<Err = ParseIntError>>(text: &str, options: ParseOptions) -> Result<(Vec<T>, Vec<T>)> {
+    let mut lists = (Vec::new(), Vec::new());
+    for (i, line) in text.lines().enumerate() {
+        push_line(line, i + 1, options, &mut lists)?;
+    }
+    Ok(lists)
+}
 
-        list1.push(number1);
-        list2.push(number2);
+/// Parse the two numbers of a line onto the lists, a blank line is skipped
+fn push_line<T: FromStr<Err = ParseIntError>>(line: &str, line_number: usize, options: ParseOptions, lists: &mut (Vec<T>, Vec<T>)) -> Result<()> {
+    if let Some((sym1, sym2)) = split_line(line, line_number, options)? {
+        lists.0.push(parse_field(sym1, line_number, 1)?);
+        lists.1.push(parse_field(sym2, line_number, 2)?);
     }
+    Ok(())
+}
 
-    Ok((list1, list2))
+/// Parse a symbol of a numbered line to a number
+fn parse_field<T: FromStr<Err = ParseIntError>>(token: &str, line: usize, column: usize) -> Result<T> {
+    token.parse()
+    .map_err(|source| Day1Error::Number { line, column, token: token.to_string(), source })
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "fs")]
     use assert_fs::prelude::*;
     use anyhow::{Ok, Result};
-    use crate::{distance, get_lists, read_file, repetitions, similarity_score};
+    use aoc_utils::Solution;
+    use crate::*;
+
+    #[test]
+    fn test_solution_part1_sample() -> Result<()> {
+        assert_eq!(Day1.part1(SAMPLE)?, "11");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part2_sample() -> Result<()> {
+        assert_eq!(Day1.part2(SAMPLE)?, "31");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_both_parses_once() -> Result<()> {
+        PARSES.with(|parses| parses.set(0));
+        assert_eq!(Day1.solve_both(SAMPLE)?, ("11".to_string(), "31".to_string()));
+        assert_eq!(PARSES.with(|parses| parses.get()), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_try_from() -> Result<()> {
+        let input = Day1Input::try_from("10   2\n9   100000\n")?;
+        assert_eq!(input, Day1Input { left: vec![10, 9], right: vec![2, 100000] });
+        // numeric order, not the order of the text
+        assert_eq!(part1(&input)?, 7 + 99990);
+        assert!(matches!(Day1Input::try_from("1   x\n"), Err(Day1Error::Number { line: 1, column: 2, token, .. }) if token == "x"));
+        assert!(matches!(Day1Input::try_from("1\n"), Err(Day1Error::Split { .. })));
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day1Input::try_from(SAMPLE)?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"{"left":[3,4,2,1,3,3],"right":[4,3,5,3,9,3]}"#);
+        assert_eq!(serde_json::from_str::<Day1Input>(&json)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_not_supported() {
+        let e = Day1.explain(SAMPLE, "1").unwrap_err();
+        assert!(matches!(e, SolutionError::NotSupported { day: 1, hook: "explain" }));
+        assert_eq!(e.to_string(), "day 1 does not support explain");
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day1.describe(SAMPLE).problem, None);
+        assert_eq!(Day1.describe("3   4\n4   x\n").problem.as_deref(), Some("input line 2, right column: failed parsing 'x' to number"));
+        assert!(Day1.describe("3   4\n4\n").problem.is_some());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day1.part1(&input)?, SAMPLE_ANSWERS[0]);
+        assert_eq!(Day1.part2(&input)?, SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day1(seed, 1000);
+            assert_eq!(Day1.part1(&generated.input)?, generated.part1);
+            assert_eq!(Day1.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
 
     #[test]
     fn test_similarity_score_success() -> Result<()> {
@@ -194,6 +1324,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_similarity_score_duplicates() -> Result<()> {
+        // every 3 on the left counts the two 3s on the right again
+        let v1 = vec!["3", "3", "3", "7"];
+        let v2 = vec!["3", "8", "3", "5"];
+        assert_eq!(similarity_score(&v1, &v2)?, 3 * (3 * 2));
+        // numbers missing from the right list add nothing
+        assert_eq!(similarity_score(&["7", "8"], &["3", "4"])?, 0);
+        assert_eq!(similarity_score(&[], &[])?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_right_counts() {
+        let counts = right_counts(&[4, 3, 5, 3, 9, 3]);
+        assert_eq!(counts, HashMap::from([(3, 3), (4, 1), (5, 1), (9, 1)]));
+        assert!(right_counts::<i32>(&[]).is_empty());
+        // the same counts as the right side of the histogram
+        let histogram = histogram(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert!(counts.iter().all(|(num, &count)| histogram[num].1 == count));
+    }
+
+    #[test]
+    fn test_histogram() -> Result<()> {
+        let (n1, n2) = get_number_lists(SAMPLE)?;
+        let counts = histogram(&n1, &n2);
+        assert_eq!(counts, HashMap::from([(1, (1, 0)), (2, (1, 0)), (3, (3, 3)), (4, (1, 1)), (5, (0, 1)), (9, (0, 1))]));
+        assert_eq!(histogram_score(&counts)?, 31);
+        assert_eq!(ListPair::new(n1, n2)?.histogram(), counts);
+        // values present in only one list
+        assert_eq!(histogram(&[7, 7], &[8, 8]), HashMap::from([(7, (2, 0)), (8, (0, 2))]));
+        assert_eq!(histogram_score(&histogram(&[7, 7], &[8, 8]))?, 0);
+        assert!(histogram::<i32>(&[], &[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_error_keeps_day_error() {
+        let result = Day1.part1("3   4\nasd   3\n");
+        assert!(result.is_err_and(|e| matches!(e.day_error::<Day1Error>(),
+        Some(Day1Error::Number { line: 2, column: 1, token, .. }) if token == "asd")));
+    }
+
     #[test]
     fn test_repetitions_success() -> Result<()> {
         let v = vec!["3", "4", "2", "1", "3", "3"];
@@ -221,6 +1394,302 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_distance_numeric_order() -> Result<()> {
+        // sorted as text the lists would pair 10 with 1 and 9 with 99, a distance of 180
+        let v1 = vec!["9", "10", "100", "2"];
+        let v2 = vec!["3", "20", "1", "99"];
+        assert_eq!(distance(&v1, &v2)?, 1 + 6 + 10 + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_sorted() -> Result<()> {
+        let (mut n1, mut n2) = get_number_lists::<i32>(SAMPLE)?;
+        let unsorted = distance_sorted(&n1, &n2);
+        assert!(matches!(unsorted, Err(Day1Error::Unsorted { column: 1, index: 1 })));
+        assert_eq!(unsorted.unwrap_err().to_string(), "left list is not sorted, entry 1 is larger than the next one");
+        n1.sort_unstable();
+        assert!(matches!(distance_sorted(&n1, &n2), Err(Day1Error::Unsorted { column: 2, index: 0 })));
+        n2.sort_unstable();
+        assert_eq!(distance_sorted(&n1, &n2)?, 11);
+        assert!(matches!(distance_sorted(&n1, &n2[1..]), Err(Day1Error::Lengths { left: 6, right: 5 })));
+        assert_eq!(distance_sorted::<i32>(&[], &[])?, 0);
+
+        let (n1, n2) = get_number_lists::<i32>(&aoc_testing::day1(0x2024_0024, 500).input)?;
+        let (sorted1, sorted2) = (sorted_serial(&n1), sorted_serial(&n2));
+        assert_eq!(distance_sorted(&sorted1, &sorted2)?, ListPair::new(n1, n2)?.distance()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_differences() -> Result<()> {
+        let (n1, n2) = get_number_lists(SAMPLE)?;
+        let top = top_differences(&n1, &n2, 10);
+        assert_eq!(top, [(4, 9, 5), (1, 3, 2), (3, 5, 2), (2, 3, 1), (3, 4, 1), (3, 3, 0)]);
+        assert_eq!(top.iter().map(|&(_, _, difference)| difference).sum::<i128>(), i128::from(distance(&get_lists(SAMPLE)?.0, &get_lists(SAMPLE)?.1)?));
+        // ties keep the order of the pairs, the smaller numbers first
+        assert_eq!(top_differences(&[5, 1, 3], &[4, 2, 6], 2), [(1, 2, 1), (3, 4, 1)]);
+        assert_eq!(top_differences(&n1, &n2, 0), []);
+        let pair = ListPair::new(vec![i64::MIN], vec![i64::MAX])?;
+        assert_eq!(pair.top_differences(1), [(i64::MIN, i64::MAX, i128::from(u64::MAX))]);
+        assert!(matches!(pair.distance(), Err(Day1Error::Overflow { part: 1 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_signs() -> Result<()> {
+        let v1 = vec!["-5", "3", "-10", "0"];
+        let v2 = vec!["4", "-7", "2", "-1"];
+        let (n1, n2) = get_number_lists("-5   4\n3   -7\n-10   2\n0   -1\n")?;
+        assert_eq!(sorted_pairs(&n1, &n2).collect::<Vec<_>>(), [(-10, -7), (-5, -1), (0, 2), (3, 4)]);
+        assert_eq!(distance(&v1, &v2)?, 3 + 4 + 2 + 1);
+        let v1 = vec!["-3", "2", "-3"];
+        let v2 = vec!["-3", "-3", "5"];
+        assert_eq!(similarity_score(&v1, &v2)?, 2 * (-3 * 2));
+        assert_eq!(solve(&[-3, 2, -3], &[-3, -3, 5])?, Answers { distance: 3, similarity: -12 });
+        Ok(())
+    }
+
+    #[test]
+    fn test_i32_min() -> Result<()> {
+        // the absolute difference of i32::MIN and 0 does not fit i32
+        let v1 = vec!["-2147483648", "-2147483648"];
+        let v2 = vec!["0", "2147483647"];
+        let dist = -i64::from(i32::MIN) + (i64::from(i32::MAX) - i64::from(i32::MIN));
+        assert_eq!(distance(&v1, &v2)?, dist);
+        assert_eq!(similarity_score(&v1, &v1)?, 4 * i64::from(i32::MIN));
+        let pair = ListPair::new(vec![i32::MIN, i32::MIN], vec![0, i32::MAX])?;
+        assert_eq!(pair.distance()?, dist);
+        assert_eq!(pair.solve()?.distance, dist);
+        assert_eq!(Day1.part1("-2147483648   0\n-2147483648   2147483647\n")?, dist.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_keeps_inputs() -> Result<()> {
+        let v1 = vec!["3", "4", "2", "1", "3", "3"];
+        let v2 = vec!["4", "3", "5", "3", "9", "3"];
+        let (c1, c2) = (v1.clone(), v2.clone());
+        assert_eq!(distance(&v1, &v2)?, 11);
+        // the numbers are sorted in copies, the callers' lists keep their order
+        assert_eq!((v1, v2), (c1, c2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_parse_error_variant() {
+        let v1 = vec!["3", "4", "asd"];
+        let v2 = vec!["4", "3", "5"];
+        let dist = distance(&v1, &v2);
+        assert!(matches!(dist, Err(Day1Error::Parse { token, .. }) if token == "asd"));
+    }
+
+    #[test]
+    fn test_sums_wider_than_i32() -> Result<()> {
+        let v1 = vec!["2147483647", "2147483647"];
+        let v2 = vec!["-2147483648", "-2147483648"];
+        assert_eq!(distance(&v1, &v2)?, 2 * (2 * i64::from(i32::MAX) + 1));
+        assert_eq!(similarity_score(&v1, &v1)?, 2 * 2 * i64::from(i32::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generic_number_types() -> Result<()> {
+        let (l1, l2) = get_lists(SAMPLE)?;
+        assert_eq!(distance_of::<i64>(&l1, &l2)?, 11);
+        assert_eq!(distance_of::<u64>(&l1, &l2)?, 11);
+        assert_eq!(similarity_score_of::<i64>(&l1, &l2)?, 31);
+        assert_eq!(similarity_score_of::<u64>(&l1, &l2)?, 31);
+
+        let v1 = vec!["9223372036854775807", "-9223372036854775808"];
+        let v2 = vec!["-9223372036854775808", "9223372036854775807"];
+        assert_eq!(distance_of::<i64>(&v1, &v2)?, 0);
+        assert_eq!(similarity_score_of::<i64>(&v1, &v2)?, -1);
+        let v1 = vec!["18446744073709551615", "18446744073709551615"];
+        let v2 = vec!["0", "0"];
+        assert_eq!(distance_of::<u64>(&v1, &v2)?, 2 * i128::from(u64::MAX));
+        assert_eq!(similarity_score_of::<u64>(&v1, &v1)?, 4 * i128::from(u64::MAX));
+        // too wide for the i32 entry points
+        assert!(matches!(distance(&v1, &v2), Err(Day1Error::Parse { .. })));
+        // and negative numbers are not u64
+        assert!(matches!(distance_of::<u64>(&["-1"], &["1"]), Err(Day1Error::Parse { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_similarity_score_wider_than_i32() -> Result<()> {
+        // every one of the 2000 left numbers is counted 2000 times on the right
+        let list = vec!["2000000"; 2000];
+        let score = 2000 * 2_000_000 * 2000;
+        assert!(score > i64::from(i32::MAX));
+        assert_eq!(similarity_score(&list, &list)?, score);
+        let pair = ListPair::new(vec![2_000_000; 2000], vec![2_000_000; 2000])?;
+        assert_eq!(pair.similarity()?, score);
+        assert_eq!(pair.solve()?.similarity, score);
+        assert_eq!(Day1.part2(&"2000000   2000000\n".repeat(2000))?, score.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sums_overflow_i64() {
+        let sums = checked_sum(1, [i128::from(i64::MAX), 1].map(std::result::Result::Ok)).and_then(|sum| narrow(1, sum));
+        assert!(matches!(sums, Err(Day1Error::Overflow { part: 1 })));
+        assert!(matches!(narrow(2, i128::from(i64::MIN) - 1), Err(Day1Error::Overflow { part: 2 })));
+        assert!(matches!(checked_sum(2, [i128::MIN, -1].map(std::result::Result::Ok)), Err(Day1Error::Overflow { part: 2 })));
+        // a count far beyond any real list, the product no longer fits i64
+        let counts = HashMap::from([(3, (1, i64::MAX as usize / 2))]);
+        let sc = histogram_score_serial(&counts).and_then(|sc| narrow(2, sc));
+        assert!(sc.is_err_and(|e| e.to_string() == "part 2 overflowed"));
+        // nor i128 for the largest u64 number
+        let counts = HashMap::from([(u64::MAX, (2, usize::MAX))]);
+        let sc = histogram_score_serial(&counts);
+        assert!(matches!(sc, Err(Day1Error::Overflow { part: 2 })));
+    }
+
+    #[test]
+    fn test_generate_input() -> Result<()> {
+        let input = generate_input(10_000, 0x2024_0032);
+        assert_eq!(input, generate_input(10_000, 0x2024_0032));
+        assert_ne!(input, generate_input(10_000, 0x2024_0033));
+        let (l1, l2) = get_lists(&input)?;
+        assert_eq!((l1.len(), l2.len()), (10_000, 10_000));
+        assert!(l1.iter().chain(&l2).all(|sym| sym.len() == 5));
+        assert!(similarity_score(&l1, &l2)? > 0);
+        assert_eq!(generate_input(0, 1), "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_matches_parts() -> Result<()> {
+        let (l1, l2) = get_lists(SAMPLE)?;
+        let (n1, n2) = get_number_lists::<i32>(SAMPLE)?;
+        let answers = solve(&n1, &n2)?;
+        assert_eq!(answers, Answers { distance: distance(&l1, &l2)?, similarity: similarity_score(&l1, &l2)? });
+        assert_eq!(answers, Answers { distance: 11, similarity: 31 });
+
+        let text = aoc_testing::day1(7, 1000).input;
+        let (l1, l2) = get_lists(&text)?;
+        let (n1, n2) = get_number_lists::<i32>(&text)?;
+        assert_eq!(solve(&n1, &n2)?, Answers { distance: distance(&l1, &l2)?, similarity: similarity_score(&l1, &l2)? });
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_edges() -> Result<()> {
+        // repeated left numbers, right numbers missing on the left and the other way around
+        assert_eq!(solve(&[5, 1, 5, 9], &[5, 2, 5, 7])?, Answers { distance: 3, similarity: 20 });
+        // runs of equal numbers on both sides, and right numbers skipped between them
+        assert_eq!(solve(&[7, 3, 1, 3], &[3, 5, 7, 3])?, Answers { distance: 4, similarity: 19 });
+        assert_eq!(solve::<i32>(&[], &[])?, Answers::default());
+        assert_eq!(solve(&[i32::MAX, i32::MAX], &[i32::MAX, i32::MIN])?.similarity, 2 * i64::from(i32::MAX));
+        assert!(matches!(solve(&[1, 2], &[1]), Err(Day1Error::Lengths { left: 2, right: 1 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_pair_from_cursor() -> Result<()> {
+        let pair = ListPair::from_reader(Cursor::new(SAMPLE))?;
+        assert_eq!(pair, SAMPLE.parse::<ListPair>()?);
+        assert_eq!((pair.left(), pair.right()), ([3, 4, 2, 1, 3, 3].as_slice(), [4, 3, 5, 3, 9, 3].as_slice()));
+        assert_eq!((pair.len(), pair.is_empty()), (6, false));
+        assert_eq!((pair.distance()?, pair.similarity()?), (11, 31));
+        assert_eq!(pair.solve()?, Answers { distance: 11, similarity: 31 });
+        // the sides matter for the similarity score
+        let swapped = ListPair::new(pair.right().to_vec(), pair.left().to_vec())?;
+        assert_eq!(swapped.distance()?, 11);
+        assert_eq!(swapped.similarity()?, 4 + 3 * 3 + 3 * 3 + 3 * 3);
+        assert!(ListPair::<i32>::from_str("")?.is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_list_pair_from_file() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(SAMPLE)?;
+        let pair: ListPair = ListPair::from_reader(BufReader::new(File::open(file.path())?))?;
+        assert_eq!(pair, ListPair::from_str(&read_file(file.path())?)?);
+        assert_eq!(pair.solve()?, Answers { distance: 11, similarity: 31 });
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_pair_errors() {
+        assert!(matches!(ListPair::<i32>::from_str("3   4\n4   x\n"), Err(Day1Error::Number { line: 2, column: 2, .. })));
+        assert!(matches!(ListPair::<i32>::from_str("3   4\n4\n"), Err(Day1Error::Split { .. })));
+        assert!(matches!(ListPair::new(vec![1], vec![]), Err(Day1Error::Lengths { left: 1, right: 0 })));
+    }
+
+    #[test]
+    fn test_sorted_pairs() -> Result<()> {
+        let (l1, l2) = get_number_lists(SAMPLE)?;
+        let pairs: Vec<(i64, i64)> = sorted_pairs(&l1, &l2).collect();
+        assert_eq!(pairs, [(1, 3), (2, 3), (3, 3), (3, 4), (3, 5), (4, 9)]);
+        assert_eq!(ListPair::from_str(SAMPLE)?.sorted_pairs().collect::<Vec<_>>(), pairs);
+        // the distance is the sum over the same pairs
+        let (s1, s2) = get_lists(SAMPLE)?;
+        let sum: u64 = pairs.iter().map(|&(a, b)| a.abs_diff(b)).sum();
+        assert_eq!(sum, distance(&s1, &s2)?.unsigned_abs());
+        // numeric order, and the lists given are left as they are
+        assert_eq!(sorted_pairs(&[10, 9], &[100, -1]).collect::<Vec<_>>(), [(9, -1), (10, 100)]);
+        assert_eq!(sorted_pairs(&[1, 2, 3], &[4]).count(), 1);
+        assert_eq!(l1, [3, 4, 2, 1, 3, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_short_right() {
+        let v1 = vec!["3", "4", "2"];
+        let v2 = vec!["4", "3"];
+        let dist = distance(&v1, &v2);
+        assert!(matches!(dist, Err(Day1Error::Lengths { left: 3, right: 2 })));
+        let sc = similarity_score(&v1, &v2);
+        assert!(sc.is_err_and(|e| e.to_string() == "left list has 3 entries but right list has 2"));
+    }
+
+    #[test]
+    fn test_distance_short_left() {
+        let v1 = vec!["3"];
+        let v2 = vec!["4", "3", "5"];
+        let dist = distance(&v1, &v2);
+        assert!(dist.is_err_and(|e| e.to_string() == "left list has 1 entries but right list has 3"));
+        // checked before any symbol is parsed
+        let sc = similarity_score(&["x"], &v2);
+        assert!(matches!(sc, Err(Day1Error::Lengths { left: 1, right: 3 })));
+        assert_eq!(Day1Error::Lengths { left: 1, right: 3 }.kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_similarity_score_parallel_matches_serial() -> Result<()> {
+        let (n1, n2) = get_number_lists::<i32>(SAMPLE)?;
+        let counts = histogram(&n1, &n2);
+        assert_eq!(histogram_score_parallel(&counts)?, histogram_score_serial(&counts)?);
+
+        let (n1, n2) = get_number_lists::<i32>(&aoc_testing::day1(0x2024_0001, 2000).input)?;
+        let counts = histogram(&n1, &n2);
+        assert_eq!(histogram_score_parallel(&counts)?, histogram_score_serial(&counts)?);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_distance_parallel_matches_serial() -> Result<()> {
+        let mut rng = aoc_testing::Rng::new(0x2024_0017);
+        let mut numbers = |size| (0..size).map(|_| rng.next() as i32).collect::<Vec<_>>();
+        let (n1, n2) = (numbers(100_000), numbers(100_000));
+        assert_eq!(sorted_parallel(&n1), sorted_serial(&n1));
+        assert_eq!(sorted_parallel(&n2), sorted_serial(&n2));
+        let (s1, s2) = (sorted_serial(&n1), sorted_serial(&n2));
+        assert!(sorted_pairs(&n1, &n2).eq(s1.iter().copied().zip(s2.iter().copied())));
+        let serial = sorted_distance_serial(&s1, &s2)?;
+        assert_eq!(sorted_distance_parallel(&s1, &s2)?, serial);
+        assert_eq!(number_distance(&n1, &n2)?, serial);
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_success() -> Result<()> {
         let file = assert_fs::NamedTempFile::new("sample.txt")?;
@@ -231,6 +1700,16 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_file_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("sample.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        assert_eq!(read_file(file.path())?, SAMPLE);
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_negative() -> Result<()> {
         let result = read_file(std::path::Path::new("sample.txt"));
@@ -238,10 +1717,75 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_numbers_large() -> Result<()> {
+        let mut rng = aoc_testing::Rng::new(0x2024_0025);
+        let text: String = (0..400_000).map(|_| format!("{}   {}\r\n", rng.range(10000, 99999), rng.range(10000, 99999))).collect();
+        assert!(text.len() > 4 << 20);
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&text)?;
+
+        let streamed = read_numbers::<i32>(file.path())?;
+        assert_eq!(streamed, get_number_lists(&read_file(file.path())?)?);
+        let pair = ListPair::new(streamed.0, streamed.1)?;
+        let input = Day1Input::try_from(text.as_str())?;
+        assert_eq!(pair.distance()?, part1(&input)?);
+        assert_eq!(pair.similarity()?, part2(&input)?);
+        assert!(matches!(read_numbers::<i32>(std::path::Path::new("sample.txt")), Err(Day1Error::Io { .. })));
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_numbers() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(SAMPLE)?;
+        assert_eq!(read_numbers(file.path())?, get_number_lists::<i32>(SAMPLE)?);
+
+        file.write_str("3   4\n\n4   -3\n5   x7\n")?;
+        let e = read_numbers::<i32>(file.path()).unwrap_err();
+        let message = error_chain(&e);
+        assert!(message.contains(&file.path().display().to_string()), "{}", message);
+        assert!(message.contains("input line 4, right column: failed parsing 'x7' to number"), "{}", message);
+        assert!(matches!(&e, Day1Error::File { path, source } if path == file.path()
+            && matches!(**source, Day1Error::Number { line: 4, column: 2, .. })));
+        assert_eq!(e.location(), Some(Location::Field { line: 4, field: 2 }));
+        assert_eq!(e.kind(), ErrorKind::Parse);
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_owned_lists_outlive_text() -> Result<()> {
+        // the text is dropped at the end of each helper, the lists are returned
+        fn lists_of_file(path: &std::path::Path) -> Result<(Vec<i64>, Vec<i64>), Day1Error> {
+            let text = read_file(path)?;
+            get_number_lists(&text)
+        }
+        fn pair_of_file(path: &std::path::Path) -> Result<ListPair, Day1Error> {
+            read_file(path)?.parse()
+        }
+
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(SAMPLE)?;
+        let (l1, l2) = lists_of_file(file.path())?;
+        assert_eq!(solve(&l1, &l2)?, Answers { distance: 11, similarity: 31 });
+        assert_eq!(pair_of_file(file.path())?, ListPair::new(l1, l2)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_file_io_variant() {
+        let result = read_file(std::path::Path::new("sample.txt"));
+        assert!(matches!(result, Err(Day1Error::Io { path, .. }) if path == std::path::Path::new("sample.txt")));
+    }
+
     #[test]
     fn test_get_lists_success() -> Result<()> {
         let text = "123   45\n33   45\n2   45\n";
-        let (l1,l2) = get_lists(&text)?;
+        let (l1,l2) = get_lists(text)?;
         assert!(!l1.is_empty());
         assert!(!l2.is_empty());
         Ok(())
@@ -250,8 +1794,223 @@ mod tests {
     #[test]
     fn test_get_lists_negative() -> Result<()> {
         let text = "123";
-        let result = get_lists(&text);
+        let result = get_lists(text);
         assert!(result.is_err_and(|e| e.to_string().contains("split failed")));
         Ok(())
     }
+
+    #[test]
+    fn test_get_lists_whitespace() -> Result<()> {
+        let (l1, l2) = get_lists("3\t4\n\n4  3\n")?;
+        assert_eq!(l1, vec!["3", "4"]);
+        assert_eq!(l2, vec!["4", "3"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_lists_tab_separated() -> Result<()> {
+        let (l1, l2) = get_lists("3\t4\n4\t3\n")?;
+        assert_eq!((l1, l2), (vec!["3", "4"], vec!["4", "3"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_lists_double_space() -> Result<()> {
+        let (l1, l2) = get_lists("10  20\n30  40\n")?;
+        assert_eq!((l1, l2), (vec!["10", "30"], vec!["20", "40"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_lists_mixed_whitespace() -> Result<()> {
+        let (l1, l2) = get_lists("1 \t 2\n  3    4  \n5\t\t6\n")?;
+        assert_eq!((l1, l2), (vec!["1", "3", "5"], vec!["2", "4", "6"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_number_lists_success() -> Result<()> {
+        let (l1, l2) = get_number_lists::<i32>(SAMPLE)?;
+        assert_eq!(l1, vec![3, 4, 2, 1, 3, 3]);
+        assert_eq!(l2, vec![4, 3, 5, 3, 9, 3]);
+        assert_eq!(get_number_lists::<i32>("")?, (vec![], vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_number_lists_negative() {
+        let text = "3   4\n".repeat(36) + "abc   4\n";
+        let result = get_number_lists::<i32>(&text);
+        assert!(result.is_err_and(|e| e.to_string() == "input line 37, left column: failed parsing 'abc' to number"));
+        // blank lines still count towards the line number
+        let result = get_number_lists::<i32>("3   4\n\n\n4   x\n");
+        assert!(result.is_err_and(|e| e.to_string().starts_with("input line 4, right column:")));
+        let result = get_number_lists::<i32>("3   4\n4   99999999999\n");
+        assert!(matches!(&result, Err(Day1Error::Number { line: 2, column: 2, token, .. }) if token == "99999999999"));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Field { line: 2, field: 2 }));
+        assert!(matches!(get_number_lists::<i32>("3   4\n5\n"), Err(Day1Error::Split { text, .. }) if text == "5"));
+    }
+
+    #[test]
+    fn test_common_values() {
+        assert_eq!(common_values(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]), 4);
+        assert_eq!(common_values(&[1, 2, 2], &[3, 4, 4]), 0);
+        assert_eq!(common_values(&[5, 1, 5, 2], &[2, 5, 1, 5]), 4);
+        // the smaller count of each number
+        assert_eq!(common_values(&[7, 7, 7, 8], &[7, 8, 8, 8]), 2);
+        assert_eq!(common_values::<i32>(&[], &[]), 0);
+        assert_eq!(ListPair::new(vec![6, 6], vec![6, 1]).unwrap().common_values(), 1);
+    }
+
+    #[test]
+    fn test_unmatched() {
+        let sample = unmatched(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert_eq!(sample, Unmatched { left_only: vec![1, 2], right_only: vec![5, 9] });
+        // disjoint lists, duplicates listed once
+        let disjoint = unmatched(&[7, -1, 7], &[2, 8, 2]);
+        assert_eq!(disjoint, Unmatched { left_only: vec![-1, 7], right_only: vec![2, 8] });
+        // the same numbers in any order and count
+        assert_eq!(unmatched(&[1, 2, 2, 3], &[3, 1, 2, 3]), Unmatched::default());
+        assert_eq!(unmatched::<i32>(&[], &[]), Unmatched::default());
+        let pair = ListPair::new(vec![5, 6, 7], vec![7, 8, 5]).unwrap();
+        assert_eq!(pair.unmatched(), Unmatched { left_only: vec![6], right_only: vec![8] });
+    }
+
+    #[test]
+    fn test_parse_error_line_and_column() {
+        let text = "3   4\n\n".repeat(205) + "asd   4\n3   5\n";
+        let message = "input line 411, left column: failed parsing 'asd' to number";
+        assert!(get_number_lists::<i32>(&text).is_err_and(|e| e.to_string() == message));
+        assert!(Day1Input::try_from(text.as_str()).is_err_and(|e| e.to_string() == message));
+        let text = "3   4\n\n".repeat(205) + "3   5\n3   asd\n";
+        let message = "input line 412, right column: failed parsing 'asd' to number";
+        assert!(read_lists::<i32, _>(&mut Cursor::new(&text)).is_err_and(|e| e.to_string() == message));
+        assert!(Day1.part1(&text).is_err_and(|e| e.to_string().starts_with(message)));
+    }
+
+    #[test]
+    fn test_three_columns_strict() {
+        // a strict parse fails on the line of a third column, whichever parser reads it
+        let text = "3   4\n\n4   3   7\n";
+        let strict = ParseOptions::strict();
+        let three_columns = |result: std::result::Result<(), Day1Error>| matches!(result,
+            Err(Day1Error::Split { source: ParseError::Columns { line: 3, expected: 2, found: 3 }, .. }));
+        assert!(three_columns(get_lists_with(text, strict).map(drop)));
+        let csv = ParseOptions { delimiter: Delimiter::Comma, strict: true };
+        assert!(three_columns(get_lists_with(&text.replace("   ", ","), csv).map(drop)));
+        assert!(three_columns(read_lists_with::<i32, _>(&mut Cursor::new(text), strict).map(drop)));
+        assert!(three_columns(Day1Input::parse_with(text, strict).map(drop)));
+        let e = get_number_lists_with::<i32>(text, strict).unwrap_err();
+        assert_eq!(e.location(), Some(Location::Line(3)));
+        assert!(matches!(e, Day1Error::Split { text, .. } if text == "4   3   7"));
+        // the problem describe reports, even though the puzzle runner reads the lists leniently
+        assert_eq!(Day1.describe(text).problem.as_deref(), Some("expected 2 columns on line 3, found 3"));
+    }
+
+    #[test]
+    fn test_three_columns_lenient() -> Result<()> {
+        // the default keeps the first two columns of every parser
+        let text = "3   4\n\n4   3   7\n";
+        assert_eq!(get_lists(text)?, (vec!["3", "4"], vec!["4", "3"]));
+        assert_eq!(get_lists_with(&text.replace("   ", ","), Delimiter::Comma)?, (vec!["3", "4"], vec!["4", "3"]));
+        assert_eq!(get_number_lists::<i32>(text)?, (vec![3, 4], vec![4, 3]));
+        assert_eq!(read_lists::<i32, _>(&mut Cursor::new(text))?, (vec![3, 4], vec![4, 3]));
+        assert_eq!(Day1Input::try_from(text)?, Day1Input { left: vec![3, 4], right: vec![4, 3] });
+        assert_eq!(Day1.part1(text)?, "0");
+        // a missing column is an error even when lenient
+        assert!(matches!(get_number_lists::<i32>("3   4\n5\n"), Err(Day1Error::Split { text, .. }) if text == "5"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lists_success() -> Result<()> {
+        let mut reader = Cursor::new(SAMPLE);
+        assert_eq!(read_lists(&mut reader)?, (vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]));
+        let mut reader = Cursor::new("\n10\t20\n\n  30  40  \n");
+        assert_eq!(read_lists(&mut reader)?, (vec![10, 30], vec![20, 40]));
+        assert_eq!(read_lists::<i32, _>(&mut Cursor::new(""))?, (vec![], vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lists_negative() {
+        let result = read_lists::<i32, _>(&mut Cursor::new("3   4\n\n4   abc\n"));
+        assert!(result.is_err_and(|e| e.to_string() == "input line 3, right column: failed parsing 'abc' to number"));
+        let result = read_lists_with::<i32, _>(&mut Cursor::new("3   4\n1 2 3\n"), ParseOptions::strict());
+        assert!(matches!(&result, Err(Day1Error::Split { text, .. }) if text == "1 2 3"));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = read_lists::<i32, _>(&mut Cursor::new(b"3   4\n\xff   1\n".as_slice()));
+        assert!(matches!(&result, Err(Day1Error::ReadLine { line: 2, .. })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn test_trailing_newline() -> Result<()> {
+        for text in ["3   4\n4   3", "3   4\n4   3\n", "3   4\n4   3\n\n\n", "3   4\r\n4   3\r\n"] {
+            assert_eq!(get_lists(text)?, (vec!["3", "4"], vec!["4", "3"]), "{:?}", text);
+            assert_eq!(read_lists(&mut Cursor::new(text))?, (vec![3, 4], vec![4, 3]), "{:?}", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_blank_line_in_middle() -> Result<()> {
+        let text = "3   4\n\n4   3\n \t \n2   5\n";
+        let (l1, l2) = get_lists(text)?;
+        assert_eq!(distance(&l1, &l2)?, 3);
+        let pair = ListPair::from_str(text)?;
+        assert_eq!((pair.left(), pair.right()), ([3, 4, 2].as_slice(), [4, 3, 5].as_slice()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_only_blank_lines() -> Result<()> {
+        let text = "\n  \n\t\n\n";
+        let (l1, l2) = get_lists(text)?;
+        assert!(l1.is_empty() && l2.is_empty());
+        assert_eq!(distance(&l1, &l2)?, 0);
+        assert_eq!(similarity_score(&l1, &l2)?, 0);
+        let pair: ListPair = ListPair::from_reader(Cursor::new(text))?;
+        assert!(pair.is_empty());
+        assert_eq!(pair.solve()?, Answers::default());
+        assert_eq!(Day1.solve_both(text)?, ("0".to_string(), "0".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_lists_with_delimiters() -> Result<()> {
+        let answers = |(l1, l2): (Vec<&str>, Vec<&str>)| -> Result<(i64, i64)> {
+            Ok((distance(&l1, &l2)?, similarity_score(&l1, &l2)?))
+        };
+        let expected = answers(get_lists_with(SAMPLE, Delimiter::Whitespace)?)?;
+        assert_eq!(expected, (11, 31));
+        let csv = "3,4\n4, 3\n2 ,5\n\n1 , 3\n3,9\n3,3\n";
+        assert_eq!(answers(get_lists_with(csv, Delimiter::Comma)?)?, expected);
+        let tsv = SAMPLE.replace("   ", "\t");
+        assert_eq!(answers(get_lists_with(&tsv, Delimiter::Tab)?)?, expected);
+        let custom = SAMPLE.replace("   ", " ; ");
+        assert_eq!(answers(get_lists_with(&custom, Delimiter::Char(';'))?)?, expected);
+        assert_eq!(Delimiter::default(), Delimiter::Whitespace);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_lists_with_errors() {
+        let result = get_lists_with("3,4\n4,3,5\n", ParseOptions { delimiter: Delimiter::Comma, strict: true });
+        assert!(matches!(&result, Err(Day1Error::Split { text, .. }) if text == "4,3,5"));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        // whitespace is not a column separator for the other delimiters
+        assert!(matches!(get_lists_with("3   4\n", Delimiter::Comma), Err(Day1Error::Split { .. })));
+        let (l1, l2) = get_lists_with("3,\n", Delimiter::Comma).unwrap();
+        assert!(distance(&l1, &l2).is_err_and(|e| e.to_string() == "failed parsing  to number"));
+    }
+
+    #[test]
+    fn test_get_lists_split_variant() {
+        assert!(matches!(get_lists("123"), Err(Day1Error::Split { text, .. }) if text == "123"));
+        let result = get_lists_with("3   4\n\n4 3 5\n", ParseOptions::strict());
+        assert!(matches!(&result, Err(Day1Error::Split { text, .. }) if text == "4 3 5"));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(3)));
+        assert!(get_lists("3   4\n7\n").is_err_and(|e| e.to_string() == "split failed on 7"));
+    }
 }
\ No newline at end of file
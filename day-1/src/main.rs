@@ -1,19 +1,109 @@
-use anyhow::Result;
+use std::{process::ExitCode, time::Instant};
+use aoc_prelude::*;
+use clap::{Parser, ValueEnum};
 
-fn main() -> Result<()> {
+#[derive(Parser)]
+#[command(name = "day-1", about = "Advent of Code 2024 day 1, distance and similarity score of two lists")]
+struct Cli {
+    /// Input file, `-` reads the standard input
+    #[arg(long, default_value = "./data/input.txt")]
+    input: InputSource,
+    /// Part to print the answer of, both print one answer per line
+    #[arg(long, value_enum, default_value_t)]
+    part: Part,
+    /// Also print how many numbers of each list have no match in the other, to stderr
+    #[arg(long)]
+    verbose: bool,
+    /// Print the answers and the time taken as one line of JSON
+    #[arg(long)]
+    json: bool,
+    /// Also print the N pairs of the distance furthest apart, after the answers
+    #[arg(long, value_name = "N")]
+    top: Option<usize>,
+}
 
-    let input_path = std::path::Path::new("./data/input.txt");
+/// Line printed with `--json`, a part that was not asked for is left out
+#[derive(serde::Serialize)]
+struct JsonOutput {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<i64>,
+    elapsed_ms: f64,
+    /// Left number, right number and their difference, with `--top`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_differences: Option<Vec<(i64, i64, i128)>>,
+}
 
-    let content = day_1::read_file(input_path)?;
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Part {
+    /// Total distance between the lists
+    #[value(name = "1")]
+    One,
+    /// Similarity score of the lists
+    #[value(name = "2")]
+    Two,
+    /// Both answers, the distance first
+    #[default]
+    Both,
+}
 
-    let (l1,l2) = day_1::get_lists(&content)?;
+fn run(cli: Cli) -> Result<()> {
+    let start = Instant::now();
+    let lists: day_1::ListPair = match &cli.input {
+        // a file is read through read_numbers, so its errors name the path
+        InputSource::Path(path) => {
+            let (left, right) = day_1::read_numbers(path)?;
+            day_1::ListPair::new(left, right)?
+        },
+        input => day_1::ListPair::from_reader(input.reader()?)?,
+    };
+    // nothing piped in is a mistake more often than an empty puzzle
+    if lists.is_empty() && matches!(cli.input, InputSource::Stdin) {
+        let source = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "no lists were piped in");
+        return Err(InputError::Io { input: cli.input.to_string(), source }.into());
+    }
+    if cli.verbose {
+        let unmatched = lists.unmatched();
+        eprintln!("{} left values have no match", unmatched.left_only.len());
+        eprintln!("{} right values have no match", unmatched.right_only.len());
+        eprintln!("{} pairs of equal values", lists.common_values());
+    }
 
-    let dist = day_1::distance(&l1, &l2)?;
+    let (part1, part2) = match cli.part {
+        Part::One => (Some(lists.distance()?), None),
+        Part::Two => (None, Some(lists.similarity()?)),
+        Part::Both => {
+            let answers = lists.solve()?;
+            (Some(answers.distance), Some(answers.similarity))
+        },
+    };
 
-    let sc = day_1::similarity_score(&l1, &l2)?;
+    let top_differences = cli.top.map(|n| lists.top_differences(n));
 
-    println!("Lists path: {}\nDistance: {}\nSimilarity score: {}", 
-    input_path.display(), dist, sc);
+    if cli.json {
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        let output = JsonOutput { day: 1, part1, part2, elapsed_ms, top_differences };
+        println!("{}", serde_json::to_string(&output)?);
+    } else {
+        for answer in [part1, part2].into_iter().flatten() {
+            println!("{}", answer);
+        }
+        for (left, right, difference) in top_differences.into_iter().flatten() {
+            println!("{}   {}   differ by {}", left, right, difference);
+        }
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_1::Day1Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,22 @@
+//! Upper bounds on the allocations of the solves, a return to copying
+//! every location ID doubles the counts and fails these tests
+
+use aoc_prelude::Solution;
+use aoc_testing::{AllocationGuard, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_allocations_1000_lines() -> anyhow::Result<()> {
+    // part 2 counts the repetitions of every ID in the whole list, more lines get slow
+    let input = aoc_testing::day1(7, 1_000).input;
+    let guard = AllocationGuard::new();
+    day_1::Day1.part1(&input)?;
+    assert!(guard.allocations() < 1_200, "part 1 allocated {} times", guard.allocations());
+
+    let guard = AllocationGuard::new();
+    day_1::Day1.part2(&input)?;
+    assert!(guard.allocations() < 1_200, "part 2 allocated {} times", guard.allocations());
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use assert_cmd::Command;
+use assert_fs::prelude::*;
+
+/// Sample input written to a temporary file
+fn sample_file() -> assert_fs::NamedTempFile {
+    let file = assert_fs::NamedTempFile::new("input.txt").unwrap();
+    file.write_str(day_1::SAMPLE).unwrap();
+    file
+}
+
+#[test]
+fn test_cli_parts() {
+    let file = sample_file();
+    let day_1 = || Command::cargo_bin("day-1").unwrap();
+    day_1().arg("--input").arg(file.path()).assert().success().stdout("11\n31\n");
+    day_1().arg("--input").arg(file.path()).args(["--part", "both"]).assert().success().stdout("11\n31\n");
+    day_1().arg("--input").arg(file.path()).args(["--part", "1"]).assert().success().stdout("11\n");
+    day_1().arg("--input").arg(file.path()).args(["--part", "2"]).assert().success().stdout("31\n");
+}
+
+#[test]
+fn test_cli_verbose() {
+    let file = sample_file();
+    Command::cargo_bin("day-1").unwrap().arg("--input").arg(file.path()).args(["--part", "2", "--verbose"])
+    .assert().success().stdout("31\n").stderr("2 left values have no match\n2 right values have no match\n4 pairs of equal values\n");
+}
+
+#[test]
+fn test_cli_json() {
+    let file = sample_file();
+    let day_1 = || Command::cargo_bin("day-1").unwrap();
+    let output = day_1().arg("--input").arg(file.path()).arg("--json").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1, "{}", stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["day"], 1);
+    assert_eq!(json["part1"], 11);
+    assert_eq!(json["part2"], 31);
+    assert!(json["elapsed_ms"].as_f64().is_some_and(|ms| ms >= 0.0), "{}", json);
+
+    let output = day_1().arg("--input").arg(file.path()).args(["--json", "--part", "2"]).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["part2"], 31);
+    assert!(json.get("part1").is_none(), "{}", json);
+}
+
+#[test]
+fn test_cli_top() {
+    let file = sample_file();
+    let day_1 = || Command::cargo_bin("day-1").unwrap();
+    day_1().arg("--input").arg(file.path()).args(["--part", "1", "--top", "2"])
+    .assert().success().stdout("11\n4   9   differ by 5\n1   3   differ by 2\n");
+    let output = day_1().arg("--input").arg(file.path()).args(["--json", "--top", "1"]).output().unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["top_differences"], serde_json::json!([[4, 9, 5]]));
+}
+
+#[test]
+fn test_cli_missing_input() {
+    let dir = assert_fs::TempDir::new().unwrap();
+    let missing = dir.child("missing.txt");
+    let output = Command::cargo_bin("day-1").unwrap().arg("--input").arg(missing.path()).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("missing.txt"), "{}", stderr);
+}
+
+#[test]
+fn test_cli_bad_line_in_file() {
+    let file = assert_fs::NamedTempFile::new("input.txt").unwrap();
+    file.write_str("3   4\n4   x\n").unwrap();
+    let output = Command::cargo_bin("day-1").unwrap().arg("--input").arg(file.path()).output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(&file.path().display().to_string()) && stderr.contains("line 2"), "{}", stderr);
+}
+
+#[test]
+fn test_cli_default_input() {
+    // without --input the binary reads data/input.txt of the directory it runs in
+    let dir = assert_fs::TempDir::new().unwrap();
+    dir.child("data/input.txt").write_str(day_1::SAMPLE).unwrap();
+    Command::cargo_bin("day-1").unwrap().current_dir(dir.path()).args(["--part", "1"]).assert().success().stdout("11\n");
+}
+
+#[test]
+fn test_cli_stdin() {
+    let day_1 = || Command::cargo_bin("day-1").unwrap();
+    day_1().args(["--input", "-"]).write_stdin(day_1::SAMPLE).assert().success().stdout("11\n31\n");
+    let output = day_1().args(["--input", "-"]).write_stdin("\n\n").output().unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("could not read standard input") && stderr.contains("no lists were piped in"), "{}", stderr);
+}
+
+#[test]
+fn test_cli_bad_part() {
+    let file = sample_file();
+    Command::cargo_bin("day-1").unwrap().arg("--input").arg(file.path()).args(["--part", "3"])
+    .assert().failure().stdout("");
+}
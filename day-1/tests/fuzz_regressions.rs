@@ -0,0 +1,15 @@
+//! Replays the crashers saved from the `day_1` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_1", |data| {
+        let Ok(text) = std::str::from_utf8(data) else {
+            return;
+        };
+        if let Ok((list1, list2)) = day_1::get_lists(text) {
+            let _ = day_1::distance(&list1, &list2);
+            let _ = day_1::similarity_score(&list1, &list2);
+        }
+        let _ = day_1::get_number_lists::<i32>(text);
+    });
+}
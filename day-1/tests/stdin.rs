@@ -0,0 +1,42 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-1"))
+    .args(["--input", "-"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-1");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_11.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-1");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "11\n31\n");
+}
+
+/// Exit code of day-1 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-1"))
+    .args(["--input", "-"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-1");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-1").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("3   4\n"), Some(0));
+    assert_eq!(exit_code("3   x\n"), Some(3));
+    assert_eq!(exit_code("-9223372036854775808   1\n"), Some(4));
+    let status = Command::new(env!("CARGO_BIN_EXE_day-1"))
+    .args(["--input", "missing/input.txt"])
+    .stderr(Stdio::null())
+    .status()
+    .expect("failed to start day-1");
+    assert_eq!(status.code(), Some(2));
+}
@@ -0,0 +1,371 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 10 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 10 functions
+#[derive(Debug, Error)]
+pub enum Day10Error {
+    /// Lines of the map have different lengths
+    #[error("malformed topographic map")]
+    Grid(#[from] GridError),
+    /// A cell is neither a height nor impassable
+    #[error("unexpected {cell} on line {line}, column {column}")]
+    UnknownCell {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        cell: char,
+    },
+}
+
+impl From<Day10Error> for SolutionError {
+    fn from(e: Day10Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day10Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day10Error::Grid(e) => e.location(),
+            Day10Error::UnknownCell { line, .. } => Some(Location::Line(*line)),
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day10Error::Grid(_) | Day10Error::UnknownCell { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 10 functions
+pub type Result<T, E = Day10Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "89010123\n78121874\n87430965\n96549874\n45678903\n32019012\n01329801\n10456732\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["36", "81"];
+
+/// Height of a trail end
+const TOP: u8 = 9;
+
+/// Topographic map of the lava production facility, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_10::Result<()> {
+///     let map = day_10::parse_map("0123\n.654\n")?;
+///     assert_eq!(map.heights[(0, 3)], Some(3));
+///     assert_eq!(map.heights[(1, 0)], None);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TopoMap {
+    /// Height of every position, `None` for the impassable `.` of the smaller examples
+    pub heights: Grid<Option<u8>>,
+}
+
+impl TopoMap {
+    /// Positions with height 0, where the trails start
+    fn trailheads(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.heights.iter().filter(|(_, &height)| height == Some(0)).map(|(pos, _)| pos)
+    }
+
+    /// Neighbours of `pos` exactly one higher
+    fn uphill(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let next = self.heights[pos].map(|height| height + 1);
+        self.heights.neighbors4(pos).filter(move |&n| next.is_some() && self.heights[n] == next)
+    }
+}
+
+impl TryFrom<&str> for TopoMap {
+    type Error = Day10Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let grid: Grid<char> = text.parse()?;
+        if let Some(((row, col), &cell)) = grid.iter().find(|(_, &c)| c != '.' && !c.is_ascii_digit()) {
+            return Err(Day10Error::UnknownCell { line: row + 1, column: col + 1, cell });
+        }
+        Ok(TopoMap { heights: grid.map(|c| c.to_digit(10).map(|height| height as u8)) })
+    }
+}
+
+/// Parse the topographic map from text
+/// 
+/// Every cell is a height from `0` to `9`, or `.` for a position no trail goes through
+/// 
+/// # Examples
+/// ```
+/// let map = day_10::parse_map(day_10::SAMPLE).unwrap();
+/// assert_eq!((map.heights.width(), map.heights.height()), (8, 8));
+/// assert!(matches!(day_10::parse_map("01\n2x\n"), Err(day_10::Day10Error::UnknownCell { line: 2, column: 2, cell: 'x' })));
+/// ```
+pub fn parse_map(text: &str) -> Result<TopoMap> {
+    TopoMap::try_from(text)
+}
+
+/// Number of `9`s reachable from `trailhead` by climbing one height at a time
+fn score(map: &TopoMap, trailhead: Pos) -> usize {
+    let mut seen = HashSet::from([trailhead]);
+    let mut stack = vec![trailhead];
+    let mut tops = 0;
+    while let Some(pos) = stack.pop() {
+        if map.heights[pos] == Some(TOP) {
+            tops += 1;
+            continue;
+        }
+        for next in map.uphill(pos) {
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    tops
+}
+
+/// Sum of the trailhead scores, the number of `9`s every trailhead reaches, the answer of part 1
+/// 
+/// A trail climbs exactly one height with every step up, down, left or right
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_10::Result<()> {
+///     let map = day_10::parse_map(day_10::SAMPLE)?;
+///     assert_eq!(day_10::trailhead_scores(&map), 36);
+///     Ok(())
+/// }
+/// ```
+pub fn trailhead_scores(map: &TopoMap) -> usize {
+    map.trailheads().map(|trailhead| score(map, trailhead)).sum()
+}
+
+/// Number of distinct trails from `pos` up to a `9`, remembered in `memo`
+/// 
+/// Trails are at most ten positions long, so the recursion stays shallow
+fn rating(map: &TopoMap, pos: Pos, memo: &mut Grid<Option<usize>>) -> usize {
+    if let Some(trails) = memo[pos] {
+        return trails;
+    }
+    let trails = match map.heights[pos] {
+        Some(TOP) => 1,
+        _ => map.uphill(pos).collect::<Vec<_>>().into_iter().map(|next| rating(map, next, memo)).sum(),
+    };
+    memo[pos] = Some(trails);
+    trails
+}
+
+/// Sum of the trailhead ratings, the number of distinct trails from every trailhead, the answer of part 2
+/// 
+/// The trails from every position are counted once and shared by all the
+/// trailheads below it
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_10::Result<()> {
+///     let map = day_10::parse_map(day_10::SAMPLE)?;
+///     assert_eq!(day_10::trailhead_ratings(&map), 81);
+///     Ok(())
+/// }
+/// ```
+pub fn trailhead_ratings(map: &TopoMap) -> usize {
+    let mut memo = Grid::new(map.heights.height(), map.heights.width(), None);
+    map.trailheads().map(|trailhead| rating(map, trailhead, &mut memo)).sum()
+}
+
+/// Day 10 solution used by the `aoc` runner
+/// 
+/// Part 1 sums the trailhead scores of the [`TopoMap`],
+/// part 2 the trailhead ratings
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_10::Day10.part1(day_10::SAMPLE)?, "36");
+///     assert_eq!(day_10::Day10.part2(day_10::SAMPLE)?, "81");
+///     Ok(())
+/// }
+/// ```
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(trailhead_scores(&TopoMap::try_from(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(trailhead_ratings(&TopoMap::try_from(input)?).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let map = TopoMap::try_from(input)?;
+        Ok((map.solve(1)?, map.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(TopoMap::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for TopoMap {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(trailhead_scores(self).to_string()),
+            _ => Ok(trailhead_ratings(self).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a topographic map
+fn format_problem(input: &str) -> Option<String> {
+    parse_map(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 10 input with a `size` x `size` topographic map, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_10::generate(50, 7);
+/// assert_eq!(input.lines().count(), 50);
+/// assert_eq!(day_10::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day10(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_trailhead_scores_sample() -> Result<()> {
+        let map = parse_map(SAMPLE)?;
+        assert_eq!(trailhead_scores(&map), 36);
+        // the nine trailheads in reading order
+        let scores: Vec<usize> = map.trailheads().map(|trailhead| score(&map, trailhead)).collect();
+        assert_eq!(scores, vec![5, 6, 5, 3, 1, 3, 5, 3, 5]);
+        assert_eq!(Day10.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailhead_scores_unreachable_top() -> Result<()> {
+        // the trail forks at 3 and reaches a 9 on both sides
+        let map = parse_map("...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9\n")?;
+        assert_eq!(trailhead_scores(&map), 2);
+        // the 9 in the corner is cut off by the impassable tiles
+        let map = parse_map("0123\n.654\n.789\n9...\n")?;
+        assert_eq!(trailhead_scores(&map), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailhead_scores_shared_top() -> Result<()> {
+        // both trailheads reach the lower 9, each of them scores it once
+        let map = parse_map("10..9..\n2...8..\n3...7..\n4567654\n...8..3\n...9..2\n.....01\n")?;
+        let scores: Vec<usize> = map.trailheads().map(|trailhead| score(&map, trailhead)).collect();
+        assert_eq!(scores, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailhead_ratings_sample() -> Result<()> {
+        let map = parse_map(SAMPLE)?;
+        assert_eq!(trailhead_ratings(&map), 81);
+        assert_eq!(Day10.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day10.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailhead_ratings_examples() -> Result<()> {
+        let map = parse_map(".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....\n")?;
+        assert_eq!(trailhead_ratings(&map), 3);
+        let map = parse_map("012345\n123456\n234567\n345678\n4.6789\n56789.\n")?;
+        assert_eq!(trailhead_ratings(&map), 227);
+        // the single trailhead reaches only two 9s along all those trails
+        assert_eq!(trailhead_scores(&map), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_map_errors() {
+        let result = parse_map("012\n34\n");
+        assert!(matches!(&result, Err(Day10Error::Grid(GridError::Ragged { row: 1, .. }))));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_map("012\n3-5\n");
+        assert!(matches!(&result, Err(Day10Error::UnknownCell { line: 2, column: 2, cell: '-' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_map_serde_round_trip() -> Result<()> {
+        let map = parse_map("0.\n12\n")?;
+        let json = serde_json::to_string(&map)?;
+        assert_eq!(json, r#"{"heights":[[0,null],[1,2]]}"#);
+        assert_eq!(serde_json::from_str::<TopoMap>(&json)?, map);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day10.describe(SAMPLE).problem, None);
+        assert_eq!(Day10.describe("012\n3-5\n").problem.as_deref(), Some("unexpected - on line 2, column 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day10.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day10(seed, 40);
+            assert_eq!(Day10.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let map = day_10::parse_map(&input.read_to_string()?)?;
+
+    println!("TRAILHEAD_SCORES: {}", day_10::trailhead_scores(&map));
+    println!("TRAILHEAD_RATINGS: {}", day_10::trailhead_ratings(&map));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_10::Day10Error::kind)).into()
+        },
+    }
+}
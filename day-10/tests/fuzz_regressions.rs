@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_10` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_10", |data| {
+        if let Ok(map) = day_10::parse_map(&String::from_utf8_lossy(data)) {
+            let _ = day_10::trailhead_scores(&map);
+            let _ = day_10::trailhead_ratings(&map);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-10"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-10");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_36.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-10");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "TRAILHEAD_SCORES: 36\nTRAILHEAD_RATINGS: 81\n");
+}
+
+/// Exit code of day-10 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-10"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-10");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-10").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("01\n32\n"), Some(0));
+    assert_eq!(exit_code("01\n3x\n"), Some(3));
+}
@@ -0,0 +1,398 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 11 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 11 functions
+#[derive(Debug, Error)]
+pub enum Day11Error {
+    /// A stone is not engraved with a number that fits `u64`
+    #[error("failed parsing stone {token}")]
+    Number {
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// An engraved number or the number of stones does not fit `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day11Error> for SolutionError {
+    fn from(e: Day11Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day11Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day11Error::Number { token, .. } => Some(Location::Token(token.clone())),
+            Day11Error::Overflow { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day11Error::Number { .. } => ErrorKind::Parse,
+            Day11Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 11 functions
+pub type Result<T, E = Day11Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "125 17\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["55312", "65601038650482"];
+
+/// Numbers engraved on the stones in a line, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_11::Result<()> {
+///     let stones = day_11::Stones::try_from("0 1 10 99 999")?;
+///     assert_eq!(stones.0, vec![0, 1, 10, 99, 999]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stones(pub Vec<u64>);
+
+impl TryFrom<&str> for Stones {
+    type Error = Day11Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        text.split_whitespace()
+        .map(|token| token.parse().map_err(|source| Day11Error::Number { token: token.to_string(), source }))
+        .collect::<Result<Vec<u64>>>()
+        .map(Stones)
+    }
+}
+
+/// Parse the whitespace separated numbers engraved on the stones
+/// 
+/// # Examples
+/// ```
+/// let stones = day_11::parse_stones(day_11::SAMPLE).unwrap();
+/// assert_eq!(stones.0, vec![125, 17]);
+/// assert!(matches!(day_11::parse_stones("125 x"), Err(day_11::Day11Error::Number { .. })));
+/// ```
+pub fn parse_stones(text: &str) -> Result<Stones> {
+    Stones::try_from(text)
+}
+
+/// Stones a single stone turns into with a blink, `None` when the new number does not fit `u64`
+/// 
+/// A `0` becomes a `1`, a number with an even count of digits splits into
+/// its left and right halves and any other number is multiplied by 2024
+fn blink(stone: u64) -> Option<(u64, Option<u64>)> {
+    if stone == 0 {
+        return Some((1, None));
+    }
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+        return Some((stone / half, Some(stone % half)));
+    }
+    stone.checked_mul(2024).map(|stone| (stone, None))
+}
+
+/// Number of stones after `blinks` blinks, `None` when a number does not fit `u64`
+/// 
+/// Stones with the same number change the same way and the order of the
+/// stones never matters, so only the count of every number is kept
+fn try_stones_after(initial: &[u64], blinks: usize) -> Option<u64> {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &stone in initial {
+        *counts.entry(stone).or_default() += 1;
+    }
+    for _ in 0..blinks {
+        let mut next: HashMap<u64, u64> = HashMap::with_capacity(counts.len());
+        for (stone, count) in counts {
+            let (left, right) = blink(stone)?;
+            for stone in std::iter::once(left).chain(right) {
+                let total = next.entry(stone).or_default();
+                *total = total.checked_add(count)?;
+            }
+        }
+        counts = next;
+    }
+    counts.values().try_fold(0u64, |sum, &count| sum.checked_add(count))
+}
+
+/// Number of stones after `blinks` blinks
+/// 
+/// Keeps the count of every engraved number instead of the stones
+/// themselves, a few thousand distinct numbers even after 75 blinks.
+/// Saturates at [`u64::MAX`], [`part1`] and [`part2`] report the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(day_11::stones_after(&[125, 17], 6), 22);
+/// assert_eq!(day_11::stones_after(&[125, 17], 25), 55312);
+/// ```
+pub fn stones_after(initial: &[u64], blinks: usize) -> u64 {
+    try_stones_after(initial, blinks).unwrap_or(u64::MAX)
+}
+
+/// [`stones_after`] keeping every stone in a vector that grows with every blink
+/// 
+/// The stones grow exponentially, it runs out of memory around 40 blinks
+/// and is kept to check [`stones_after`]
+/// 
+/// # Panics
+/// 
+/// When an engraved number does not fit `u64`
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(day_11::stones_after_naive(&[125, 17], 6), 22);
+/// ```
+pub fn stones_after_naive(initial: &[u64], blinks: usize) -> u64 {
+    let mut stones = initial.to_vec();
+    for _ in 0..blinks {
+        stones = stones.into_iter()
+        .flat_map(|stone| {
+            let (left, right) = blink(stone).expect("engraved number fits u64");
+            std::iter::once(left).chain(right)
+        })
+        .collect();
+    }
+    stones.len() as u64
+}
+
+/// Number of stones after 25 blinks, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_11::Result<()> {
+///     let stones = day_11::parse_stones(day_11::SAMPLE)?;
+///     assert_eq!(day_11::part1(&stones)?, 55312);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(stones: &Stones) -> Result<u64> {
+    try_stones_after(&stones.0, 25).ok_or(Day11Error::Overflow { part: 1 })
+}
+
+/// Number of stones after 75 blinks, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_11::Result<()> {
+///     let stones = day_11::parse_stones(day_11::SAMPLE)?;
+///     assert_eq!(day_11::part2(&stones)?, 65601038650482);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(stones: &Stones) -> Result<u64> {
+    try_stones_after(&stones.0, 75).ok_or(Day11Error::Overflow { part: 2 })
+}
+
+/// Day 11 solution used by the `aoc` runner
+/// 
+/// Part 1 counts the [`Stones`] after 25 blinks,
+/// part 2 after 75 blinks
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_11::Day11.part1(day_11::SAMPLE)?, "55312");
+///     assert_eq!(day_11::Day11.part2(day_11::SAMPLE)?, "65601038650482");
+///     Ok(())
+/// }
+/// ```
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Stones::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Stones::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let stones = Stones::try_from(input)?;
+        Ok((stones.solve(1)?, stones.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Stones::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Stones {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a line of stones
+fn format_problem(input: &str) -> Option<String> {
+    parse_stones(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 11 input with `size` stones, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_11::generate(50, 7);
+/// assert_eq!(input.split_whitespace().count(), 50);
+/// assert_eq!(day_11::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day11(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_blink_rules() {
+        assert_eq!(blink(0), Some((1, None)));
+        assert_eq!(blink(1), Some((2024, None)));
+        assert_eq!(blink(10), Some((1, Some(0))));
+        // the right half drops its leading zeros
+        assert_eq!(blink(1000), Some((10, Some(0))));
+        assert_eq!(blink(999), Some((2021976, None)));
+        assert_eq!(blink(u64::MAX / 1000), None);
+    }
+
+    #[test]
+    fn test_stones_after_sample() -> Result<()> {
+        let stones = parse_stones(SAMPLE)?;
+        let counts: Vec<u64> = (0..=6).map(|blinks| stones_after(&stones.0, blinks)).collect();
+        assert_eq!(counts, vec![2, 3, 4, 5, 9, 13, 22]);
+        assert_eq!(stones_after(&stones.0, 25), 55312);
+        assert_eq!(stones_after_naive(&stones.0, 25), 55312);
+        assert_eq!(Day11.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stones_after_75_blinks() -> Result<()> {
+        assert_eq!(Day11.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day11.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stones_after_edges() {
+        assert_eq!(stones_after(&[], 75), 0);
+        assert_eq!(stones_after(&[0, 0, 0], 0), 3);
+        // the same number twice counts both stones
+        assert_eq!(stones_after(&[17, 17], 25), 2 * stones_after(&[17], 25));
+    }
+
+    #[test]
+    fn test_stones_after_overflow() {
+        // 19 digits multiplied by 2024 do not fit u64
+        let stones = Stones(vec![1_000_000_000_000_000_000]);
+        assert_eq!(stones_after(&stones.0, 1), u64::MAX);
+        assert!(matches!(part1(&stones), Err(Day11Error::Overflow { part: 1 })));
+        assert_eq!(Day11Error::Overflow { part: 1 }.kind(), ErrorKind::Solver);
+    }
+
+    #[test]
+    fn test_parse_stones_errors() {
+        let result = parse_stones("125 -17\n");
+        assert!(matches!(&result, Err(Day11Error::Number { token, .. }) if token == "-17"));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Token("-17".to_string())));
+        let result = parse_stones("18446744073709551616");
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_stones_serde_round_trip() -> Result<()> {
+        let stones = parse_stones(SAMPLE)?;
+        let json = serde_json::to_string(&stones)?;
+        assert_eq!(json, "[125,17]");
+        assert_eq!(serde_json::from_str::<Stones>(&json)?, stones);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day11.describe(SAMPLE).problem, None);
+        assert_eq!(Day11.describe("125 x\n").problem.as_deref(), Some("failed parsing stone x: invalid digit found in string"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day11.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day11(seed, 100);
+            assert_eq!(Day11.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_stones_after_matches_naive(initial in proptest::collection::vec(0u64..10_000, 1..4), blinks in 0usize..=25) {
+            proptest::prop_assert_eq!(stones_after(&initial, blinks), stones_after_naive(&initial, blinks));
+        }
+
+        #[test]
+        fn test_stones_after_matches_oracle(initial in proptest::collection::vec(0u64..1_000_000_000, 1..3), blinks in 0usize..=40) {
+            // the numbers of stones below a billion never grow past u64, the oracle does not check for it
+            proptest::prop_assert_eq!(stones_after(&initial, blinks), aoc_testing::oracle::stones_after(&initial, blinks));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let stones = day_11::parse_stones(&input.read_to_string()?)?;
+
+    println!("STONES_AFTER_25: {}", day_11::part1(&stones)?);
+    println!("STONES_AFTER_75: {}", day_11::part2(&stones)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_11::Day11Error::kind)).into()
+        },
+    }
+}
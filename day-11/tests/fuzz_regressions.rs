@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_11` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_11", |data| {
+        if let Ok(stones) = day_11::parse_stones(&String::from_utf8_lossy(data)) {
+            let _ = day_11::part1(&stones);
+            let _ = day_11::part2(&stones);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-11"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-11");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_55312.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-11");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "STONES_AFTER_25: 55312\nSTONES_AFTER_75: 65601038650482\n");
+}
+
+/// Exit code of day-11 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-11"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-11");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-11").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("0 1\n"), Some(0));
+    assert_eq!(exit_code("0 x\n"), Some(3));
+}
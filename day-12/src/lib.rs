@@ -0,0 +1,433 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 12 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 12 functions
+#[derive(Debug, Error)]
+pub enum Day12Error {
+    /// Lines of the map have different lengths
+    #[error("malformed garden map")]
+    Grid(#[from] GridError),
+    /// A plot is not a plant letter
+    #[error("unexpected {plot} on line {line}, column {column}")]
+    UnknownPlant {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        plot: char,
+    },
+}
+
+impl From<Day12Error> for SolutionError {
+    fn from(e: Day12Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day12Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day12Error::Grid(e) => e.location(),
+            Day12Error::UnknownPlant { line, .. } => Some(Location::Line(*line)),
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day12Error::Grid(_) | Day12Error::UnknownPlant { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 12 functions
+pub type Result<T, E = Day12Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "RRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\nVVRCCCJFFF\nVVVVCJJCFE\n\
+VVIVCCJJEE\nVVIIICJJEE\nMIIIIIJJEE\nMIIISIJEEE\nMMMISSJEEE\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["1930", "1206"];
+
+/// Steps to the plots above, right, below and left of a plot, in clockwise order
+const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Garden map with the plant growing on every plot, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_12::Result<()> {
+///     let garden = day_12::parse_garden("AAB\nACC\n")?;
+///     assert_eq!(garden.plots[(1, 2)], 'C');
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Garden {
+    /// Plant letter of every plot
+    pub plots: Grid<char>,
+}
+
+impl TryFrom<&str> for Garden {
+    type Error = Day12Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let plots: Grid<char> = text.parse()?;
+        if let Some(((row, col), &plot)) = plots.iter().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(Day12Error::UnknownPlant { line: row + 1, column: col + 1, plot });
+        }
+        Ok(Garden { plots })
+    }
+}
+
+/// Parse the garden map from text
+/// 
+/// Every plot is a letter naming the plant growing on it
+/// 
+/// # Examples
+/// ```
+/// let garden = day_12::parse_garden(day_12::SAMPLE).unwrap();
+/// assert_eq!((garden.plots.width(), garden.plots.height()), (10, 10));
+/// assert!(matches!(day_12::parse_garden("AB\nB.\n"), Err(day_12::Day12Error::UnknownPlant { line: 2, column: 2, plot: '.' })));
+/// ```
+pub fn parse_garden(text: &str) -> Result<Garden> {
+    Garden::try_from(text)
+}
+
+/// Plots of the same plant connected up, down, left or right
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_12::Result<()> {
+///     let garden = day_12::parse_garden("AAAA\nBBCD\nBBCC\nEEEC\n")?;
+///     let regions = day_12::regions(&garden.plots);
+///     assert_eq!(regions[0], day_12::Region { plant: 'A', area: 4, perimeter: 10, sides: 4 });
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    /// Plant growing in the region
+    pub plant: char,
+    /// Number of plots
+    pub area: usize,
+    /// Number of fence pieces between the region and the other plots or the edge of the map
+    pub perimeter: usize,
+    /// Number of straight sections of fence
+    pub sides: usize,
+}
+
+impl Region {
+    /// Price of the fence, the area times the perimeter
+    pub fn price(&self) -> usize {
+        self.area * self.perimeter
+    }
+
+    /// Price of the fence with the bulk discount, the area times the number of sides
+    pub fn discounted_price(&self) -> usize {
+        self.area * self.sides
+    }
+}
+
+/// Whether the plot a `step` away from `pos` grows the same plant
+fn same_plant(plots: &Grid<char>, pos: Pos, step: (isize, isize)) -> bool {
+    plots.offset(pos, step).is_some_and(|(_, &plant)| plant == plots[pos])
+}
+
+/// Corners of the region at `pos`, every corner starts a new side
+/// 
+/// For every pair of clockwise neighbouring directions the corner is outside
+/// when neither neighbour grows the same plant, and inside when both do but
+/// the plot between them diagonally does not
+fn corners(plots: &Grid<char>, pos: Pos) -> usize {
+    (0..STEPS.len()).filter(|&i| {
+        let (first, second) = (STEPS[i], STEPS[(i + 1) % STEPS.len()]);
+        let diagonal = (first.0 + second.0, first.1 + second.1);
+        match (same_plant(plots, pos, first), same_plant(plots, pos, second)) {
+            (false, false) => true,
+            (true, true) => !same_plant(plots, pos, diagonal),
+            _ => false,
+        }
+    })
+    .count()
+}
+
+/// Regions of the garden in reading order of their first plot, found with a flood fill
+/// 
+/// A region inside another one leaves a hole, the fence around the hole
+/// counts for the perimeter and the sides of both regions
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_12::Result<()> {
+///     let garden = day_12::parse_garden("OOO\nOXO\nOOO\n")?;
+///     let regions = day_12::regions(&garden.plots);
+///     assert_eq!(regions.len(), 2);
+///     assert_eq!((regions[0].area, regions[0].perimeter, regions[0].sides), (8, 16, 8));
+///     assert_eq!((regions[1].area, regions[1].perimeter, regions[1].sides), (1, 4, 4));
+///     Ok(())
+/// }
+/// ```
+pub fn regions(plots: &Grid<char>) -> Vec<Region> {
+    let mut seen = Grid::new(plots.height(), plots.width(), false);
+    let mut regions = Vec::new();
+    for (start, &plant) in plots.iter() {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut region = Region { plant, area: 0, perimeter: 0, sides: 0 };
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            region.area += 1;
+            region.perimeter += STEPS.iter().filter(|&&step| !same_plant(plots, pos, step)).count();
+            region.sides += corners(plots, pos);
+            for next in plots.neighbors4(pos) {
+                if plots[next] == plant && !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// Total price of fencing all the regions, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_12::Result<()> {
+///     let garden = day_12::parse_garden(day_12::SAMPLE)?;
+///     assert_eq!(day_12::total_price(&garden.plots), 1930);
+///     Ok(())
+/// }
+/// ```
+pub fn total_price(plots: &Grid<char>) -> usize {
+    regions(plots).iter().map(Region::price).sum()
+}
+
+/// Total price of fencing all the regions with the bulk discount, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_12::Result<()> {
+///     let garden = day_12::parse_garden(day_12::SAMPLE)?;
+///     assert_eq!(day_12::discounted_price(&garden.plots), 1206);
+///     Ok(())
+/// }
+/// ```
+pub fn discounted_price(plots: &Grid<char>) -> usize {
+    regions(plots).iter().map(Region::discounted_price).sum()
+}
+
+/// Day 12 solution used by the `aoc` runner
+/// 
+/// Part 1 prices the fences of the [`Garden`] by perimeter,
+/// part 2 by the number of sides
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_12::Day12.part1(day_12::SAMPLE)?, "1930");
+///     assert_eq!(day_12::Day12.part2(day_12::SAMPLE)?, "1206");
+///     Ok(())
+/// }
+/// ```
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(total_price(&Garden::try_from(input)?.plots).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(discounted_price(&Garden::try_from(input)?.plots).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let garden = Garden::try_from(input)?;
+        Ok((garden.solve(1)?, garden.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Garden::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Garden {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(total_price(&self.plots).to_string()),
+            _ => Ok(discounted_price(&self.plots).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a garden map
+fn format_problem(input: &str) -> Option<String> {
+    parse_garden(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 12 input with a `size` x `size` garden map, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_12::generate(50, 7);
+/// assert_eq!(input.lines().count(), 50);
+/// assert_eq!(day_12::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day12(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Regions of the garden map in `text`
+    fn regions_of(text: &str) -> Result<Vec<Region>> {
+        Ok(regions(&parse_garden(text)?.plots))
+    }
+
+    #[test]
+    fn test_regions_small_example() -> Result<()> {
+        let regions = regions_of("AAAA\nBBCD\nBBCC\nEEEC\n")?;
+        let summary: Vec<(char, usize, usize, usize)> = regions.iter()
+        .map(|region| (region.plant, region.area, region.perimeter, region.sides))
+        .collect();
+        assert_eq!(summary, vec![('A', 4, 10, 4), ('B', 4, 8, 4), ('C', 4, 10, 8), ('D', 1, 4, 4), ('E', 3, 8, 4)]);
+        assert_eq!(regions.iter().map(Region::price).sum::<usize>(), 140);
+        assert_eq!(regions.iter().map(Region::discounted_price).sum::<usize>(), 80);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regions_single_plots() -> Result<()> {
+        // plots touching only diagonally are separate regions
+        let regions = regions_of("AB\nBA\n")?;
+        assert_eq!(regions.len(), 4);
+        assert!(regions.iter().all(|region| (region.area, region.perimeter, region.sides) == (1, 4, 4)));
+        let regions = regions_of("Z\n")?;
+        assert_eq!(regions, vec![Region { plant: 'Z', area: 1, perimeter: 4, sides: 4 }]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regions_with_holes() -> Result<()> {
+        // the X plots are four separate holes in the O region
+        let garden = parse_garden("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n")?;
+        let found = regions(&garden.plots);
+        assert_eq!(found[0], Region { plant: 'O', area: 21, perimeter: 36, sides: 20 });
+        assert_eq!(total_price(&garden.plots), 772);
+        assert_eq!(discounted_price(&garden.plots), 436);
+        // the two B holes touch the A region only at a corner, A still has 12 sides
+        let garden = parse_garden("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n")?;
+        let found = regions(&garden.plots);
+        assert_eq!(found[0], Region { plant: 'A', area: 28, perimeter: 40, sides: 12 });
+        assert_eq!(discounted_price(&garden.plots), 368);
+        Ok(())
+    }
+
+    #[test]
+    fn test_regions_same_plant_apart() -> Result<()> {
+        // the E shape has 12 sides, the X plots are two separate regions
+        let garden = parse_garden("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n")?;
+        let found = regions(&garden.plots);
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].sides, 12);
+        assert_eq!(discounted_price(&garden.plots), 236);
+        Ok(())
+    }
+
+    #[test]
+    fn test_prices_sample() -> Result<()> {
+        let garden = parse_garden(SAMPLE)?;
+        assert_eq!(regions(&garden.plots).len(), 11);
+        assert_eq!(total_price(&garden.plots), 1930);
+        assert_eq!(discounted_price(&garden.plots), 1206);
+        assert_eq!(Day12.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_garden_errors() {
+        let result = parse_garden("AB\nA\n");
+        assert!(matches!(&result, Err(Day12Error::Grid(GridError::Ragged { row: 1, .. }))));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_garden("AB\nA1\n");
+        assert!(matches!(&result, Err(Day12Error::UnknownPlant { line: 2, column: 2, plot: '1' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_garden_serde_round_trip() -> Result<()> {
+        let garden = parse_garden("AB\n")?;
+        let json = serde_json::to_string(&garden)?;
+        assert_eq!(json, r#"{"plots":[["A","B"]]}"#);
+        assert_eq!(serde_json::from_str::<Garden>(&json)?, garden);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day12.describe(SAMPLE).problem, None);
+        assert_eq!(Day12.describe("AB\nA1\n").problem.as_deref(), Some("unexpected 1 on line 2, column 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day12.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day12(seed, 40);
+            assert_eq!(Day12.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let garden = day_12::parse_garden(&input.read_to_string()?)?;
+
+    println!("TOTAL_PRICE: {}", day_12::total_price(&garden.plots));
+    println!("DISCOUNTED_PRICE: {}", day_12::discounted_price(&garden.plots));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_12::Day12Error::kind)).into()
+        },
+    }
+}
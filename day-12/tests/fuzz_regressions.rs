@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_12` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_12", |data| {
+        if let Ok(garden) = day_12::parse_garden(&String::from_utf8_lossy(data)) {
+            let _ = day_12::total_price(&garden.plots);
+            let _ = day_12::discounted_price(&garden.plots);
+        }
+    });
+}
@@ -0,0 +1,630 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 13 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 13 functions
+#[derive(Debug, Error)]
+pub enum Day13Error {
+    /// A line does not have the format of the button or prize line expected there
+    #[error("expected {expected} on line {line}, found {text}")]
+    MalformedLine {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Label the line should start with
+        expected: &'static str,
+        /// Text of the line
+        text: String,
+    },
+    /// A coordinate is not a number that fits `i64`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// The input ends before the buttons and the prize of a machine
+    #[error("machine starting on line {line} is incomplete")]
+    IncompleteMachine {
+        /// Line number (starting from 1) of the first line of the machine
+        line: usize,
+    },
+    /// A moved prize or the sum of the tokens does not fit `i64` and `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day13Error> for SolutionError {
+    fn from(e: Day13Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day13Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day13Error::MalformedLine { line, .. } | Day13Error::Number { line, .. }
+            | Day13Error::IncompleteMachine { line } => Some(Location::Line(*line)),
+            Day13Error::Overflow { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day13Error::MalformedLine { .. } | Day13Error::Number { .. }
+            | Day13Error::IncompleteMachine { .. } => ErrorKind::Parse,
+            Day13Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 13 functions
+pub type Result<T, E = Day13Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\n\
+Button A: X+26, Y+66\nButton B: X+67, Y+21\nPrize: X=12748, Y=12176\n\n\
+Button A: X+17, Y+86\nButton B: X+84, Y+37\nPrize: X=7870, Y=6450\n\n\
+Button A: X+69, Y+23\nButton B: X+27, Y+71\nPrize: X=18641, Y=10279\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["480", "875318608908"];
+
+/// Most presses of a button part 1 allows
+pub const PRESS_LIMIT: u64 = 100;
+
+/// Distance the prizes move along both axes in part 2
+pub const PRIZE_OFFSET: i64 = 10_000_000_000_000;
+
+/// Claw machine with its two buttons and the position of the prize
+/// 
+/// # Examples
+/// ```
+/// let machine = day_13::Machine { a: (94, 34), b: (22, 67), prize: (8400, 5400) };
+/// assert_eq!(machine.tokens(None), Some(280));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Machine {
+    /// Move of the claw along X and Y for a press of button A, costing 3 tokens
+    pub a: (i64, i64),
+    /// Move of the claw along X and Y for a press of button B, costing 1 token
+    pub b: (i64, i64),
+    /// Position of the prize, the claw starts at `(0, 0)`
+    pub prize: (i64, i64),
+}
+
+impl Machine {
+    /// Fewest tokens to move the claw onto the prize, `None` when no presses do
+    /// 
+    /// With a `limit` neither button can be pressed more than that many times.
+    /// The presses solve the 2x2 linear system of the two axes: when the
+    /// buttons are not collinear there is a single solution and Cramer's rule
+    /// finds it, it only counts when it is whole and not negative. Collinear
+    /// buttons leave a line of solutions to pick the cheapest one from.
+    /// Tokens that do not fit `u128` saturate at [`u128::MAX`].
+    /// 
+    /// # Examples
+    /// ```
+    /// let machine = day_13::Machine { a: (26, 66), b: (67, 21), prize: (12748, 12176) };
+    /// assert_eq!(machine.tokens(None), None);
+    /// let machine = machine.far().unwrap();
+    /// assert_eq!(machine.tokens(None), Some(459236326669));
+    /// assert_eq!(machine.tokens(Some(day_13::PRESS_LIMIT)), None);
+    /// ```
+    pub fn tokens(&self, limit: Option<u64>) -> Option<u128> {
+        let (a, b) = self.presses(limit.map_or(i128::MAX, i128::from))?;
+        Some((a as u128).saturating_mul(3).saturating_add(b as u128))
+    }
+
+    /// Machine with the prize moved by [`PRIZE_OFFSET`] along both axes, `None` when it does not fit `i64`
+    /// 
+    /// # Examples
+    /// ```
+    /// let machine = day_13::Machine { a: (94, 34), b: (22, 67), prize: (8400, 5400) };
+    /// assert_eq!(machine.far().unwrap().prize, (10000000008400, 10000000005400));
+    /// ```
+    pub fn far(&self) -> Option<Machine> {
+        let prize = (self.prize.0.checked_add(PRIZE_OFFSET)?, self.prize.1.checked_add(PRIZE_OFFSET)?);
+        Some(Machine { prize, ..*self })
+    }
+
+    /// Cheapest presses of A and B, each at most `limit`, moving the claw onto the prize
+    fn presses(&self, limit: i128) -> Option<(i128, i128)> {
+        let [ax, ay, bx, by, px, py] = [self.a.0, self.a.1, self.b.0, self.b.1, self.prize.0, self.prize.1].map(i128::from);
+        // products of two i64 values are below 2^126, their differences fit i128
+        let det = ax * by - ay * bx;
+        let (a, b) = if det != 0 {
+            let (a, b) = (px * by - py * bx, ax * py - ay * px);
+            if a % det != 0 || b % det != 0 {
+                return None;
+            }
+            (a / det, b / det)
+        } else {
+            // the buttons move along the same line, solve on an axis they move
+            // along and check the other axis once the presses are known
+            let (u, v, w) = if (ax, bx) != (0, 0) { (ax, bx, px) } else { (ay, by, py) };
+            let (a, b) = cheapest_on_line(u, v, w, limit)?;
+            let lands = |step_a: i128, step_b: i128, target: i128| {
+                a.checked_mul(step_a).zip(b.checked_mul(step_b))
+                .and_then(|(x, y)| x.checked_add(y)) == Some(target)
+            };
+            if !lands(ax, bx, px) || !lands(ay, by, py) {
+                return None;
+            }
+            (a, b)
+        };
+        ((0..=limit).contains(&a) && (0..=limit).contains(&b)).then_some((a, b))
+    }
+}
+
+/// Presses `(a, b)`, both within `0..=limit`, with `a * u + b * v == w` and the fewest tokens `3 * a + b`
+/// 
+/// When both steps are non zero the solutions of the linear Diophantine
+/// equation are `a = a0 + t * v / g` and `b = b0 - t * u / g` for any whole
+/// `t`, with `g` the greatest common divisor of the steps. The bounds on
+/// the presses bound `t` and the tokens change linearly with it, so the
+/// cheapest presses are at one end of its range.
+fn cheapest_on_line(u: i128, v: i128, w: i128, limit: i128) -> Option<(i128, i128)> {
+    match (u, v) {
+        (0, 0) => return (w == 0).then_some((0, 0)),
+        // pressing the button that does not move only costs tokens
+        (0, _) => return (w % v == 0).then(|| (0, w / v)),
+        (_, 0) => return (w % u == 0).then(|| (w / u, 0)),
+        _ => (),
+    }
+    let (g, x, y) = extended_gcd(u, v);
+    if w % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x.checked_mul(w / g)?, y.checked_mul(w / g)?);
+    let (step_a, step_b) = (v / g, -u / g);
+    let (mut low, mut high) = (i128::MIN, i128::MAX);
+    for (start, step) in [(a0, step_a), (b0, step_b)] {
+        // 0 <= start + step * t <= limit
+        let (from, to) = (start.checked_neg()?, limit.saturating_sub(start));
+        let (lo, hi) = if step > 0 {
+            (div_ceil(from, step), div_floor(to, step))
+        } else {
+            (div_ceil(to, step), div_floor(from, step))
+        };
+        (low, high) = (low.max(lo), high.min(hi));
+    }
+    if low > high {
+        return None;
+    }
+    // when the tokens do not change along the line either end will do,
+    // both ends are bounded then since the steps have opposite signs
+    let t = if 3 * step_a + step_b > 0 { low } else { high };
+    Some((a0 + step_a * t, b0 + step_b * t))
+}
+
+/// Greatest common divisor `g` of `a` and `b`, never negative, with `x` and `y` such that `a * x + b * y == g`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+/// `a / b` rounded towards negative infinity
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) { q - 1 } else { q }
+}
+
+/// `a / b` rounded towards positive infinity
+fn div_ceil(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) == (b < 0) { q + 1 } else { q }
+}
+
+/// Claw machines of the arcade, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_13::Result<()> {
+///     let arcade = day_13::Arcade::try_from("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n")?;
+///     assert_eq!(arcade.0[0], day_13::Machine { a: (94, 34), b: (22, 67), prize: (8400, 5400) });
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arcade(pub Vec<Machine>);
+
+impl TryFrom<&str> for Arcade {
+    type Error = Day13Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut lines = text.lines().enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+        let mut machines = Vec::new();
+        while let Some((first, text)) = lines.next() {
+            let a = parse_line(first, text, "Button A", '+')?;
+            let (line, text) = lines.next().ok_or(Day13Error::IncompleteMachine { line: first })?;
+            let b = parse_line(line, text, "Button B", '+')?;
+            let (line, text) = lines.next().ok_or(Day13Error::IncompleteMachine { line: first })?;
+            let prize = parse_line(line, text, "Prize", '=')?;
+            machines.push(Machine { a, b, prize });
+        }
+        Ok(Arcade(machines))
+    }
+}
+
+/// Parse one `label: X<sign>x, Y<sign>y` line with coordinates that are not negative
+fn parse_line(line: usize, text: &str, label: &'static str, sign: char) -> Result<(i64, i64)> {
+    let malformed = || Day13Error::MalformedLine { line, expected: label, text: text.to_string() };
+    let coordinate = |token: &str, axis: char| {
+        let digits = token.strip_prefix(axis).and_then(|token| token.strip_prefix(sign))
+        .filter(|digits| digits.starts_with(|c: char| c.is_ascii_digit()))
+        .ok_or_else(malformed)?;
+        digits.parse::<i64>().map_err(|source| Day13Error::Number { line, token: token.to_string(), source })
+    };
+    let (x, y) = text.strip_prefix(label).and_then(|rest| rest.strip_prefix(": "))
+    .and_then(|rest| rest.split_once(", "))
+    .ok_or_else(malformed)?;
+    Ok((coordinate(x, 'X')?, coordinate(y, 'Y')?))
+}
+
+/// Parse the machines, every one a `Button A`, a `Button B` and a `Prize` line
+/// 
+/// Blank lines are skipped, the puzzle input puts one between the machines.
+/// 
+/// # Examples
+/// ```
+/// let arcade = day_13::parse_machines(day_13::SAMPLE).unwrap();
+/// assert_eq!(arcade.0.len(), 4);
+/// assert_eq!(arcade.0[3].prize, (18641, 10279));
+/// assert!(matches!(day_13::parse_machines("Button A: X+1, Y+2\n"), Err(day_13::Day13Error::IncompleteMachine { line: 1 })));
+/// ```
+pub fn parse_machines(text: &str) -> Result<Arcade> {
+    Arcade::try_from(text)
+}
+
+/// Sum of the fewest tokens over the machines with a reachable prize, `None` when it does not fit `u64`
+fn try_total(machines: &[Machine], limit: Option<u64>) -> Option<u64> {
+    machines.iter()
+    .filter_map(|machine| machine.tokens(limit))
+    .try_fold(0u64, |sum, tokens| sum.checked_add(u64::try_from(tokens).ok()?))
+}
+
+/// Sum of the [`PRIZE_OFFSET`] moved machines, `None` when a prize or the sum does not fit
+fn try_total_far(machines: &[Machine]) -> Option<u64> {
+    let far = machines.iter().map(Machine::far).collect::<Option<Vec<Machine>>>()?;
+    try_total(&far, None)
+}
+
+/// Fewest tokens to win every prize that takes at most [`PRESS_LIMIT`] presses of each button
+/// 
+/// Saturates at [`u64::MAX`], [`part1`] reports the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_13::Result<()> {
+///     let arcade = day_13::parse_machines(day_13::SAMPLE)?;
+///     assert_eq!(day_13::fewest_tokens(&arcade.0), 480);
+///     Ok(())
+/// }
+/// ```
+pub fn fewest_tokens(machines: &[Machine]) -> u64 {
+    try_total(machines, Some(PRESS_LIMIT)).unwrap_or(u64::MAX)
+}
+
+/// Fewest tokens to win every prize once they move by [`PRIZE_OFFSET`], with no limit on the presses
+/// 
+/// Saturates at [`u64::MAX`], [`part2`] reports the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_13::Result<()> {
+///     let arcade = day_13::parse_machines(day_13::SAMPLE)?;
+///     assert_eq!(day_13::fewest_tokens_far(&arcade.0), 875318608908);
+///     Ok(())
+/// }
+/// ```
+pub fn fewest_tokens_far(machines: &[Machine]) -> u64 {
+    try_total_far(machines).unwrap_or(u64::MAX)
+}
+
+/// Fewest tokens to win every prize within the press limit, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_13::Result<()> {
+///     let arcade = day_13::parse_machines(day_13::SAMPLE)?;
+///     assert_eq!(day_13::part1(&arcade)?, 480);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(arcade: &Arcade) -> Result<u64> {
+    try_total(&arcade.0, Some(PRESS_LIMIT)).ok_or(Day13Error::Overflow { part: 1 })
+}
+
+/// Fewest tokens to win every moved prize, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_13::Result<()> {
+///     let arcade = day_13::parse_machines(day_13::SAMPLE)?;
+///     assert_eq!(day_13::part2(&arcade)?, 875318608908);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(arcade: &Arcade) -> Result<u64> {
+    try_total_far(&arcade.0).ok_or(Day13Error::Overflow { part: 2 })
+}
+
+/// Day 13 solution used by the `aoc` runner
+/// 
+/// Part 1 sums the fewest tokens to win the prizes within 100 presses,
+/// part 2 after the prizes move by [`PRIZE_OFFSET`]
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_13::Day13.part1(day_13::SAMPLE)?, "480");
+///     assert_eq!(day_13::Day13.part2(day_13::SAMPLE)?, "875318608908");
+///     Ok(())
+/// }
+/// ```
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Arcade::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Arcade::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let arcade = Arcade::try_from(input)?;
+        Ok((arcade.solve(1)?, arcade.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Arcade::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Arcade {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a list of claw machines
+fn format_problem(input: &str) -> Option<String> {
+    parse_machines(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 13 input with `size` machines, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_13::generate(50, 7);
+/// assert_eq!(input.lines().filter(|line| line.starts_with("Prize")).count(), 50);
+/// assert_eq!(day_13::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day13(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Machine with the given buttons and prize
+    fn machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Machine {
+        Machine { a, b, prize }
+    }
+
+    #[test]
+    fn test_fewest_tokens_sample() -> Result<()> {
+        let arcade = parse_machines(SAMPLE)?;
+        let tokens: Vec<Option<u128>> = arcade.0.iter().map(|m| m.tokens(Some(PRESS_LIMIT))).collect();
+        assert_eq!(tokens, vec![Some(280), None, Some(200), None]);
+        assert_eq!(fewest_tokens(&arcade.0), 480);
+        assert_eq!(Day13.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fewest_tokens_far_sample() -> Result<()> {
+        let arcade = parse_machines(SAMPLE)?;
+        let tokens: Vec<Option<u128>> = arcade.0.iter().map(|m| m.far().and_then(|m| m.tokens(None))).collect();
+        assert_eq!(tokens, vec![None, Some(459236326669), None, Some(416082282239)]);
+        assert_eq!(fewest_tokens_far(&arcade.0), 875318608908);
+        assert_eq!(Day13.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day13.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokens_unsolvable() {
+        assert_eq!(machine((26, 66), (67, 21), (12748, 12176)).tokens(None), None);
+        // a = 1.5 and b = 2 is the only solution
+        assert_eq!(machine((2, 0), (0, 2), (3, 4)).tokens(None), None);
+        // a = -1 and b = 2 is the only solution
+        assert_eq!(machine((2, 1), (1, 2), (0, 3)).tokens(None), None);
+        assert_eq!(machine((1, 0), (0, 1), (0, 0)).tokens(None), Some(0));
+    }
+
+    #[test]
+    fn test_tokens_press_limit() {
+        let m = machine((1, 0), (0, 1), (101, 5));
+        assert_eq!(m.tokens(None), Some(308));
+        assert_eq!(m.tokens(Some(PRESS_LIMIT)), None);
+        assert_eq!(m.tokens(Some(101)), Some(308));
+    }
+
+    #[test]
+    fn test_tokens_collinear() {
+        // A moves twice as far for three times the tokens, B is cheaper
+        assert_eq!(machine((2, 2), (1, 1), (10, 10)).tokens(None), Some(10));
+        // A moves four times as far for three times the tokens, A is cheaper
+        assert_eq!(machine((4, 4), (1, 1), (8, 8)).tokens(None), Some(6));
+        assert_eq!(machine((4, 4), (1, 1), (8, 9)).tokens(None), None);
+        // 3a + 2b = 7 has a single solution in whole presses
+        assert_eq!(machine((3, 6), (2, 4), (7, 14)).tokens(None), Some(5));
+        assert_eq!(machine((4, 4), (2, 2), (7, 7)).tokens(None), None);
+        // the limit rules out the cheap end of the line
+        assert_eq!(machine((4, 4), (1, 1), (800, 800)).tokens(None), Some(600));
+        assert_eq!(machine((4, 4), (1, 1), (800, 800)).tokens(Some(PRESS_LIMIT)), None);
+        assert_eq!(machine((4, 4), (1, 1), (410, 410)).tokens(Some(PRESS_LIMIT)), Some(310));
+        // buttons that do not move along X
+        assert_eq!(machine((0, 3), (0, 1), (0, 7)).tokens(None), Some(7));
+    }
+
+    #[test]
+    fn test_tokens_buttons_not_moving() {
+        assert_eq!(machine((0, 0), (0, 0), (0, 0)).tokens(None), Some(0));
+        assert_eq!(machine((0, 0), (0, 0), (1, 0)).tokens(None), None);
+        assert_eq!(machine((0, 0), (1, 2), (3, 6)).tokens(None), Some(3));
+        assert_eq!(machine((1, 2), (0, 0), (3, 6)).tokens(None), Some(9));
+        assert_eq!(machine((1, 2), (0, 0), (3, 5)).tokens(None), None);
+    }
+
+    #[test]
+    fn test_cheapest_on_line() {
+        assert_eq!(cheapest_on_line(3, 2, 7, i128::MAX), Some((1, 2)));
+        assert_eq!(cheapest_on_line(4, 1, 9, i128::MAX), Some((2, 1)));
+        // steps of opposite signs leave the presses unbounded on one side
+        assert_eq!(cheapest_on_line(3, -2, 1, i128::MAX), Some((1, 1)));
+        assert_eq!(cheapest_on_line(3, -2, 1, 0), None);
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(div_floor(-7, 2), -4);
+        assert_eq!(div_ceil(-7, 2), -3);
+        assert_eq!(div_floor(7, -2), -4);
+        assert_eq!(div_ceil(7, -2), -3);
+    }
+
+    #[test]
+    fn test_part2_overflow() -> Result<()> {
+        let input = "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=9223372036854775807, Y=1\n";
+        let arcade = parse_machines(input)?;
+        assert_eq!(part1(&arcade)?, 0);
+        assert!(matches!(part2(&arcade), Err(Day13Error::Overflow { part: 2 })));
+        assert_eq!(fewest_tokens_far(&arcade.0), u64::MAX);
+        // every prize fits but the tokens do not
+        let arcade = Arcade(vec![machine((1, 0), (0, 1), (i64::MAX - PRIZE_OFFSET, 0)); 2]);
+        assert!(matches!(part2(&arcade), Err(Day13Error::Overflow { part: 2 })));
+        assert_eq!(Day13Error::Overflow { part: 2 }.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_machines_errors() {
+        let result = parse_machines("Button A: X+1, Y+2\nButton C: X+1, Y+2\nPrize: X=1, Y=2\n");
+        assert!(matches!(&result, Err(Day13Error::MalformedLine { line: 2, expected: "Button B", .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_machines("Button A: X+1, Y+2\nButton B: X+1, Y+2\nPrize: X=1 Y=2\n");
+        assert!(matches!(&result, Err(Day13Error::MalformedLine { line: 3, expected: "Prize", .. })));
+        let result = parse_machines("Button A: X+1, Y+-2\nButton B: X+1, Y+2\nPrize: X=1, Y=2\n");
+        assert!(matches!(&result, Err(Day13Error::MalformedLine { line: 1, .. })));
+        let result = parse_machines("Button A: X+1, Y+2\nButton B: X+1, Y+2\nPrize: X=99999999999999999999, Y=2\n");
+        assert!(matches!(&result, Err(Day13Error::Number { line: 3, token, .. }) if token == "X=99999999999999999999"));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        let result = parse_machines(&SAMPLE[..SAMPLE.len() - 24]);
+        assert!(matches!(&result, Err(Day13Error::IncompleteMachine { line: 13 })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(13)));
+        assert!(matches!(parse_machines(""), Result::Ok(Arcade(machines)) if machines.is_empty()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_arcade_serde_round_trip() -> Result<()> {
+        let arcade = parse_machines("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n")?;
+        let json = serde_json::to_string(&arcade)?;
+        assert_eq!(json, r#"[{"a":[94,34],"b":[22,67],"prize":[8400,5400]}]"#);
+        assert_eq!(serde_json::from_str::<Arcade>(&json)?, arcade);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day13.describe(SAMPLE).problem, None);
+        assert_eq!(
+            Day13.describe("Button B: X+1, Y+2\n").problem.as_deref(),
+            Some("expected Button A on line 1, found Button B: X+1, Y+2")
+        );
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day13.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day13(seed, 100);
+            assert_eq!(Day13.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_tokens_matches_oracle(a in (0i64..8, 0i64..8), b in (0i64..8, 0i64..8), presses in (0i64..=100, 0i64..=100), miss in 0i64..2) {
+            // small buttons are often collinear, with many ways to win the prize
+            let prize = (presses.0 * a.0 + presses.1 * b.0 + miss, presses.0 * a.1 + presses.1 * b.1);
+            let tokens = machine(a, b, prize).tokens(Some(PRESS_LIMIT));
+            proptest::prop_assert_eq!(tokens, aoc_testing::oracle::claw_tokens(a, b, prize).map(|tokens| tokens as u128));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let arcade = day_13::parse_machines(&input.read_to_string()?)?;
+
+    println!("TOKENS: {}", day_13::part1(&arcade)?);
+    println!("FAR_TOKENS: {}", day_13::part2(&arcade)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_13::Day13Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_13` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_13", |data| {
+        if let Ok(arcade) = day_13::parse_machines(&String::from_utf8_lossy(data)) {
+            let _ = day_13::part1(&arcade);
+            let _ = day_13::part2(&arcade);
+        }
+    });
+}
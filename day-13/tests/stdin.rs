@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-13"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-13");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_480.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-13");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "TOKENS: 480\nFAR_TOKENS: 875318608908\n");
+}
+
+/// Exit code of day-13 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-13"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-13");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-13").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=2, Y=3\n"), Some(0));
+    assert_eq!(exit_code("Button A: X+1, Y+0\nPrize: X=2, Y=3\n"), Some(3));
+}
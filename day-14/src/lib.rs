@@ -0,0 +1,604 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 14 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::{cmp::Reverse, num::ParseIntError};
+
+/// Errors returned by the day 14 functions
+#[derive(Debug, Error)]
+pub enum Day14Error {
+    /// A line does not have the `p=x,y v=x,y` format
+    #[error("malformed robot {text} on line {line}")]
+    MalformedRobot {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A coordinate is not a number that fits `i64`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// The safety factor does not fit `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day14Error> for SolutionError {
+    fn from(e: Day14Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day14Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day14Error::MalformedRobot { line, .. } | Day14Error::Number { line, .. } => Some(Location::Line(*line)),
+            Day14Error::Overflow { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day14Error::MalformedRobot { .. } | Day14Error::Number { .. } => ErrorKind::Parse,
+            Day14Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 14 functions
+pub type Result<T, E = Day14Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\np=3,0 v=-2,-2\n\
+p=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+/// 
+/// The sample robots draw no picture and the puzzle gives no answer of
+/// part 2 for them, it is left empty.
+pub const SAMPLE_ANSWERS: [&str; 2] = ["12", ""];
+
+/// Width and height of the floor in [`SAMPLE`]
+pub const SAMPLE_FLOOR: (i64, i64) = (11, 7);
+
+/// Width and height of the floor in the puzzle input
+pub const FLOOR: (i64, i64) = (101, 103);
+
+/// Steps the robots take before part 1 measures the safety factor
+pub const STEPS: usize = 100;
+
+/// Security robot with its position and the tiles it moves every step
+/// 
+/// # Examples
+/// ```
+/// let robot = day_14::Robot { position: (2, 4), velocity: (2, -3) };
+/// assert_eq!(robot.position_after(5, 11, 7), (1, 3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Robot {
+    /// Column and row of the robot, counted from the top left tile
+    pub position: (i64, i64),
+    /// Tiles the robot moves right and down every step, negative to the left and up
+    pub velocity: (i64, i64),
+}
+
+impl Robot {
+    /// Position after `steps` steps on a `width` x `height` floor, wrapping around its edges
+    /// 
+    /// Every axis moves on its own, so the position is the start plus the
+    /// velocity times the steps modulo the size of the floor. Both factors
+    /// are reduced modulo the size first and the product is taken in `i128`,
+    /// so any velocity and any number of steps work.
+    /// 
+    /// # Panics
+    /// 
+    /// When `width` or `height` is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// let robot = day_14::Robot { position: (2, 4), velocity: (2, -3) };
+    /// assert_eq!(robot.position_after(1, 11, 7), (4, 1));
+    /// assert_eq!(robot.position_after(2, 11, 7), (6, 5));
+    /// ```
+    pub fn position_after(&self, steps: usize, width: i64, height: i64) -> (i64, i64) {
+        (wrap(self.position.0, self.velocity.0, steps, width), wrap(self.position.1, self.velocity.1, steps, height))
+    }
+}
+
+/// Coordinate on an axis of `size` tiles after `steps` steps of `velocity` from `start`
+fn wrap(start: i64, velocity: i64, steps: usize, size: i64) -> i64 {
+    let size = i128::from(size);
+    let moved = i128::from(velocity).rem_euclid(size) * (steps as i128 % size);
+    (i128::from(start) + moved).rem_euclid(size) as i64
+}
+
+/// Robots in the lobby, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_14::Result<()> {
+///     let robots = day_14::Robots::try_from("p=0,4 v=3,-3\n")?;
+///     assert_eq!(robots.0[0], day_14::Robot { position: (0, 4), velocity: (3, -3) });
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Robots(pub Vec<Robot>);
+
+impl TryFrom<&str> for Robots {
+    type Error = Day14Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_robot(i + 1, line.trim()))
+        .collect::<Result<Vec<Robot>>>()
+        .map(Robots)
+    }
+}
+
+/// Parse one `p=x,y v=x,y` line
+fn parse_robot(line: usize, text: &str) -> Result<Robot> {
+    let malformed = || Day14Error::MalformedRobot { line, text: text.to_string() };
+    let pair = |token: &str, prefix: &str| {
+        let (x, y) = token.strip_prefix(prefix).and_then(|pair| pair.split_once(',')).ok_or_else(malformed)?;
+        let number = |token: &str| token.parse::<i64>()
+        .map_err(|source| Day14Error::Number { line, token: token.to_string(), source });
+        Ok((number(x)?, number(y)?))
+    };
+    let (position, velocity) = text.split_once(' ').ok_or_else(malformed)?;
+    Ok(Robot { position: pair(position, "p=")?, velocity: pair(velocity.trim_start(), "v=")? })
+}
+
+/// Parse a robot on every line, blank lines are skipped
+/// 
+/// # Examples
+/// ```
+/// let robots = day_14::parse_robots(day_14::SAMPLE).unwrap();
+/// assert_eq!(robots.0.len(), 12);
+/// assert_eq!(robots.0[11].velocity, (-3, -3));
+/// assert!(matches!(day_14::parse_robots("p=0,4\n"), Err(day_14::Day14Error::MalformedRobot { line: 1, .. })));
+/// ```
+pub fn parse_robots(text: &str) -> Result<Robots> {
+    Robots::try_from(text)
+}
+
+/// Positions of the robots after `steps` steps on a `width` x `height` floor
+/// 
+/// # Panics
+/// 
+/// When `width` or `height` is zero
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_14::Result<()> {
+///     let robots = day_14::parse_robots(day_14::SAMPLE)?;
+///     let positions = day_14::positions_after(&robots.0, 100, 11, 7);
+///     assert_eq!(positions[0], (3, 5));
+///     Ok(())
+/// }
+/// ```
+pub fn positions_after(robots: &[Robot], steps: usize, width: i64, height: i64) -> Vec<(i64, i64)> {
+    robots.iter().map(|robot| robot.position_after(steps, width, height)).collect()
+}
+
+/// Product of the robots in the four quadrants, `None` when it does not fit `u64`
+fn try_safety_factor(positions: &[(i64, i64)], width: i64, height: i64) -> Option<u64> {
+    let mut quadrants = [0u64; 4];
+    for &(x, y) in positions {
+        // on an odd floor the middle row and column belong to no quadrant
+        let (right, below) = (x >= width - width / 2, y >= height - height / 2);
+        if (x < width / 2 || right) && (y < height / 2 || below) {
+            quadrants[usize::from(right) + 2 * usize::from(below)] += 1;
+        }
+    }
+    quadrants.iter().try_fold(1u64, |product, &count| product.checked_mul(count))
+}
+
+/// Product of the robots in the four quadrants of a `width` x `height` floor
+/// 
+/// Robots exactly on the middle row or column of the floor are in no
+/// quadrant and do not count.
+/// Saturates at [`u64::MAX`], [`part1`] reports the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_14::Result<()> {
+///     let robots = day_14::parse_robots(day_14::SAMPLE)?;
+///     let positions = day_14::positions_after(&robots.0, 100, 11, 7);
+///     assert_eq!(day_14::safety_factor(&positions, 11, 7), 12);
+///     Ok(())
+/// }
+/// ```
+pub fn safety_factor(positions: &[(i64, i64)], width: i64, height: i64) -> u64 {
+    try_safety_factor(positions, width, height).unwrap_or(u64::MAX)
+}
+
+/// Sum of the squared counts of the robots on every line of an axis, large when they crowd a few lines
+/// 
+/// Robots spread evenly over `size` lines make about `n^2 / size + n`,
+/// robots on a single line make `n^2`
+fn crowding(coordinates: impl Iterator<Item = i64>) -> u64 {
+    let mut coordinates: Vec<i64> = coordinates.collect();
+    coordinates.sort_unstable();
+    coordinates.chunk_by(|a, b| a == b).map(|line| (line.len() as u64).pow(2)).sum()
+}
+
+/// Greatest common divisor of `a` and `b`
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// First step the robots on a `width` x `height` floor draw a picture, the one where they crowd together the most
+/// 
+/// The picture is a large group of robots close to each other while the
+/// other robots stay spread over the floor, so its columns and rows hold
+/// far more robots than usual. The heuristic scores a step by the
+/// crowding of the columns plus the crowding of the rows, the
+/// sums of the squared robots on every line. Unlike the variance of the
+/// positions it does not care where on the wrapping floor the picture is.
+/// 
+/// Every axis wraps on its own, the columns repeat every `width` steps and
+/// the rows every `height` steps, so the crowding is only computed for the
+/// first `width` and `height` steps. The robots are back where they started
+/// after the least common multiple of the two, the step with the highest
+/// score within it is the answer, the first one on ties.
+/// 
+/// # Panics
+/// 
+/// When `width` or `height` is not positive
+/// 
+/// # Examples
+/// ```
+/// use day_14::Robot;
+/// 
+/// // the robots meet on the middle tile after 4 steps
+/// let robots = [
+///     Robot { position: (1, 1), velocity: (1, 1) },
+///     Robot { position: (9, 9), velocity: (-1, -1) },
+///     Robot { position: (1, 9), velocity: (1, -1) },
+/// ];
+/// assert_eq!(day_14::find_easter_egg(&robots, 11, 11), 4);
+/// ```
+pub fn find_easter_egg(robots: &[Robot], width: i64, height: i64) -> usize {
+    assert!(width > 0 && height > 0, "floor of {width} x {height} tiles");
+    let crowdings = |size: i64, axis: fn(&Robot) -> (i64, i64)| -> Vec<u64> {
+        (0..size as usize)
+        .map(|step| crowding(robots.iter().map(|robot| {
+            let (start, velocity) = axis(robot);
+            wrap(start, velocity, step, size)
+        })))
+        .collect()
+    };
+    let columns = crowdings(width, |robot| (robot.position.0, robot.velocity.0));
+    let rows = crowdings(height, |robot| (robot.position.1, robot.velocity.1));
+    let period = (width / gcd(width, height) * height) as usize;
+    (0..period).min_by_key(|&step| Reverse(columns[step % columns.len()] + rows[step % rows.len()])).unwrap_or(0)
+}
+
+/// Safety factor after [`STEPS`] steps on a `(width, height)` floor, the answer of part 1
+/// 
+/// The input does not say how large the floor is, the puzzle input moves
+/// on [`FLOOR`] and the sample on [`SAMPLE_FLOOR`].
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_14::Result<()> {
+///     let robots = day_14::parse_robots(day_14::SAMPLE)?;
+///     assert_eq!(day_14::part1(&robots, day_14::SAMPLE_FLOOR)?, 12);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(robots: &Robots, (width, height): (i64, i64)) -> Result<u64> {
+    try_safety_factor(&positions_after(&robots.0, STEPS, width, height), width, height)
+    .ok_or(Day14Error::Overflow { part: 1 })
+}
+
+/// First step with the Easter egg on a `(width, height)` floor, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_14::Result<()> {
+///     let robots = day_14::parse_robots(day_14::SAMPLE)?;
+///     // the robots are back where they started after 11 x 7 steps
+///     assert!(day_14::part2(&robots, day_14::SAMPLE_FLOOR) < 77);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(robots: &Robots, (width, height): (i64, i64)) -> usize {
+    find_easter_egg(&robots.0, width, height)
+}
+
+/// Robots parsed for the runner with the floor they move on
+struct Lobby {
+    robots: Robots,
+    floor: (i64, i64),
+}
+
+impl Lobby {
+    fn parse(input: &str, floor: (i64, i64)) -> Result<Self> {
+        Ok(Lobby { robots: Robots::try_from(input)?, floor })
+    }
+}
+
+/// Day 14 solution used by the `aoc` runner
+/// 
+/// Part 1 multiplies the robots in every quadrant after 100 steps,
+/// part 2 finds the first step the robots draw a picture. The default
+/// moves the robots on the [`FLOOR`] of the puzzle input.
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     let sample = day_14::Day14 { floor: day_14::SAMPLE_FLOOR };
+///     assert_eq!(sample.part1(day_14::SAMPLE)?, "12");
+///     assert_eq!(day_14::Day14::default().floor, day_14::FLOOR);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day14 {
+    /// Width and height of the floor the robots move on
+    pub floor: (i64, i64),
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 { floor: FLOOR }
+    }
+}
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Lobby::parse(input, self.floor)?.solve(1)
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Lobby::parse(input, self.floor)?.solve(2)
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let lobby = Lobby::parse(input, self.floor)?;
+        Ok((lobby.solve(1)?, lobby.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn sample_solution(&self) -> Option<Box<dyn Solution>> {
+        Some(Box::new(Day14 { floor: SAMPLE_FLOOR }))
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Lobby::parse(input, self.floor)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Lobby {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(&self.robots, self.floor)?.to_string()),
+            _ => Ok(part2(&self.robots, self.floor).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a list of robots
+fn format_problem(input: &str) -> Option<String> {
+    parse_robots(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 14 input with `size` robots, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_14::generate(50, 7);
+/// assert_eq!(input.lines().count(), 50);
+/// assert_eq!(day_14::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day14(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Robot at `position` moving `velocity` every step
+    fn robot(position: (i64, i64), velocity: (i64, i64)) -> Robot {
+        Robot { position, velocity }
+    }
+
+    #[test]
+    fn test_safety_factor_sample() -> Result<()> {
+        let robots = parse_robots(SAMPLE)?;
+        let positions = positions_after(&robots.0, STEPS, 11, 7);
+        let mut tiles = vec![vec![0; 11]; 7];
+        for (x, y) in positions.iter().copied() {
+            tiles[y as usize][x as usize] += 1;
+        }
+        assert_eq!(tiles[0], vec![0, 0, 0, 0, 0, 0, 2, 0, 0, 1, 0]);
+        assert_eq!(tiles[2], vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(tiles[3], vec![0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(safety_factor(&positions, 11, 7), 12);
+        assert_eq!(Day14 { floor: SAMPLE_FLOOR }.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_position_after_wraps() {
+        let r = robot((2, 4), (2, -3));
+        let path: Vec<(i64, i64)> = (0..=5).map(|steps| r.position_after(steps, 11, 7)).collect();
+        assert_eq!(path, vec![(2, 4), (4, 1), (6, 5), (8, 2), (10, 6), (1, 3)]);
+        // the floor size is the period of every robot
+        assert_eq!(r.position_after(77, 11, 7), (2, 4));
+        // starting off the floor wraps onto it
+        assert_eq!(robot((-1, 8), (0, 0)).position_after(0, 11, 7), (10, 1));
+    }
+
+    #[test]
+    fn test_position_after_extremes() {
+        let r = robot((0, 0), (i64::MIN, i64::MAX));
+        let expected = ((i64::MIN as i128 * usize::MAX as i128).rem_euclid(101) as i64, (i64::MAX as i128 * usize::MAX as i128).rem_euclid(103) as i64);
+        assert_eq!(r.position_after(usize::MAX, 101, 103), expected);
+        assert_eq!(robot((i64::MAX, i64::MIN), (1, -1)).position_after(1, i64::MAX, i64::MAX), (1, i64::MAX - 2));
+    }
+
+    #[test]
+    fn test_safety_factor_middle() {
+        // the middle row and column of the 11 x 7 floor are x = 5 and y = 3
+        let positions = [(0, 0), (10, 0), (0, 6), (10, 6), (5, 0), (0, 3), (5, 3), (4, 2), (6, 4)];
+        assert_eq!(safety_factor(&positions, 11, 7), 4);
+        assert_eq!(safety_factor(&positions[4..], 11, 7), 0);
+        // an even floor has no middle, x = 2 is already on the right
+        assert_eq!(safety_factor(&[(1, 1), (2, 1), (1, 2), (2, 2)], 4, 4), 1);
+        assert_eq!(safety_factor(&[], 11, 7), 0);
+    }
+
+    #[test]
+    fn test_solution_floor() -> Result<()> {
+        let robots = parse_robots(SAMPLE)?;
+        // the sample moves on the puzzle floor unless told otherwise
+        assert_eq!(Day14::default().part1(SAMPLE)?, part1(&robots, FLOOR)?.to_string());
+        assert_eq!(Day14 { floor: (5, 5) }.part1(SAMPLE)?, part1(&robots, (5, 5))?.to_string());
+        let sample = Day14::default().sample_solution().map(|solution| solution.part1(SAMPLE));
+        assert_eq!(sample.transpose()?.as_deref(), Some(SAMPLE_ANSWERS[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_easter_egg_cluster() {
+        const EGG: usize = 4321;
+        let (width, height) = FLOOR;
+        let mut rng = aoc_testing::Rng::new(14);
+        let mut velocity = || (rng.range(0, 201) as i64 - 100, rng.range(0, 201) as i64 - 100);
+        // 60 robots fill an 8 x 8 square at the egg step, walked back to their start
+        let mut robots: Vec<Robot> = (0..60).map(|i| {
+            let velocity = velocity();
+            let back = robot((40 + i % 8, 50 + i / 8), (-velocity.0, -velocity.1)).position_after(EGG, width, height);
+            robot(back, velocity)
+        })
+        .collect();
+        // 40 more robots anywhere on the floor
+        let mut rng = aoc_testing::Rng::new(41);
+        robots.extend((0..40).map(|_| {
+            let position = (rng.range(0, width as u64) as i64, rng.range(0, height as u64) as i64);
+            robot(position, (rng.range(0, 201) as i64 - 100, rng.range(0, 201) as i64 - 100))
+        }));
+        assert_eq!(find_easter_egg(&robots, width, height), EGG);
+        let tuples: Vec<(i64, i64, i64, i64)> = robots.iter().map(|r| (r.position.0, r.position.1, r.velocity.0, r.velocity.1)).collect();
+        assert_eq!(aoc_testing::oracle::easter_egg(&tuples, width, height), EGG);
+    }
+
+    #[test]
+    fn test_find_easter_egg_edges() {
+        assert_eq!(find_easter_egg(&[], 11, 7), 0);
+        // robots that never move crowd the same at every step, the first step wins the tie
+        let still = [robot((0, 0), (0, 0)), robot((5, 5), (0, 0))];
+        assert_eq!(find_easter_egg(&still, 11, 7), 0);
+        // on a square floor the axes repeat together, the period is the side
+        let robots = [robot((0, 0), (1, 0)), robot((2, 0), (-1, 0))];
+        assert_eq!(find_easter_egg(&robots, 4, 4), 1);
+    }
+
+    #[test]
+    fn test_parse_robots_errors() {
+        let result = parse_robots("p=0,4 v=3,-3\np=0,4 x=3,-3\n");
+        assert!(matches!(&result, Err(Day14Error::MalformedRobot { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_robots("p=0;4 v=3,-3\n");
+        assert!(matches!(&result, Err(Day14Error::MalformedRobot { line: 1, .. })));
+        let result = parse_robots("\np=0,4 v=3,x\n");
+        assert!(matches!(&result, Err(Day14Error::Number { line: 2, token, .. }) if token == "x"));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse_robots("p=0,99999999999999999999 v=1,1\n"), Err(Day14Error::Number { line: 1, .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_robots_serde_round_trip() -> Result<()> {
+        let robots = parse_robots("p=0,4 v=3,-3\n")?;
+        let json = serde_json::to_string(&robots)?;
+        assert_eq!(json, r#"[{"position":[0,4],"velocity":[3,-3]}]"#);
+        assert_eq!(serde_json::from_str::<Robots>(&json)?, robots);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day14::default().describe(SAMPLE).problem, None);
+        assert_eq!(Day14::default().describe("p=0,4 v=3,-3\np=0,4\n").problem.as_deref(), Some("malformed robot p=0,4 on line 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day14 { floor: SAMPLE_FLOOR }.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day14(seed, 100);
+            assert_eq!(Day14::default().solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_position_after_matches_single_steps(
+            position in (-20i64..20, -20i64..20), velocity in (-150i64..150, -150i64..150),
+            steps in 0usize..300, width in 1i64..30, height in 1i64..30,
+        ) {
+            let mut expected = (position.0.rem_euclid(width), position.1.rem_euclid(height));
+            for _ in 0..steps {
+                expected = ((expected.0 + velocity.0).rem_euclid(width), (expected.1 + velocity.1).rem_euclid(height));
+            }
+            proptest::prop_assert_eq!(robot(position, velocity).position_after(steps, width, height), expected);
+        }
+    }
+}
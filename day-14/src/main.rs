@@ -0,0 +1,39 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+/// Argument at `position` parsed as a floor dimension, `default` when it is missing
+fn dimension(position: usize, name: &str, default: i64) -> Result<i64> {
+    let Some(arg) = std::env::args().nth(position) else {
+        return Ok(default);
+    };
+    let value: i64 = arg.parse().with_context(|| format!("invalid {} {}", name, arg))?;
+    anyhow::ensure!(value > 0, "{} must be positive, got {}", name, value);
+    Ok(value)
+}
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    // the floor is not part of the input, the sample moves on `11 7`
+    let (width, height) = day_14::FLOOR;
+    let floor = (dimension(2, "width", width)?, dimension(3, "height", height)?);
+
+    let (part1, part2) = day_14::Day14 { floor }.solve_both(&input.read_to_string()?)?;
+
+    println!("SAFETY_FACTOR: {}", part1);
+    println!("EASTER_EGG: {}", part2);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_14::Day14Error::kind)).into()
+        },
+    }
+}
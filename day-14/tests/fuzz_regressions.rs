@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_14` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_14", |data| {
+        if let Ok(robots) = day_14::parse_robots(&String::from_utf8_lossy(data)) {
+            let _ = day_14::part1(&robots, day_14::FLOOR);
+            let _ = day_14::part2(&robots, day_14::FLOOR);
+        }
+    });
+}
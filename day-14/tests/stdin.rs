@@ -0,0 +1,40 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-14"))
+    .args(["-", "11", "7"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-14");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_12.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-14");
+
+    assert!(output.status.success());
+    // the sample has no published answer of part 2
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("SAFETY_FACTOR: 12\nEASTER_EGG: "));
+}
+
+/// Exit code of day-14 solving the input piped to stdin
+fn exit_code(input: &str, args: &[&str]) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-14"))
+    .arg("-")
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-14");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-14").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("p=0,4 v=3,-3\n", &[]), Some(0));
+    assert_eq!(exit_code("p=0,4\n", &[]), Some(3));
+    assert_eq!(exit_code("p=0,4 v=3,-3\n", &["11", "7"]), Some(0));
+    assert_eq!(exit_code("p=0,4 v=3,-3\n", &["11", "0"]), exit_code("p=0,4 v=3,-3\n", &["x"]));
+    assert_ne!(exit_code("p=0,4 v=3,-3\n", &["x"]), Some(0));
+}
@@ -0,0 +1,684 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 15 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::collections::HashSet;
+
+/// Errors returned by the day 15 functions
+#[derive(Debug, Error)]
+pub enum Day15Error {
+    /// Lines of the map have different lengths
+    #[error("malformed warehouse map")]
+    Grid(#[from] GridError),
+    /// A tile of the map is neither a wall, a box, the robot nor empty
+    #[error("unexpected {tile} on line {line}, column {column}")]
+    UnknownTile {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        tile: char,
+    },
+    /// No robot (`@`) on the map
+    #[error("the map has no robot")]
+    MissingRobot,
+    /// More than one robot on the map
+    #[error("second robot on line {line}, column {column}")]
+    ExtraRobot {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+    },
+    /// A move is not one of `^`, `>`, `v` and `<`
+    #[error("unexpected move {character} on line {line}, column {column}")]
+    UnknownMove {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        character: char,
+    },
+}
+
+impl From<Day15Error> for SolutionError {
+    fn from(e: Day15Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day15Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day15Error::Grid(e) => e.location(),
+            Day15Error::UnknownTile { line, .. } | Day15Error::ExtraRobot { line, .. }
+            | Day15Error::UnknownMove { line, .. } => Some(Location::Line(*line)),
+            Day15Error::MissingRobot => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day15Error::Grid(_) | Day15Error::UnknownTile { .. } | Day15Error::MissingRobot
+            | Day15Error::ExtraRobot { .. } | Day15Error::UnknownMove { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 15 functions
+pub type Result<T, E = Day15Error> = std::result::Result<T, E>;
+
+/// Larger example input from the puzzle description
+pub const SAMPLE: &str = "##########\n#..O..O.O#\n#......O.#\n#.OO..O.O#\n#..O@..O.#\n\
+#O#..O...#\n#O..O..O.#\n#.OO.O.OO#\n#....O...#\n##########\n\n\
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^\n\
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v\n\
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<\n\
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^\n\
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><\n\
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^\n\
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^\n\
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>\n\
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>\n\
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["10092", "9021"];
+
+/// Content of a tile of the warehouse, the robot is kept apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tile {
+    /// Nothing on the tile, drawn as `.`
+    Empty,
+    /// A wall nothing moves through, drawn as `#`
+    Wall,
+    /// A box one tile wide, drawn as `O`
+    Box,
+    /// Left half of a box two tiles wide, drawn as `[`
+    BoxLeft,
+    /// Right half of a box two tiles wide, drawn as `]`
+    BoxRight,
+}
+
+impl Tile {
+    /// Character drawing the tile
+    pub fn symbol(self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+/// Move the robot attempts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Move {
+    /// Towards the first row, drawn as `^`
+    Up,
+    /// Towards the last column, drawn as `>`
+    Right,
+    /// Towards the last row, drawn as `v`
+    Down,
+    /// Towards the first column, drawn as `<`
+    Left,
+}
+
+impl Move {
+    /// Move drawn as `c`, `None` for any other character
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_15::Move;
+    /// 
+    /// assert_eq!(Move::from_arrow('v'), Some(Move::Down));
+    /// assert_eq!(Move::from_arrow('#'), None);
+    /// ```
+    pub fn from_arrow(c: char) -> Option<Move> {
+        match c {
+            '^' => Some(Move::Up),
+            '>' => Some(Move::Right),
+            'v' => Some(Move::Down),
+            '<' => Some(Move::Left),
+            _ => None,
+        }
+    }
+
+    /// Row and column offset of one step
+    pub fn step(self) -> (isize, isize) {
+        match self {
+            Move::Up => (-1, 0),
+            Move::Right => (0, 1),
+            Move::Down => (1, 0),
+            Move::Left => (0, -1),
+        }
+    }
+}
+
+/// Warehouse map with the boxes and the robot
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_15::Result<()> {
+///     let (warehouse, moves) = day_15::parse("####\n#@O#\n####\n\n<>\n")?;
+///     assert_eq!(warehouse.tiles[(1, 2)], day_15::Tile::Box);
+///     assert_eq!(warehouse.robot, (1, 1));
+///     assert_eq!(moves, vec![day_15::Move::Left, day_15::Move::Right]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Warehouse {
+    /// Tiles of the warehouse, the tile under the robot is [`Tile::Empty`]
+    pub tiles: Grid<Tile>,
+    /// Position of the robot
+    pub robot: Pos,
+}
+
+impl Warehouse {
+    /// Warehouse twice as wide, every tile becoming two
+    /// 
+    /// Walls and empty tiles double, a box becomes a box two tiles wide
+    /// and the robot stays on the left of its two tiles. Each half of a box
+    /// that is already wide becomes a whole wide box.
+    /// 
+    /// # Examples
+    /// ```
+    /// fn main() -> day_15::Result<()> {
+    ///     let (warehouse, _) = day_15::parse("#####\n#.O@#\n#####\n")?;
+    ///     assert_eq!(warehouse.widen().to_string(), "##########\n##..[]@.##\n##########\n");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn widen(&self) -> Warehouse {
+        let rows = self.tiles.rows().map(|row| {
+            row.iter().flat_map(|&tile| match tile {
+                Tile::Empty => [Tile::Empty, Tile::Empty],
+                Tile::Wall => [Tile::Wall, Tile::Wall],
+                Tile::Box | Tile::BoxLeft | Tile::BoxRight => [Tile::BoxLeft, Tile::BoxRight],
+            })
+            .collect::<Vec<Tile>>()
+        });
+        let tiles = Grid::from_rows(rows).expect("every row doubles");
+        Warehouse { tiles, robot: (self.robot.0, 2 * self.robot.1) }
+    }
+
+    /// Let the robot attempt a move, pushing the boxes in its way, `false` when a wall stops it
+    /// 
+    /// Starting from the robot, every tile that moves pushes the tile in front
+    /// of it. Pushing either half of a wide box up or down pushes the other
+    /// half too, so a push can spread into a tree of boxes. Nothing moves
+    /// when any tile of the tree would hit a wall or the edge of the map.
+    /// The tiles are found breadth first, one step further along the move
+    /// at a time, and move in reverse order so none overwrites another.
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_15::Move;
+    /// 
+    /// fn main() -> day_15::Result<()> {
+    ///     let (mut warehouse, _) = day_15::parse("######\n#@OO.#\n######\n")?;
+    ///     assert!(warehouse.try_move(Move::Right));
+    ///     assert_eq!(warehouse.to_string(), "######\n#.@OO#\n######\n");
+    ///     assert!(!warehouse.try_move(Move::Right));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_move(&mut self, step: Move) -> bool {
+        let (d_row, d_col) = step.step();
+        let vertical = d_row != 0;
+        let mut moving = vec![self.robot];
+        let mut seen = HashSet::from([self.robot]);
+        let mut i = 0;
+        while let Some(&pos) = moving.get(i) {
+            i += 1;
+            let Some((next, &tile)) = self.tiles.offset(pos, (d_row, d_col)) else {
+                return false;
+            };
+            let partner = match tile {
+                Tile::Wall => return false,
+                Tile::Empty => continue,
+                Tile::Box => None,
+                Tile::BoxLeft => vertical.then(|| (next.0, next.1 + 1)),
+                Tile::BoxRight => vertical.then(|| (next.0, next.1 - 1)),
+            };
+            for pushed in std::iter::once(next).chain(partner) {
+                if seen.insert(pushed) {
+                    moving.push(pushed);
+                }
+            }
+        }
+        for &pos in moving.iter().rev() {
+            let (next, _) = self.tiles.offset(pos, (d_row, d_col)).expect("checked before moving");
+            self.tiles[next] = self.tiles[pos];
+            self.tiles[pos] = Tile::Empty;
+        }
+        self.robot = self.tiles.offset(self.robot, (d_row, d_col)).expect("checked before moving").0;
+        true
+    }
+}
+
+impl std::fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, tiles) in self.tiles.rows().enumerate() {
+            for (col, tile) in tiles.iter().enumerate() {
+                let symbol = if (row, col) == self.robot { '@' } else { tile.symbol() };
+                write!(f, "{symbol}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Warehouse and the moves of the robot, parsed once for the two parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day15Input {
+    /// Warehouse before the robot moves
+    pub warehouse: Warehouse,
+    /// Moves the robot attempts, in order
+    pub moves: Vec<Move>,
+}
+
+impl TryFrom<&str> for Day15Input {
+    type Error = Day15Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut lines = text.lines().enumerate();
+        let mut robot = None;
+        // rows stop at the first failure, which is returned once the grid is built
+        let mut failure = None;
+        let rows = lines.by_ref().map_while(|(row, line)| {
+            // a blank line ends the map
+            if line.trim().is_empty() {
+                return None;
+            }
+            let mut tiles = Vec::with_capacity(line.len());
+            for (col, tile) in line.chars().enumerate() {
+                let (line, column) = (row + 1, col + 1);
+                tiles.push(match tile {
+                    '.' => Tile::Empty,
+                    '#' => Tile::Wall,
+                    'O' => Tile::Box,
+                    '@' if robot.is_some() => {
+                        failure = Some(Day15Error::ExtraRobot { line, column });
+                        return None;
+                    },
+                    '@' => {
+                        robot = Some((row, col));
+                        Tile::Empty
+                    },
+                    _ => {
+                        failure = Some(Day15Error::UnknownTile { line, column, tile });
+                        return None;
+                    },
+                });
+            }
+            Some(tiles)
+        });
+        let tiles = Grid::from_rows(rows)?;
+        if let Some(e) = failure {
+            return Err(e);
+        }
+        let robot = robot.ok_or(Day15Error::MissingRobot)?;
+        let moves = lines
+        .flat_map(|(row, line)| line.chars().enumerate().map(move |(col, c)| (row + 1, col + 1, c)))
+        .filter(|(_, _, c)| !c.is_whitespace())
+        .map(|(line, column, character)| Move::from_arrow(character)
+        .ok_or(Day15Error::UnknownMove { line, column, character }))
+        .collect::<Result<Vec<Move>>>()?;
+        Ok(Day15Input { warehouse: Warehouse { tiles, robot }, moves })
+    }
+}
+
+/// Parse the warehouse map and, after a blank line, the moves of the robot
+/// 
+/// The map has walls `#`, boxes `O`, empty tiles `.` and exactly one
+/// robot `@`. The moves are `^`, `>`, `v` and `<`, the line breaks between
+/// them do not matter.
+/// 
+/// # Examples
+/// ```
+/// let (warehouse, moves) = day_15::parse(day_15::SAMPLE).unwrap();
+/// assert_eq!(warehouse.robot, (4, 4));
+/// assert_eq!(moves.len(), 700);
+/// assert!(matches!(day_15::parse("#.#\n"), Err(day_15::Day15Error::MissingRobot)));
+/// ```
+pub fn parse(text: &str) -> Result<(Warehouse, Vec<Move>)> {
+    let input = Day15Input::try_from(text)?;
+    Ok((input.warehouse, input.moves))
+}
+
+/// Warehouse after the robot attempts every move in order
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_15::Result<()> {
+///     let (warehouse, moves) = day_15::parse("#####\n#@O.#\n#####\n\n>>>\n")?;
+///     assert_eq!(day_15::simulate(&warehouse, &moves).to_string(), "#####\n#.@O#\n#####\n");
+///     Ok(())
+/// }
+/// ```
+pub fn simulate(warehouse: &Warehouse, moves: &[Move]) -> Warehouse {
+    let mut warehouse = warehouse.clone();
+    for &step in moves {
+        warehouse.try_move(step);
+    }
+    warehouse
+}
+
+/// Sum of the GPS coordinates of the boxes, 100 times the row plus the column of their left edge
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_15::Result<()> {
+///     let (warehouse, _) = day_15::parse("#######\n#...O..\n#.....@\n")?;
+///     assert_eq!(day_15::gps_sum(&warehouse), 104);
+///     assert_eq!(day_15::gps_sum(&warehouse.widen()), 108);
+///     Ok(())
+/// }
+/// ```
+pub fn gps_sum(warehouse: &Warehouse) -> usize {
+    warehouse.tiles.iter()
+    .filter(|(_, &tile)| tile == Tile::Box || tile == Tile::BoxLeft)
+    .map(|((row, col), _)| 100 * row + col)
+    .sum()
+}
+
+/// Sum of the GPS coordinates of the boxes once the robot is done, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_15::Result<()> {
+///     let input = day_15::Day15Input::try_from(day_15::SAMPLE)?;
+///     assert_eq!(day_15::part1(&input), 10092);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day15Input) -> usize {
+    gps_sum(&simulate(&input.warehouse, &input.moves))
+}
+
+/// Sum of the GPS coordinates of the boxes once the robot is done in the
+/// [widened](Warehouse::widen) warehouse, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_15::Result<()> {
+///     let input = day_15::Day15Input::try_from(day_15::SAMPLE)?;
+///     assert_eq!(day_15::part2(&input), 9021);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day15Input) -> usize {
+    gps_sum(&simulate(&input.warehouse.widen(), &input.moves))
+}
+
+/// Day 15 solution used by the `aoc` runner
+/// 
+/// Part 1 sums the GPS coordinates of the boxes after the robot moves,
+/// part 2 does the same in the warehouse twice as wide
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_15::Day15.part1(day_15::SAMPLE)?, "10092");
+///     assert_eq!(day_15::Day15.part2(day_15::SAMPLE)?, "9021");
+///     Ok(())
+/// }
+/// ```
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day15Input::try_from(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day15Input::try_from(input)?).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day15Input::try_from(input)?;
+        Ok((input.solve(1)?, input.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day15Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Day15Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self).to_string()),
+            _ => Ok(part2(self).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a warehouse map and moves
+fn format_problem(input: &str) -> Option<String> {
+    parse(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 15 input with a `size` x `size` warehouse, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_15::generate(20, 7);
+/// assert_eq!(input.lines().take_while(|line| !line.is_empty()).count(), 20);
+/// assert_eq!(day_15::generate(20, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day15(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Smaller example from the puzzle description
+    const SMALL: &str = "########\n#..O.O.#\n##@.O..#\n#...O..#\n#.#.O..#\n#...O..#\n#......#\n########\n\n<^^>>>vv<v>>v<<\n";
+
+    /// Warehouse drawn with wide boxes, which [`parse`] does not read
+    fn wide(text: &str) -> Warehouse {
+        let grid: Grid<char> = text.parse().expect("rows have the same length");
+        let robot = grid.find(|&c| c == '@').expect("a robot");
+        let tiles = grid.map(|&c| match c {
+            '#' => Tile::Wall,
+            'O' => Tile::Box,
+            '[' => Tile::BoxLeft,
+            ']' => Tile::BoxRight,
+            _ => Tile::Empty,
+        });
+        Warehouse { tiles, robot }
+    }
+
+    #[test]
+    fn test_simulate_small_sample() -> Result<()> {
+        let (warehouse, moves) = parse(SMALL)?;
+        let end = simulate(&warehouse, &moves);
+        assert_eq!(end.to_string(), "########\n#....OO#\n##.....#\n#.....O#\n#.#O@..#\n#...O..#\n#...O..#\n########\n");
+        assert_eq!(gps_sum(&end), 2028);
+        Ok(())
+    }
+
+    #[test]
+    fn test_simulate_sample() -> Result<()> {
+        let (warehouse, moves) = parse(SAMPLE)?;
+        let end = simulate(&warehouse, &moves);
+        assert_eq!(end.to_string(), "##########\n#.O.O.OOO#\n#........#\n#OO......#\n#OO@.....#\n\
+#O#.....O#\n#O.....OO#\n#O.....OO#\n#OO....OO#\n##########\n");
+        assert_eq!(gps_sum(&end), 10092);
+        assert_eq!(Day15.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_simulate_wide_sample() -> Result<()> {
+        let (warehouse, moves) = parse(SAMPLE)?;
+        let end = simulate(&warehouse.widen(), &moves);
+        assert_eq!(end.to_string(), "####################\n##[].......[].[][]##\n##[]...........[].##\n\
+##[]........[][][]##\n##[]......[]....[]##\n##..##......[]....##\n##..[]............##\n\
+##..@......[].[][]##\n##......[][]..[]..##\n####################\n");
+        assert_eq!(gps_sum(&end), 9021);
+        assert_eq!(Day15.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day15.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_move_chain_against_wall() -> Result<()> {
+        let (mut warehouse, _) = parse("#######\n#@OOO#.\n#######\n")?;
+        let before = warehouse.clone();
+        assert!(!warehouse.try_move(Move::Right));
+        assert_eq!(warehouse, before);
+        // the robot alone walks into the wall too
+        let (mut warehouse, _) = parse("###\n#@#\n###\n")?;
+        assert!(!warehouse.try_move(Move::Up));
+        assert_eq!(warehouse.robot, (1, 1));
+        // nothing is pushed off a map without walls around it
+        let (mut warehouse, _) = parse("@O\n")?;
+        assert!(!warehouse.try_move(Move::Right));
+        assert!(!warehouse.try_move(Move::Left));
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_move_wide_horizontal() {
+        let mut warehouse = wide("##########\n##@[][].##\n##########\n");
+        assert!(warehouse.try_move(Move::Right));
+        assert_eq!(warehouse.to_string(), "##########\n##.@[][]##\n##########\n");
+        assert!(!warehouse.try_move(Move::Right));
+    }
+
+    #[test]
+    fn test_try_move_wide_half_blocked() {
+        // the right half of the box is under a wall
+        let mut warehouse = wide("##########\n##...#..##\n##..[]..##\n##...@..##\n##########\n");
+        let before = warehouse.clone();
+        assert!(!warehouse.try_move(Move::Up));
+        assert_eq!(warehouse, before);
+        // the box on the right half of the lower box is stuck, so is the lower box
+        let mut warehouse = wide("##########\n##....#.##\n##...[].##\n##..[]..##\n##..@...##\n##########\n");
+        let before = warehouse.clone();
+        assert!(!warehouse.try_move(Move::Up));
+        assert_eq!(warehouse, before);
+        // without the wall the whole tree moves up
+        let mut warehouse = wide("##########\n##......##\n##...[].##\n##..[]..##\n##..@...##\n##########\n");
+        assert!(warehouse.try_move(Move::Up));
+        assert_eq!(warehouse.to_string(), "##########\n##...[].##\n##..[]..##\n##..@...##\n##......##\n##########\n");
+    }
+
+    #[test]
+    fn test_try_move_wide_diamond() {
+        // the top box is pushed by both boxes below it and moves once
+        let mut warehouse = wide("##########\n##......##\n##..[]..##\n##.[][].##\n##..[]..##\n##..@...##\n##########\n");
+        assert!(warehouse.try_move(Move::Up));
+        assert_eq!(
+            warehouse.to_string(),
+            "##########\n##..[]..##\n##.[][].##\n##..[]..##\n##..@...##\n##......##\n##########\n"
+        );
+        assert_eq!(gps_sum(&warehouse), 104 + 203 + 205 + 304);
+    }
+
+    #[test]
+    fn test_widen() -> Result<()> {
+        let (warehouse, _) = parse(SMALL)?;
+        let warehouse = warehouse.widen();
+        assert_eq!((warehouse.tiles.width(), warehouse.tiles.height()), (16, 8));
+        assert_eq!(warehouse.robot, (2, 4));
+        assert_eq!(wide("[]@\n").widen().to_string(), "[][]@.\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse("###\n#@x\n");
+        assert!(matches!(&result, Err(Day15Error::UnknownTile { line: 2, column: 3, tile: 'x' })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse("#@#\n#@#\n"), Err(Day15Error::ExtraRobot { line: 2, column: 2 })));
+        assert!(matches!(parse("###\n#.#\n"), Err(Day15Error::MissingRobot)));
+        assert!(matches!(parse("###\n#@\n"), Err(Day15Error::Grid(_))));
+        let result = parse("#@#\n\n<>\n^x\n");
+        assert!(matches!(&result, Err(Day15Error::UnknownMove { line: 4, column: 2, character: 'x' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        // the moves may be missing, or broken over lines with stray spaces
+        assert!(matches!(parse("#@#\n"), Result::Ok((_, moves)) if moves.is_empty()));
+        assert!(matches!(parse("#@#\n\n< >\n\n^\n"), Result::Ok((_, moves)) if moves == vec![Move::Left, Move::Right, Move::Up]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day15Input::try_from("#@O\n\n<\n")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"{"warehouse":{"tiles":[["Wall","Empty","Box"]],"robot":[0,1]},"moves":["Left"]}"#);
+        assert_eq!(serde_json::from_str::<Day15Input>(&json)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day15.describe(SAMPLE).problem, None);
+        assert_eq!(Day15.describe("#@#\n\n<a\n").problem.as_deref(), Some("unexpected move a on line 3, column 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day15.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day15(seed, 30);
+            assert_eq!(Day15.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
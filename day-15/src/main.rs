@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let input = day_15::Day15Input::try_from(input.read_to_string()?.as_str())?;
+
+    println!("GPS_SUM: {}", day_15::part1(&input));
+    println!("WIDE_GPS_SUM: {}", day_15::part2(&input));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_15::Day15Error::kind)).into()
+        },
+    }
+}
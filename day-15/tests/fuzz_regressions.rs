@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_15` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_15", |data| {
+        if let Ok(input) = day_15::Day15Input::try_from(String::from_utf8_lossy(data).as_ref()) {
+            let _ = day_15::part1(&input);
+            let _ = day_15::part2(&input);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-15"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-15");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_10092.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-15");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "GPS_SUM: 10092\nWIDE_GPS_SUM: 9021\n");
+}
+
+/// Exit code of day-15 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-15"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-15");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-15").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("#@O\n\n<>\n"), Some(0));
+    assert_eq!(exit_code("#@x\n"), Some(3));
+}
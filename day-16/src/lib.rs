@@ -0,0 +1,517 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 16 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Errors returned by the day 16 functions
+#[derive(Debug, Error)]
+pub enum Day16Error {
+    /// Lines of the maze have different lengths
+    #[error("malformed maze")]
+    Grid(#[from] GridError),
+    /// A tile is neither a wall, empty, the start nor the end
+    #[error("unexpected {tile} on line {line}, column {column}")]
+    UnknownTile {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        tile: char,
+    },
+    /// No start (`S`) or no end (`E`) in the maze
+    #[error("the maze has no {tile}")]
+    MissingTile {
+        /// The missing tile, `S` or `E`
+        tile: char,
+    },
+    /// More than one start or end in the maze
+    #[error("second {tile} on line {line}, column {column}")]
+    ExtraTile {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The repeated tile, `S` or `E`
+        tile: char,
+    },
+    /// Walls separate the end from the start
+    #[error("the end cannot be reached from the start")]
+    NoPath,
+}
+
+impl From<Day16Error> for SolutionError {
+    fn from(e: Day16Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day16Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day16Error::Grid(e) => e.location(),
+            Day16Error::UnknownTile { line, .. } | Day16Error::ExtraTile { line, .. } => Some(Location::Line(*line)),
+            Day16Error::MissingTile { .. } | Day16Error::NoPath => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day16Error::Grid(_) | Day16Error::UnknownTile { .. } | Day16Error::MissingTile { .. }
+            | Day16Error::ExtraTile { .. } => ErrorKind::Parse,
+            Day16Error::NoPath => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 16 functions
+pub type Result<T, E = Day16Error> = std::result::Result<T, E>;
+
+/// First example input from the puzzle description
+pub const SAMPLE: &str = "###############\n#.......#....E#\n#.#.###.#.###.#\n#.....#.#...#.#\n#.###.#####.#.#\n\
+#.#.#.......#.#\n#.#.#####.###.#\n#...........#.#\n###.#.#####.#.#\n#...#.....#.#.#\n#.#.#.###.#.#.#\n\
+#.....#...#.#.#\n#.###.#.#.#.#.#\n#S..#.....#...#\n###############\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["7036", "45"];
+
+/// Score of a step forward
+pub const STEP_COST: u64 = 1;
+
+/// Score of turning 90 degrees on the spot
+pub const TURN_COST: u64 = 1000;
+
+/// Direction the reindeer is facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Towards the first row
+    North,
+    /// Towards the last column, the way the reindeer faces at the start
+    East,
+    /// Towards the last row
+    South,
+    /// Towards the first column
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Directions after turning 90 degrees counterclockwise and clockwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_16::Direction;
+    /// 
+    /// assert_eq!(Direction::North.turns(), [Direction::West, Direction::East]);
+    /// ```
+    pub fn turns(self) -> [Direction; 2] {
+        match self {
+            Direction::North => [Direction::West, Direction::East],
+            Direction::East => [Direction::North, Direction::South],
+            Direction::South => [Direction::East, Direction::West],
+            Direction::West => [Direction::South, Direction::North],
+        }
+    }
+
+    /// Row and column offset of one step forward
+    pub fn step(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+        }
+    }
+}
+
+/// Tile of the reindeer and the direction it faces
+pub type State = (Pos, Direction);
+
+/// Maze with its walls, start and end, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_16::Result<()> {
+///     let maze = day_16::parse_maze("#####\n#S.E#\n#####\n")?;
+///     assert_eq!((maze.walls.width(), maze.walls.height()), (5, 3));
+///     assert!(maze.walls[(0, 2)]);
+///     assert_eq!((maze.start, maze.end), ((1, 1), (1, 3)));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Maze {
+    /// Tiles of the maze, `true` where there is a wall
+    pub walls: Grid<bool>,
+    /// Tile the reindeer starts on, facing [`Direction::East`]
+    pub start: Pos,
+    /// Tile the reindeer has to reach, facing any direction
+    pub end: Pos,
+}
+
+impl TryFrom<&str> for Maze {
+    type Error = Day16Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let (walls, start, end) = Grid::parse_maze(text)?;
+        Ok(Maze { walls, start, end })
+    }
+}
+
+impl From<MazeError> for Day16Error {
+    fn from(e: MazeError) -> Self {
+        match e {
+            MazeError::Grid(e) => Day16Error::Grid(e),
+            MazeError::UnknownTile { line, column, tile } => Day16Error::UnknownTile { line, column, tile },
+            MazeError::MissingTile { tile } => Day16Error::MissingTile { tile },
+            MazeError::ExtraTile { line, column, tile } => Day16Error::ExtraTile { line, column, tile },
+        }
+    }
+}
+
+/// Parse the maze from text
+/// 
+/// Walls are `#` and empty tiles `.`, with exactly one start `S` and one
+/// end `E`. Tiles off the map count as walls.
+/// 
+/// # Examples
+/// ```
+/// let maze = day_16::parse_maze(day_16::SAMPLE).unwrap();
+/// assert_eq!(maze.start, (13, 1));
+/// assert!(matches!(day_16::parse_maze("S.\n.."), Err(day_16::Day16Error::MissingTile { tile: 'E' })));
+/// ```
+pub fn parse_maze(text: &str) -> Result<Maze> {
+    Maze::try_from(text)
+}
+
+/// Lowest score of every state settled by the search and the states it is reached from with that score
+struct Search {
+    costs: HashMap<State, u64>,
+    predecessors: HashMap<State, Vec<State>>,
+    best: Option<u64>,
+}
+
+/// Dijkstra over the states of the reindeer, from the start facing east
+/// until every state scoring at most as much as the best end state is settled
+fn search(maze: &Maze) -> Search {
+    let start = (maze.start, Direction::East);
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut predecessors: HashMap<State, Vec<State>> = HashMap::new();
+    let mut best = None;
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        // stale entries were pushed before a lower score was found
+        if cost > costs[&state] {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        let (pos, facing) = state;
+        if pos == maze.end {
+            best.get_or_insert(cost);
+        }
+        let forward = maze.walls.offset(pos, facing.step())
+        .filter(|(_, &wall)| !wall)
+        .map(|(next, _)| ((next, facing), cost + STEP_COST));
+        let turns = facing.turns().map(|turned| ((pos, turned), cost + TURN_COST));
+        for (next, next_cost) in forward.into_iter().chain(turns) {
+            match costs.get(&next) {
+                Some(&known) if next_cost > known => {},
+                Some(&known) if next_cost == known => predecessors.entry(next).or_default().push(state),
+                _ => {
+                    costs.insert(next, next_cost);
+                    predecessors.insert(next, vec![state]);
+                    queue.push(Reverse((next_cost, next)));
+                },
+            }
+        }
+    }
+    Search { costs, predecessors, best }
+}
+
+/// Lowest score of a path from the start to the end, `None` when walls separate them
+/// 
+/// Every step forward scores [`STEP_COST`] and every 90 degree turn
+/// [`TURN_COST`], so a longer path with fewer turns can score lower.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_16::Result<()> {
+///     let maze = day_16::parse_maze(day_16::SAMPLE)?;
+///     assert_eq!(day_16::lowest_score(&maze), Some(7036));
+///     // the end is behind the reindeer, it turns around first
+///     assert_eq!(day_16::lowest_score(&day_16::parse_maze("#E.S#")?), Some(2002));
+///     Ok(())
+/// }
+/// ```
+pub fn lowest_score(maze: &Maze) -> Option<u64> {
+    search(maze).best
+}
+
+/// Tiles on any path from the start to the end with the lowest score, `None` when walls separate them
+/// 
+/// The search keeps every state each state is reached from with its lowest
+/// score. Following those back from the end states with the lowest score
+/// walks every best path at once.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_16::Result<()> {
+///     let maze = day_16::parse_maze(day_16::SAMPLE)?;
+///     assert_eq!(day_16::best_path_tiles(&maze).map(|tiles| tiles.len()), Some(45));
+///     Ok(())
+/// }
+/// ```
+pub fn best_path_tiles(maze: &Maze) -> Option<HashSet<Pos>> {
+    let search = search(maze);
+    let best = search.best?;
+    let mut stack: Vec<State> = Direction::ALL.into_iter()
+    .map(|facing| (maze.end, facing))
+    .filter(|state| search.costs.get(state) == Some(&best))
+    .collect();
+    let mut seen: HashSet<State> = stack.iter().copied().collect();
+    while let Some(state) = stack.pop() {
+        for &previous in search.predecessors.get(&state).into_iter().flatten() {
+            if seen.insert(previous) {
+                stack.push(previous);
+            }
+        }
+    }
+    Some(seen.into_iter().map(|(pos, _)| pos).collect())
+}
+
+/// Lowest score the reindeer can get, the answer of part 1
+/// 
+/// Fails with [`Day16Error::NoPath`] when walls separate the end from the start
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_16::Result<()> {
+///     let maze = day_16::parse_maze(day_16::SAMPLE)?;
+///     assert_eq!(day_16::part1(&maze)?, 7036);
+///     let walled = day_16::parse_maze("S#E")?;
+///     assert!(matches!(day_16::part1(&walled), Err(day_16::Day16Error::NoPath)));
+///     Ok(())
+/// }
+/// ```
+pub fn part1(maze: &Maze) -> Result<u64> {
+    lowest_score(maze).ok_or(Day16Error::NoPath)
+}
+
+/// Number of tiles on at least one of the best paths, the answer of part 2
+/// 
+/// Fails with [`Day16Error::NoPath`] when walls separate the end from the start
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_16::Result<()> {
+///     let maze = day_16::parse_maze(day_16::SAMPLE)?;
+///     assert_eq!(day_16::part2(&maze)?, 45);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(maze: &Maze) -> Result<usize> {
+    best_path_tiles(maze).map(|tiles| tiles.len()).ok_or(Day16Error::NoPath)
+}
+
+/// Day 16 solution used by the `aoc` runner
+/// 
+/// Part 1 finds the lowest score through the maze, part 2 counts the
+/// tiles on the paths with that score
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_16::Day16.part1(day_16::SAMPLE)?, "7036");
+///     assert_eq!(day_16::Day16.part2(day_16::SAMPLE)?, "45");
+///     Ok(())
+/// }
+/// ```
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_maze(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_maze(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let maze = parse_maze(input)?;
+        Ok((maze.solve(1)?, maze.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_maze(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Maze {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a maze
+fn format_problem(input: &str) -> Option<String> {
+    parse_maze(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 16 input with a `size` x `size` maze, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_16::generate(21, 7);
+/// assert_eq!(input.lines().count(), 21);
+/// assert_eq!(day_16::generate(21, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day16(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Second example from the puzzle description
+    const SECOND_SAMPLE: &str = "#################\n#...#...#...#..E#\n#.#.#.#.#.#.#.#.#\n#.#.#.#...#...#.#\n\
+#.#.#.#.###.#.#.#\n#...#.#.#.....#.#\n#.#.#.#.#.#####.#\n#.#...#.#.#.....#\n#.#.#####.#.###.#\n\
+#.#.#.......#...#\n#.#.###.#####.###\n#.#.#...#.....#.#\n#.#.#.#####.###.#\n#.#.#.........#.#\n\
+#.#.#.#########.#\n#S#.............#\n#################\n";
+
+    #[test]
+    fn test_samples() -> Result<()> {
+        let maze = parse_maze(SAMPLE)?;
+        assert_eq!(part1(&maze)?, 7036);
+        assert_eq!(part2(&maze)?, 45);
+        let maze = parse_maze(SECOND_SAMPLE)?;
+        assert_eq!(part1(&maze)?, 11048);
+        assert_eq!(part2(&maze)?, 64);
+        assert_eq!(Day16.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_turn_late() -> Result<()> {
+        // every shortest walk through the room reaches the end, only the one turning once scores lowest
+        let maze = parse_maze("######\n#...E#\n#....#\n#S...#\n######\n")?;
+        assert_eq!(lowest_score(&maze), Some(TURN_COST + 5 * STEP_COST));
+        let tiles = best_path_tiles(&maze).unwrap_or_default();
+        assert_eq!(tiles, HashSet::from([(3, 1), (3, 2), (3, 3), (3, 4), (2, 4), (1, 4)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_longer_path_fewer_turns() -> Result<()> {
+        // the staircase is 10 steps but turns 9 times, around the outside is 16 steps and 4 turns
+        let maze = parse_maze("###########\n#######E..#\n######..#.#\n#####..##.#\n####..###.#\n\
+###..####.#\n##S.#####.#\n#.........#\n###########\n")?;
+        assert_eq!(lowest_score(&maze), Some(4 * TURN_COST + 16 * STEP_COST));
+        // turning south on the start or one step east of it scores the same
+        let tiles = best_path_tiles(&maze).unwrap_or_default();
+        assert_eq!(tiles.len(), 18);
+        assert!(tiles.contains(&(7, 2)) && tiles.contains(&(6, 3)) && !tiles.contains(&(5, 3)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_start_on_end() -> Result<()> {
+        let maze = parse_maze("#.#\n#SE\n")?;
+        assert_eq!(part1(&maze)?, 1);
+        assert_eq!(part2(&maze)?, 2);
+        // a reindeer surrounded by walls turns nowhere
+        let maze = parse_maze("###\n#S#\n###\n#E#\n")?;
+        assert!(matches!(part1(&maze), Err(Day16Error::NoPath)));
+        assert!(matches!(part2(&maze), Err(Day16Error::NoPath)));
+        assert_eq!(Day16Error::NoPath.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_maze("#S.\n#x.\n");
+        assert!(matches!(&result, Err(Day16Error::UnknownTile { line: 2, column: 2, tile: 'x' })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse_maze("SE\nE.\n"), Err(Day16Error::ExtraTile { line: 2, column: 1, tile: 'E' })));
+        assert!(matches!(parse_maze("S.\nS.\n"), Err(Day16Error::ExtraTile { line: 2, column: 1, tile: 'S' })));
+        assert!(matches!(parse_maze(".E\n"), Err(Day16Error::MissingTile { tile: 'S' })));
+        assert!(matches!(parse_maze("SE\n.\n"), Err(Day16Error::Grid(_))));
+        assert_eq!(parse_maze("").unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_maze_serde_round_trip() -> Result<()> {
+        let maze = parse_maze("#SE\n")?;
+        let json = serde_json::to_string(&maze)?;
+        assert_eq!(json, r#"{"walls":[[true,false,false]],"start":[0,1],"end":[0,2]}"#);
+        assert_eq!(serde_json::from_str::<Maze>(&json)?, maze);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day16.describe(SAMPLE).problem, None);
+        assert_eq!(Day16.describe("#S#\n").problem.as_deref(), Some("the maze has no E"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day16.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day16(seed, 41);
+            assert_eq!(Day16.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let maze = day_16::parse_maze(&input.read_to_string()?)?;
+
+    println!("LOWEST_SCORE: {}", day_16::part1(&maze)?);
+    println!("BEST_PATH_TILES: {}", day_16::part2(&maze)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_16::Day16Error::kind)).into()
+        },
+    }
+}
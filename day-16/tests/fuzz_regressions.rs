@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_16` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_16", |data| {
+        if let Ok(maze) = day_16::parse_maze(&String::from_utf8_lossy(data)) {
+            let _ = day_16::part1(&maze);
+            let _ = day_16::part2(&maze);
+        }
+    });
+}
@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-16"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-16");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_7036.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-16");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "LOWEST_SCORE: 7036\nBEST_PATH_TILES: 45\n");
+}
+
+/// Exit code of day-16 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-16"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-16");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-16").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("#SE\n"), Some(0));
+    assert_eq!(exit_code("#Sx\n"), Some(3));
+}
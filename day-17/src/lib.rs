@@ -0,0 +1,678 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 17 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 17 functions
+#[derive(Debug, Error)]
+pub enum Day17Error {
+    /// A line does not have the format of the register or program line expected there
+    #[error("expected {expected} on line {line}, found {text}")]
+    MalformedLine {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Label the line should start with
+        expected: &'static str,
+        /// Text of the line
+        text: String,
+    },
+    /// The input ends before a register or the program
+    #[error("missing {expected} line")]
+    MissingLine {
+        /// Label of the missing line
+        expected: &'static str,
+    },
+    /// A register or a program value is not a number
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// A program value does not fit 3 bits
+    #[error("program value {value} on line {line} is not a 3-bit number")]
+    NotThreeBit {
+        /// Line number (starting from 1)
+        line: usize,
+        /// The value above 7
+        value: u8,
+    },
+    /// An instruction reads the reserved combo operand 7
+    #[error("reserved combo operand 7 at instruction {ip}")]
+    ReservedOperand {
+        /// Instruction pointer of the instruction
+        ip: usize,
+    },
+    /// The program runs for more than [`STEP_LIMIT`] instructions
+    #[error("the program did not halt within {steps} instructions")]
+    StepLimit {
+        /// Instructions executed before giving up
+        steps: usize,
+    },
+    /// No initial value of register A makes the program output itself
+    #[error("no value of register A makes the program output itself")]
+    NoQuine,
+}
+
+impl From<Day17Error> for SolutionError {
+    fn from(e: Day17Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day17Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day17Error::MalformedLine { line, .. } | Day17Error::Number { line, .. }
+            | Day17Error::NotThreeBit { line, .. } => Some(Location::Line(*line)),
+            Day17Error::MissingLine { .. } | Day17Error::ReservedOperand { .. } | Day17Error::StepLimit { .. }
+            | Day17Error::NoQuine => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day17Error::MalformedLine { .. } | Day17Error::MissingLine { .. } | Day17Error::Number { .. }
+            | Day17Error::NotThreeBit { .. } => ErrorKind::Parse,
+            Day17Error::ReservedOperand { .. } | Day17Error::StepLimit { .. } | Day17Error::NoQuine => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 17 functions
+pub type Result<T, E = Day17Error> = std::result::Result<T, E>;
+
+/// Example input of part 2 from the puzzle description, a program that can output itself
+pub const SAMPLE: &str = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["5,7,3,0", "117440"];
+
+/// Instructions executed before a run gives up on the program halting
+pub const STEP_LIMIT: usize = 1_000_000;
+
+/// Instruction of the computer, the first 3-bit value of every pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    /// Divide A by 2 to the power of the combo operand into A
+    Adv,
+    /// Bitwise XOR of B and the literal operand into B
+    Bxl,
+    /// Combo operand modulo 8 into B
+    Bst,
+    /// Jump to the literal operand unless A is zero
+    Jnz,
+    /// Bitwise XOR of B and C into B, ignoring the operand
+    Bxc,
+    /// Output the combo operand modulo 8
+    Out,
+    /// Divide A by 2 to the power of the combo operand into B
+    Bdv,
+    /// Divide A by 2 to the power of the combo operand into C
+    Cdv,
+}
+
+impl Opcode {
+    /// Instruction with the 3-bit code `bits`, `None` above 7
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_17::Opcode;
+    /// 
+    /// assert_eq!(Opcode::from_bits(3), Some(Opcode::Jnz));
+    /// assert_eq!(Opcode::from_bits(8), None);
+    /// ```
+    pub fn from_bits(bits: u8) -> Option<Opcode> {
+        match bits {
+            0 => Some(Opcode::Adv),
+            1 => Some(Opcode::Bxl),
+            2 => Some(Opcode::Bst),
+            3 => Some(Opcode::Jnz),
+            4 => Some(Opcode::Bxc),
+            5 => Some(Opcode::Out),
+            6 => Some(Opcode::Bdv),
+            7 => Some(Opcode::Cdv),
+            _ => None,
+        }
+    }
+}
+
+/// Program of 3-bit values, opcodes and operands in turn
+/// 
+/// # Examples
+/// ```
+/// let program = day_17::Program(vec![0, 3, 5, 4, 3, 0]);
+/// assert_eq!(program.to_string(), "0,3,5,4,3,0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program(pub Vec<u8>);
+
+impl std::fmt::Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&join(&self.0))
+    }
+}
+
+/// Values joined with commas
+fn join(values: &[u8]) -> String {
+    values.iter().map(u8::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// A divided by 2 to the power of `exponent`, zero once the power is past [`u64`]
+fn divide(a: u64, exponent: u64) -> u64 {
+    u32::try_from(exponent).ok().and_then(|exponent| a.checked_shr(exponent)).unwrap_or(0)
+}
+
+/// Registers and instruction pointer of the 3-bit computer
+/// 
+/// # Examples
+/// ```
+/// use day_17::{Machine, Program};
+/// 
+/// fn main() -> day_17::Result<()> {
+///     let mut machine = Machine { a: 0, b: 0, c: 9, ip: 0 };
+///     machine.run(&Program(vec![2, 6]))?;
+///     assert_eq!(machine.b, 1);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Machine {
+    /// Register A
+    pub a: u64,
+    /// Register B
+    pub b: u64,
+    /// Register C
+    pub c: u64,
+    /// Index of the next opcode in the program
+    pub ip: usize,
+}
+
+impl Machine {
+    /// Value of a combo operand, 0 to 3 themselves and 4 to 6 the registers A to C
+    /// 
+    /// Fails with [`Day17Error::ReservedOperand`] for the reserved operand 7
+    pub fn combo(&self, operand: u8) -> Result<u64> {
+        match operand {
+            0..=3 => Ok(u64::from(operand)),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(Day17Error::ReservedOperand { ip: self.ip }),
+        }
+    }
+
+    /// Whether the machine halted, the instruction pointer being past the last opcode of the `program`
+    /// 
+    /// An opcode on the last value of the program has no operand to read,
+    /// so the machine halts there too.
+    pub fn halted(&self, program: &Program) -> bool {
+        self.ip + 1 >= program.0.len()
+    }
+
+    /// Execute the instruction at the instruction pointer, returning the value it outputs
+    /// 
+    /// Every instruction but a jump taken moves the instruction pointer
+    /// past its operand. Nothing happens once the machine
+    /// [halted](Machine::halted).
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_17::{Machine, Program};
+    /// 
+    /// fn main() -> day_17::Result<()> {
+    ///     let program = Program(vec![5, 0, 5, 4]);
+    ///     let mut machine = Machine { a: 12, b: 0, c: 0, ip: 2 };
+    ///     assert_eq!(machine.step(&program)?, Some(4));
+    ///     assert_eq!(machine.ip, 4);
+    ///     assert_eq!(machine.step(&program)?, None);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn step(&mut self, program: &Program) -> Result<Option<u8>> {
+        let (Some(opcode), Some(&operand)) = (program.0.get(self.ip).copied().and_then(Opcode::from_bits), program.0.get(self.ip + 1)) else {
+            return Ok(None);
+        };
+        let mut output = None;
+        match opcode {
+            Opcode::Adv => self.a = divide(self.a, self.combo(operand)?),
+            Opcode::Bxl => self.b ^= u64::from(operand),
+            Opcode::Bst => self.b = self.combo(operand)? % 8,
+            Opcode::Jnz if self.a != 0 => {
+                self.ip = usize::from(operand);
+                return Ok(None);
+            },
+            Opcode::Jnz => {},
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => output = Some((self.combo(operand)? % 8) as u8),
+            Opcode::Bdv => self.b = divide(self.a, self.combo(operand)?),
+            Opcode::Cdv => self.c = divide(self.a, self.combo(operand)?),
+        }
+        self.ip += 2;
+        Ok(output)
+    }
+
+    /// Run the `program` until the machine halts, returning every value it outputs
+    /// 
+    /// Fails with [`Day17Error::StepLimit`] when the program is still
+    /// running after [`STEP_LIMIT`] instructions.
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_17::{Machine, Program};
+    /// 
+    /// fn main() -> day_17::Result<()> {
+    ///     let mut machine = Machine { a: 2024, b: 0, c: 0, ip: 0 };
+    ///     assert_eq!(machine.run(&Program(vec![0, 1, 5, 4, 3, 0]))?, [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    ///     assert_eq!(machine.a, 0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn run(&mut self, program: &Program) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        for _ in 0..STEP_LIMIT {
+            if self.halted(program) {
+                return Ok(output);
+            }
+            output.extend(self.step(program)?);
+        }
+        if self.halted(program) {
+            Ok(output)
+        } else {
+            Err(Day17Error::StepLimit { steps: STEP_LIMIT })
+        }
+    }
+}
+
+/// Registers and program of the computer, parsed once for the two parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day17Input {
+    /// Machine with the starting registers
+    pub machine: Machine,
+    /// Program the machine runs
+    pub program: Program,
+}
+
+impl TryFrom<&str> for Day17Input {
+    type Error = Day17Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut lines = text.lines().enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+        let mut next = |label: &'static str| {
+            let (line, text) = lines.next().ok_or(Day17Error::MissingLine { expected: label })?;
+            let values = text.strip_prefix(label).and_then(|rest| rest.strip_prefix(": "))
+            .ok_or_else(|| Day17Error::MalformedLine { line, expected: label, text: text.to_string() })?;
+            Ok((line, values))
+        };
+        let mut register = |label: &'static str| {
+            let (line, value) = next(label)?;
+            value.parse::<u64>().map_err(|source| Day17Error::Number { line, token: value.to_string(), source })
+        };
+        let (a, b, c) = (register("Register A")?, register("Register B")?, register("Register C")?);
+        let (line, values) = next("Program")?;
+        let program = values.split(',')
+        .map(|token| {
+            let value = token.trim().parse::<u8>()
+            .map_err(|source| Day17Error::Number { line, token: token.to_string(), source })?;
+            if value < 8 { Ok(value) } else { Err(Day17Error::NotThreeBit { line, value }) }
+        })
+        .collect::<Result<Vec<u8>>>()?;
+        Ok(Day17Input { machine: Machine { a, b, c, ip: 0 }, program: Program(program) })
+    }
+}
+
+/// Parse the registers A, B and C and the program
+/// 
+/// # Examples
+/// ```
+/// let (machine, program) = day_17::parse(day_17::SAMPLE).unwrap();
+/// assert_eq!((machine.a, machine.b, machine.c, machine.ip), (2024, 0, 0, 0));
+/// assert_eq!(program.0, [0, 3, 5, 4, 3, 0]);
+/// assert!(matches!(day_17::parse("Register A: 1\n"), Err(day_17::Day17Error::MissingLine { expected: "Register B" })));
+/// ```
+pub fn parse(text: &str) -> Result<(Machine, Program)> {
+    let input = Day17Input::try_from(text)?;
+    Ok((input.machine, input.program))
+}
+
+/// Values the `program` outputs when run on a copy of the `machine`, joined with commas
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_17::Result<()> {
+///     let (machine, program) = day_17::parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n")?;
+///     assert_eq!(day_17::run_program(&machine, &program)?, "4,6,3,5,6,3,5,2,1,0");
+///     Ok(())
+/// }
+/// ```
+pub fn run_program(machine: &Machine, program: &Program) -> Result<String> {
+    Ok(join(&machine.clone().run(program)?))
+}
+
+/// Lowest A of a machine already holding `a` with its last 3-bit digit still to
+/// choose, outputting the last `matched + 1` values of the `program`
+fn quine_digits(program: &Program, a: u64, matched: usize) -> Option<u64> {
+    if matched == program.0.len() {
+        return Some(a);
+    }
+    let high = a.checked_mul(8)?;
+    let tail = &program.0[program.0.len() - matched - 1..];
+    (0..8).map(|digit| high + digit)
+    .filter(|&a| Machine { a, b: 0, c: 0, ip: 0 }.run(program).is_ok_and(|output| output == tail))
+    .find_map(|a| quine_digits(program, a, matched + 1))
+}
+
+/// Lowest initial A, with B and C zero, making the `program` output itself, `None` when there is none
+/// 
+/// Like every puzzle input the program has to be a loop outputting one
+/// value and shifting A right by 3 bits on each pass, until A is zero.
+/// The last pass sees only the highest 3-bit digit of A, so the digits are
+/// chosen from the highest down, each the lowest one making the program
+/// output its last values, backtracking when no digit below it fits.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_17::Result<()> {
+///     let (_, program) = day_17::parse(day_17::SAMPLE)?;
+///     assert_eq!(day_17::find_quine_a(&program), Some(117440));
+///     Ok(())
+/// }
+/// ```
+pub fn find_quine_a(program: &Program) -> Option<u64> {
+    quine_digits(program, 0, 0)
+}
+
+/// Output of the program, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_17::Result<()> {
+///     let input = day_17::Day17Input::try_from(day_17::SAMPLE)?;
+///     assert_eq!(day_17::part1(&input)?, "5,7,3,0");
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day17Input) -> Result<String> {
+    run_program(&input.machine, &input.program)
+}
+
+/// Lowest A making the program output itself, the answer of part 2
+/// 
+/// Fails with [`Day17Error::NoQuine`] when there is no such A
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_17::Result<()> {
+///     let input = day_17::Day17Input::try_from(day_17::SAMPLE)?;
+///     assert_eq!(day_17::part2(&input)?, 117440);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day17Input) -> Result<u64> {
+    find_quine_a(&input.program).ok_or(Day17Error::NoQuine)
+}
+
+/// Day 17 solution used by the `aoc` runner
+/// 
+/// Part 1 runs the program, part 2 finds the A making it output itself
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_17::Day17.part1(day_17::SAMPLE)?, "5,7,3,0");
+///     assert_eq!(day_17::Day17.part2(day_17::SAMPLE)?, "117440");
+///     Ok(())
+/// }
+/// ```
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day17Input::try_from(input)?)?)
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day17Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day17Input::try_from(input)?;
+        Ok((input.solve(1)?, input.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day17Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Day17Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like the registers and a program
+fn format_problem(input: &str) -> Option<String> {
+    parse(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 17 input with `size` 3-bit digits in register A, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_17::generate(10, 7);
+/// assert!(input.starts_with("Register A: "));
+/// assert_eq!(day_17::generate(10, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day17(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Example input of part 1 from the puzzle description
+    const FIRST_SAMPLE: &str = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+
+    /// Machine with the registers after running the `program` and its output
+    fn run(a: u64, b: u64, c: u64, program: &[u8]) -> Result<(Machine, Vec<u8>)> {
+        let mut machine = Machine { a, b, c, ip: 0 };
+        let output = machine.run(&Program(program.to_vec()))?;
+        Ok((machine, output))
+    }
+
+    #[test]
+    fn test_samples() -> Result<()> {
+        let (machine, program) = parse(FIRST_SAMPLE)?;
+        assert_eq!(run_program(&machine, &program)?, "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(Day17.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        assert_eq!(Day17.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_adv() -> Result<()> {
+        assert_eq!(run(100, 0, 0, &[0, 2])?.0.a, 25);
+        // the combo operand 5 reads B
+        assert_eq!(run(100, 3, 0, &[0, 5])?.0.a, 12);
+        // dividing by 2 to the power of 64 or more leaves nothing
+        assert_eq!(run(u64::MAX, 63, 0, &[0, 5])?.0.a, 1);
+        assert_eq!(run(u64::MAX, 64, 0, &[0, 5])?.0.a, 0);
+        assert_eq!(run(u64::MAX, u64::MAX, 0, &[0, 5])?.0.a, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bxl() -> Result<()> {
+        assert_eq!(run(0, 29, 0, &[1, 7])?.0.b, 26);
+        // the literal operand 4 is not register A
+        assert_eq!(run(9, 1, 0, &[1, 4])?.0.b, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bst() -> Result<()> {
+        assert_eq!(run(0, 0, 9, &[2, 6])?.0.b, 1);
+        assert_eq!(run(0, 0, 0, &[2, 3])?.0.b, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_jnz() -> Result<()> {
+        // halves A until it is zero, outputting it on every pass
+        assert_eq!(run(8, 0, 0, &[0, 1, 5, 4, 3, 0])?.1, [4, 2, 1, 0]);
+        assert_eq!(run(24, 0, 0, &[0, 3, 5, 4, 3, 0])?.1, [3, 0]);
+        // A zero falls through to the next instruction
+        let (machine, output) = run(0, 0, 0, &[3, 0, 5, 3])?;
+        assert_eq!((machine.ip, output), (4, vec![3]));
+        // a jump onto the last value halts, it has no operand
+        let (machine, output) = run(1, 0, 0, &[3, 3, 5])?;
+        assert_eq!((machine.ip, output), (3, vec![]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bxc() -> Result<()> {
+        assert_eq!(run(0, 2024, 43690, &[4, 0])?.0.b, 44354);
+        // the operand is read but ignored, even the reserved 7
+        assert_eq!(run(0, 1, 2, &[4, 7])?.0.b, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_out() -> Result<()> {
+        assert_eq!(run(10, 0, 0, &[5, 0, 5, 1, 5, 4])?.1, [0, 1, 2]);
+        assert_eq!(run(2024, 0, 0, &[0, 1, 5, 4, 3, 0])?.1, [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(run(0, 0, 15, &[5, 6])?.1, [7]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bdv_cdv() -> Result<()> {
+        let (machine, _) = run(100, 0, 0, &[6, 3, 7, 2])?;
+        assert_eq!((machine.a, machine.b, machine.c), (100, 12, 25));
+        // only the target register changes
+        let (machine, _) = run(100, 5, 2, &[7, 6])?;
+        assert_eq!((machine.a, machine.b, machine.c), (100, 5, 25));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let mut machine = Machine { a: 1, b: 0, c: 0, ip: 0 };
+        let result = machine.run(&Program(vec![5, 4, 0, 7]));
+        assert!(matches!(&result, Err(Day17Error::ReservedOperand { ip: 2 })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Solver);
+        // A never changes, so the program loops
+        let mut machine = Machine { a: 1, b: 0, c: 0, ip: 0 };
+        let result = machine.run(&Program(vec![3, 0]));
+        assert!(matches!(result, Err(Day17Error::StepLimit { steps: STEP_LIMIT })));
+        // an empty program halts before it starts
+        assert_eq!(Machine { a: 1, b: 0, c: 0, ip: 0 }.run(&Program(Vec::new())).ok(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_find_quine_a() -> Result<()> {
+        let (_, program) = parse(SAMPLE)?;
+        let a = find_quine_a(&program).unwrap_or_default();
+        assert_eq!(a, 117440);
+        assert_eq!(Machine { a, b: 0, c: 0, ip: 0 }.run(&program)?, program.0);
+        assert_eq!(find_quine_a(&Program(Vec::new())), Some(0));
+        // a program that never outputs cannot output itself
+        assert_eq!(find_quine_a(&Program(vec![0, 3, 3, 0])), None);
+        let input = Day17Input::try_from("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,3,0\n")?;
+        assert!(matches!(part2(&input), Err(Day17Error::NoQuine)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse("Register A: 1\nRegister B: x\n");
+        assert!(matches!(&result, Err(Day17Error::Number { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse("Register A: 1\nRegister C: 0\n"), Err(Day17Error::MalformedLine { line: 2, expected: "Register B", .. })));
+        assert!(matches!(parse("Register A: 1\nRegister B: 0\nRegister C: 0\n"), Err(Day17Error::MissingLine { expected: "Program" })));
+        let result = parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8\n");
+        assert!(matches!(&result, Err(Day17Error::NotThreeBit { line: 5, value: 8 })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse("Register A: -1\n"), Err(Day17Error::Number { line: 1, .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day17Input::try_from(SAMPLE)?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"{"machine":{"a":2024,"b":0,"c":0,"ip":0},"program":[0,3,5,4,3,0]}"#);
+        assert_eq!(serde_json::from_str::<Day17Input>(&json)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day17.describe(SAMPLE).problem, None);
+        assert_eq!(Day17.describe("Register A: 1\n").problem.as_deref(), Some("missing Register B line"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day17.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day17(seed, 16);
+            assert_eq!(Day17.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
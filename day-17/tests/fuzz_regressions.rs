@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_17` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_17", |data| {
+        if let Ok(input) = day_17::Day17Input::try_from(String::from_utf8_lossy(data).as_ref()) {
+            let _ = day_17::part1(&input);
+            let _ = day_17::part2(&input);
+        }
+    });
+}
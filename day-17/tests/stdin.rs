@@ -0,0 +1,36 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-17"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-17");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_117440.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-17");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "OUTPUT: 5,7,3,0\nQUINE_A: 117440\n");
+}
+
+/// Exit code of day-17 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-17"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-17");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-17").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n"), Some(0));
+    assert_eq!(exit_code("Register A: x\n"), Some(3));
+    assert_eq!(exit_code("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4\n"), Some(4));
+}
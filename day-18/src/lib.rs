@@ -0,0 +1,484 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 18 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::{collections::VecDeque, num::ParseIntError};
+
+/// Errors returned by the day 18 functions
+#[derive(Debug, Error)]
+pub enum Day18Error {
+    /// A line is not a pair of coordinates `x,y`
+    #[error("expected x,y on line {line}, found {text}")]
+    MalformedByte {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A coordinate is not a number that fits `usize`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// The fallen bytes cut the exit off from the start
+    #[error("the exit cannot be reached once {fallen} bytes fell")]
+    NoPath {
+        /// Bytes fallen before the search
+        fallen: usize,
+    },
+    /// The exit can still be reached once every byte fell
+    #[error("no byte cuts the exit off")]
+    NeverBlocked,
+}
+
+impl From<Day18Error> for SolutionError {
+    fn from(e: Day18Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day18Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day18Error::MalformedByte { line, .. } | Day18Error::Number { line, .. } => Some(Location::Line(*line)),
+            Day18Error::NoPath { .. } | Day18Error::NeverBlocked => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day18Error::MalformedByte { .. } | Day18Error::Number { .. } => ErrorKind::Parse,
+            Day18Error::NoPath { .. } | Day18Error::NeverBlocked => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 18 functions
+pub type Result<T, E = Day18Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n\
+6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["22", "6,1"];
+
+/// Side of the memory space and bytes fallen before part 1 in [`SAMPLE`]
+pub const SAMPLE_SPACE: (usize, usize) = (7, 12);
+
+/// Side of the memory space and bytes fallen before part 1 in the puzzle input
+pub const SPACE: (usize, usize) = (71, 1024);
+
+/// Column X and row Y of a byte, counted from the top left corner
+pub type Point = (usize, usize);
+
+/// Bytes in the order they fall, parsed once for the two parts
+///
+/// # Examples
+/// ```
+/// fn main() -> day_18::Result<()> {
+///     let bytes = day_18::Bytes::try_from("5,4\n4,2\n")?;
+///     assert_eq!(bytes.0, [(5, 4), (4, 2)]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bytes(pub Vec<Point>);
+
+impl TryFrom<&str> for Bytes {
+    type Error = Day18Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_byte(i + 1, line.trim()))
+        .collect::<Result<Vec<Point>>>()
+        .map(Bytes)
+    }
+}
+
+/// Parse one `x,y` line
+fn parse_byte(line: usize, text: &str) -> Result<Point> {
+    let (x, y) = text.split_once(',').ok_or_else(|| Day18Error::MalformedByte { line, text: text.to_string() })?;
+    let number = |token: &str| token.trim().parse::<usize>()
+    .map_err(|source| Day18Error::Number { line, token: token.to_string(), source });
+    Ok((number(x)?, number(y)?))
+}
+
+/// Parse the coordinates of a byte on every line, blank lines are skipped
+///
+/// # Examples
+/// ```
+/// let bytes = day_18::parse_bytes(day_18::SAMPLE).unwrap();
+/// assert_eq!(bytes.0.len(), 25);
+/// assert_eq!(bytes.0[20], (6, 1));
+/// assert!(matches!(day_18::parse_bytes("5;4\n"), Err(day_18::Day18Error::MalformedByte { line: 1, .. })));
+/// ```
+pub fn parse_bytes(text: &str) -> Result<Bytes> {
+    Bytes::try_from(text)
+}
+
+/// Fewest steps from the top left to the bottom right corner of a `size` x
+/// `size` memory space with the `bytes` corrupted, `None` when they cut it off
+///
+/// The search is breadth first, one step up, down, left or right at a
+/// time. Bytes outside the memory space corrupt nothing.
+///
+/// # Examples
+/// ```
+/// fn main() -> day_18::Result<()> {
+///     let bytes = day_18::parse_bytes(day_18::SAMPLE)?;
+///     assert_eq!(day_18::shortest_path(&bytes.0[..12], 7), Some(22));
+///     assert_eq!(day_18::shortest_path(&[], 3), Some(4));
+///     assert_eq!(day_18::shortest_path(&[(1, 0), (0, 1)], 3), None);
+///     Ok(())
+/// }
+/// ```
+pub fn shortest_path(bytes: &[Point], size: usize) -> Option<usize> {
+    let mut corrupted = Grid::new(size, size, false);
+    for &(x, y) in bytes {
+        if let Some(cell) = corrupted.get_mut(y, x) {
+            *cell = true;
+        }
+    }
+    let exit = (size.checked_sub(1)?, size - 1);
+    let mut steps = Grid::new(size, size, None);
+    let mut queue = VecDeque::new();
+    if !corrupted[(0, 0)] {
+        steps[(0, 0)] = Some(0);
+        queue.push_back((0, 0));
+    }
+    while let Some(pos) = queue.pop_front() {
+        let here = steps[pos]?;
+        if pos == exit {
+            return Some(here);
+        }
+        for next in corrupted.neighbors4(pos) {
+            if !corrupted[next] && steps[next].is_none() {
+                steps[next] = Some(here + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// First byte whose fall cuts the exit off from the start, `None` when the exit is reachable to the end
+///
+/// Once cut off the exit stays cut off, so a binary search over the number
+/// of fallen bytes needs a [`shortest_path`] for only a logarithmic number
+/// of them.
+///
+/// # Examples
+/// ```
+/// fn main() -> day_18::Result<()> {
+///     let bytes = day_18::parse_bytes(day_18::SAMPLE)?;
+///     assert_eq!(day_18::first_blocking_byte(&bytes.0, 7), Some((6, 1)));
+///     assert_eq!(day_18::first_blocking_byte(&bytes.0[..20], 7), None);
+///     Ok(())
+/// }
+/// ```
+pub fn first_blocking_byte(bytes: &[Point], size: usize) -> Option<Point> {
+    let reachable = |fallen: usize| shortest_path(&bytes[..fallen], size).is_some();
+    if !reachable(0) || reachable(bytes.len()) {
+        return None;
+    }
+    // the exit is reachable after `low` bytes and cut off after `high`
+    let (mut low, mut high) = (0, bytes.len());
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if reachable(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(bytes[high - 1])
+}
+
+/// Fewest steps to the exit of the `(side, fallen)` memory space once the first bytes fell, the answer of part 1
+///
+/// The input does not say how large the memory space is, the puzzle
+/// input falls on [`SPACE`] and the sample on [`SAMPLE_SPACE`].
+/// Fails with [`Day18Error::NoPath`] when they cut the exit off
+///
+/// # Examples
+/// ```
+/// fn main() -> day_18::Result<()> {
+///     let bytes = day_18::parse_bytes(day_18::SAMPLE)?;
+///     assert_eq!(day_18::part1(&bytes, day_18::SAMPLE_SPACE)?, 22);
+///     assert_eq!(day_18::part1(&bytes, day_18::SPACE)?, 146);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(bytes: &Bytes, (size, fallen): (usize, usize)) -> Result<usize> {
+    let fallen = fallen.min(bytes.0.len());
+    shortest_path(&bytes.0[..fallen], size).ok_or(Day18Error::NoPath { fallen })
+}
+
+/// Coordinates `x,y` of the first byte cutting the exit of the `(side, _)` memory space off, the answer of part 2
+///
+/// Fails with [`Day18Error::NeverBlocked`] when the exit is reachable after every byte
+///
+/// # Examples
+/// ```
+/// fn main() -> day_18::Result<()> {
+///     let bytes = day_18::parse_bytes(day_18::SAMPLE)?;
+///     assert_eq!(day_18::part2(&bytes, day_18::SAMPLE_SPACE)?, "6,1");
+///     Ok(())
+/// }
+/// ```
+pub fn part2(bytes: &Bytes, (size, _): (usize, usize)) -> Result<String> {
+    let (x, y) = first_blocking_byte(&bytes.0, size).ok_or(Day18Error::NeverBlocked)?;
+    Ok(format!("{x},{y}"))
+}
+
+/// Bytes parsed for the runner with the memory space they fall on
+struct Memory {
+    bytes: Bytes,
+    space: (usize, usize),
+}
+
+impl Memory {
+    fn parse(input: &str, space: (usize, usize)) -> Result<Self> {
+        Ok(Memory { bytes: parse_bytes(input)?, space })
+    }
+}
+
+/// Day 18 solution used by the `aoc` runner
+///
+/// Part 1 finds the fewest steps to the exit, part 2 the first byte
+/// cutting it off. The default drops the bytes on the [`SPACE`] of the
+/// puzzle input.
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     let sample = day_18::Day18 { space: day_18::SAMPLE_SPACE };
+///     assert_eq!(sample.part1(day_18::SAMPLE)?, "22");
+///     assert_eq!(sample.part2(day_18::SAMPLE)?, "6,1");
+///     assert_eq!(day_18::Day18::default().space, day_18::SPACE);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day18 {
+    /// Side of the memory space and bytes fallen before part 1
+    pub space: (usize, usize),
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Day18 { space: SPACE }
+    }
+}
+
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Memory::parse(input, self.space)?.solve(1)
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Memory::parse(input, self.space)?.solve(2)
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let memory = Memory::parse(input, self.space)?;
+        Ok((memory.solve(1)?, memory.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn sample_solution(&self) -> Option<Box<dyn Solution>> {
+        Some(Box::new(Day18 { space: SAMPLE_SPACE }))
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Memory::parse(input, self.space)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Memory {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(&self.bytes, self.space)?.to_string()),
+            _ => Ok(part2(&self.bytes, self.space)?),
+        }
+    }
+}
+
+/// Why the input does not look like falling bytes
+fn format_problem(input: &str) -> Option<String> {
+    parse_bytes(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 18 input with `size` bytes past the first one cutting the exit off, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_18::generate(10, 7);
+/// assert!(input.lines().count() > 1024);
+/// assert_eq!(day_18::generate(10, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day18(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let bytes = parse_bytes(SAMPLE)?;
+        assert_eq!(shortest_path(&bytes.0[..12], 7), Some(22));
+        assert_eq!(first_blocking_byte(&bytes.0, 7), Some((6, 1)));
+        assert_eq!(Day18 { space: SAMPLE_SPACE }.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_space() -> Result<()> {
+        // the sample falls on the puzzle memory space unless told otherwise
+        assert_eq!(Day18::default().part1(SAMPLE)?, "146");
+        assert_eq!(Day18 { space: (7, 20) }.part1(SAMPLE)?, part1(&parse_bytes(SAMPLE)?, (7, 20))?.to_string());
+        let sample = Day18::default().sample_solution().map(|solution| solution.solve_both(SAMPLE));
+        assert_eq!(sample.transpose()?, Some((SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_already_blocked() -> Result<()> {
+        // the first byte falls on the start, or on the exit
+        assert_eq!(first_blocking_byte(&[(0, 0), (1, 1)], 3), Some((0, 0)));
+        assert_eq!(first_blocking_byte(&[(2, 2), (1, 1)], 3), Some((2, 2)));
+        let bytes = parse_bytes("0,0\n")?;
+        assert!(matches!(part1(&bytes, SPACE), Err(Day18Error::NoPath { fallen: 1 })));
+        assert_eq!(part2(&bytes, SPACE)?, "0,0");
+        // a memory space without cells has no start to cut off
+        assert_eq!(shortest_path(&[], 0), None);
+        assert_eq!(first_blocking_byte(&[(0, 0)], 0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_never_blocked() -> Result<()> {
+        // bytes outside the memory space or fallen twice leave a way around
+        let bytes = parse_bytes("1,1\n1,1\n3,3\n9,0\n")?;
+        assert_eq!(first_blocking_byte(&bytes.0, 3), None);
+        assert_eq!(shortest_path(&bytes.0, 3), Some(4));
+        assert!(matches!(part2(&bytes, SPACE), Err(Day18Error::NeverBlocked)));
+        assert_eq!(Day18Error::NeverBlocked.kind(), ErrorKind::Solver);
+        assert_eq!(shortest_path(&[], 1), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocking_boundary_exact() {
+        // a wall across the space closes with its last byte, wherever that falls in the list
+        let wall: Vec<Point> = (0..5).map(|x| (x, 2)).collect();
+        for extra in 0..12 {
+            // the bytes before it fall along the bottom row, some of them twice, never on the exit
+            let mut bytes: Vec<Point> = (0..extra).map(|i| (i % 4, 4)).collect();
+            let before = bytes.len();
+            bytes.extend(&wall);
+            bytes.extend([(0, 3), (1, 3)]);
+            let fallen = before + wall.len();
+            assert!(shortest_path(&bytes[..fallen - 1], 5).is_some());
+            assert!(shortest_path(&bytes[..fallen], 5).is_none());
+            assert_eq!(first_blocking_byte(&bytes, 5), Some((4, 2)));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_bytes("1,2\n3\n");
+        assert!(matches!(&result, Err(Day18Error::MalformedByte { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_bytes("1,-2\n");
+        assert!(matches!(&result, Err(Day18Error::Number { line: 1, .. })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse_bytes("\n\n"), Result::Ok(bytes) if bytes.0.is_empty()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bytes_serde_round_trip() -> Result<()> {
+        let bytes = parse_bytes("5,4\n4,2\n")?;
+        let json = serde_json::to_string(&bytes)?;
+        assert_eq!(json, "[[5,4],[4,2]]");
+        assert_eq!(serde_json::from_str::<Bytes>(&json)?, bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day18::default().describe(SAMPLE).problem, None);
+        assert_eq!(Day18::default().describe("5,4\n4-2\n").problem.as_deref(), Some("expected x,y on line 2, found 4-2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day18 { space: SAMPLE_SPACE }.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day18(seed, 100);
+            assert_eq!(Day18::default().solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_first_blocking_byte_matches_every_prefix(
+            bytes in proptest::collection::vec((0usize..6, 0usize..6), 0..40), size in 1usize..6,
+        ) {
+            let expected = (1..=bytes.len())
+            .find(|&fallen| shortest_path(&bytes[..fallen], size).is_none())
+            .map(|fallen| bytes[fallen - 1]);
+            proptest::prop_assert_eq!(first_blocking_byte(&bytes, size), expected);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+/// Argument at `position` parsed as a number, `default` when it is missing
+fn number(position: usize, name: &str, default: usize) -> Result<usize> {
+    let Some(arg) = std::env::args().nth(position) else {
+        return Ok(default);
+    };
+    arg.parse().with_context(|| format!("invalid {} {}", name, arg))
+}
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    // the memory space is not part of the input, the sample falls on `7 12`
+    let (size, fallen) = day_18::SPACE;
+    let space = (number(2, "size", size)?, number(3, "fallen", fallen)?);
+
+    let (part1, part2) = day_18::Day18 { space }.solve_both(&input.read_to_string()?)?;
+
+    println!("MIN_STEPS: {}", part1);
+    println!("FIRST_BLOCKING_BYTE: {}", part2);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_18::Day18Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_18` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_18", |data| {
+        if let Ok(bytes) = day_18::parse_bytes(&String::from_utf8_lossy(data)) {
+            let _ = day_18::part1(&bytes, day_18::SPACE);
+            let _ = day_18::part2(&bytes, day_18::SPACE);
+        }
+    });
+}
@@ -0,0 +1,40 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-18"))
+    .args(["-", "7", "12"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-18");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_22.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-18");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "MIN_STEPS: 22\nFIRST_BLOCKING_BYTE: 6,1\n");
+}
+
+/// Exit code of day-18 solving the input piped to stdin
+fn exit_code(input: &str, args: &[&str]) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-18"))
+    .arg("-")
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-18");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-18").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code(&format!("{}1,0\n0,1\n", "3,3\n".repeat(1024)), &[]), Some(0));
+    assert_eq!(exit_code("1;1\n", &[]), Some(3));
+    assert_eq!(exit_code("0,0\n", &[]), Some(4));
+    // the same bytes fall on a space of the given size
+    assert_eq!(exit_code("3,3\n1,0\n0,1\n", &["7", "1"]), Some(0));
+    assert_ne!(exit_code("3,3\n", &["-7"]), Some(0));
+}
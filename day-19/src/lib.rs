@@ -0,0 +1,524 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 19 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 19 functions
+#[derive(Debug, Error)]
+pub enum Day19Error {
+    /// The input has no line of towel patterns
+    #[error("the input has no towel patterns")]
+    MissingPatterns,
+    /// Two commas of the pattern line have nothing between them
+    #[error("empty towel pattern on line {line}")]
+    EmptyPattern {
+        /// Line number (starting from 1)
+        line: usize,
+    },
+    /// A stripe is none of the colors `w`, `u`, `b`, `r` and `g`
+    #[error("unexpected {color} on line {line}, column {column}")]
+    UnknownColor {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        color: char,
+    },
+    /// The number of ways to arrange a design does not fit `u64`
+    #[error("the arrangements of design {design} overflowed")]
+    Overflow {
+        /// Design number (starting from 1)
+        design: usize,
+    },
+    /// The number of ways to arrange all the designs does not fit `u64`
+    #[error("the total number of arrangements overflowed")]
+    TotalOverflow,
+}
+
+impl From<Day19Error> for SolutionError {
+    fn from(e: Day19Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day19Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day19Error::EmptyPattern { line } | Day19Error::UnknownColor { line, .. } => Some(Location::Line(*line)),
+            Day19Error::MissingPatterns | Day19Error::Overflow { .. } | Day19Error::TotalOverflow => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day19Error::MissingPatterns | Day19Error::EmptyPattern { .. } | Day19Error::UnknownColor { .. } => ErrorKind::Parse,
+            Day19Error::Overflow { .. } | Day19Error::TotalOverflow => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 19 functions
+pub type Result<T, E = Day19Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr\nbggr\ngbbr\nrrbgbr\nubwu\nbwurrg\nbrgr\nbbrgwb\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["6", "16"];
+
+/// Colors a stripe can have, white, blue, black, red and green
+pub const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// Position of the stripe in [`COLORS`]
+fn color(stripe: u8) -> Option<usize> {
+    COLORS.iter().position(|&color| color as u8 == stripe)
+}
+
+/// Towel patterns on offer and the designs to arrange, parsed once for the two parts
+///
+/// # Examples
+/// ```
+/// fn main() -> day_19::Result<()> {
+///     let towels = day_19::Towels::try_from("r, wr\n\nwrr\n")?;
+///     assert_eq!(towels.patterns, ["r", "wr"]);
+///     assert_eq!(towels.designs, ["wrr"]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Towels {
+    /// Stripes of every towel pattern, any number of each is available
+    pub patterns: Vec<String>,
+    /// Stripes of every design to arrange
+    pub designs: Vec<String>,
+}
+
+impl TryFrom<&str> for Towels {
+    type Error = Day19Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut lines = text.lines().enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+        let (line, patterns) = lines.next().ok_or(Day19Error::MissingPatterns)?;
+        check_colors(line, patterns, ", ")?;
+        let patterns = patterns.split(',')
+        .map(|pattern| match pattern.trim() {
+            "" => Err(Day19Error::EmptyPattern { line }),
+            pattern => Ok(pattern.to_string()),
+        })
+        .collect::<Result<Vec<String>>>()?;
+        let designs = lines
+        .map(|(line, design)| check_colors(line, design, "").map(|()| design.to_string()))
+        .collect::<Result<Vec<String>>>()?;
+        Ok(Towels { patterns, designs })
+    }
+}
+
+/// Check every character of the line is a stripe color or one of the `separators`
+fn check_colors(line: usize, text: &str, separators: &str) -> Result<()> {
+    match text.chars().enumerate().find(|&(_, c)| !COLORS.contains(&c) && !separators.contains(c)) {
+        Some((i, color)) => Err(Day19Error::UnknownColor { line, column: i + 1, color }),
+        None => Ok(()),
+    }
+}
+
+/// Parse the comma separated towel patterns on the first line and a design on every line after them
+///
+/// # Examples
+/// ```
+/// let towels = day_19::parse_towels(day_19::SAMPLE).unwrap();
+/// assert_eq!(towels.patterns.len(), 8);
+/// assert_eq!(towels.designs[0], "brwrr");
+/// assert!(matches!(day_19::parse_towels("r, , b\n"), Err(day_19::Day19Error::EmptyPattern { line: 1 })));
+/// ```
+pub fn parse_towels(text: &str) -> Result<Towels> {
+    Towels::try_from(text)
+}
+
+/// Node of the [`TowelIndex`] trie
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Node after one more stripe of each of the [`COLORS`], 0 for none since the root is never a child
+    children: [u32; 5],
+    /// Whether the stripes leading to the node are a whole pattern
+    towel: bool,
+}
+
+/// Trie of the towel patterns, stripe by stripe
+///
+/// Matching the patterns at a position of a design follows a single
+/// branch of the trie, instead of comparing the design against every one
+/// of the hundreds of patterns of a puzzle input.
+///
+/// # Examples
+/// ```
+/// let index = day_19::TowelIndex::new(&["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+/// assert_eq!(index.arrangements("rrbgbr"), Some(6));
+/// assert_eq!(index.arrangements("ubwu"), Some(0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TowelIndex {
+    nodes: Vec<Node>,
+}
+
+impl TowelIndex {
+    /// Index of the `patterns`, stripes of any other color are never matched
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let mut nodes = vec![Node::default()];
+        for pattern in patterns {
+            let Some(stripes) = pattern.as_ref().bytes().map(color).collect::<Option<Vec<usize>>>() else {
+                continue;
+            };
+            let mut node = 0;
+            for stripe in stripes {
+                if nodes[node].children[stripe] == 0 {
+                    nodes[node].children[stripe] = nodes.len() as u32;
+                    nodes.push(Node::default());
+                }
+                node = nodes[node].children[stripe] as usize;
+            }
+            nodes[node].towel = true;
+        }
+        TowelIndex { nodes }
+    }
+
+    /// Lengths of the patterns the `stripes` start with, shortest first
+    fn prefixes<'a>(&'a self, stripes: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        stripes.iter()
+        .scan(0, |node, &stripe| {
+            let next = self.nodes[*node].children[color(stripe)?];
+            *node = next as usize;
+            (next != 0).then_some(self.nodes[*node].towel)
+        })
+        .enumerate()
+        .filter_map(|(i, towel)| towel.then_some(i + 1))
+    }
+
+    /// Number of ways to arrange the `design` out of the patterns, `None` when it does not fit `u64`
+    ///
+    /// Counted over the prefixes of the design, each one adding its ways to
+    /// the longer prefixes it is a pattern away from. The empty design has
+    /// the single arrangement of no towels.
+    pub fn arrangements(&self, design: &str) -> Option<u64> {
+        let stripes = design.as_bytes();
+        let mut ways = vec![0u64; stripes.len() + 1];
+        ways[0] = 1;
+        for start in 0..stripes.len() {
+            if ways[start] == 0 {
+                continue;
+            }
+            for len in self.prefixes(&stripes[start..]) {
+                ways[start + len] = ways[start + len].checked_add(ways[start])?;
+            }
+        }
+        Some(ways[stripes.len()])
+    }
+}
+
+/// [`TowelIndex::arrangements`] comparing every pattern against every suffix of the design
+///
+/// It is kept to check and benchmark the [`TowelIndex`]
+///
+/// # Examples
+/// ```
+/// let patterns = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+/// let index = day_19::TowelIndex::new(&patterns);
+/// assert_eq!(day_19::arrangements_naive(&patterns, "gbbr"), Some(4));
+/// assert_eq!(day_19::arrangements_naive(&patterns, "bbrgwb"), index.arrangements("bbrgwb"));
+/// ```
+pub fn arrangements_naive<S: AsRef<str>>(patterns: &[S], design: &str) -> Option<u64> {
+    // a pattern listed twice is still a single pattern, as in the trie
+    let patterns: HashSet<&str> = patterns.iter().map(AsRef::as_ref).filter(|pattern| !pattern.is_empty()).collect();
+    let mut ways = vec![0u64; design.len() + 1];
+    ways[design.len()] = 1;
+    for start in (0..design.len()).rev() {
+        for pattern in &patterns {
+            if design[start..].starts_with(pattern) {
+                ways[start] = ways[start].checked_add(ways[start + pattern.len()])?;
+            }
+        }
+    }
+    Some(ways[0])
+}
+
+/// Number of ways to arrange each of the designs, 0 for the impossible ones
+///
+/// # Examples
+/// ```
+/// fn main() -> day_19::Result<()> {
+///     let towels = day_19::parse_towels(day_19::SAMPLE)?;
+///     assert_eq!(day_19::design_counts(&towels)?, [2, 1, 4, 6, 0, 1, 2, 0]);
+///     Ok(())
+/// }
+/// ```
+pub fn design_counts(towels: &Towels) -> Result<Vec<u64>> {
+    let index = TowelIndex::new(&towels.patterns);
+    towels.designs.iter().enumerate()
+    .map(|(i, design)| index.arrangements(design).ok_or(Day19Error::Overflow { design: i + 1 }))
+    .collect()
+}
+
+/// Number of designs with at least one arrangement
+pub fn possible_designs(counts: &[u64]) -> usize {
+    counts.iter().filter(|&&count| count > 0).count()
+}
+
+/// Sum of the arrangements of every design
+///
+/// Fails with [`Day19Error::TotalOverflow`] when it does not fit `u64`
+pub fn total_arrangements(counts: &[u64]) -> Result<u64> {
+    counts.iter().try_fold(0u64, |total, &count| total.checked_add(count)).ok_or(Day19Error::TotalOverflow)
+}
+
+/// Possible designs and the total number of arrangements, both out of a single [`design_counts`]
+///
+/// # Examples
+/// ```
+/// fn main() -> day_19::Result<()> {
+///     let towels = day_19::parse_towels(day_19::SAMPLE)?;
+///     assert_eq!(day_19::solve(&towels)?, (6, 16));
+///     Ok(())
+/// }
+/// ```
+pub fn solve(towels: &Towels) -> Result<(usize, u64)> {
+    let counts = design_counts(towels)?;
+    Ok((possible_designs(&counts), total_arrangements(&counts)?))
+}
+
+/// Number of designs that can be arranged, the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_19::Result<()> {
+///     let towels = day_19::parse_towels(day_19::SAMPLE)?;
+///     assert_eq!(day_19::part1(&towels)?, 6);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(towels: &Towels) -> Result<usize> {
+    Ok(possible_designs(&design_counts(towels)?))
+}
+
+/// Number of ways to arrange all the designs, the answer of part 2
+///
+/// # Examples
+/// ```
+/// fn main() -> day_19::Result<()> {
+///     let towels = day_19::parse_towels(day_19::SAMPLE)?;
+///     assert_eq!(day_19::part2(&towels)?, 16);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(towels: &Towels) -> Result<u64> {
+    total_arrangements(&design_counts(towels)?)
+}
+
+/// Day 19 solution used by the `aoc` runner
+///
+/// Part 1 counts the possible designs, part 2 the ways to arrange them
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_19::Day19.part1(day_19::SAMPLE)?, "6");
+///     assert_eq!(day_19::Day19.part2(day_19::SAMPLE)?, "16");
+///     Ok(())
+/// }
+/// ```
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_towels(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_towels(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let (possible, total) = solve(&parse_towels(input)?)?;
+        Ok((possible.to_string(), total.to_string()))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_towels(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Towels {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like towel patterns and designs
+fn format_problem(input: &str) -> Option<String> {
+    parse_towels(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 19 input with `size` designs, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_19::generate(10, 7);
+/// assert_eq!(input.lines().count(), 12);
+/// assert_eq!(day_19::generate(10, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day19(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let towels = parse_towels(SAMPLE)?;
+        assert_eq!(part1(&towels)?, 6);
+        assert_eq!(part2(&towels)?, 16);
+        assert_eq!(Day19.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_impossible_design() -> Result<()> {
+        // no pattern starts with u, and none ends the design after its w
+        let towels = parse_towels("r, b, wr\n\nubr\nrbw\nrwr\n")?;
+        assert_eq!(design_counts(&towels)?, [0, 0, 1]);
+        assert_eq!(solve(&towels)?, (1, 1));
+        // nothing can be arranged without any pattern of a color
+        assert_eq!(TowelIndex::new(&["w", "u"]).arrangements("wub"), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlapping_patterns_multiply() {
+        // each half splits as b|r, br, the whole design also as b|rb|r
+        let index = TowelIndex::new(&["b", "r", "br", "rb"]);
+        assert_eq!(index.arrangements("br"), Some(2));
+        assert_eq!(index.arrangements("brbr"), Some(5));
+        assert_eq!(index.arrangements(""), Some(1));
+        // a pattern that is also a prefix of a longer one counts both
+        let index = TowelIndex::new(&["g", "gg", "ggg"]);
+        assert_eq!(index.arrangements("gggg"), Some(7));
+    }
+
+    #[test]
+    fn test_single_color_alphabet() -> Result<()> {
+        // arrangements of r^n out of r and rr are Fibonacci numbers
+        let towels = parse_towels("r, rr\n\nr\nrr\nrrr\nrrrr\nrrrrrrrrrr\n")?;
+        assert_eq!(design_counts(&towels)?, [1, 2, 3, 5, 89]);
+        assert_eq!(solve(&towels)?, (5, 100));
+        // until they no longer fit u64
+        let towels = Towels { patterns: vec!["r".into(), "rr".into()], designs: vec!["r".into(), "r".repeat(100)] };
+        assert!(matches!(design_counts(&towels), Err(Day19Error::Overflow { design: 2 })));
+        let counts = [u64::MAX, 1];
+        assert_eq!(possible_designs(&counts), 2);
+        assert!(matches!(total_arrangements(&counts), Err(Day19Error::TotalOverflow)));
+        assert_eq!(Day19Error::TotalOverflow.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(parse_towels("\n\n"), Err(Day19Error::MissingPatterns)));
+        let result = parse_towels("r, b,\n\nrb\n");
+        assert!(matches!(&result, Err(Day19Error::EmptyPattern { line: 1 })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(1)));
+        let result = parse_towels("r, b\n\nrb\nrxb\n");
+        assert!(matches!(&result, Err(Day19Error::UnknownColor { line: 4, column: 2, color: 'x' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse_towels("r; b\n"), Err(Day19Error::UnknownColor { line: 1, column: 2, color: ';' })));
+        assert!(matches!(parse_towels("r, b\n"), Result::Ok(towels) if towels.designs.is_empty()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_towels_serde_round_trip() -> Result<()> {
+        let towels = parse_towels("r, wr\n\nwrr\n")?;
+        let json = serde_json::to_string(&towels)?;
+        assert_eq!(json, r#"{"patterns":["r","wr"],"designs":["wrr"]}"#);
+        assert_eq!(serde_json::from_str::<Towels>(&json)?, towels);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day19.describe(SAMPLE).problem, None);
+        assert_eq!(Day19.describe("r, b\n\nbrx\n").problem.as_deref(), Some("unexpected x on line 3, column 3"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day19.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day19(seed, 100);
+            assert_eq!(Day19.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_index_matches_naive(
+            patterns in proptest::collection::vec("[wubrg]{1,4}", 1..12), design in "[wubrg]{0,30}",
+        ) {
+            let index = TowelIndex::new(&patterns);
+            proptest::prop_assert_eq!(index.arrangements(&design), arrangements_naive(&patterns, &design));
+        }
+    }
+
+    #[test]
+    fn test_repeated_pattern_counts_once() {
+        let patterns = ["bu", "b", "bu", "u"];
+        assert_eq!(TowelIndex::new(&patterns).arrangements("bu"), Some(2));
+        assert_eq!(arrangements_naive(&patterns, "bu"), Some(2));
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let towels = day_19::parse_towels(&input.read_to_string()?)?;
+
+    println!("POSSIBLE_DESIGNS: {}", day_19::part1(&towels)?);
+    println!("ARRANGEMENTS: {}", day_19::part2(&towels)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_19::Day19Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_19` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_19", |data| {
+        if let Ok(towels) = day_19::parse_towels(&String::from_utf8_lossy(data)) {
+            let _ = day_19::part1(&towels);
+            let _ = day_19::part2(&towels);
+        }
+    });
+}
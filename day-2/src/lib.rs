@@ -5,13 +5,288 @@
 //! 
 //! [`Read more`](../../../README.md)
 
-use anyhow::{Context, Error, Ok, Result};
-use std::{fs::File, io::{BufRead, BufReader}};
+use aoc_prelude::*;
+use std::num::ParseIntError;
+#[cfg(feature = "fs")]
+use std::{fs::File, path::PathBuf};
+
+/// Errors returned by the day 2 functions
+#[derive(Debug, Error)]
+pub enum Day2Error {
+    /// Input file could not be opened
+    #[cfg(feature = "fs")]
+    #[error("could not read file {}", path.display())]
+    Io {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// A line could not be read from the input
+    #[error("failed to read line")]
+    ReadLine(#[source] std::io::Error),
+    /// A level is not a number
+    #[error("failed parsing {token} to number")]
+    Parse {
+        /// Symbol that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// The input layout could not be parsed
+    #[error(transparent)]
+    Input(ParseError<ParseIntError>),
+    /// A level index is out of the report bounds
+    #[error("failed getting a level with index {index}")]
+    MissingLevel {
+        /// Index of the level
+        index: usize,
+    },
+}
+
+impl From<Day2Error> for SolutionError {
+    fn from(e: Day2Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day2Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day2Error::Parse { token, .. } => Some(Location::Token(token.clone())),
+            Day2Error::Input(e) => e.location(),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "fs")]
+            Day2Error::Io { source, .. } => ErrorKind::io(source),
+            Day2Error::ReadLine(source) => ErrorKind::io(source),
+            Day2Error::Parse { .. } | Day2Error::Input(_) => ErrorKind::Parse,
+            Day2Error::MissingLevel { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 2 functions
+pub type Result<T, E = Day2Error> = std::result::Result<T, E>;
+
+/// Levels of a single report
+pub type Report = Vec<i32>;
+
+#[cfg(test)]
+thread_local! {
+    /// Inputs parsed on this thread, the tests check that both parts share one parse
+    static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Reports of the input, one per line, parsed once for the two parts
+/// 
+/// Blank lines are skipped. A level that is not a number is reported
+/// as [`Day2Error::Input`] with its line and column.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_2::Result<()> {
+///     let input = day_2::Day2Input::try_from("7 6 4\n1 2 7 8\n")?;
+///     assert_eq!(input.0, vec![vec![7, 6, 4], vec![1, 2, 7, 8]]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day2Input(pub Vec<Report>);
+
+impl TryFrom<&str> for Day2Input {
+    type Error = Day2Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        #[cfg(test)]
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        parse_grid_lines(text)
+        .map(Day2Input)
+        .map_err(Day2Error::Input)
+    }
+}
+
+/// Number of reports that are safe as they are, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_2::Result<()> {
+///     let input = day_2::Day2Input::try_from(day_2::SAMPLE)?;
+///     assert_eq!(day_2::part1(&input)?, 2);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day2Input) -> Result<usize> {
+    count_safe(input, false)
+}
+
+/// Number of reports that are safe with the [`problem_dampener`], the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_2::Result<()> {
+///     let input = day_2::Day2Input::try_from(day_2::SAMPLE)?;
+///     assert_eq!(day_2::part2(&input)?, 4);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day2Input) -> Result<usize> {
+    count_safe(input, true)
+}
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["2", "4"];
+
+/// Day 2 solution used by the `aoc` runner
+/// 
+/// Part 1 counts the reports that are safe as they are and
+/// part 2 also counts the reports fixed by the [`problem_dampener`].
+/// Both parts are solved from the same [`Day2Input`].
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     let input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n";
+///     assert_eq!(day_2::Day2.part1(input)?, "2");
+///     assert_eq!(day_2::Day2.part2(input)?, "4");
+///     Ok(())
+/// }
+/// ```
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day2Input::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day2Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day2Input::try_from(input)?;
+        Ok((part1(&input)?.to_string(), part2(&input)?.to_string()))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day2Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+
+    fn explain(&self, input: &str, selector: &str) -> SolutionResult<String> {
+        let reports: Vec<&str> = input.lines().collect();
+        let line = selector.trim().parse::<usize>().ok()
+        .filter(|line| (1..=reports.len()).contains(line))
+        .ok_or_else(|| SolutionError::InvalidSelector {
+            selector: selector.to_string(),
+            expected: format!("a report line from 1 to {}", reports.len()),
+        })?;
+        let report = report_as_vector(reports[line - 1])?;
+        Ok(format!("Report on line {}: {}\n{}", line, reports[line - 1].trim(), explain_report(&report)?))
+    }
+}
+
+impl Parsed for Day2Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like reports of numbers, one per line
+fn format_problem(input: &str) -> Option<String> {
+    input.lines().enumerate()
+    .find_map(|(i, report)| report_as_vector(report).err().map(|e| format!("line {}: {}", i + 1, e)))
+}
+
+/// Day 2 input with `size` reports, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_2::generate(50, 7);
+/// assert_eq!(day_2::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day2(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+/// Count safe reports, optionally using the [`problem_dampener`]
+fn count_safe(input: &Day2Input, dampener: bool) -> Result<usize> {
+    #[cfg(feature = "parallel")]
+    return count_safe_parallel(input, dampener);
+    #[cfg(not(feature = "parallel"))]
+    return count_safe_serial(input, dampener);
+}
+
+/// Check a single report, optionally using the [`problem_dampener`]
+fn is_safe(report: &[i32], dampener: bool) -> Result<bool> {
+    let (report_safe, fail_index) = safe_report(report)?;
+    if !report_safe && dampener {
+        return problem_dampener(report, fail_index);
+    }
+    Ok(report_safe)
+}
+
+/// [`count_safe`] checking one report at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn count_safe_serial(input: &Day2Input, dampener: bool) -> Result<usize> {
+    let mut num_safe_reports = 0;
+    for report in &input.0 {
+        if is_safe(report, dampener)? {
+            num_safe_reports += 1;
+        }
+    }
+    Ok(num_safe_reports)
+}
+
+/// [`count_safe`] splitting the reports between the rayon threads
+#[cfg(feature = "parallel")]
+fn count_safe_parallel(input: &Day2Input, dampener: bool) -> Result<usize> {
+    use rayon::prelude::*;
+
+    input.0.par_iter()
+    .map(|report| Ok(usize::from(is_safe(report, dampener)?)))
+    .try_reduce(|| 0, |a, b| Ok(a + b))
+}
 
 /// Problem dampener finds report safe if it has only 1 bad level
 /// 
 /// Problem dampener takes a report and location of the bad level
-/// and does retries by removing the bad level or nearby levels
+/// and does retries by removing the bad level or nearby levels.
+/// A location past the end of the report fails with
+/// [`Day2Error::MissingLevel`].
 /// 
 /// # Examples
 /// ```
@@ -24,9 +299,12 @@ use std::{fs::File, io::{BufRead, BufReader}};
 ///     Ok(())
 /// }
 /// ```
-pub fn problem_dampener(report: &Vec<i32>, fail_idx: usize) -> Result<bool, Error> {
+pub fn problem_dampener(report: &[i32], fail_idx: usize) -> Result<bool> {
+    if fail_idx >= report.len() {
+        return Err(Day2Error::MissingLevel { index: fail_idx });
+    }
     // retry by removing fail value
-    let mut rep_copy = report.clone();
+    let mut rep_copy = report.to_vec();
     rep_copy.remove(fail_idx);
     let (safe, _) = safe_report(&rep_copy)?;
     if safe {
@@ -34,16 +312,16 @@ pub fn problem_dampener(report: &Vec<i32>, fail_idx: usize) -> Result<bool, Erro
     }
     // retry by removing value before fail value
     if fail_idx != 0 {
-        let mut rep_copy = report.clone();
+        let mut rep_copy = report.to_vec();
         rep_copy.remove(fail_idx - 1);
         let (safe, _) = safe_report(&rep_copy)?;
         if safe {
             return Ok(true);
         }
     }
-    // retry by removing value after fail value
-    if fail_idx != rep_copy.len() {
-        let mut rep_copy = report.clone();
+    // retry by removing value after fail value, unless fail value is the last one
+    if fail_idx + 1 < report.len() {
+        let mut rep_copy = report.to_vec();
         rep_copy.remove(fail_idx + 1);
         let (safe, _) = safe_report(&rep_copy)?;
         if safe {
@@ -53,37 +331,102 @@ pub fn problem_dampener(report: &Vec<i32>, fail_idx: usize) -> Result<bool, Erro
     Ok(false)
 }
 
+/// Problem dampener trying the removal of every level in turn
+/// 
+/// Finds the same reports safe as [`problem_dampener`] at the first
+/// failure of [`safe_report`]: that failure is found at the latest on the
+/// pair after a level that has to go, so removing a level further away
+/// never fixes a report the neighbours of the failure do not.
+/// Kept as the reference the tests check the dampener against.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_2::Result<()> {
+///     assert!(day_2::dampener_exhaustive(&[5, 1, 2, 3, 4])?);
+///     assert!(!day_2::dampener_exhaustive(&[1, 2, 7, 8, 9])?);
+///     Ok(())
+/// }
+/// ```
+pub fn dampener_exhaustive(report: &[i32]) -> Result<bool> {
+    for index in 0..report.len() {
+        let mut rep_copy = report.to_vec();
+        rep_copy.remove(index);
+        if safe_report(&rep_copy)?.0 {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Why a report is safe or unsafe, and whether the [`problem_dampener`] fixes it
+/// 
+/// Levels are numbered from 1 in the explanation
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_2::Result<()> {
+///     let explanation = day_2::explain_report(&[1, 3, 2, 4, 5])?;
+///     assert_eq!(explanation, "Unsafe: 3 -> 2 turns decreasing at level 2\n\
+///     Safe with the problem dampener, removing level 2 (3)");
+///     Ok(())
+/// }
+/// ```
+pub fn explain_report(report: &[i32]) -> Result<String> {
+    let (safe, fail_index) = safe_report(report)?;
+    if safe {
+        let direction = match report {
+            [first, second, ..] if second < first => "decreasing",
+            _ => "increasing",
+        };
+        return Ok(format!("Safe: the levels are all {} by 1 to 3", direction));
+    }
+
+    let (previous, current) = (report[fail_index], report[fail_index + 1]);
+    let reason = match current.abs_diff(previous) {
+        0 => format!("{} -> {} does not change", previous, current),
+        diff if diff > 3 => format!("{} -> {} changes by {}", previous, current, diff),
+        _ if current > previous => format!("{} -> {} turns increasing", previous, current),
+        _ => format!("{} -> {} turns decreasing", previous, current),
+    };
+    // the same levels the problem dampener tries, in the same order
+    let candidates = [Some(fail_index), fail_index.checked_sub(1), Some(fail_index + 1)];
+    for index in candidates.into_iter().flatten().filter(|&index| index < report.len()) {
+        let mut fixed = report.to_vec();
+        fixed.remove(index);
+        if safe_report(&fixed)?.0 {
+            return Ok(format!("Unsafe: {} at level {}\nSafe with the problem dampener, removing level {} ({})",
+            reason, fail_index + 1, index + 1, report[index]));
+        }
+    }
+    Ok(format!("Unsafe: {} at level {}\nUnsafe with the problem dampener too", reason, fail_index + 1))
+}
+
 /// Find the number of safe reports
 /// 
-/// Returns a number of safe reports or error of operation failed
-/// Uses [`read_file`] to read file and 
-/// [`safe_report`] to determine if report is safe
+/// Returns a number of safe reports read line by line from the `reader`
+/// or error if operation failed. Uses [`safe_report`] to determine if
+/// report is safe and [`problem_dampener`] to retry the unsafe ones
 /// 
 /// # Example
 /// ```
+/// use std::io::Cursor;
 /// use anyhow::Result;
 /// 
 /// fn main() -> Result<()> {
-///     let file = day_2::read_file(std::path::Path::new("./data/input_test_4.txt"))?;
-///
-///     let num_safe_reports = day_2::safe_reports_number(file)?;
-///
+///     let num_safe_reports = day_2::safe_reports_number(Cursor::new(day_2::SAMPLE))?;
+/// 
 ///     assert_eq!(num_safe_reports, 4);
 ///     Ok(())
 /// }
 /// ```
-pub fn safe_reports_number(file: File) -> Result<i32, Error> {
+pub fn safe_reports_number<B: BufRead>(reader: B) -> Result<i32> {
     let mut num_safe_reports = 0;
 
-    let reader = BufReader::new(file);
+    let reader = NormalizingReader::new(reader);
     for line in reader.lines() {
-        let report = line.with_context(|| format!("failed to read line"))?;
+        let report = line.map_err(Day2Error::ReadLine)?;
         let report_vec = report_as_vector(&report)?;
-        let (mut report_safe, fail_index) = crate::safe_report(&report_vec)?;
-        if !report_safe {
-            report_safe = problem_dampener(&report_vec, fail_index)?;
-        }
-        if report_safe {
+        if is_safe(&report_vec, true)? {
             num_safe_reports += 1;
         }
     }
@@ -91,6 +434,31 @@ pub fn safe_reports_number(file: File) -> Result<i32, Error> {
     Ok(num_safe_reports)
 }
 
+/// Find the number of safe reports in the file at `path`
+/// 
+/// Opens the file with [`read_file`] and counts its safe reports
+/// with [`safe_reports_number`]
+/// 
+/// # Example
+/// ```
+/// use assert_fs::prelude::*;
+/// use anyhow::Result;
+/// 
+/// fn main() -> Result<()> {
+///     let temp_file = assert_fs::NamedTempFile::new("input.txt")?;
+///     temp_file.write_str(day_2::SAMPLE)?;
+/// 
+///     let num_safe_reports = day_2::safe_reports_number_from_path(temp_file.path())?;
+/// 
+///     assert_eq!(num_safe_reports, 4);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "fs")]
+pub fn safe_reports_number_from_path(path: &std::path::Path) -> Result<i32> {
+    safe_reports_number(BufReader::new(read_file(path)?))
+}
+
 /// Check if 2 number sequence is descending
 /// 
 /// If numbers are not descending return false
@@ -114,12 +482,12 @@ pub fn is_descending(previous_number: &i32, current_number: &i32) -> bool {
     }
     if current_number < previous_number {
         let diff = previous_number - current_number;
-        if diff < 1 || diff > 3 {
+        if !(1..=3).contains(&diff) {
             return false;
         }
     }
 
-    return true;
+    true
 }
 
 /// Check if 2 number sequence is ascending
@@ -145,18 +513,19 @@ pub fn is_ascending(previous_number: &i32, current_number: &i32) -> bool {
     }
     if current_number > previous_number {
         let diff = current_number - previous_number;
-        if diff < 1 || diff > 3 {
+        if !(1..=3).contains(&diff) {
             return false;
         }
     }
 
-    return true;
+    true
 }
 
 /// Create a vector containing report values
 /// 
 /// Takes a string line, parses all symbols to integer values 
-/// and returns Vector containing those values
+/// and returns Vector containing those values.
+/// Levels may be separated by any amount of whitespace
 /// 
 /// # Examples
 /// ```
@@ -169,15 +538,13 @@ pub fn is_ascending(previous_number: &i32, current_number: &i32) -> bool {
 ///     Ok(())
 /// }
 /// ```
-pub fn report_as_vector(report_str: &str) -> Result<Vec<i32>, Error> {
-    let mut report_vec: Vec<i32> = Vec::new();
-    let symbols = report_str.split(" ");
-    for sym in symbols {
-        let num = sym.parse::<i32>()
-        .with_context(|| format!("failed parsing {} to number", sym))?;
-        report_vec.push(num);
-    }
-    Ok(report_vec)
+pub fn report_as_vector(report_str: &str) -> Result<Vec<i32>> {
+    let mut rows = parse_grid_lines::<i32>(report_str)
+    .map_err(|e| match e {
+        ParseError::Token { token, source, .. } => Day2Error::Parse { token, source },
+        e => Day2Error::Input(e),
+    })?;
+    Ok(rows.pop().unwrap_or_default())
 }
 
 /// Determines whether provided report is safe or not
@@ -197,15 +564,15 @@ pub fn report_as_vector(report_str: &str) -> Result<Vec<i32>, Error> {
 ///     Ok(())
 /// }
 /// ```
-pub fn safe_report(report: &Vec<i32>) -> Result<(bool, usize), Error> {
+pub fn safe_report(report: &[i32]) -> Result<(bool, usize)> {
     let mut prev_is_ascending = false;
     let mut prev_is_descending = false;
     for i in 1..report.len() {
         let level_curr = report.get(i)
-        .with_context(|| format!("failed getting a level with index {}", i))?;
+        .ok_or(Day2Error::MissingLevel { index: i })?;
 
         let level_prev = report.get(i-1)
-        .with_context(|| format!("failed getting a level with index {}", i-1))?;
+        .ok_or(Day2Error::MissingLevel { index: i - 1 })?;
 
         let is_ascending = crate::is_ascending(level_prev, level_curr);
         let is_descending = crate::is_descending(level_prev, level_curr);
@@ -242,27 +609,107 @@ pub fn safe_report(report: &Vec<i32>) -> Result<(bool, usize), Error> {
 /// fn main() -> Result<()> {
 ///     let temp_file = assert_fs::NamedTempFile::new("sample.txt")?;
 ///     temp_file.write_str("A test\nActual content\nMore content\nAnother test")?;
-///
+/// 
 ///     let mut file_obj = day_2::read_file(temp_file.path())?;
 ///     let mut contents = String::new();
 ///     file_obj.read_to_string(&mut contents)?;
-///
+/// 
 ///     assert_eq!(contents, "A test\nActual content\nMore content\nAnother test");
 ///     Ok(())
 /// }
 /// ```
-pub fn read_file(path: &std::path::Path) -> Result<File, Error> {
+#[cfg(feature = "fs")]
+pub fn read_file(path: &std::path::Path) -> Result<File> {
     let file = File::open(path)
-    .with_context(|| format!("could not read file {}", path.display()))?;
+    .map_err(|source| Day2Error::Io { path: path.to_path_buf(), source })?;
     
     Ok(file)
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "fs")]
     use std::io::Read;
+    #[cfg(feature = "fs")]
     use assert_fs::prelude::*;
     use anyhow::{Ok, Result};
+    use aoc_utils::Solution;
+    use std::io::Cursor;
+    use crate::{Day2Error, SAMPLE};
+
+    #[test]
+    fn test_solution_part1_sample() -> Result<()> {
+        assert_eq!(crate::Day2.part1(SAMPLE)?, "2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part2_sample() -> Result<()> {
+        assert_eq!(crate::Day2.part2(SAMPLE)?, "4");
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(crate::Day2.describe(SAMPLE).problem, None);
+        assert_eq!(crate::Day2.describe("1 2 3\n4 five 6\n").problem.as_deref(),
+        Some("line 2: failed parsing five to number"));
+    }
+
+    #[test]
+    fn test_explain_sample() -> Result<()> {
+        assert_eq!(crate::Day2.explain(SAMPLE, "1")?, "Report on line 1: 7 6 4 2 1\n\
+        Safe: the levels are all decreasing by 1 to 3");
+        assert_eq!(crate::Day2.explain(SAMPLE, "2")?, "Report on line 2: 1 2 7 8 9\n\
+        Unsafe: 2 -> 7 changes by 5 at level 2\nUnsafe with the problem dampener too");
+        assert_eq!(crate::Day2.explain(SAMPLE, "5")?, "Report on line 5: 8 6 4 4 1\n\
+        Unsafe: 4 -> 4 does not change at level 3\nSafe with the problem dampener, removing level 3 (4)");
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_invalid_selector() {
+        for selector in ["0", "7", "first"] {
+            let e = crate::Day2.explain(SAMPLE, selector).unwrap_err();
+            assert_eq!(e.to_string(), format!("invalid selector `{}`, expected a report line from 1 to 6", selector));
+        }
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(crate::Day2.part1(&input)?, crate::SAMPLE_ANSWERS[0]);
+        assert_eq!(crate::Day2.part2(&input)?, crate::SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day2(seed, 1000);
+            assert_eq!(crate::Day2.part1(&generated.input)?, generated.part1);
+            assert_eq!(crate::Day2.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_count_safe_parallel_matches_serial() -> Result<()> {
+        let sample = crate::Day2Input::try_from(SAMPLE)?;
+        for dampener in [false, true] {
+            assert_eq!(crate::count_safe_parallel(&sample, dampener)?, crate::count_safe_serial(&sample, dampener)?);
+        }
+
+        let input = crate::Day2Input::try_from(aoc_testing::day2(0x2024_0002, 2000).input.as_str())?;
+        for dampener in [false, true] {
+            assert_eq!(crate::count_safe_parallel(&input, dampener)?, crate::count_safe_serial(&input, dampener)?);
+        }
+        Ok(())
+    }
 
     #[test]
     fn test_problem_dampener() -> Result<()> {
@@ -272,10 +719,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_problem_dampener_last_pair() -> Result<()> {
+        // only the last pair fails, removing the last level fixes the report
+        for report in [vec![1, 2, 3, 4, 9], vec![1, 2, 3, 9, 4]] {
+            let (safe, fail_index) = crate::safe_report(&report)?;
+            assert!(!safe);
+            assert!(crate::problem_dampener(&report, fail_index)?, "{:?}", report);
+            // any level of the report is a valid location, the last one included
+            for fail_idx in 0..report.len() {
+                crate::problem_dampener(&report, fail_idx)?;
+            }
+            let past_end = crate::problem_dampener(&report, report.len());
+            assert!(matches!(past_end, Err(crate::Day2Error::MissingLevel { index: 5 })));
+        }
+        assert!(!crate::problem_dampener(&[1, 2, 9, 4, 10], 4)?);
+        assert!(crate::problem_dampener(&[7], 0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_problem_dampener_matches_exhaustive() -> Result<()> {
+        // (report, safe with one level removed)
+        let reports: [(&[i32], bool); 10] = [
+            (&[5, 1, 2, 3, 4], true),
+            (&[1, 5, 2, 3, 4, 5], true),
+            (&[3, 4, 3, 2, 1], true),
+            (&[4, 3, 4, 5, 6], true),
+            (&[1, 2, 3, 4, 3], true),
+            (&[9, 8, 7, 6, 7], true),
+            (&[1, 3, 2, 4, 3], false),
+            (&[1, 1, 1, 2, 3], false),
+            (&[8, 6, 4, 4, 1], true),
+            (&[1, 2, 7, 8, 9], false),
+        ];
+        for (report, safe) in reports {
+            let (_, fail_index) = crate::safe_report(report)?;
+            assert_eq!(crate::problem_dampener(report, fail_index)?, safe, "{:?}", report);
+            assert_eq!(crate::dampener_exhaustive(report)?, safe, "{:?}", report);
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_is_safe_dampened_matches_exhaustive(report in aoc_testing::report_strategy(2..9, 1..=9)) {
+            let exhaustive = crate::safe_report(&report).unwrap().0 || crate::dampener_exhaustive(&report).unwrap();
+            proptest::prop_assert_eq!(crate::is_safe(&report, true).unwrap(), exhaustive);
+        }
+    }
+
     #[test]
     fn test_report_as_vector_success() -> Result<()> {
         let report = "1 2 3 4 5";
-        let v = crate::report_as_vector(&report)?;
+        let v = crate::report_as_vector(report)?;
         assert_eq!(v.len(), 5);
         Ok(())
     }
@@ -283,21 +780,89 @@ mod tests {
     #[test]
     fn test_report_as_vector_negative() -> Result<()> {
         let report = "1 2 3 asd 5";
-        let v = crate::report_as_vector(&report);
+        let v = crate::report_as_vector(report);
         assert!(v.is_err_and(|e| e.to_string().eq("failed parsing asd to number")));
         Ok(())
     }
 
     #[test]
-    fn test_safe_reports_number_success() -> Result<()> {
-        let file = crate::read_file(std::path::Path::new("./data/input_test_4.txt"))?;
+    fn test_report_as_vector_whitespace() -> Result<()> {
+        assert_eq!(crate::report_as_vector("1  2\t3 ")?, vec![1, 2, 3]);
+        assert!(crate::report_as_vector("")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_as_vector_parse_variant() {
+        let v = crate::report_as_vector("1 2 3 asd 5");
+        assert!(matches!(v, Err(Day2Error::Parse { token, .. }) if token == "asd"));
+    }
+
+    #[test]
+    fn test_solution_error_keeps_day_error() {
+        let result = crate::Day2.part1("1 2 3\n1 x 3\n");
+        assert!(result.is_err_and(|e| matches!(e.day_error::<Day2Error>(),
+        Some(Day2Error::Input(aoc_utils::ParseError::Token { token, line: 2, column: 2, .. })) if token == "x")));
+    }
+
+    #[test]
+    fn test_solve_both_parses_once() -> Result<()> {
+        crate::PARSES.with(|parses| parses.set(0));
+        assert_eq!(crate::Day2.solve_both(SAMPLE)?, ("2".to_string(), "4".to_string()));
+        assert_eq!(crate::PARSES.with(|parses| parses.get()), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_try_from() -> Result<()> {
+        let input = crate::Day2Input::try_from("7 6 4\n\n1 2\n")?;
+        assert_eq!(input, crate::Day2Input(vec![vec![7, 6, 4], vec![1, 2]]));
+        let e = crate::Day2Input::try_from("1 2\n3 four\n").unwrap_err();
+        assert_eq!(e.to_string(), "failed parsing four on line 2, column 2");
+        assert_eq!(e.location(), Some(aoc_utils::Location::Field { line: 2, field: 2 }));
+        Ok(())
+    }
 
-        let num_safe_reports = crate::safe_reports_number(file)?;
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = crate::Day2Input::try_from("7 6 4\n1 2\n")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, "[[7,6,4],[1,2]]");
+        assert_eq!(serde_json::from_str::<crate::Day2Input>(&json)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_safe_reports_number_success() -> Result<()> {
+        let num_safe_reports = crate::safe_reports_number(Cursor::new(SAMPLE))?;
 
         assert_eq!(num_safe_reports, 4);
         Ok(())
     }
 
+    #[test]
+    fn test_safe_reports_number_crlf_bom() -> Result<()> {
+        let text = aoc_testing::crlf_bom(SAMPLE);
+        assert_eq!(crate::safe_reports_number(Cursor::new(text))?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_safe_reports_number_invalid_report() {
+        let result = crate::safe_reports_number(Cursor::new("1 2 3\n1 x 3\n"));
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_safe_reports_number_from_path() -> Result<()> {
+        let temp_file = assert_fs::NamedTempFile::new("sample.txt")?;
+        temp_file.write_str(SAMPLE)?;
+        assert_eq!(crate::safe_reports_number_from_path(temp_file.path())?, 4);
+        Ok(())
+    }
+
     #[test]
     fn test_safe_report_success_safe() -> Result<()> {
         let rep = vec![1, 2, 3, 5, 8];
@@ -314,6 +879,14 @@ mod tests {
         Ok(())
     }
 
+    proptest::proptest! {
+        #[test]
+        fn test_safe_report_matches_oracle(report in aoc_testing::report_strategy(2..9, 1..=15)) {
+            let (safe, _) = crate::safe_report(&report).unwrap();
+            proptest::prop_assert_eq!(safe, aoc_testing::oracle::is_safe(&report));
+        }
+    }
+
     #[test]
     fn test_is_descending_succes() -> Result<()> {
         let is_descending = crate::is_descending(&3, &1);
@@ -328,6 +901,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_success() -> Result<()> {
         let temp_file = assert_fs::NamedTempFile::new("sample.txt")?;
@@ -341,10 +915,18 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_negative() -> Result<()> {
         let result = crate::read_file(std::path::Path::new("sample.txt"));
         assert!(result.is_err_and(|e| e.to_string().contains("could not read file")));
         Ok(())
     }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_file_io_variant() {
+        let result = crate::read_file(std::path::Path::new("sample.txt"));
+        assert!(matches!(result, Err(Day2Error::Io { path, .. }) if path == std::path::Path::new("sample.txt")));
+    }
 }
\ No newline at end of file
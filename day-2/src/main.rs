@@ -1,12 +1,26 @@
-use anyhow::Result;
+use std::process::ExitCode;
+use aoc_prelude::*;
 
-fn main() -> Result<()> {
-    let input_path = std::path::Path::new("./data/input.txt");
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
 
-    let file = day_2::read_file(input_path)?;
+    let content = input.read_to_string()?;
 
-    let num_safe_reports = day_2::safe_reports_number(file)?;
+    let num_safe_reports = day_2::Day2.part2(&content)?;
     println!("{}", num_safe_reports);
 
     Ok(())
 }
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_2::Day2Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,23 @@
+//! Upper bounds on the allocations of the solves, a return to cloning
+//! every report adds 10 000 allocations and fails these tests
+
+use aoc_prelude::Solution;
+use aoc_testing::{AllocationGuard, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_allocations_10k_reports() -> anyhow::Result<()> {
+    let input = aoc_testing::day2(7, 10_000).input;
+    // three allocations per report to parse its levels
+    let guard = AllocationGuard::new();
+    day_2::Day2.part1(&input)?;
+    assert!(guard.allocations() < 35_000, "part 1 allocated {} times", guard.allocations());
+
+    // plus the copies the problem dampener retries on
+    let guard = AllocationGuard::new();
+    day_2::Day2.part2(&input)?;
+    assert!(guard.allocations() < 50_000, "part 2 allocated {} times", guard.allocations());
+    Ok(())
+}
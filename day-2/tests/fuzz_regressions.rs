@@ -0,0 +1,14 @@
+//! Replays the crashers saved from the `day_2` fuzz target
+
+use aoc_utils::Solution;
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_2", |data| {
+        let Ok(text) = std::str::from_utf8(data) else {
+            return;
+        };
+        let _ = day_2::Day2.part1(text);
+        let _ = day_2::Day2.part2(text);
+    });
+}
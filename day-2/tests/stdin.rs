@@ -0,0 +1,16 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-2"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-2");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_4.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-2");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "4\n");
+}
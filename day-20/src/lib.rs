@@ -0,0 +1,510 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 20 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Errors returned by the day 20 functions
+#[derive(Debug, Error)]
+pub enum Day20Error {
+    /// Lines of the racetrack have different lengths
+    #[error("malformed racetrack")]
+    Grid(#[from] GridError),
+    /// A tile is neither a wall, track, the start nor the end
+    #[error("unexpected {tile} on line {line}, column {column}")]
+    UnknownTile {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        tile: char,
+    },
+    /// No start (`S`) or no end (`E`) on the racetrack
+    #[error("the racetrack has no {tile}")]
+    MissingTile {
+        /// The missing tile, `S` or `E`
+        tile: char,
+    },
+    /// More than one start or end on the racetrack
+    #[error("second {tile} on line {line}, column {column}")]
+    ExtraTile {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The repeated tile, `S` or `E`
+        tile: char,
+    },
+    /// Walls separate the end from the start
+    #[error("the end cannot be reached from the start")]
+    NoPath,
+}
+
+impl From<Day20Error> for SolutionError {
+    fn from(e: Day20Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day20Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day20Error::Grid(e) => e.location(),
+            Day20Error::UnknownTile { line, .. } | Day20Error::ExtraTile { line, .. } => Some(Location::Line(*line)),
+            Day20Error::MissingTile { .. } | Day20Error::NoPath => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day20Error::Grid(_) | Day20Error::UnknownTile { .. } | Day20Error::MissingTile { .. }
+            | Day20Error::ExtraTile { .. } => ErrorKind::Parse,
+            Day20Error::NoPath => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 20 functions
+pub type Result<T, E = Day20Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "###############\n#...#...#.....#\n#.#.#.#.#.###.#\n#S#...#.#.#...#\n#######.#.#.###\n\
+#######.#.#...#\n#######.#.###.#\n###..E#...#...#\n###.#######.###\n#...###...#...#\n#.#####.#.###.#\n\
+#.#...#.#.#...#\n#.#.#.#.#.#.###\n#...#...#...###\n###############\n";
+
+/// Expected answers of both parts for [`SAMPLE`], counting the cheats saving at least [`SAMPLE_MIN_SAVING`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["1", "285"];
+
+/// Picoseconds a cheat has to save to be counted on [`SAMPLE`]
+pub const SAMPLE_MIN_SAVING: usize = 50;
+
+/// Picoseconds a cheat has to save to be counted on the puzzle input
+pub const MIN_SAVING: usize = 100;
+
+/// Longest cheat through the walls in part 1, in picoseconds
+pub const SHORT_CHEAT: usize = 2;
+
+/// Longest cheat through the walls in part 2, in picoseconds
+pub const LONG_CHEAT: usize = 20;
+
+/// Racetrack with its walls, start and end, parsed once for the two parts
+///
+/// # Examples
+/// ```
+/// fn main() -> day_20::Result<()> {
+///     let track = day_20::parse_track("#####\n#S.E#\n#####\n")?;
+///     assert_eq!((track.walls.width(), track.walls.height()), (5, 3));
+///     assert!(track.walls[(0, 2)]);
+///     assert_eq!((track.start, track.end), ((1, 1), (1, 3)));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Track {
+    /// Tiles of the racetrack, `true` where there is a wall
+    pub walls: Grid<bool>,
+    /// Tile the race starts on
+    pub start: Pos,
+    /// Tile the race ends on
+    pub end: Pos,
+}
+
+impl TryFrom<&str> for Track {
+    type Error = Day20Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let (walls, start, end) = Grid::parse_maze(text)?;
+        Ok(Track { walls, start, end })
+    }
+}
+
+impl From<MazeError> for Day20Error {
+    fn from(e: MazeError) -> Self {
+        match e {
+            MazeError::Grid(e) => Day20Error::Grid(e),
+            MazeError::UnknownTile { line, column, tile } => Day20Error::UnknownTile { line, column, tile },
+            MazeError::MissingTile { tile } => Day20Error::MissingTile { tile },
+            MazeError::ExtraTile { line, column, tile } => Day20Error::ExtraTile { line, column, tile },
+        }
+    }
+}
+
+/// Parse the racetrack from text
+///
+/// Walls are `#` and track tiles `.`, with exactly one start `S` and one
+/// end `E`. Tiles off the map count as walls.
+///
+/// # Examples
+/// ```
+/// let track = day_20::parse_track(day_20::SAMPLE).unwrap();
+/// assert_eq!((track.start, track.end), ((3, 1), (7, 5)));
+/// assert!(matches!(day_20::parse_track("S.\n.."), Err(day_20::Day20Error::MissingTile { tile: 'E' })));
+/// ```
+pub fn parse_track(text: &str) -> Result<Track> {
+    Track::try_from(text)
+}
+
+/// Steps from `from` to every track tile, breadth first without going through walls
+fn distances(walls: &Grid<bool>, from: Pos) -> Grid<Option<usize>> {
+    let mut steps = Grid::new(walls.height(), walls.width(), None);
+    steps[from] = Some(0);
+    let mut queue = VecDeque::from([(from, 0)]);
+    while let Some((pos, here)) = queue.pop_front() {
+        for next in walls.neighbors4(pos) {
+            if !walls[next] && steps[next].is_none() {
+                steps[next] = Some(here + 1);
+                queue.push_back((next, here + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// Row and column offsets from 2 to `radius` steps away, with their length
+fn offsets(radius: usize) -> Vec<(isize, isize, usize)> {
+    let radius = radius as isize;
+    (-radius..=radius)
+    .flat_map(|d_row| {
+        let rest = radius - d_row.abs();
+        (-rest..=rest).map(move |d_col| (d_row, d_col, (d_row.abs() + d_col.abs()) as usize))
+    })
+    .filter(|&(_, _, len)| len >= 2)
+    .collect()
+}
+
+/// Call `f` with the picoseconds saved by every cheat of at most `max_cheat` steps saving any, `None` when walls separate the end from the start
+///
+/// A cheat leaves the track at a tile reached from the start and comes
+/// back to it at a tile the end is reached from, at most `max_cheat`
+/// steps away. Only the tiles within that Manhattan radius are looked at,
+/// not every pair of track tiles.
+fn for_each_saving(track: &Track, max_cheat: usize, mut f: impl FnMut(usize)) -> Option<()> {
+    let from_start = distances(&track.walls, track.start);
+    let to_end = distances(&track.walls, track.end);
+    let fair = from_start[track.end]?;
+    let offsets = offsets(max_cheat);
+    for (pos, ran) in from_start.iter() {
+        let Some(ran) = *ran else {
+            continue;
+        };
+        for &(d_row, d_col, cheat) in &offsets {
+            let left = to_end.offset(pos, (d_row, d_col)).and_then(|(_, left)| *left);
+            if let Some(saving) = left.and_then(|left| fair.checked_sub(ran + cheat + left)).filter(|&saving| saving > 0) {
+                f(saving);
+            }
+        }
+    }
+    Some(())
+}
+
+/// Number of cheats of at most `max_cheat` steps through the walls saving at least `min_saving` picoseconds
+///
+/// A cheat is told apart by the tiles it starts and ends on, and only
+/// cheats saving some time count. `None` when walls separate the end from
+/// the start.
+///
+/// # Examples
+/// ```
+/// fn main() -> day_20::Result<()> {
+///     let track = day_20::parse_track(day_20::SAMPLE)?;
+///     assert_eq!(day_20::count_cheats(&track, 2, 64), Some(1));
+///     assert_eq!(day_20::count_cheats(&track, 2, 1), Some(44));
+///     assert_eq!(day_20::count_cheats(&track, 20, 76), Some(3));
+///     Ok(())
+/// }
+/// ```
+pub fn count_cheats(track: &Track, max_cheat: usize, min_saving: usize) -> Option<usize> {
+    let mut count = 0;
+    for_each_saving(track, max_cheat, |saving| count += usize::from(saving >= min_saving))?;
+    Some(count)
+}
+
+/// Number of cheats of at most `max_cheat` steps through the walls by the picoseconds they save
+///
+/// # Examples
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// fn main() -> day_20::Result<()> {
+///     let track = day_20::parse_track(day_20::SAMPLE)?;
+///     let savings = day_20::cheat_savings(&track, 2).unwrap_or_default();
+///     assert_eq!(savings.range(20..).collect::<Vec<_>>(), [(&20, &1), (&36, &1), (&38, &1), (&40, &1), (&64, &1)]);
+///     Ok(())
+/// }
+/// ```
+pub fn cheat_savings(track: &Track, max_cheat: usize) -> Option<BTreeMap<usize, usize>> {
+    let mut savings = BTreeMap::new();
+    for_each_saving(track, max_cheat, |saving| *savings.entry(saving).or_default() += 1)?;
+    Some(savings)
+}
+
+/// Cheats of at most [`SHORT_CHEAT`] steps saving at least `min_saving` picoseconds, the answer of part 1
+///
+/// The puzzle counts the cheats saving [`MIN_SAVING`], no cheat on the
+/// sample saves that much so its answers count [`SAMPLE_MIN_SAVING`].
+/// Fails with [`Day20Error::NoPath`] when walls separate the end from the start
+///
+/// # Examples
+/// ```
+/// fn main() -> day_20::Result<()> {
+///     let track = day_20::parse_track(day_20::SAMPLE)?;
+///     assert_eq!(day_20::part1(&track, day_20::SAMPLE_MIN_SAVING)?, 1);
+///     assert_eq!(day_20::part1(&track, day_20::MIN_SAVING)?, 0);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(track: &Track, min_saving: usize) -> Result<usize> {
+    count_cheats(track, SHORT_CHEAT, min_saving).ok_or(Day20Error::NoPath)
+}
+
+/// Cheats of at most [`LONG_CHEAT`] steps saving at least `min_saving` picoseconds, the answer of part 2
+///
+/// Fails with [`Day20Error::NoPath`] when walls separate the end from the start
+///
+/// # Examples
+/// ```
+/// fn main() -> day_20::Result<()> {
+///     let track = day_20::parse_track(day_20::SAMPLE)?;
+///     assert_eq!(day_20::part2(&track, day_20::SAMPLE_MIN_SAVING)?, 285);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(track: &Track, min_saving: usize) -> Result<usize> {
+    count_cheats(track, LONG_CHEAT, min_saving).ok_or(Day20Error::NoPath)
+}
+
+/// Racetrack parsed for the runner with the picoseconds a cheat has to save on it
+struct Race {
+    track: Track,
+    min_saving: usize,
+}
+
+impl Race {
+    fn parse(input: &str, min_saving: usize) -> Result<Self> {
+        Ok(Race { track: parse_track(input)?, min_saving })
+    }
+}
+
+/// Day 20 solution used by the `aoc` runner
+///
+/// Part 1 counts the cheats of up to 2 picoseconds saving `min_saving`,
+/// part 2 the cheats of up to 20. The default counts the cheats saving
+/// [`MIN_SAVING`] like the puzzle input.
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     let sample = day_20::Day20 { min_saving: day_20::SAMPLE_MIN_SAVING };
+///     assert_eq!(sample.part1(day_20::SAMPLE)?, "1");
+///     assert_eq!(sample.part2(day_20::SAMPLE)?, "285");
+///     assert_eq!(day_20::Day20::default().part2(day_20::SAMPLE)?, "0");
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day20 {
+    /// Picoseconds a cheat has to save to be counted
+    pub min_saving: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Day20 { min_saving: MIN_SAVING }
+    }
+}
+
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Race::parse(input, self.min_saving)?.solve(1)
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Race::parse(input, self.min_saving)?.solve(2)
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let race = Race::parse(input, self.min_saving)?;
+        Ok((race.solve(1)?, race.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn sample_solution(&self) -> Option<Box<dyn Solution>> {
+        Some(Box::new(Day20 { min_saving: SAMPLE_MIN_SAVING }))
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Race::parse(input, self.min_saving)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Race {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(&self.track, self.min_saving)?.to_string()),
+            _ => Ok(part2(&self.track, self.min_saving)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a racetrack
+fn format_problem(input: &str) -> Option<String> {
+    parse_track(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 20 input with a `size` x `size` racetrack, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_20::generate(21, 7);
+/// assert_eq!(input.lines().count(), 21);
+/// assert_eq!(day_20::generate(21, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day20(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let track = parse_track(SAMPLE)?;
+        assert_eq!(part1(&track, SAMPLE_MIN_SAVING)?, 1);
+        assert_eq!(part2(&track, SAMPLE_MIN_SAVING)?, 285);
+        assert_eq!(Day20 { min_saving: SAMPLE_MIN_SAVING }.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_min_saving() -> Result<()> {
+        // the sample counts the cheats saving the puzzle minimum unless told otherwise
+        assert_eq!(Day20::default().solve_both(SAMPLE)?, ("0".to_string(), "0".to_string()));
+        assert_eq!(Day20 { min_saving: 76 }.solve_both(SAMPLE)?, ("0".to_string(), "3".to_string()));
+        let sample = Day20::default().sample_solution().map(|solution| solution.solve_both(SAMPLE));
+        assert_eq!(sample.transpose()?, Some((SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_short_cheat_histogram() -> Result<()> {
+        let track = parse_track(SAMPLE)?;
+        let savings = cheat_savings(&track, SHORT_CHEAT).unwrap_or_default();
+        let expected = BTreeMap::from([(2, 14), (4, 14), (6, 2), (8, 4), (10, 2), (12, 3), (20, 1), (36, 1), (38, 1), (40, 1), (64, 1)]);
+        assert_eq!(savings, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_cheat_histogram() -> Result<()> {
+        let track = parse_track(SAMPLE)?;
+        let savings = cheat_savings(&track, LONG_CHEAT).unwrap_or_default();
+        let expected = BTreeMap::from([(50, 32), (52, 31), (54, 29), (56, 39), (58, 25), (60, 23), (62, 20), (64, 19),
+            (66, 12), (68, 14), (70, 12), (72, 22), (74, 4), (76, 3)]);
+        assert_eq!(savings.range(50..).map(|(&saving, &count)| (saving, count)).collect::<BTreeMap<_, _>>(), expected);
+        // the counts above a threshold add up the histogram
+        for min_saving in [50, 64, 76, 77] {
+            let above: usize = expected.range(min_saving..).map(|(_, count)| count).sum();
+            assert_eq!(count_cheats(&track, LONG_CHEAT, min_saving), Some(above));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_offsets_within_radius() {
+        // a diamond of radius 2 has 13 tiles, minus the center and its 4 neighbours
+        assert_eq!(offsets(2).len(), 8);
+        assert_eq!(offsets(20).len(), 2 * 20 * 21 + 1 - 5);
+        assert!(offsets(20).iter().all(|&(d_row, d_col, len)| (2..=20).contains(&len) && d_row.unsigned_abs() + d_col.unsigned_abs() == len));
+        assert!(offsets(1).is_empty());
+    }
+
+    #[test]
+    fn test_no_path() -> Result<()> {
+        let track = parse_track("S#E\n")?;
+        assert_eq!(count_cheats(&track, 2, 1), None);
+        assert!(matches!(part1(&track, MIN_SAVING), Err(Day20Error::NoPath)));
+        assert!(matches!(part2(&track, MIN_SAVING), Err(Day20Error::NoPath)));
+        assert_eq!(Day20Error::NoPath.kind(), ErrorKind::Solver);
+        // a straight track has no shortcut to take
+        assert_eq!(count_cheats(&parse_track("S....E\n")?, LONG_CHEAT, 1), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_track("#S.\n#x.\n");
+        assert!(matches!(&result, Err(Day20Error::UnknownTile { line: 2, column: 2, tile: 'x' })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse_track("SE\nE.\n"), Err(Day20Error::ExtraTile { line: 2, column: 1, tile: 'E' })));
+        assert!(matches!(parse_track(".E\n"), Err(Day20Error::MissingTile { tile: 'S' })));
+        assert!(matches!(parse_track("SE\n.\n"), Err(Day20Error::Grid(_))));
+        assert_eq!(parse_track("").unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_track_serde_round_trip() -> Result<()> {
+        let track = parse_track("#SE\n")?;
+        let json = serde_json::to_string(&track)?;
+        assert_eq!(json, r#"{"walls":[[true,false,false]],"start":[0,1],"end":[0,2]}"#);
+        assert_eq!(serde_json::from_str::<Track>(&json)?, track);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day20::default().describe(SAMPLE).problem, None);
+        assert_eq!(Day20::default().describe("#S#\n").problem.as_deref(), Some("the racetrack has no E"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day20 { min_saving: SAMPLE_MIN_SAVING }.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day20(seed, 41);
+            assert_eq!(Day20::default().solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
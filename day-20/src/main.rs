@@ -0,0 +1,31 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    // second argument is the picoseconds a cheat has to save, `50` for the sample
+    let min_saving = match std::env::args().nth(2) {
+        Some(arg) => arg.parse().with_context(|| format!("invalid min saving {}", arg))?,
+        None => day_20::MIN_SAVING,
+    };
+
+    let (part1, part2) = day_20::Day20 { min_saving }.solve_both(&input.read_to_string()?)?;
+
+    println!("SHORT_CHEATS: {}", part1);
+    println!("LONG_CHEATS: {}", part2);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_20::Day20Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_20` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_20", |data| {
+        if let Ok(track) = day_20::parse_track(&String::from_utf8_lossy(data)) {
+            let _ = day_20::part1(&track, day_20::MIN_SAVING);
+            let _ = day_20::part2(&track, day_20::MIN_SAVING);
+        }
+    });
+}
@@ -0,0 +1,39 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-20"))
+    .args(["-", "50"])
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-20");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_285.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-20");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "SHORT_CHEATS: 1\nLONG_CHEATS: 285\n");
+}
+
+/// Exit code of day-20 solving the input piped to stdin
+fn exit_code(input: &str, args: &[&str]) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-20"))
+    .arg("-")
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-20");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-20").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("S.E\n", &[]), Some(0));
+    assert_eq!(exit_code("S.x\n", &[]), Some(3));
+    assert_eq!(exit_code("S#E\n", &[]), Some(4));
+    assert_eq!(exit_code("S.E\n", &["0"]), Some(0));
+    assert_ne!(exit_code("S.E\n", &["-1"]), Some(0));
+}
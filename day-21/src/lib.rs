@@ -0,0 +1,517 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 21 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 21 functions
+#[derive(Debug, Error)]
+pub enum Day21Error {
+    /// A character of a code is not a key of the numeric keypad
+    #[error("unexpected {key} on line {line}, column {column}")]
+    UnknownKey {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        key: char,
+    },
+    /// The numeric part of a code does not fit `u64`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Digits that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// A button press count or the sum of the complexities does not fit `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day21Error> for SolutionError {
+    fn from(e: Day21Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day21Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day21Error::UnknownKey { line, .. } | Day21Error::Number { line, .. } => Some(Location::Line(*line)),
+            Day21Error::Overflow { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day21Error::UnknownKey { .. } | Day21Error::Number { .. } => ErrorKind::Parse,
+            Day21Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 21 functions
+pub type Result<T, E = Day21Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "029A\n980A\n179A\n456A\n379A\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["126384", "154115708116294"];
+
+/// Directional keypads operated by robots in part 1
+pub const ROBOTS: usize = 2;
+
+/// Directional keypads operated by robots in part 2
+pub const MORE_ROBOTS: usize = 25;
+
+/// Keypad a robot arm presses the keys of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Keypad {
+    /// Digits `0` to `9` and `A` of the door, with a gap in the bottom left corner
+    Numeric,
+    /// Arrows `^`, `<`, `v`, `>` and `A` moving a robot arm, with a gap in the top left corner
+    Directional,
+}
+
+impl Keypad {
+    /// Keys row by row, a space for the gap
+    fn rows(self) -> &'static [&'static str] {
+        match self {
+            Keypad::Numeric => &["789", "456", "123", " 0A"],
+            Keypad::Directional => &[" ^A", "<v>"],
+        }
+    }
+
+    /// Row and column of the `key`, `None` when the keypad has no such key
+    ///
+    /// # Examples
+    /// ```
+    /// use day_21::Keypad;
+    ///
+    /// assert_eq!(Keypad::Numeric.position('0'), Some((3, 1)));
+    /// assert_eq!(Keypad::Directional.position('<'), Some((1, 0)));
+    /// assert_eq!(Keypad::Directional.position('7'), None);
+    /// ```
+    pub fn position(self, key: char) -> Option<Pos> {
+        if key == ' ' {
+            return None;
+        }
+        self.rows().iter().enumerate().find_map(|(row, keys)| keys.find(key).map(|col| (row, col)))
+    }
+
+    /// Row and column of the gap no robot arm may ever point at
+    pub fn gap(self) -> Pos {
+        match self {
+            Keypad::Numeric => (3, 0),
+            Keypad::Directional => (0, 0),
+        }
+    }
+
+    /// Arrows moving an arm from the `from` key to the `to` key, horizontal then vertical and vertical then horizontal, skipping the ones crossing the gap
+    ///
+    /// Moves going back and forth between the two directions only cost more
+    /// presses further up, so the two moves turning once cover every
+    /// shortest sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// use day_21::Keypad;
+    ///
+    /// assert_eq!(Keypad::Numeric.moves('2', '9'), Some(vec![">^^".to_string(), "^^>".to_string()]));
+    /// // from A to 1 going left first would cross the gap
+    /// assert_eq!(Keypad::Numeric.moves('A', '1'), Some(vec!["^<<".to_string()]));
+    /// assert_eq!(Keypad::Directional.moves('<', '^'), Some(vec![">^".to_string()]));
+    /// ```
+    pub fn moves(self, from: char, to: char) -> Option<Vec<String>> {
+        let ((from_row, from_col), (to_row, to_col)) = (self.position(from)?, self.position(to)?);
+        let horizontal = if to_col > from_col { ">" } else { "<" }.repeat(from_col.abs_diff(to_col));
+        let vertical = if to_row > from_row { "v" } else { "^" }.repeat(from_row.abs_diff(to_row));
+        let mut moves = Vec::with_capacity(2);
+        if (from_row, to_col) != self.gap() {
+            moves.push(format!("{horizontal}{vertical}"));
+        }
+        let turned = format!("{vertical}{horizontal}");
+        if (to_row, from_col) != self.gap() && !moves.contains(&turned) {
+            moves.push(turned);
+        }
+        Some(moves)
+    }
+}
+
+/// Button presses on the human keypad through a chain of robots, memoized by move
+///
+/// The door's numeric keypad is pressed by a robot driven from a chain of
+/// `robots` directional keypads pressed by robots, driven in turn from
+/// the directional keypad the human presses. Moving the arm of one keypad
+/// between two keys and pressing the second only depends on the keys and
+/// how many keypads are above, so each `(from, to, depth)` is expanded
+/// once and the sequences themselves are never built.
+///
+/// # Examples
+/// ```
+/// let mut chain = day_21::RobotChain::new(2);
+/// assert_eq!(chain.presses("029A"), Some(68));
+/// assert_eq!(day_21::RobotChain::new(0).presses("029A"), Some(12));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RobotChain {
+    robots: usize,
+    memo: HashMap<(char, char, usize), u64>,
+}
+
+impl RobotChain {
+    /// Chain with `robots` directional keypads pressed by robots between the human and the door
+    pub fn new(robots: usize) -> Self {
+        RobotChain { robots, memo: HashMap::new() }
+    }
+
+    /// Fewest presses on the human keypad typing the `code` on the door, `None` when a key is not on the numeric keypad or the count does not fit `u64`
+    pub fn presses(&mut self, code: &str) -> Option<u64> {
+        self.type_keys(Keypad::Numeric, code, self.robots + 1)
+    }
+
+    /// Presses on the human keypad typing the `keys` on the `keypad` with `depth` directional keypads above it, the arm starting on `A`
+    fn type_keys(&mut self, keypad: Keypad, keys: &str, depth: usize) -> Option<u64> {
+        let mut from = 'A';
+        let mut total = 0u64;
+        for to in keys.chars() {
+            total = total.checked_add(self.press(keypad, from, to, depth)?)?;
+            from = to;
+        }
+        Some(total)
+    }
+
+    /// Presses on the human keypad moving the arm of the `keypad` from `from` to `to` and pressing it
+    ///
+    /// The human presses the keys of the keypad at depth 0 directly. The
+    /// numeric keypad is only ever at the top, so the depth tells the
+    /// keypads apart in the memo.
+    fn press(&mut self, keypad: Keypad, from: char, to: char, depth: usize) -> Option<u64> {
+        if depth == 0 {
+            return Some(1);
+        }
+        if let Some(&presses) = self.memo.get(&(from, to, depth)) {
+            return Some(presses);
+        }
+        let mut best: Option<u64> = None;
+        for moves in keypad.moves(from, to)? {
+            let presses = self.type_keys(Keypad::Directional, &format!("{moves}A"), depth - 1)?;
+            best = Some(best.map_or(presses, |best| best.min(presses)));
+        }
+        let best = best?;
+        self.memo.insert((from, to, depth), best);
+        Some(best)
+    }
+}
+
+/// Code to type on the door
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Code {
+    /// Keys of the numeric keypad, in order
+    pub keys: String,
+    /// Number the digits of the code make, ignoring leading zeros
+    pub value: u64,
+}
+
+/// Codes of the doors, parsed once for the two parts
+///
+/// # Examples
+/// ```
+/// fn main() -> day_21::Result<()> {
+///     let codes = day_21::Codes::try_from("029A\n")?;
+///     assert_eq!(codes.0[0].keys, "029A");
+///     assert_eq!(codes.0[0].value, 29);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Codes(pub Vec<Code>);
+
+impl TryFrom<&str> for Codes {
+    type Error = Day21Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_code(i + 1, line.trim()))
+        .collect::<Result<Vec<Code>>>()
+        .map(Codes)
+    }
+}
+
+/// Parse one code, checking every key is on the numeric keypad
+fn parse_code(line: usize, text: &str) -> Result<Code> {
+    if let Some((i, key)) = text.chars().enumerate().find(|&(_, key)| Keypad::Numeric.position(key).is_none()) {
+        return Err(Day21Error::UnknownKey { line, column: i + 1, key });
+    }
+    let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+    let value = match digits.as_str() {
+        "" => 0,
+        digits => digits.parse().map_err(|source| Day21Error::Number { line, token: digits.to_string(), source })?,
+    };
+    Ok(Code { keys: text.to_string(), value })
+}
+
+/// Parse a door code on every line, blank lines are skipped
+///
+/// # Examples
+/// ```
+/// let codes = day_21::parse_codes(day_21::SAMPLE).unwrap();
+/// assert_eq!(codes.0.len(), 5);
+/// assert_eq!(codes.0[4].value, 379);
+/// assert!(matches!(day_21::parse_codes("02B\n"), Err(day_21::Day21Error::UnknownKey { line: 1, column: 3, key: 'B' })));
+/// ```
+pub fn parse_codes(text: &str) -> Result<Codes> {
+    Codes::try_from(text)
+}
+
+/// Sum of the complexities of the codes, presses on the human keypad times the numeric part, with `robots` robots on directional keypads
+///
+/// `None` when it does not fit `u64`
+///
+/// # Examples
+/// ```
+/// fn main() -> day_21::Result<()> {
+///     let codes = day_21::parse_codes(day_21::SAMPLE)?;
+///     assert_eq!(day_21::complexity(&codes.0, 2), Some(126384));
+///     Ok(())
+/// }
+/// ```
+pub fn complexity(codes: &[Code], robots: usize) -> Option<u64> {
+    let mut chain = RobotChain::new(robots);
+    codes.iter().try_fold(0u64, |total, code| {
+        total.checked_add(chain.presses(&code.keys)?.checked_mul(code.value)?)
+    })
+}
+
+/// Sum of the complexities with [`ROBOTS`] robots on directional keypads, the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_21::Result<()> {
+///     let codes = day_21::parse_codes(day_21::SAMPLE)?;
+///     assert_eq!(day_21::part1(&codes)?, 126384);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(codes: &Codes) -> Result<u64> {
+    complexity(&codes.0, ROBOTS).ok_or(Day21Error::Overflow { part: 1 })
+}
+
+/// Sum of the complexities with [`MORE_ROBOTS`] robots on directional keypads, the answer of part 2
+///
+/// # Examples
+/// ```
+/// fn main() -> day_21::Result<()> {
+///     let codes = day_21::parse_codes(day_21::SAMPLE)?;
+///     assert_eq!(day_21::part2(&codes)?, 154115708116294);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(codes: &Codes) -> Result<u64> {
+    complexity(&codes.0, MORE_ROBOTS).ok_or(Day21Error::Overflow { part: 2 })
+}
+
+/// Day 21 solution used by the `aoc` runner
+///
+/// Part 1 sums the complexities through 2 robots on directional keypads,
+/// part 2 through 25
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_21::Day21.part1(day_21::SAMPLE)?, "126384");
+///     assert_eq!(day_21::Day21.part2(day_21::SAMPLE)?, "154115708116294");
+///     Ok(())
+/// }
+/// ```
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_codes(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_codes(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let codes = parse_codes(input)?;
+        Ok((codes.solve(1)?, codes.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_codes(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Codes {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like door codes
+fn format_problem(input: &str) -> Option<String> {
+    parse_codes(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 21 input with `size` door codes, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_21::generate(10, 7);
+/// assert_eq!(input.lines().count(), 10);
+/// assert_eq!(day_21::generate(10, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day21(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let codes = parse_codes(SAMPLE)?;
+        assert_eq!(part1(&codes)?, 126384);
+        assert_eq!(part2(&codes)?, 154115708116294);
+        assert_eq!(Day21.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_codes() -> Result<()> {
+        let mut chain = RobotChain::new(ROBOTS);
+        let codes = parse_codes(SAMPLE)?;
+        let lengths: Vec<Option<u64>> = codes.0.iter().map(|code| chain.presses(&code.keys)).collect();
+        assert_eq!(lengths, [Some(68), Some(60), Some(68), Some(64), Some(64)]);
+        let values: Vec<u64> = codes.0.iter().map(|code| code.value).collect();
+        assert_eq!(values, [29, 980, 179, 456, 379]);
+        // fewer robots in between, as the puzzle builds the example up
+        assert_eq!(RobotChain::new(0).presses("029A"), Some("<A^A>^^AvvvA".len() as u64));
+        assert_eq!(RobotChain::new(1).presses("029A"), Some("v<<A>>^A<A>AvA<^AA>A<vAAA>^A".len() as u64));
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_gap_avoided() {
+        // from 0 or A to the left column has to go up before going left
+        assert_eq!(Keypad::Numeric.moves('0', '1'), Some(vec!["^<".to_string()]));
+        assert_eq!(Keypad::Numeric.moves('A', '7'), Some(vec!["^^^<<".to_string()]));
+        // and back down it has to go right before going down
+        assert_eq!(Keypad::Numeric.moves('4', 'A'), Some(vec![">>vv".to_string()]));
+        assert_eq!(Keypad::Numeric.moves('5', '5'), Some(vec![String::new()]));
+        assert!(Keypad::Numeric.moves(' ', '5').is_none());
+    }
+
+    #[test]
+    fn test_directional_gap_avoided() {
+        // from < to the top row has to go right before going up
+        assert_eq!(Keypad::Directional.moves('<', 'A'), Some(vec![">>^".to_string()]));
+        assert_eq!(Keypad::Directional.moves('^', '<'), Some(vec!["v<".to_string()]));
+        assert_eq!(Keypad::Directional.moves('v', 'A'), Some(vec![">^".to_string(), "^>".to_string()]));
+        assert_eq!(Keypad::Directional.position(' '), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // every robot roughly doubles the presses
+        let codes = [Code { keys: "379A".to_string(), value: 379 }];
+        assert!(complexity(&codes, 25).is_some());
+        assert_eq!(complexity(&codes, 100), None);
+        assert_eq!(Day21Error::Overflow { part: 2 }.kind(), ErrorKind::Solver);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_codes("029A\n98x\n");
+        assert!(matches!(&result, Err(Day21Error::UnknownKey { line: 2, column: 3, key: 'x' })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_codes(&format!("{}A\n", "9".repeat(25)));
+        assert!(matches!(&result, Err(Day21Error::Number { line: 1, .. })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse_codes("A\n"), Result::Ok(codes) if codes.0[0].value == 0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_codes_serde_round_trip() -> Result<()> {
+        let codes = parse_codes("029A\n")?;
+        let json = serde_json::to_string(&codes)?;
+        assert_eq!(json, r#"[{"keys":"029A","value":29}]"#);
+        assert_eq!(serde_json::from_str::<Codes>(&json)?, codes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day21.describe(SAMPLE).problem, None);
+        assert_eq!(Day21.describe("029A\n98B\n").problem.as_deref(), Some("unexpected B on line 2, column 3"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day21.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day21(seed, 20);
+            assert_eq!(Day21.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let codes = day_21::parse_codes(&input.read_to_string()?)?;
+
+    println!("COMPLEXITY_2_ROBOTS: {}", day_21::part1(&codes)?);
+    println!("COMPLEXITY_25_ROBOTS: {}", day_21::part2(&codes)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_21::Day21Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_21` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_21", |data| {
+        if let Ok(codes) = day_21::parse_codes(&String::from_utf8_lossy(data)) {
+            let _ = day_21::part1(&codes);
+            let _ = day_21::part2(&codes);
+        }
+    });
+}
@@ -0,0 +1,36 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-21"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-21");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_126384.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-21");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "COMPLEXITY_2_ROBOTS: 126384\nCOMPLEXITY_25_ROBOTS: 154115708116294\n");
+}
+
+/// Exit code of day-21 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-21"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-21");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-21").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("029A\n"), Some(0));
+    assert_eq!(exit_code("02B\n"), Some(3));
+    assert_eq!(exit_code("9999999999999999999A\n"), Some(4));
+}
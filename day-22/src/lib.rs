@@ -0,0 +1,510 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 22 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 22 functions
+#[derive(Debug, Error)]
+pub enum Day22Error {
+    /// A line is not an unsigned number fitting `u64`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+}
+
+impl From<Day22Error> for SolutionError {
+    fn from(e: Day22Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day22Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day22Error::Number { line, .. } => Some(Location::Line(*line)),
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day22Error::Number { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 22 functions
+pub type Result<T, E = Day22Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "1\n10\n100\n2024\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["37327623", "24"];
+
+/// New secret numbers each buyer generates in a day
+pub const SECRETS: usize = 2000;
+
+/// Price changes the monkey watches for before selling
+pub const CHANGES: usize = 4;
+
+/// Distinct price changes, `-9` to `9`
+const DELTAS: usize = 19;
+
+/// Distinct sequences of [`CHANGES`] price changes, the slots of [`banana_totals`]
+pub const SEQUENCES: usize = DELTAS * DELTAS * DELTAS * DELTAS;
+
+/// Modulus of the prune step, every secret number after the first is below it
+const PRUNE: u64 = 1 << 24;
+
+/// Next secret number of a buyer, mixing in the secret times 64, divided by 32 and times 2048, pruning after each
+///
+/// Only the bits below [`PRUNE`] of a secret ever reach the next one, so a
+/// first secret too big for the multiplications is pruned up front.
+///
+/// # Examples
+/// ```
+/// assert_eq!(day_22::next_secret(123), 15887950);
+/// assert_eq!(day_22::next_secret(15887950), 16495136);
+/// ```
+pub fn next_secret(secret: u64) -> u64 {
+    let secret = secret % PRUNE;
+    let secret = (secret ^ (secret << 6)) % PRUNE;
+    let secret = secret ^ (secret >> 5);
+    (secret ^ (secret << 11)) % PRUNE
+}
+
+/// Secret number a buyer generates `n` steps after the `secret`
+///
+/// # Examples
+/// ```
+/// assert_eq!(day_22::nth_secret(1, 2000), 8685429);
+/// assert_eq!(day_22::nth_secret(123, 0), 123);
+/// ```
+pub fn nth_secret(secret: u64, n: usize) -> u64 {
+    (0..n).fold(secret, |secret, _| next_secret(secret))
+}
+
+/// Initial secret numbers of the buyers, one per line
+///
+/// # Examples
+/// ```
+/// fn main() -> day_22::Result<()> {
+///     let buyers = day_22::Buyers::try_from("1\n10\n")?;
+///     assert_eq!(buyers.0, [1, 10]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Buyers(pub Vec<u64>);
+
+impl TryFrom<&str> for Buyers {
+    type Error = Day22Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let token = line.trim();
+            token.parse().map_err(|source| Day22Error::Number { line: i + 1, token: token.to_string(), source })
+        })
+        .collect::<Result<Vec<u64>>>()
+        .map(Buyers)
+    }
+}
+
+/// Parse the initial secret number of a buyer on every line, blank lines are skipped
+///
+/// # Examples
+/// ```
+/// let buyers = day_22::parse_buyers(day_22::SAMPLE).unwrap();
+/// assert_eq!(buyers.0, [1, 10, 100, 2024]);
+/// assert!(matches!(day_22::parse_buyers("1\n-2\n"), Err(day_22::Day22Error::Number { line: 2, .. })));
+/// ```
+pub fn parse_buyers(text: &str) -> Result<Buyers> {
+    Buyers::try_from(text)
+}
+
+/// Sum of the [`SECRETS`]th secret number of every buyer, the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_22::Result<()> {
+///     let buyers = day_22::parse_buyers(day_22::SAMPLE)?;
+///     assert_eq!(day_22::part1(&buyers), 37327623);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(buyers: &Buyers) -> u64 {
+    buyers.0.iter().map(|&secret| nth_secret(secret, SECRETS)).sum()
+}
+
+/// Slot of a sequence of [`CHANGES`] price changes in [`banana_totals`], `None` when a change is not in `-9..=9`
+///
+/// # Examples
+/// ```
+/// assert_eq!(day_22::sequence_key([-9, -9, -9, -9]), Some(0));
+/// assert_eq!(day_22::sequence_key([9, 9, 9, 9]), Some(day_22::SEQUENCES - 1));
+/// assert_eq!(day_22::sequence_key([10, 0, 0, 0]), None);
+/// ```
+pub fn sequence_key(changes: [i8; CHANGES]) -> Option<usize> {
+    changes.iter().try_fold(0, |key, &change| {
+        (-9..=9).contains(&change).then(|| key * DELTAS + (change + 9) as usize)
+    })
+}
+
+/// Sequence of price changes stored in the `key` slot of [`banana_totals`]
+///
+/// # Examples
+/// ```
+/// let key = day_22::sequence_key([-2, 1, -1, 3]).unwrap();
+/// assert_eq!(day_22::sequence_of(key), [-2, 1, -1, 3]);
+/// ```
+pub fn sequence_of(key: usize) -> [i8; CHANGES] {
+    let mut changes = [0; CHANGES];
+    let mut rest = key;
+    for change in changes.iter_mut().rev() {
+        *change = (rest % DELTAS) as i8 - 9;
+        rest /= DELTAS;
+    }
+    changes
+}
+
+/// Bananas one buyer adds to `totals`, selling at the first occurrence of every sequence of changes
+///
+/// The last [`CHANGES`] changes are packed into the key as base 19 digits,
+/// so shifting a new change in drops the oldest one. `seen` is a bitmap of
+/// the keys the buyer already sold at, cleared before the buyer starts.
+fn add_buyer(totals: &mut [u64], seen: &mut [u64], secret: u64) {
+    seen.fill(0);
+    let mut secret = secret;
+    let mut price = secret % 10;
+    let mut key = 0;
+    for step in 0..SECRETS {
+        secret = next_secret(secret);
+        let next = secret % 10;
+        key = (key * DELTAS + (next + 9 - price) as usize) % SEQUENCES;
+        price = next;
+        if step + 1 < CHANGES {
+            continue;
+        }
+        let (word, bit) = (key / 64, 1 << (key % 64));
+        if seen[word] & bit == 0 {
+            seen[word] |= bit;
+            totals[key] += price;
+        }
+    }
+}
+
+/// Words of the per-buyer seen bitmap
+const SEEN_WORDS: usize = SEQUENCES.div_ceil(64);
+
+/// Go through the buyers one at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn banana_totals_serial(secrets: &[u64]) -> Vec<u64> {
+    let mut totals = vec![0; SEQUENCES];
+    let mut seen = vec![0; SEEN_WORDS];
+    for &secret in secrets {
+        add_buyer(&mut totals, &mut seen, secret);
+    }
+    totals
+}
+
+/// Split the buyers between the rayon threads, each with its own totals, and add those up
+#[cfg(feature = "parallel")]
+fn banana_totals_parallel(secrets: &[u64]) -> Vec<u64> {
+    use rayon::prelude::*;
+
+    secrets.par_iter()
+    .fold(|| (vec![0; SEQUENCES], vec![0; SEEN_WORDS]), |(mut totals, mut seen), &secret| {
+        add_buyer(&mut totals, &mut seen, secret);
+        (totals, seen)
+    })
+    .map(|(totals, _)| totals)
+    .reduce(|| vec![0; SEQUENCES], |mut totals, other| {
+        totals.iter_mut().zip(other).for_each(|(total, other)| *total += other);
+        totals
+    })
+}
+
+/// Bananas earned with every sequence of [`CHANGES`] price changes, indexed by [`sequence_key`]
+///
+/// Every buyer sells at the first time the sequence shows up in its
+/// prices, the ones digits of its secret numbers. With the `parallel`
+/// feature the buyers are split between the rayon threads.
+///
+/// # Examples
+/// ```
+/// let totals = day_22::banana_totals(&[1, 2, 3, 2024]);
+/// assert_eq!(totals.len(), day_22::SEQUENCES);
+/// assert_eq!(totals[day_22::sequence_key([-2, 1, -1, 3]).unwrap()], 23);
+/// ```
+pub fn banana_totals(secrets: &[u64]) -> Vec<u64> {
+    #[cfg(feature = "parallel")]
+    let totals = banana_totals_parallel(secrets);
+    #[cfg(not(feature = "parallel"))]
+    let totals = banana_totals_serial(secrets);
+    totals
+}
+
+/// Sequence of price changes earning the most bananas and how many, the first one on a tie
+///
+/// `None` without buyers
+///
+/// # Examples
+/// ```
+/// assert_eq!(day_22::best_sequence(&[1, 2, 3, 2024]), Some(([-2, 1, -1, 3], 23)));
+/// assert_eq!(day_22::best_sequence(&[]), None);
+/// ```
+pub fn best_sequence(secrets: &[u64]) -> Option<([i8; CHANGES], u64)> {
+    if secrets.is_empty() {
+        return None;
+    }
+    let totals = banana_totals(secrets);
+    let (key, &bananas) = totals.iter().enumerate().rev().max_by_key(|&(_, bananas)| bananas)?;
+    Some((sequence_of(key), bananas))
+}
+
+/// Most bananas a single sequence of price changes earns, the answer of part 2
+///
+/// # Examples
+/// ```
+/// fn main() -> day_22::Result<()> {
+///     assert_eq!(day_22::part2(&day_22::parse_buyers("1\n2\n3\n2024\n")?), 23);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(buyers: &Buyers) -> u64 {
+    best_sequence(&buyers.0).map_or(0, |(_, bananas)| bananas)
+}
+
+/// Day 22 solution used by the `aoc` runner
+///
+/// Part 1 sums the 2000th secret numbers of the buyers, part 2 finds the
+/// most bananas a sequence of four price changes earns
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_22::Day22.part1(day_22::SAMPLE)?, "37327623");
+///     assert_eq!(day_22::Day22.part2("1\n2\n3\n2024\n")?, "23");
+///     Ok(())
+/// }
+/// ```
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_buyers(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_buyers(input)?).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let buyers = parse_buyers(input)?;
+        Ok((buyers.solve(1)?, buyers.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_buyers(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Buyers {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self).to_string()),
+            _ => Ok(part2(self).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like the secret numbers of the buyers
+fn format_problem(input: &str) -> Option<String> {
+    parse_buyers(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 22 input with `size` buyers, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_22::generate(10, 7);
+/// assert_eq!(input.lines().count(), 10);
+/// assert_eq!(day_22::generate(10, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day22(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Secret numbers following 123 in the puzzle description
+    const TRACE: [u64; 11] = [123, 15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254];
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let buyers = parse_buyers(SAMPLE)?;
+        assert_eq!(part1(&buyers), 37327623);
+        assert_eq!(part2(&buyers), 24);
+        assert_eq!(Day22.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_secrets() {
+        let secrets: Vec<u64> = [1, 10, 100, 2024].iter().map(|&secret| nth_secret(secret, SECRETS)).collect();
+        assert_eq!(secrets, [8685429, 4700978, 15273692, 8667524]);
+        assert_eq!(TRACE.windows(2).find(|pair| next_secret(pair[0]) != pair[1]), None);
+    }
+
+    #[test]
+    fn test_second_sample() -> Result<()> {
+        let buyers = parse_buyers("1\n2\n3\n2024\n")?;
+        assert_eq!(best_sequence(&buyers.0), Some(([-2, 1, -1, 3], 23)));
+        // the buyer with 3 never sees the sequence
+        let totals = banana_totals(&[3]);
+        assert_eq!(totals[sequence_key([-2, 1, -1, 3]).unwrap()], 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_occurrence_only() {
+        let prices: Vec<u64> = TRACE.iter().map(|secret| secret % 10).collect();
+        assert_eq!(prices, [3, 0, 6, 5, 4, 4, 6, 4, 4, 2, 4]);
+        let totals = banana_totals(&[123]);
+        assert_eq!(totals[sequence_key([-1, -1, 0, 2]).unwrap()], 6);
+        // every buyer sells once per sequence, so a single buyer never tops 9
+        assert!(banana_totals(&[1, 10, 100]).iter().all(|&total| total <= 27));
+        assert!(banana_totals(&[123]).iter().all(|&total| total <= 9));
+    }
+
+    #[test]
+    fn test_big_secret_pruned() {
+        assert_eq!(next_secret(u64::MAX), next_secret(u64::MAX % PRUNE));
+        assert_eq!(nth_secret(123 + 5 * PRUNE, 10), TRACE[10]);
+    }
+
+    #[test]
+    fn test_sequence_keys() {
+        for key in [0, 1, 18, 19, 6859, SEQUENCES - 1] {
+            assert_eq!(sequence_key(sequence_of(key)), Some(key));
+        }
+        assert_eq!(sequence_key([0, 0, 0, -10]), None);
+    }
+
+    #[test]
+    fn test_no_buyers() -> Result<()> {
+        let buyers = parse_buyers("\n")?;
+        assert_eq!((part1(&buyers), part2(&buyers)), (0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_buyers("1\n2x\n");
+        assert!(matches!(&result, Err(Day22Error::Number { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_buyers("99999999999999999999\n");
+        assert!(matches!(&result, Err(Day22Error::Number { line: 1, .. })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse_buyers(" 7 \n"), Result::Ok(buyers) if buyers.0 == [7]));
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_banana_totals_parallel_matches_serial() {
+        let buyers = parse_buyers(&aoc_testing::day22(0x2024_0022, 200).input).unwrap();
+        assert_eq!(banana_totals_parallel(&buyers.0), banana_totals_serial(&buyers.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_buyers_serde_round_trip() -> Result<()> {
+        let buyers = parse_buyers("1\n10\n")?;
+        let json = serde_json::to_string(&buyers)?;
+        assert_eq!(json, "[1,10]");
+        assert_eq!(serde_json::from_str::<Buyers>(&json)?, buyers);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day22.describe(SAMPLE).problem, None);
+        assert_eq!(Day22.describe("1\nten\n").problem.as_deref(), Some("failed parsing ten on line 2: invalid digit found in string"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day22.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day22(seed, 20);
+            assert_eq!(Day22.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_secrets_follow_trace(from in 0..TRACE.len(), steps in 0..TRACE.len()) {
+            let steps = steps.min(TRACE.len() - 1 - from);
+            proptest::prop_assert_eq!(nth_secret(TRACE[from], steps), TRACE[from + steps]);
+        }
+
+        #[test]
+        fn test_secret_stays_pruned(secret in proptest::num::u64::ANY, wraps in 0..1024u64) {
+            let next = next_secret(secret);
+            proptest::prop_assert!(next < PRUNE);
+            proptest::prop_assert_eq!(next_secret(secret % PRUNE + wraps * PRUNE), next);
+        }
+    }
+}
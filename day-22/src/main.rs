@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let buyers = day_22::parse_buyers(&input.read_to_string()?)?;
+
+    println!("SECRET_SUM: {}", day_22::part1(&buyers));
+    println!("MOST_BANANAS: {}", day_22::part2(&buyers));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_22::Day22Error::kind)).into()
+        },
+    }
+}
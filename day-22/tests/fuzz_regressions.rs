@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_22` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_22", |data| {
+        if let Ok(buyers) = day_22::parse_buyers(&String::from_utf8_lossy(data)) {
+            let _ = day_22::part1(&buyers);
+            let _ = day_22::part2(&buyers);
+        }
+    });
+}
@@ -0,0 +1,483 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 23 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::collections::BTreeSet;
+
+/// Errors returned by the day 23 functions
+#[derive(Debug, Error)]
+pub enum Day23Error {
+    /// A line is not two computer names joined by a dash
+    #[error("expected a-b on line {line}, found {text}")]
+    Connection {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A computer is connected to itself
+    #[error("computer {name} on line {line} is connected to itself")]
+    SelfConnection {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Name of the computer
+        name: String,
+    },
+    /// The input has no connections, so there is no LAN party to find
+    #[error("the network has no computers")]
+    Empty,
+}
+
+impl From<Day23Error> for SolutionError {
+    fn from(e: Day23Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day23Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day23Error::Connection { line, .. } | Day23Error::SelfConnection { line, .. } => Some(Location::Line(*line)),
+            Day23Error::Empty => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day23Error::Connection { .. } | Day23Error::SelfConnection { .. } => ErrorKind::Parse,
+            Day23Error::Empty => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 23 functions
+pub type Result<T, E = Day23Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "kh-tc\nqp-kh\nde-cg\nka-co\nyn-aq\nqp-ub\ncg-tb\nvc-aq\ntb-ka\nwh-tc\nyn-cg\nkh-ub\nta-co\nde-co\ntc-td\ntb-wq\n\
+wh-td\nta-ka\ntd-qp\naq-cg\nwq-ub\nub-vc\nde-ta\nwq-aq\nwq-vc\nwh-yn\nka-de\nkh-ta\nco-tc\nwh-qp\ntb-vc\ntd-yn\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["7", "co,de,ka,ta"];
+
+/// Prefix of the computer names the Chief Historian may be using
+pub const HISTORIAN_PREFIX: &str = "t";
+
+/// Computers of the LAN and the connections between them
+///
+/// The computers are numbered in the order of their names, so sorting
+/// the numbers of a group of computers sorts their names too.
+///
+/// # Examples
+/// ```
+/// fn main() -> day_23::Result<()> {
+///     let network = day_23::Network::try_from("kh-tc\ntc-kh\nqp-kh\n")?;
+///     assert_eq!(network.names, ["kh", "qp", "tc"]);
+///     assert_eq!(network.links, [vec![1, 2], vec![0], vec![0]]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Network {
+    /// Names of the computers, sorted
+    pub names: Vec<String>,
+    /// Sorted numbers of the computers connected to each computer
+    pub links: Vec<Vec<usize>>,
+}
+
+impl TryFrom<&str> for Network {
+    type Error = Day23Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let pairs = text.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse_connection(i + 1, line.trim()))
+        .collect::<Result<Vec<_>>>()?;
+        let names: Vec<String> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect::<BTreeSet<_>>()
+        .into_iter().map(str::to_string).collect();
+        let mut links = vec![BTreeSet::new(); names.len()];
+        let number = |name: &str| names.binary_search_by(|probe| probe.as_str().cmp(name)).unwrap_or_default();
+        for &(a, b) in &pairs {
+            let (a, b) = (number(a), number(b));
+            links[a].insert(b);
+            links[b].insert(a);
+        }
+        Ok(Network { links: links.into_iter().map(|set| set.into_iter().collect()).collect(), names })
+    }
+}
+
+/// Parse one `a-b` connection, the names trimmed
+fn parse_connection(line: usize, text: &str) -> Result<(&str, &str)> {
+    let malformed = || Day23Error::Connection { line, text: text.to_string() };
+    let (a, b) = text.split_once('-').ok_or_else(malformed)?;
+    let (a, b) = (a.trim(), b.trim());
+    if a.is_empty() || b.is_empty() || b.contains('-') {
+        return Err(malformed());
+    }
+    if a == b {
+        return Err(Day23Error::SelfConnection { line, name: a.to_string() });
+    }
+    Ok((a, b))
+}
+
+/// Parse a connection between two computers on every line, blank lines are skipped
+///
+/// Connections are undirected, so a repeated or reversed line adds nothing.
+///
+/// # Examples
+/// ```
+/// let network = day_23::parse_network(day_23::SAMPLE).unwrap();
+/// assert_eq!(network.names.len(), 16);
+/// assert!(matches!(day_23::parse_network("kh-tc\nkhtc\n"), Err(day_23::Day23Error::Connection { line: 2, .. })));
+/// ```
+pub fn parse_network(text: &str) -> Result<Network> {
+    Network::try_from(text)
+}
+
+impl Network {
+    /// Number of the computer called `name`
+    pub fn number(&self, name: &str) -> Option<usize> {
+        self.names.binary_search_by(|probe| probe.as_str().cmp(name)).ok()
+    }
+
+    /// Whether computers `a` and `b` are connected
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        self.links.get(a).is_some_and(|links| links.binary_search(&b).is_ok())
+    }
+
+    /// Every set of three computers connected to each other, numbers in increasing order
+    ///
+    /// Each triangle is found once, from its lowest computer, by going
+    /// through the ordered pairs of its higher neighbors.
+    ///
+    /// # Examples
+    /// ```
+    /// let network = day_23::parse_network(day_23::SAMPLE).unwrap();
+    /// assert_eq!(network.triangles().len(), 12);
+    /// ```
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let mut triangles = Vec::new();
+        for (a, links) in self.links.iter().enumerate() {
+            let higher = &links[links.partition_point(|&b| b < a)..];
+            for (i, &b) in higher.iter().enumerate() {
+                for &c in &higher[i + 1..] {
+                    if self.connected(b, c) {
+                        triangles.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// Largest set of computers all connected to each other, numbers in increasing order
+    ///
+    /// Bron–Kerbosch with pivoting lists the maximal cliques, skipping the
+    /// neighbors of the pivot which only lead to cliques found from the
+    /// pivot itself, and the branches too small to beat the best one. On a
+    /// tie the clique with the smallest password wins.
+    ///
+    /// # Examples
+    /// ```
+    /// let network = day_23::parse_network("a-b\nb-c\na-c\nc-d\n").unwrap();
+    /// assert_eq!(network.max_clique(), [0, 1, 2]);
+    /// ```
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        let candidates: Vec<usize> = (0..self.names.len()).collect();
+        self.bron_kerbosch(&mut Vec::new(), candidates, Vec::new(), &mut best);
+        best
+    }
+
+    /// Extend the `clique` with the `candidates`, `excluded` holding the computers whose cliques were all listed already
+    fn bron_kerbosch(&self, clique: &mut Vec<usize>, mut candidates: Vec<usize>, mut excluded: Vec<usize>, best: &mut Vec<usize>) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && self.beats(clique, best) {
+                *best = clique.clone();
+                best.sort_unstable();
+            }
+            return;
+        }
+        if clique.len() + candidates.len() < best.len() {
+            return;
+        }
+        let pivot = candidates.iter().chain(&excluded)
+        .copied()
+        .max_by_key(|&pivot| candidates.iter().filter(|&&v| self.connected(pivot, v)).count())
+        .unwrap_or_default();
+        let branches: Vec<usize> = candidates.iter().copied().filter(|&v| !self.connected(pivot, v)).collect();
+        for v in branches {
+            let links = &self.links[v];
+            clique.push(v);
+            self.bron_kerbosch(clique,
+                candidates.iter().copied().filter(|u| links.binary_search(u).is_ok()).collect(),
+                excluded.iter().copied().filter(|u| links.binary_search(u).is_ok()).collect(),
+                best);
+            clique.pop();
+            candidates.retain(|&u| u != v);
+            excluded.push(v);
+        }
+    }
+
+    /// Whether the maximal `clique` is bigger than the `best` one or as big with a smaller password
+    fn beats(&self, clique: &[usize], best: &[usize]) -> bool {
+        match clique.len().cmp(&best.len()) {
+            std::cmp::Ordering::Equal => {
+                let mut sorted = clique.to_vec();
+                sorted.sort_unstable();
+                self.password(&sorted) < self.password(best)
+            },
+            ordering => ordering.is_gt(),
+        }
+    }
+
+    /// Names of the `computers` joined by commas
+    ///
+    /// # Examples
+    /// ```
+    /// let network = day_23::parse_network(day_23::SAMPLE).unwrap();
+    /// assert_eq!(network.password(&network.max_clique()), "co,de,ka,ta");
+    /// ```
+    pub fn password(&self, computers: &[usize]) -> String {
+        computers.iter().map(|&computer| self.names[computer].as_str()).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Number of triangles with a computer whose name starts with [`HISTORIAN_PREFIX`], the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_23::Result<()> {
+///     assert_eq!(day_23::part1(&day_23::parse_network(day_23::SAMPLE)?), 7);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(network: &Network) -> usize {
+    network.triangles().iter()
+    .filter(|triangle| triangle.iter().any(|&computer| network.names[computer].starts_with(HISTORIAN_PREFIX)))
+    .count()
+}
+
+/// Password of the LAN party, the sorted names of the largest clique joined by commas, the answer of part 2
+///
+/// Fails with [`Day23Error::Empty`] when the network has no computers
+///
+/// # Examples
+/// ```
+/// fn main() -> day_23::Result<()> {
+///     assert_eq!(day_23::part2(&day_23::parse_network(day_23::SAMPLE)?)?, "co,de,ka,ta");
+///     assert!(matches!(day_23::part2(&day_23::parse_network("")?), Err(day_23::Day23Error::Empty)));
+///     Ok(())
+/// }
+/// ```
+pub fn part2(network: &Network) -> Result<String> {
+    if network.names.is_empty() {
+        return Err(Day23Error::Empty);
+    }
+    Ok(network.password(&network.max_clique()))
+}
+
+/// Day 23 solution used by the `aoc` runner
+///
+/// Part 1 counts the triangles of computers with a possible Chief
+/// Historian, part 2 finds the password of the largest clique
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_23::Day23.part1(day_23::SAMPLE)?, "7");
+///     assert_eq!(day_23::Day23.part2(day_23::SAMPLE)?, "co,de,ka,ta");
+///     Ok(())
+/// }
+/// ```
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_network(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_network(input)?)?)
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let network = parse_network(input)?;
+        Ok((network.solve(1)?, network.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_network(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Network {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self).to_string()),
+            _ => Ok(part2(self)?),
+        }
+    }
+}
+
+/// Why the input does not look like a network map
+fn format_problem(input: &str) -> Option<String> {
+    parse_network(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 23 input with `size` computers, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_23::generate(20, 7);
+/// assert!(input.lines().all(|line| line.len() == 5));
+/// assert_eq!(day_23::generate(20, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day23(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let network = parse_network(SAMPLE)?;
+        assert_eq!(part1(&network), 7);
+        assert_eq!(part2(&network)?, "co,de,ka,ta");
+        assert_eq!(Day23.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_triangles() -> Result<()> {
+        let network = parse_network(SAMPLE)?;
+        let mut passwords: Vec<String> = network.triangles().iter().map(|triangle| network.password(triangle)).collect();
+        passwords.sort();
+        assert_eq!(passwords, ["aq,cg,yn", "aq,vc,wq", "co,de,ka", "co,de,ta", "co,ka,ta", "de,ka,ta",
+            "kh,qp,ub", "qp,td,wh", "tb,vc,wq", "tc,td,wh", "td,wh,yn", "ub,vc,wq"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_two_maximal_cliques() -> Result<()> {
+        // a triangle and a square of fully connected computers sharing bb
+        let network = parse_network("aa-bb\nbb-cc\naa-cc\nbb-dd\nbb-ee\nbb-ff\ndd-ee\ndd-ff\nee-ff\n")?;
+        assert_eq!(part2(&network)?, "bb,dd,ee,ff");
+        assert_eq!(network.triangles().len(), 5);
+        // the names decide between two cliques of the same size
+        let network = parse_network("zz-yy\nyy-xx\nxx-zz\naa-bb\nbb-cc\ncc-aa\n")?;
+        assert_eq!(part2(&network)?, "aa,bb,cc");
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_and_reversed_edges() -> Result<()> {
+        let network = parse_network("ta-co\nco-ta\nta-co\nde-ta\nco-de\n\nde-co\n")?;
+        assert_eq!(network.links, [vec![1, 2], vec![0, 2], vec![0, 1]]);
+        assert_eq!(part1(&network), 1);
+        assert_eq!(part2(&network)?, "co,de,ta");
+        Ok(())
+    }
+
+    #[test]
+    fn test_historian_anywhere() -> Result<()> {
+        // the t computer counts wherever its name falls in the triangle
+        let network = parse_network("aa-bb\nbb-ta\nta-aa\nab-tb\ntb-zz\nzz-ab\nxx-yy\nyy-zz\nzz-xx\n")?;
+        assert_eq!(network.triangles().len(), 3);
+        assert_eq!(part1(&network), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_triangles() -> Result<()> {
+        let network = parse_network("aa-bb\nbb-cc\n")?;
+        assert_eq!(part1(&network), 0);
+        assert_eq!(part2(&network)?, "aa,bb");
+        assert!(!network.connected(0, 2));
+        assert!(!network.connected(0, 5));
+        assert_eq!(network.number("cc"), Some(2));
+        assert_eq!(network.number("dd"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_network("kh-tc\nkh\n");
+        assert!(matches!(&result, Err(Day23Error::Connection { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse_network("-tc\n"), Err(Day23Error::Connection { line: 1, .. })));
+        assert!(matches!(parse_network("kh-tc-qp\n"), Err(Day23Error::Connection { line: 1, .. })));
+        let result = parse_network("kh-tc\n\ntc-tc\n");
+        assert!(matches!(&result, Err(Day23Error::SelfConnection { line: 3, name }) if name == "tc"));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert_eq!(Day23Error::Empty.kind(), ErrorKind::Solver);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_network_serde_round_trip() -> Result<()> {
+        let network = parse_network("kh-tc\n")?;
+        let json = serde_json::to_string(&network)?;
+        assert_eq!(json, r#"{"names":["kh","tc"],"links":[[1],[0]]}"#);
+        assert_eq!(serde_json::from_str::<Network>(&json)?, network);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day23.describe(SAMPLE).problem, None);
+        assert_eq!(Day23.describe("kh-tc\nkhtc\n").problem.as_deref(), Some("expected a-b on line 2, found khtc"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day23.solve_both(&input)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day23(seed, 40);
+            assert_eq!(Day23.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_23` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_23", |data| {
+        if let Ok(network) = day_23::parse_network(&String::from_utf8_lossy(data)) {
+            let _ = day_23::part1(&network);
+            let _ = day_23::part2(&network);
+        }
+    });
+}
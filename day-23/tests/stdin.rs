@@ -0,0 +1,36 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-23"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-23");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_7.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-23");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "HISTORIAN_TRIANGLES: 7\nPASSWORD: co,de,ka,ta\n");
+}
+
+/// Exit code of day-23 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-23"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-23");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-23").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("kh-tc\n"), Some(0));
+    assert_eq!(exit_code("kh-kh\n"), Some(3));
+    assert_eq!(exit_code("\n"), Some(4));
+}
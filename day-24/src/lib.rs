@@ -0,0 +1,677 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 24 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Errors returned by the day 24 functions
+#[derive(Debug, Error)]
+pub enum Day24Error {
+    /// A line with a colon is not a wire name and its initial value
+    #[error("expected wire: 0 or 1 on line {line}, found {text}")]
+    Wire {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A line with an arrow is not a gate definition
+    #[error("expected a AND|OR|XOR b -> c on line {line}, found {text}")]
+    Gate {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A wire gets a second initial value or a second gate driving it
+    #[error("wire {wire} on line {line} already has a value")]
+    Driven {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Name of the wire
+        wire: String,
+    },
+    /// A gate reads a wire nothing gives a value to
+    #[error("wire {wire} on line {line} has no value and no gate")]
+    Undriven {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Name of the wire
+        wire: String,
+    },
+    /// A gate depends on its own output, so the circuit never settles
+    #[error("the gates driving {wire} form a cycle")]
+    Cycle {
+        /// Output of a gate on the cycle
+        wire: String,
+    },
+    /// A `z` wire is past the bits of a `u64`
+    #[error("wire {wire} is past the 64 bits of the output")]
+    Overflow {
+        /// Name of the wire
+        wire: String,
+    },
+    /// The adder does not break in exactly [`SWAPPED_OUTPUTS`] places
+    #[error("expected {SWAPPED_OUTPUTS} swapped outputs, found {found}")]
+    Swaps {
+        /// Outputs breaking the shape of the adder
+        found: usize,
+    },
+}
+
+impl From<Day24Error> for SolutionError {
+    fn from(e: Day24Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day24Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day24Error::Wire { line, .. } | Day24Error::Gate { line, .. }
+            | Day24Error::Driven { line, .. } | Day24Error::Undriven { line, .. } => Some(Location::Line(*line)),
+            Day24Error::Cycle { .. } | Day24Error::Overflow { .. } | Day24Error::Swaps { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day24Error::Wire { .. } | Day24Error::Gate { .. }
+            | Day24Error::Driven { .. } | Day24Error::Undriven { .. } => ErrorKind::Parse,
+            Day24Error::Cycle { .. } | Day24Error::Overflow { .. } | Day24Error::Swaps { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 24 functions
+pub type Result<T, E = Day24Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "x00: 1\nx01: 0\nx02: 1\nx03: 1\nx04: 0\ny00: 1\ny01: 1\ny02: 1\ny03: 1\ny04: 1\n\n\
+ntg XOR fgs -> mjb\ny02 OR x01 -> tnw\nkwq OR kpj -> z05\nx00 OR x03 -> fst\ntgd XOR rvg -> z01\nvdt OR tnw -> bfw\n\
+bfw AND frj -> z10\nffh OR nrd -> bqk\ny00 AND y03 -> djm\ny03 OR y00 -> psh\nbqk OR frj -> z08\ntnw OR fst -> frj\n\
+gnj AND tgd -> z11\nbfw XOR mjb -> z00\nx03 OR x00 -> vdt\ngnj AND wpb -> z02\nx04 AND y00 -> kjc\ndjm OR pbm -> qhw\n\
+nrd AND vdt -> hwm\nkjc AND fst -> rvg\ny04 OR y02 -> fgs\ny01 AND x02 -> pbm\nntg OR kjc -> kwq\npsh XOR fgs -> tgd\n\
+qhw XOR tgd -> z09\npbm OR djm -> kpj\nx03 XOR y03 -> ffh\nx00 XOR y04 -> ntg\nbfw OR bqk -> z06\nnrd XOR fgs -> wpb\n\
+frj XOR qhw -> z04\nbqk OR frj -> z07\ny03 OR x01 -> nrd\nhwm AND bqk -> z03\ntgd XOR rvg -> z12\ntnw OR pbm -> gnj\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+///
+/// The example is no adder and the puzzle gives no answer of part 2 for
+/// it, it is left empty.
+pub const SAMPLE_ANSWERS: [&str; 2] = ["2024", ""];
+
+/// Gate outputs swapped in the adder of the puzzle input, four pairs
+pub const SWAPPED_OUTPUTS: usize = 8;
+
+/// Logic gate combining two wires
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Op {
+    /// 1 when both inputs are 1
+    And,
+    /// 1 when either input is 1
+    Or,
+    /// 1 when the inputs differ
+    Xor,
+}
+
+impl Op {
+    /// Output of the gate for the two inputs
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Xor => a != b,
+        }
+    }
+}
+
+/// Gate reading two wires and driving a third
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gate {
+    /// First input wire
+    pub left: String,
+    /// Operation of the gate
+    pub op: Op,
+    /// Second input wire
+    pub right: String,
+    /// Wire the gate drives
+    pub output: String,
+}
+
+impl Gate {
+    /// Whether the gate reads the `wire`
+    pub fn reads(&self, wire: &str) -> bool {
+        self.left == wire || self.right == wire
+    }
+}
+
+/// Initial values of the input wires and the gates between them
+///
+/// # Examples
+/// ```
+/// fn main() -> day_24::Result<()> {
+///     let circuit = day_24::Circuit::try_from("x00: 1\ny00: 0\n\nx00 XOR y00 -> z00\n")?;
+///     assert_eq!(circuit.wires["x00"], true);
+///     assert_eq!(circuit.gates[0].op, day_24::Op::Xor);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circuit {
+    /// Initial values of the input wires
+    pub wires: BTreeMap<String, bool>,
+    /// Gates in the order of the input
+    pub gates: Vec<Gate>,
+}
+
+impl TryFrom<&str> for Circuit {
+    type Error = Day24Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut wires = BTreeMap::new();
+        let mut gates = Vec::new();
+        let mut driven = HashSet::new();
+        let mut lines = Vec::new();
+        for (i, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let (line, text) = (i + 1, line.trim());
+            let wire = if text.contains("->") {
+                let gate = parse_gate(line, text)?;
+                let output = gate.output.clone();
+                lines.push(line);
+                gates.push(gate);
+                output
+            } else {
+                let (wire, value) = parse_wire(line, text)?;
+                wires.insert(wire.to_string(), value);
+                wire.to_string()
+            };
+            if !driven.insert(wire.clone()) {
+                return Err(Day24Error::Driven { line, wire });
+            }
+        }
+        for (gate, &line) in gates.iter().zip(&lines) {
+            if let Some(wire) = [&gate.left, &gate.right].into_iter().find(|&wire| !driven.contains(wire)) {
+                return Err(Day24Error::Undriven { line, wire: wire.clone() });
+            }
+        }
+        Ok(Circuit { wires, gates })
+    }
+}
+
+/// Whether the `name` can name a wire
+fn is_wire(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Parse one `wire: value` line
+fn parse_wire(line: usize, text: &str) -> Result<(&str, bool)> {
+    let malformed = || Day24Error::Wire { line, text: text.to_string() };
+    let (wire, value) = text.split_once(':').ok_or_else(malformed)?;
+    let wire = wire.trim();
+    match value.trim() {
+        "0" if is_wire(wire) => Ok((wire, false)),
+        "1" if is_wire(wire) => Ok((wire, true)),
+        _ => Err(malformed()),
+    }
+}
+
+/// Parse one `a OP b -> c` line
+fn parse_gate(line: usize, text: &str) -> Result<Gate> {
+    let malformed = || Day24Error::Gate { line, text: text.to_string() };
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let [left, op, right, "->", output] = tokens[..] else {
+        return Err(malformed());
+    };
+    let op = match op {
+        "AND" => Op::And,
+        "OR" => Op::Or,
+        "XOR" => Op::Xor,
+        _ => return Err(malformed()),
+    };
+    if ![left, right, output].into_iter().all(is_wire) {
+        return Err(malformed());
+    }
+    Ok(Gate { left: left.to_string(), op, right: right.to_string(), output: output.to_string() })
+}
+
+/// Parse the initial wire values and the gates, blank lines are skipped
+///
+/// Lines with an arrow are gates, the others wire values, so the two
+/// sections can come in any order.
+///
+/// # Examples
+/// ```
+/// let circuit = day_24::parse_circuit(day_24::SAMPLE).unwrap();
+/// assert_eq!((circuit.wires.len(), circuit.gates.len()), (10, 36));
+/// assert!(matches!(day_24::parse_circuit("x00: 2\n"), Err(day_24::Day24Error::Wire { line: 1, .. })));
+/// ```
+pub fn parse_circuit(text: &str) -> Result<Circuit> {
+    Circuit::try_from(text)
+}
+
+/// Bit of the number a wire named `prefix` followed by digits stands for
+fn bit(wire: &str, prefix: char) -> Option<usize> {
+    let digits = wire.strip_prefix(prefix)?;
+    (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())).then(|| digits.parse().ok())?
+}
+
+impl Circuit {
+    /// Values of all the wires once the signals went through every gate
+    ///
+    /// The gates are evaluated in topological order: a gate is queued once
+    /// both its inputs have a value. Gates never queued sit on a cycle or
+    /// behind one, which is an error instead of a hang.
+    ///
+    /// # Examples
+    /// ```
+    /// let circuit = day_24::parse_circuit("x00: 1\ny00: 1\n\nx00 AND y00 -> z00\n").unwrap();
+    /// assert_eq!(circuit.evaluate().unwrap()["z00"], true);
+    /// let looped = day_24::parse_circuit("x00: 1\n\nx00 AND b -> a\nx00 OR a -> b\n").unwrap();
+    /// assert!(matches!(looped.evaluate(), Err(day_24::Day24Error::Cycle { .. })));
+    /// ```
+    pub fn evaluate(&self) -> Result<HashMap<&str, bool>> {
+        let mut values: HashMap<&str, bool> = self.wires.iter().map(|(wire, &value)| (wire.as_str(), value)).collect();
+        let mut readers: HashMap<&str, Vec<usize>> = HashMap::new();
+        let mut missing = vec![0u8; self.gates.len()];
+        let mut ready = VecDeque::new();
+        for (i, gate) in self.gates.iter().enumerate() {
+            for wire in [&gate.left, &gate.right] {
+                if !values.contains_key(wire.as_str()) {
+                    missing[i] += 1;
+                    readers.entry(wire).or_default().push(i);
+                }
+            }
+            if missing[i] == 0 {
+                ready.push_back(i);
+            }
+        }
+        while let Some(i) = ready.pop_front() {
+            let gate = &self.gates[i];
+            let value = gate.op.apply(values[gate.left.as_str()], values[gate.right.as_str()]);
+            values.insert(&gate.output, value);
+            for &reader in readers.get(gate.output.as_str()).into_iter().flatten() {
+                missing[reader] -= 1;
+                if missing[reader] == 0 {
+                    ready.push_back(reader);
+                }
+            }
+        }
+        match self.gates.iter().filter(|gate| !values.contains_key(gate.output.as_str())).map(|gate| &gate.output).min() {
+            Some(wire) => Err(Day24Error::Cycle { wire: wire.clone() }),
+            None => Ok(values),
+        }
+    }
+
+    /// Number the wires starting with `prefix` make, `prefix` followed by `00` the lowest bit
+    ///
+    /// # Examples
+    /// ```
+    /// let circuit = day_24::parse_circuit(day_24::SAMPLE).unwrap();
+    /// assert_eq!(circuit.number('x').unwrap(), 0b01101);
+    /// assert_eq!(circuit.number('z').unwrap(), 2024);
+    /// ```
+    pub fn number(&self, prefix: char) -> Result<u64> {
+        let values = self.evaluate()?;
+        let mut number = 0u64;
+        for (&wire, &value) in &values {
+            let Some(bit) = bit(wire, prefix) else {
+                continue;
+            };
+            if bit >= 64 {
+                return Err(Day24Error::Overflow { wire: wire.to_string() });
+            }
+            number |= u64::from(value) << bit;
+        }
+        Ok(number)
+    }
+}
+
+/// Number on the `z` wires once the circuit settles, the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_24::Result<()> {
+///     assert_eq!(day_24::part1(&day_24::parse_circuit(day_24::SAMPLE)?)?, 2024);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(circuit: &Circuit) -> Result<u64> {
+    circuit.number('z')
+}
+
+/// Gate outputs breaking the shape of a ripple-carry adder of the `x` and `y` wires, sorted
+///
+/// Bit `i` of a ripple-carry adder computes `s = x ^ y`, `z = s ^ c`
+/// with the carry `c` from the bit below, and the next carry
+/// `(x & y) | (s & c)`. Bit 0 has no carry in, `x00 ^ y00` is `z00` and
+/// `x00 & y00` the first carry, or the highest `z` of a single bit adder.
+/// The last carry is the highest `z`, driven by an OR. Otherwise:
+///
+/// - a `z` wire is driven by a XOR
+/// - a XOR not reading `x` and `y` drives a `z` wire
+/// - a XOR reading `x` and `y` drives a wire read by a XOR
+/// - an AND drives a wire read by an OR
+///
+/// # Examples
+/// ```
+/// // z01 and the carry out of bit 1 swapped in a two bit adder
+/// let circuit = day_24::parse_circuit("x00 XOR y00 -> z00\nx00 AND y00 -> c01\nx01 XOR y01 -> s01\n\
+/// s01 XOR c01 -> z02\nx01 AND y01 -> a01\ns01 AND c01 -> b01\na01 OR b01 -> z01\n\
+/// x00: 0\nx01: 0\ny00: 0\ny01: 0\n").unwrap();
+/// assert_eq!(day_24::flagged_outputs(&circuit), ["z01", "z02"]);
+/// ```
+pub fn flagged_outputs(circuit: &Circuit) -> Vec<String> {
+    let last_z = circuit.gates.iter().filter_map(|gate| bit(&gate.output, 'z')).max();
+    let is_input = |wire: &str| bit(wire, 'x').is_some() || bit(wire, 'y').is_some();
+    let first_bit = |gate: &Gate| [&gate.left, &gate.right].iter().all(|wire| {
+        (bit(wire, 'x') == Some(0)) || (bit(wire, 'y') == Some(0))
+    });
+    let read_by = |wire: &str, op: Op| circuit.gates.iter().any(|gate| gate.op == op && gate.reads(wire));
+    let mut swapped = BTreeSet::new();
+    for gate in &circuit.gates {
+        let z = bit(&gate.output, 'z');
+        let from_inputs = is_input(&gate.left) && is_input(&gate.right);
+        let wrong = match gate.op {
+            Op::Xor if first_bit(gate) => z != Some(0),
+            Op::And if first_bit(gate) => z.is_some() && z != last_z,
+            _ if z.is_some() && z == last_z => gate.op != Op::Or,
+            Op::Xor if from_inputs => z.is_some() || !read_by(&gate.output, Op::Xor),
+            Op::Xor => z.is_none(),
+            Op::And => z.is_some() || !read_by(&gate.output, Op::Or),
+            Op::Or => z.is_some() || first_bit(gate),
+        };
+        if wrong {
+            swapped.insert(gate.output.clone());
+        }
+    }
+    swapped.into_iter().collect()
+}
+
+/// Outputs of the [`SWAPPED_OUTPUTS`] gates swapped in the adder, sorted
+///
+/// These are the [`flagged_outputs`], an adder breaking in any other
+/// number of places does not have four pairs of outputs swapped.
+///
+/// # Examples
+/// ```
+/// let circuit = day_24::parse_circuit("x00: 1\ny00: 1\n\nx00 XOR y00 -> z00\nx00 AND y00 -> z01\n").unwrap();
+/// let result = day_24::find_swapped_outputs(&circuit);
+/// assert!(matches!(result, Err(day_24::Day24Error::Swaps { found: 0 })));
+/// ```
+pub fn find_swapped_outputs(circuit: &Circuit) -> Result<Vec<String>> {
+    let swapped = flagged_outputs(circuit);
+    if swapped.len() != SWAPPED_OUTPUTS {
+        return Err(Day24Error::Swaps { found: swapped.len() });
+    }
+    Ok(swapped)
+}
+
+/// Sorted outputs of the swapped gates joined by commas, the answer of part 2
+///
+/// # Examples
+/// ```
+/// fn main() -> day_24::Result<()> {
+///     let circuit = day_24::parse_circuit(day_24::SAMPLE)?;
+///     // the sample is no adder with four pairs swapped
+///     assert!(matches!(day_24::part2(&circuit), Err(day_24::Day24Error::Swaps { found: 15 })));
+///     Ok(())
+/// }
+/// ```
+pub fn part2(circuit: &Circuit) -> Result<String> {
+    Ok(find_swapped_outputs(circuit)?.join(","))
+}
+
+/// Day 24 solution used by the `aoc` runner
+///
+/// Part 1 reads the number off the `z` wires, part 2 finds the gate
+/// outputs swapped in the adder
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_24::Day24.part1(day_24::SAMPLE)?, "2024");
+///     Ok(())
+/// }
+/// ```
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse_circuit(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&parse_circuit(input)?)?)
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let circuit = parse_circuit(input)?;
+        Ok((circuit.solve(1)?, circuit.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse_circuit(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Circuit {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?),
+        }
+    }
+}
+
+/// Why the input does not look like a circuit
+fn format_problem(input: &str) -> Option<String> {
+    parse_circuit(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 24 input with an adder of `size` bits and four pairs of swapped outputs, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_24::generate(20, 7);
+/// assert_eq!(answers[1].split(',').count(), 8);
+/// assert_eq!(day_24::generate(20, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day24(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Gates of a ripple-carry adder of `bits` bits, the wires of bit `i` named after it
+    fn adder(bits: usize) -> Vec<String> {
+        let mut gates = vec!["x00 XOR y00 -> z00".to_string(), "x00 AND y00 -> c01".to_string()];
+        for i in 1..bits {
+            let carry = if i + 1 == bits { format!("z{:02}", i + 1) } else { format!("c{:02}", i + 1) };
+            gates.extend([
+                format!("x{i:02} XOR y{i:02} -> s{i:02}"),
+                format!("s{i:02} XOR c{i:02} -> z{i:02}"),
+                format!("y{i:02} AND x{i:02} -> a{i:02}"),
+                format!("c{i:02} AND s{i:02} -> b{i:02}"),
+                format!("a{i:02} OR b{i:02} -> {carry}"),
+            ]);
+        }
+        gates
+    }
+
+    /// Adder input adding `x` and `y` with the outputs of the `swaps` exchanged
+    fn adder_input(bits: usize, x: u64, y: u64, swaps: &[(&str, &str)]) -> String {
+        let mut input: String = (0..bits).map(|i| format!("x{i:02}: {}\n", x >> i & 1))
+        .chain((0..bits).map(|i| format!("y{i:02}: {}\n", y >> i & 1)))
+        .collect();
+        input.push('\n');
+        for gate in adder(bits) {
+            let (wiring, output) = gate.split_once(" -> ").unwrap();
+            let output = swaps.iter().find_map(|&(a, b)| match output {
+                _ if output == a => Some(b),
+                _ if output == b => Some(a),
+                _ => None,
+            }).unwrap_or(output);
+            input.push_str(&format!("{wiring} -> {output}\n"));
+        }
+        input
+    }
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let circuit = parse_circuit(SAMPLE)?;
+        assert_eq!(part1(&circuit)?, 2024);
+        assert_eq!(Day24.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        // the structural checks flag 15 outputs of the example
+        assert_eq!(flagged_outputs(&circuit).len(), 15);
+        assert!(matches!(part2(&circuit), Err(Day24Error::Swaps { found: 15 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_small_sample() -> Result<()> {
+        let circuit = parse_circuit("x00: 1\nx01: 1\nx02: 1\ny00: 0\ny01: 1\ny02: 0\n\n\
+        x00 AND y00 -> z00\nx01 XOR y01 -> z01\nx02 OR y02 -> z02\n")?;
+        let values = circuit.evaluate()?;
+        assert_eq!((values["z00"], values["z01"], values["z02"]), (false, false, true));
+        assert_eq!(part1(&circuit)?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_adder_adds() -> Result<()> {
+        let circuit = parse_circuit(&adder_input(8, 0b1011_0110, 0b0110_1111, &[]))?;
+        assert_eq!(circuit.number('x')? + circuit.number('y')?, part1(&circuit)?);
+        assert_eq!(part1(&circuit)?, 0b1011_0110 + 0b0110_1111);
+        assert!(flagged_outputs(&circuit).is_empty());
+        assert!(matches!(find_swapped_outputs(&circuit), Err(Day24Error::Swaps { found: 0 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_adder_swaps_found() -> Result<()> {
+        // the sum and the carry of bit 2 half adder, and z05 with the carry out of bit 5
+        let circuit = parse_circuit(&adder_input(8, 0b1011_0110, 0b0110_1111, &[("s02", "a02"), ("z05", "c06")]))?;
+        assert_eq!(flagged_outputs(&circuit), ["a02", "c06", "s02", "z05"]);
+        assert_ne!(part1(&circuit)?, 0b1011_0110 + 0b0110_1111);
+        // z03 with the AND of the carry in of bit 3
+        let circuit = parse_circuit(&adder_input(8, 1, 2, &[("z03", "b03")]))?;
+        assert_eq!(flagged_outputs(&circuit), ["b03", "z03"]);
+        assert!(matches!(part2(&circuit), Err(Day24Error::Swaps { found: 2 })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_adder_four_swaps() -> Result<()> {
+        let swaps = [("s02", "a02"), ("z05", "c06"), ("z03", "b03"), ("z07", "b07")];
+        let circuit = parse_circuit(&adder_input(8, 0b1011_0110, 0b0110_1111, &swaps))?;
+        assert_eq!(part2(&circuit)?, "a02,b03,b07,c06,s02,z03,z05,z07");
+        assert_eq!(Day24Error::Swaps { found: 6 }.to_string(), "expected 8 swapped outputs, found 6");
+        assert_eq!(Day24Error::Swaps { found: 6 }.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle() -> Result<()> {
+        let circuit = parse_circuit("x00: 1\ny00: 0\n\nx00 XOR d -> b\nb AND y00 -> c\nc OR x00 -> d\nx00 OR y00 -> z00\n")?;
+        let result = circuit.evaluate();
+        assert!(matches!(&result, Err(Day24Error::Cycle { wire }) if wire == "b"));
+        assert!(matches!(part1(&circuit), Err(Day24Error::Cycle { .. })));
+        assert_eq!(Day24Error::Cycle { wire: "b".to_string() }.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> Result<()> {
+        let circuit = parse_circuit("x00: 1\n\nx00 OR x00 -> z64\n")?;
+        assert!(matches!(part1(&circuit), Err(Day24Error::Overflow { wire }) if wire == "z64"));
+        let circuit = parse_circuit("x00: 1\n\nx00 OR x00 -> z63\nx00 AND x00 -> zz\n")?;
+        assert_eq!(part1(&circuit)?, 1 << 63);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse_circuit("x00: 1\nx01 1\n");
+        assert!(matches!(&result, Err(Day24Error::Wire { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        assert!(matches!(parse_circuit("x00: 1\n\nx00 NAND x00 -> z00\n"), Err(Day24Error::Gate { line: 3, .. })));
+        assert!(matches!(parse_circuit("x00: 1\n\nx00 AND -> z00\n"), Err(Day24Error::Gate { line: 3, .. })));
+        let result = parse_circuit("x00: 1\nx00: 0\n");
+        assert!(matches!(&result, Err(Day24Error::Driven { line: 2, wire }) if wire == "x00"));
+        assert!(matches!(parse_circuit("x00: 1\n\nx00 OR x00 -> x00\n"), Err(Day24Error::Driven { line: 3, .. })));
+        let result = parse_circuit("x00: 1\n\nx00 OR y00 -> z00\n");
+        assert!(matches!(&result, Err(Day24Error::Undriven { line: 3, wire }) if wire == "y00"));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_circuit_serde_round_trip() -> Result<()> {
+        let circuit = parse_circuit("x00: 1\n\nx00 XOR x00 -> z00\n")?;
+        let json = serde_json::to_string(&circuit)?;
+        assert_eq!(json, r#"{"wires":{"x00":true},"gates":[{"left":"x00","op":"Xor","right":"x00","output":"z00"}]}"#);
+        assert_eq!(serde_json::from_str::<Circuit>(&json)?, circuit);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day24.describe(SAMPLE).problem, None);
+        assert_eq!(Day24.describe("x00: 1\n\nx00 OR y00 -> z00\n").problem.as_deref(), Some("wire y00 on line 3 has no value and no gate"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day24.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day24(seed, 20);
+            assert_eq!(Day24.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let circuit = day_24::parse_circuit(&input.read_to_string()?)?;
+
+    println!("Z_NUMBER: {}", day_24::part1(&circuit)?);
+    println!("SWAPPED_OUTPUTS: {}", day_24::part2(&circuit)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_24::Day24Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_24` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_24", |data| {
+        if let Ok(circuit) = day_24::parse_circuit(&String::from_utf8_lossy(data)) {
+            let _ = day_24::part1(&circuit);
+            let _ = day_24::part2(&circuit);
+        }
+    });
+}
@@ -0,0 +1,38 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-24"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-24");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_2024.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-24");
+
+    // the sample is no adder with four pairs of outputs swapped
+    assert_eq!(output.status.code(), Some(4));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Z_NUMBER: 2024\n");
+}
+
+/// Exit code of day-24 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-24"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-24");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-24").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code(&aoc_testing::day24(1, 6).input), Some(0));
+    assert_eq!(exit_code("x00: 1\ny00: 0\n\nx00 XOR y00 -> z00\n"), Some(4));
+    assert_eq!(exit_code("x00: 2\n"), Some(3));
+    assert_eq!(exit_code("a AND b -> b\nb OR a -> a\n"), Some(4));
+}
@@ -0,0 +1,439 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 25 Challenge
+//!
+//! Functions to complete the task for advent of code 2024
+//!
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 25 functions
+#[derive(Debug, Error)]
+pub enum Day25Error {
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// A row is not five `#` or `.` pins
+    #[error("expected {WIDTH} of # or . on line {line} of schematic {schematic}, found {text}")]
+    Row {
+        /// Schematic number (starting from 1)
+        schematic: usize,
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// A schematic does not have seven rows
+    #[error("schematic {schematic} on line {line} has {rows} rows instead of {HEIGHT}")]
+    Height {
+        /// Schematic number (starting from 1)
+        schematic: usize,
+        /// Line number of its first row
+        line: usize,
+        /// Number of rows it has
+        rows: usize,
+    },
+    /// Neither only the top nor only the bottom row of a schematic is filled
+    #[error("schematic {schematic} on line {line} is neither a lock nor a key")]
+    Kind {
+        /// Schematic number (starting from 1)
+        schematic: usize,
+        /// Line number of its first row
+        line: usize,
+    },
+    /// The filled pins of a column do not all touch the filled row
+    #[error("column {column} of schematic {schematic} on line {line} has a gap")]
+    Gap {
+        /// Schematic number (starting from 1)
+        schematic: usize,
+        /// Line number of its first row
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+    },
+}
+
+impl From<Day25Error> for SolutionError {
+    fn from(e: Day25Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day25Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day25Error::Row { line, .. } | Day25Error::Height { line, .. }
+            | Day25Error::Kind { line, .. } | Day25Error::Gap { line, .. } => Some(Location::Line(*line)),
+            Day25Error::ReadLine(_) => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day25Error::ReadLine(source) => ErrorKind::io(source),
+            Day25Error::Row { .. } | Day25Error::Height { .. }
+            | Day25Error::Kind { .. } | Day25Error::Gap { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 25 functions
+pub type Result<T, E = Day25Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n\
+#####\n##.##\n.#.##\n...##\n...#.\n...#.\n.....\n\n\
+.....\n#....\n#....\n#...#\n#.#.#\n#.###\n#####\n\n\
+.....\n.....\n#.#..\n###..\n###.#\n###.#\n#####\n\n\
+.....\n.....\n.....\n#....\n#.#..\n#.#.#\n#####\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+///
+/// Day 25 has no second puzzle, its answer is left empty.
+pub const SAMPLE_ANSWERS: [&str; 2] = ["3", ""];
+
+/// Pins in a row of a schematic
+pub const WIDTH: usize = 5;
+
+/// Rows of a schematic, the filled row included
+pub const HEIGHT: usize = 7;
+
+/// Pin height a lock and a key fitting together may add up to in a column
+pub const SPACE: u8 = HEIGHT as u8 - 2;
+
+/// Height of the pins of a lock or a key in every column, the filled row left out
+pub type Heights = [u8; WIDTH];
+
+/// Locks and keys of the schematics
+///
+/// # Examples
+/// ```
+/// fn main() -> day_25::Result<()> {
+///     let schematics = day_25::Schematics::try_from(day_25::SAMPLE)?;
+///     assert_eq!(schematics.locks, [[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+///     assert_eq!(schematics.keys.len(), 3);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schematics {
+    /// Heights of the locks, filled from the top
+    pub locks: Vec<Heights>,
+    /// Heights of the keys, filled from the bottom
+    pub keys: Vec<Heights>,
+}
+
+impl TryFrom<&str> for Schematics {
+    type Error = Day25Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let (locks, keys) = parse_schematics(text.as_bytes())?;
+        Ok(Schematics { locks, keys })
+    }
+}
+
+/// Whether one schematic is a lock, and the heights of its pins
+fn parse_schematic(schematic: usize, line: usize, rows: &[Vec<bool>]) -> Result<(bool, Heights)> {
+    if rows.len() != HEIGHT {
+        return Err(Day25Error::Height { schematic, line, rows: rows.len() });
+    }
+    let filled = |row: &[bool]| row.iter().all(|&pin| pin);
+    let lock = match (filled(&rows[0]), filled(&rows[HEIGHT - 1])) {
+        (true, false) => true,
+        (false, true) => false,
+        _ => return Err(Day25Error::Kind { schematic, line }),
+    };
+    let mut heights = [0; WIDTH];
+    for (column, height) in heights.iter_mut().enumerate() {
+        let pins: Vec<bool> = rows.iter().map(|row| row[column]).collect();
+        let filled = pins.iter().filter(|&&pin| pin).count();
+        // locks are filled from the top and keys from the bottom without any gap
+        let touching = match lock {
+            true => pins.iter().take_while(|&&pin| pin).count(),
+            false => pins.iter().rev().take_while(|&&pin| pin).count(),
+        };
+        if touching != filled {
+            return Err(Day25Error::Gap { schematic, line, column: column + 1 });
+        }
+        *height = filled as u8 - 1;
+    }
+    Ok((lock, heights))
+}
+
+/// Read the schematics from a [BufRead], the locks first and the keys second
+///
+/// Schematics are seven rows of five `#` or `.` pins, separated by blank lines.
+/// Locks have their top row filled and keys their bottom row.
+///
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+///
+/// fn main() -> day_25::Result<()> {
+///     let (locks, keys) = day_25::parse_schematics(Cursor::new(day_25::SAMPLE))?;
+///     assert_eq!(locks[1], [1, 2, 0, 5, 3]);
+///     assert_eq!(keys, [[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]);
+///     Ok(())
+/// }
+/// ```
+pub fn parse_schematics<B: BufRead>(reader: B) -> Result<(Vec<Heights>, Vec<Heights>)> {
+    let (mut locks, mut keys) = (Vec::new(), Vec::new());
+    let mut rows = Vec::new();
+    let (mut schematic, mut first) = (0, 0);
+    let mut finish = |rows: &mut Vec<Vec<bool>>, schematic: usize, first: usize| -> Result<()> {
+        if !rows.is_empty() {
+            let (lock, heights) = parse_schematic(schematic, first, rows)?;
+            match lock {
+                true => locks.push(heights),
+                false => keys.push(heights),
+            }
+            rows.clear();
+        }
+        Ok(())
+    };
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(Day25Error::ReadLine)?;
+        let text = line.trim();
+        if text.is_empty() {
+            finish(&mut rows, schematic, first)?;
+            continue;
+        }
+        if rows.is_empty() {
+            schematic += 1;
+            first = i + 1;
+        }
+        if text.len() != WIDTH || !text.bytes().all(|pin| matches!(pin, b'#' | b'.')) {
+            return Err(Day25Error::Row { schematic, line: i + 1, text: text.to_string() });
+        }
+        rows.push(text.bytes().map(|pin| pin == b'#').collect());
+    }
+    finish(&mut rows, schematic, first)?;
+    Ok((locks, keys))
+}
+
+/// Parse the schematics of the whole input
+///
+/// # Examples
+/// ```
+/// fn main() -> day_25::Result<()> {
+///     let schematics = day_25::parse(day_25::SAMPLE)?;
+///     assert_eq!(schematics.keys[0], [5, 0, 2, 1, 3]);
+///     Ok(())
+/// }
+/// ```
+pub fn parse(text: &str) -> Result<Schematics> {
+    Schematics::try_from(text)
+}
+
+/// Whether the pins of a lock and a key overlap in no column
+///
+/// # Examples
+/// ```
+/// assert!(day_25::fits(&[0, 5, 3, 4, 3], &[3, 0, 2, 0, 1]));
+/// assert!(!day_25::fits(&[0, 5, 3, 4, 3], &[5, 0, 2, 1, 3]));
+/// ```
+pub fn fits(lock: &Heights, key: &Heights) -> bool {
+    lock.iter().zip(key).all(|(lock, key)| lock + key <= SPACE)
+}
+
+/// Number of lock and key pairs that fit together
+///
+/// Every lock is tried with every key, a real input has only a few
+/// hundred of each.
+///
+/// # Examples
+/// ```
+/// fn main() -> day_25::Result<()> {
+///     let schematics = day_25::parse(day_25::SAMPLE)?;
+///     assert_eq!(day_25::fitting_pairs(&schematics.locks, &schematics.keys), 3);
+///     Ok(())
+/// }
+/// ```
+pub fn fitting_pairs(locks: &[Heights], keys: &[Heights]) -> usize {
+    locks.iter().map(|lock| keys.iter().filter(|key| fits(lock, key)).count()).sum()
+}
+
+/// Number of lock and key pairs that fit together, the answer of part 1
+///
+/// # Examples
+/// ```
+/// fn main() -> day_25::Result<()> {
+///     assert_eq!(day_25::part1(&day_25::parse(day_25::SAMPLE)?), 3);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(schematics: &Schematics) -> usize {
+    fitting_pairs(&schematics.locks, &schematics.keys)
+}
+
+/// Day 25 solution used by the `aoc` runner
+///
+/// Part 1 counts the lock and key pairs that fit together,
+/// day 25 has no part 2.
+///
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+///
+/// fn main() -> Result<()> {
+///     assert_eq!(day_25::Day25.part1(day_25::SAMPLE)?, "3");
+///     assert!(day_25::Day25.part2(day_25::SAMPLE).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&parse(input)?).to_string())
+    }
+
+    fn part2(&self, _input: &str) -> SolutionResult<String> {
+        Err(SolutionError::NotImplemented { day: 25, part: 2 })
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let schematics = parse(input)?;
+        Ok((schematics.solve(1)?, schematics.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(parse(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Schematics {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self).to_string()),
+            _ => Err(SolutionError::NotImplemented { day: 25, part: 2 }),
+        }
+    }
+}
+
+/// Why the input does not look like lock and key schematics
+fn format_problem(input: &str) -> Option<String> {
+    parse(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 25 input with `size` schematics, generated from `seed`, and its expected answers
+///
+/// # Examples
+/// ```
+/// let (input, answers) = day_25::generate(20, 7);
+/// assert_eq!(input.split("\n\n").count(), 20);
+/// assert_eq!(day_25::generate(20, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day25(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_sample() -> Result<()> {
+        let schematics = parse(SAMPLE)?;
+        assert_eq!(schematics.locks, [[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+        assert_eq!(schematics.keys, [[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]);
+        assert_eq!(part1(&schematics), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_full_columns() -> Result<()> {
+        // a lock filled to the last free row still fits an empty key
+        let schematics = parse("#####\n#####\n#####\n#####\n#####\n#####\n.....\n\n\
+            .....\n.....\n.....\n.....\n.....\n.....\n#####\n")?;
+        assert_eq!(schematics.locks, [[5; WIDTH]]);
+        assert_eq!(schematics.keys, [[0; WIDTH]]);
+        assert_eq!(part1(&schematics), 1);
+        assert_eq!(fitting_pairs(&schematics.locks, &[]), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let result = parse("#####\n.####\n.####\n\n");
+        assert!(matches!(&result, Err(Day25Error::Height { schematic: 1, line: 1, rows: 3 })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(1)));
+        // both the top and the bottom rows filled
+        let both = "#####\n.....\n.....\n.....\n.....\n.....\n#####\n";
+        let result = parse(&format!("{}\n{}", &SAMPLE[..42], both));
+        assert!(matches!(&result, Err(Day25Error::Kind { schematic: 2, line: 9 })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        assert!(matches!(parse(".....\n.....\n.....\n.....\n.....\n.....\n.....\n"), Err(Day25Error::Kind { schematic: 1, .. })));
+        let result = parse("#####\n.####\n.#x##\n");
+        assert!(matches!(&result, Err(Day25Error::Row { schematic: 1, line: 3, text }) if text == ".#x##"));
+        assert!(matches!(parse("#####\n.###\n"), Err(Day25Error::Row { line: 2, .. })));
+        let result = parse("#####\n.####\n#####\n.....\n.....\n.....\n.....\n");
+        assert!(matches!(result, Err(Day25Error::Gap { schematic: 1, column: 1, .. })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_schematics_serde_round_trip() -> Result<()> {
+        let schematics = parse(SAMPLE)?;
+        let json = serde_json::to_string(&schematics)?;
+        assert!(json.starts_with(r#"{"locks":[[0,5,3,4,3],"#));
+        assert_eq!(serde_json::from_str::<Schematics>(&json)?, schematics);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day25.describe(SAMPLE).problem, None);
+        assert_eq!(Day25.describe("#####\n").problem.as_deref(), Some("schematic 1 on line 1 has 1 rows instead of 7"));
+    }
+
+    #[test]
+    fn test_solution_part2_not_implemented() {
+        assert!(matches!(Day25.part2(SAMPLE), Err(SolutionError::NotImplemented { day: 25, part: 2 })));
+        assert!(matches!(Day25.solve_both(SAMPLE), Err(SolutionError::NotImplemented { day: 25, part: 2 })));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day25.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day25(seed, 40);
+            assert_eq!(Day25.part1(&generated.input)?, generated.part1);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+
+    let (locks, keys) = day_25::parse_schematics(input.reader()?)?;
+
+    println!("FITTING_PAIRS: {}", day_25::fitting_pairs(&locks, &keys));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_25::Day25Error::kind)).into()
+        },
+    }
+}
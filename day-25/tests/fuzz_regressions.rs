@@ -0,0 +1,10 @@
+//! Replays the crashers saved from the `day_25` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_25", |data| {
+        if let Ok((locks, keys)) = day_25::parse_schematics(data) {
+            let _ = day_25::fitting_pairs(&locks, &keys);
+        }
+    });
+}
@@ -5,9 +5,403 @@
 //! 
 //! [`Read more`](../../../README.md)
 
-use anyhow::{Context, Error, Ok, Result};
+use aoc_prelude::*;
 use regex::Regex;
-use std::{fs::File, io::{BufRead, BufReader}};
+use std::num::ParseIntError;
+#[cfg(feature = "fs")]
+use std::{fs::File, path::PathBuf};
+
+/// Errors returned by the day 3 functions
+#[derive(Debug, Error)]
+pub enum Day3Error {
+    /// Input file could not be opened
+    #[cfg(feature = "fs")]
+    #[error("could not read file {}", path.display())]
+    Io {
+        /// Path of the file
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// An instruction pattern failed to compile
+    #[error("regex failed")]
+    Regex(#[from] regex::Error),
+    /// A multiplication operand is not a number
+    #[error("failed parsing {token} to number")]
+    Parse {
+        /// Symbol that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// Scanning was stopped with a [`CancelToken`]
+    #[error(transparent)]
+    Cancelled(#[from] Cancelled),
+}
+
+impl From<Day3Error> for SolutionError {
+    fn from(e: Day3Error) -> Self {
+        match e {
+            Day3Error::Cancelled(_) => SolutionError::Cancelled,
+            e => SolutionError::Day(Box::new(e)),
+        }
+    }
+}
+
+impl Day3Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day3Error::Parse { token, .. } => Some(Location::Token(token.clone())),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "fs")]
+            Day3Error::Io { source, .. } => ErrorKind::io(source),
+            Day3Error::ReadLine(source) => ErrorKind::io(source),
+            Day3Error::Parse { .. } => ErrorKind::Parse,
+            Day3Error::Regex(_) => ErrorKind::Solver,
+            Day3Error::Cancelled(_) => ErrorKind::Timeout,
+        }
+    }
+}
+
+/// Result type returned by the day 3 functions
+pub type Result<T, E = Day3Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["161", "48"];
+
+/// Uncorrupted `mul`, `do()` and `don't()` instructions
+const INSTRUCTION_PATTERN: &str = r"mul\([\d]{1,3},[\d]{1,3}\)|do\(\)|don't\(\)";
+
+/// Uncorrupted instruction found in the memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Instruction {
+    /// `mul(X,Y)`, multiplying its two operands
+    Mul(i64, i64),
+    /// `do()`, enabling the multiplications after it
+    Do,
+    /// `don't()`, disabling the multiplications after it
+    Dont,
+}
+
+impl Instruction {
+    /// Instruction matched by [`INSTRUCTION_PATTERN`]
+    fn parse(text: &str) -> Result<Self> {
+        let operand = |token: &str| token.parse::<i64>()
+        .map_err(|source| Day3Error::Parse { token: token.to_string(), source });
+        match text {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            mul => {
+                let operands = mul.trim_start_matches("mul(").trim_end_matches(')');
+                let (x, y) = operands.split_once(',').unwrap_or((operands, ""));
+                Ok(Instruction::Mul(operand(x)?, operand(y)?))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Inputs parsed on this thread, the tests check that both parts share one parse
+    static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    /// Passes over parsed instructions on this thread, the tests check that both parts share one pass
+    static PASSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Uncorrupted instructions of the memory in order, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// use day_3::{Day3Input, Instruction};
+/// 
+/// fn main() -> day_3::Result<()> {
+///     let input = Day3Input::try_from("xmul(2,4)%don't()_mul(5,5)")?;
+///     assert_eq!(input.0, vec![Instruction::Mul(2, 4), Instruction::Dont, Instruction::Mul(5, 5)]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day3Input(pub Vec<Instruction>);
+
+impl Day3Input {
+    /// Scan the memory, reporting the bytes scanned to `progress` and stopping once `cancel` is cancelled
+    fn scan(text: &str, progress: Option<&dyn Progress>, cancel: Option<&CancelToken>) -> Result<Self> {
+        #[cfg(test)]
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        scan(text, progress, cancel)?
+        .iter()
+        .map(|instruction| Instruction::parse(instruction))
+        .collect::<Result<Vec<Instruction>>>()
+        .map(Day3Input)
+    }
+}
+
+impl TryFrom<&str> for Day3Input {
+    type Error = Day3Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        Day3Input::scan(text, None, None)
+    }
+}
+
+/// Sum of every multiplication, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_3::Result<()> {
+///     let input = day_3::Day3Input::try_from(day_3::SAMPLE)?;
+///     assert_eq!(day_3::part1(&input), 161);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day3Input) -> i64 {
+    #[cfg(test)]
+    PASSES.with(|passes| passes.set(passes.get() + 1));
+    input.0.iter()
+    .map(|instruction| match instruction {
+        Instruction::Mul(x, y) => x * y,
+        _ => 0,
+    })
+    .sum()
+}
+
+/// Sum of the multiplications enabled by the latest `do()` or `don't()`, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_3::Result<()> {
+///     let input = day_3::Day3Input::try_from(day_3::SAMPLE)?;
+///     assert_eq!(day_3::part2(&input), 48);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day3Input) -> i64 {
+    #[cfg(test)]
+    PASSES.with(|passes| passes.set(passes.get() + 1));
+    let mut enabled = true;
+    let mut sum = 0;
+    for instruction in &input.0 {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(x, y) if enabled => sum += x * y,
+            Instruction::Mul(..) => {},
+        }
+    }
+    sum
+}
+
+/// Answers of both parts from a single pass over the instructions
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_3::Result<()> {
+///     let input = day_3::Day3Input::try_from(day_3::SAMPLE)?;
+///     assert_eq!(day_3::evaluate(&input), (161, 48));
+///     Ok(())
+/// }
+/// ```
+pub fn evaluate(input: &Day3Input) -> (i64, i64) {
+    #[cfg(test)]
+    PASSES.with(|passes| passes.set(passes.get() + 1));
+    let mut enabled = true;
+    let (mut all, mut sum) = (0, 0);
+    for instruction in &input.0 {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(x, y) => {
+                all += x * y;
+                if enabled {
+                    sum += x * y;
+                }
+            },
+        }
+    }
+    (all, sum)
+}
+
+/// Day 3 solution used by the `aoc` runner
+/// 
+/// Part 1 sums all uncorrupted multiplications and
+/// part 2 skips the ones disabled by `don't()` instructions.
+/// Both parts are solved from the same [`Day3Input`].
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+///     assert_eq!(day_3::Day3.part1(input)?, "161");
+///     assert_eq!(day_3::Day3.part2(input)?, "48");
+///     Ok(())
+/// }
+/// ```
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day3Input::try_from(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day3Input::try_from(input)?).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let (part1, part2) = evaluate(&Day3Input::try_from(input)?);
+        Ok((part1.to_string(), part2.to_string()))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day3Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn solve_with_cancel(&self, part: u8, input: &str, progress: &dyn Progress, 
+        cancel: &CancelToken) -> SolutionResult<String> {
+        let input = Day3Input::scan(input, Some(progress), Some(cancel))?;
+        match part {
+            1 => Ok(part1(&input).to_string()),
+            _ => Ok(part2(&input).to_string()),
+        }
+    }
+
+    fn explain(&self, input: &str, selector: &str) -> SolutionResult<String> {
+        let regions = toggle_regions(input)?;
+        let selected: Vec<(usize, &Region)> = match selector.trim() {
+            "all" => regions.iter().enumerate().collect(),
+            number => number.parse::<usize>().ok()
+            .filter(|number| (1..=regions.len()).contains(number))
+            .map(|number| vec![(number - 1, &regions[number - 1])])
+            .ok_or_else(|| SolutionError::InvalidSelector {
+                selector: selector.to_string(),
+                expected: format!("`all` or a region from 1 to {}", regions.len()),
+            })?,
+        };
+
+        let mut lines = Vec::new();
+        for (index, region) in selected {
+            let products = match region.muls.is_empty() {
+                true => "no mul".to_string(),
+                false => region.muls.join(", "),
+            };
+            let state = match &input[region.start..] {
+                rest if rest.starts_with("do()") => "enabled by do()",
+                rest if rest.starts_with("don't()") => "disabled by don't()",
+                _ => "enabled",
+            };
+            let sum = match region.enabled {
+                true => format!(" = {}", multiply(&region.muls)?),
+                false => " skipped".to_string(),
+            };
+            lines.push(format!("Region {}: bytes {}..{}, {}: {}{}", index + 1, region.start, region.end, state, products, sum));
+        }
+        if selector.trim() == "all" {
+            let enabled: Vec<String> = regions.into_iter().filter(|r| r.enabled).flat_map(|r| r.muls).collect();
+            lines.push(format!("Enabled total: {}", multiply(&enabled)?));
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Parsed for Day3Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self).to_string()),
+            _ => Ok(part2(self).to_string()),
+        }
+    }
+}
+
+/// Stretch of memory between two `do()` or `don't()` instructions
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Region {
+    /// Byte offset of the first character, the toggling instruction included
+    pub start: usize,
+    /// Byte offset after the last character
+    pub end: usize,
+    /// Whether the multiplications of the region count in part 2
+    pub enabled: bool,
+    /// Uncorrupted `mul` instructions of the region
+    pub muls: Vec<String>,
+}
+
+/// Split the memory into regions started by each `do()` and `don't()`
+/// 
+/// The first region starts enabled at the beginning of the memory,
+/// it is left out when the memory starts with a toggle
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_3::Result<()> {
+///     let regions = day_3::toggle_regions("mul(2,4)don't()mul(5,5)")?;
+///     assert_eq!(regions.len(), 2);
+///     assert_eq!((regions[1].start, regions[1].enabled, regions[1].muls.len()), (8, false, 1));
+///     Ok(())
+/// }
+/// ```
+pub fn toggle_regions(text: &str) -> Result<Vec<Region>> {
+    let re = Regex::new(INSTRUCTION_PATTERN)?;
+    let mut regions = vec![Region { start: 0, end: text.len(), enabled: true, muls: Vec::new() }];
+    for m in re.find_iter(text) {
+        let current = regions.last_mut().expect("there is always a region");
+        match m.as_str() {
+            "do()" | "don't()" => {
+                current.end = m.start();
+                if current.end == current.start {
+                    regions.pop();
+                }
+                regions.push(Region { start: m.start(), end: text.len(), enabled: m.as_str() == "do()", muls: Vec::new() });
+            },
+            instruction => current.muls.push(instruction.to_string()),
+        }
+    }
+    Ok(regions)
+}
+
+/// Day 3 input with `size` instructions mixed with corrupted memory, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_3::generate(50, 7);
+/// assert_eq!(day_3::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day3(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
 
 /// Find and disable required instructions
 /// 
@@ -30,7 +424,7 @@ use std::{fs::File, io::{BufRead, BufReader}};
 ///     Ok(())
 /// }
 /// ```
-pub fn filter_disabled(instructions: &Vec<String>) -> Result<Vec<String>, Error> {
+pub fn filter_disabled(instructions: &Vec<String>) -> Result<Vec<String>> {
     let mut filtered = Vec::new();
     let mut enabled = true;
     for instruction in instructions {
@@ -63,17 +457,16 @@ pub fn filter_disabled(instructions: &Vec<String>) -> Result<Vec<String>, Error>
 ///     Ok(())
 /// }
 /// ```
-pub fn multiply(instructions: &Vec<String>) -> Result<i32, Error> {
+pub fn multiply(instructions: &Vec<String>) -> Result<i32> {
     let mut sum = 0;
+    let re = Regex::new(r"[\d]{1,3}")?;
     for instruction in instructions {
-        let re = Regex::new(r"[\d]{1,3}")
-        .with_context(|| format!("regex failed"))?;
         let v: Vec<String> = re.find_iter(instruction)
         .map(|m| m.as_str().to_string()).collect();
         let mut multiplied = 1;
         for sym in v {
             let num = sym.parse::<i32>()
-            .with_context(|| format!("failed parsing {} to number", sym))?;
+            .map_err(|source| Day3Error::Parse { token: sym.to_string(), source })?;
             multiplied *= num;
         }
         sum += multiplied;
@@ -83,32 +476,76 @@ pub fn multiply(instructions: &Vec<String>) -> Result<i32, Error> {
 
 /// Filter the corrupted memory
 /// 
-/// Filter corrupted memory and return only uncorrupted instructions
+/// Filter corrupted memory and return only uncorrupted instructions.
+/// Memory can be read from a file or any other [Read] source
 /// 
 /// # Examples
 /// ```
 /// use anyhow::Result;
 /// 
 /// fn main() -> Result<()> {
-///     let file = day_3::read_file(std::path::Path::new("./data/input_test_48.txt"))?;
-///     let v = day_3::filter_corrupted(file)?;
+///     let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+///     let v = day_3::filter_corrupted(memory.as_bytes())?;
 ///     assert_eq!(v, vec!["mul(2,4)", "don't()", "mul(5,5)", "mul(11,8)", "do()", "mul(8,5)"]);
 ///     Ok(())
 /// }
 /// ```
-pub fn filter_corrupted(file: File) -> Result<Vec<String>, anyhow::Error> {
+pub fn filter_corrupted<R: Read>(input: R) -> Result<Vec<String>> {
+    let mut contents = String::new();
+    BufReader::new(input).read_to_string(&mut contents).map_err(Day3Error::ReadLine)?;
+    scan_instructions(&contents, None)
+}
+
+/// Scan corrupted memory for uncorrupted instructions
+/// 
+/// Same as [`filter_corrupted`] for memory already in a string,
+/// reporting the number of bytes scanned so far to `progress`
+/// 
+/// # Examples
+/// ```
+/// use anyhow::Result;
+/// 
+/// fn main() -> Result<()> {
+///     let v = day_3::scan_instructions("xmul(2,4)%&mul[3,7]\ndo()_mul(5,5)", None)?;
+///     assert_eq!(v, vec!["mul(2,4)", "do()", "mul(5,5)"]);
+///     Ok(())
+/// }
+/// ```
+pub fn scan_instructions(text: &str, progress: Option<&dyn Progress>) -> Result<Vec<String>> {
+    scan(text, progress, None)
+}
+
+/// [`scan_instructions`] stopping with [`Day3Error::Cancelled`] once `cancel` is cancelled
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::CancelToken;
+/// 
+/// let cancel = CancelToken::new();
+/// cancel.cancel();
+/// let result = day_3::scan_instructions_with_cancel("mul(2,4)", &cancel);
+/// assert!(matches!(result, Err(day_3::Day3Error::Cancelled(_))));
+/// ```
+pub fn scan_instructions_with_cancel(text: &str, cancel: &CancelToken) -> Result<Vec<String>> {
+    scan(text, None, Some(cancel))
+}
+
+fn scan(text: &str, progress: Option<&dyn Progress>, cancel: Option<&CancelToken>) -> Result<Vec<String>> {
     let mut filtered = Vec::new();
-    let reader = BufReader::new(file);
-    
-    for line in reader.lines() {
-        let contents:String = line.with_context(|| format!("failed reading line"))?;
-        let re = Regex::new(r"mul\([\d]{1,3},[\d]{1,3}\)|do\(\)|don't\(\)")
-        .with_context(|| format!("regex failed"))?;
-        let mut uncorrupted = re.find_iter(&contents)
-        .map(|m| m.as_str().to_string()).collect();
-        filtered.append(&mut uncorrupted);
+    let re = Regex::new(INSTRUCTION_PATTERN)?;
+    let ticker = Ticker::new(progress, Some(text.len() as u64));
+
+    for line in text.split_inclusive('\n') {
+        for m in re.find_iter(line) {
+            if let Some(cancel) = cancel {
+                cancel.poll(filtered.len())?;
+            }
+            filtered.push(m.as_str().to_string());
+        }
+        ticker.tick(line.len() as u64);
     }
 
+    ticker.finish();
     Ok(filtered)
 }
 
@@ -126,27 +563,155 @@ pub fn filter_corrupted(file: File) -> Result<Vec<String>, anyhow::Error> {
 /// fn main() -> Result<()> {
 ///     let temp_file = assert_fs::NamedTempFile::new("sample.txt")?;
 ///     temp_file.write_str("A test\nActual content\nMore content\nAnother test")?;
-///
+/// 
 ///     let mut file_obj = day_3::read_file(temp_file.path())?;
 ///     let mut contents = String::new();
 ///     file_obj.read_to_string(&mut contents)?;
-///
+/// 
 ///     assert_eq!(contents, "A test\nActual content\nMore content\nAnother test");
 ///     Ok(())
 /// }
 /// ```
-pub fn read_file(path: &std::path::Path) -> Result<File, Error> {
+#[cfg(feature = "fs")]
+pub fn read_file(path: &std::path::Path) -> Result<File> {
     let file = File::open(path)
-    .with_context(|| format!("could not read file {}", path.display()))?;
+    .map_err(|source| Day3Error::Io { path: path.to_path_buf(), source })?;
     
     Ok(file)
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "fs")]
     use std::io::Read;
+    #[cfg(feature = "fs")]
     use assert_fs::prelude::*;
     use anyhow::{Ok, Result};
+    use aoc_utils::Solution;
+    use crate::{Day3Error, SAMPLE};
+
+    #[test]
+    fn test_solution_part1_sample() -> Result<()> {
+        assert_eq!(crate::Day3.part1(SAMPLE)?, "161");
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_sample() -> Result<()> {
+        assert_eq!(crate::Day3.explain(SAMPLE, "all")?, concat!(
+            "Region 1: bytes 0..20, enabled: mul(2,4) = 8\n",
+            "Region 2: bytes 20..59, disabled by don't(): mul(5,5), mul(11,8) skipped\n",
+            "Region 3: bytes 59..73, enabled by do(): mul(8,5) = 40\n",
+            "Enabled total: 48",
+        ));
+        assert_eq!(crate::Day3.explain(SAMPLE, "3")?, "Region 3: bytes 59..73, enabled by do(): mul(8,5) = 40");
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_leading_toggle() -> Result<()> {
+        assert_eq!(crate::Day3.explain("don't()x", "all")?, "Region 1: bytes 0..8, disabled by don't(): no mul skipped\n\
+        Enabled total: 0");
+        let e = crate::Day3.explain(SAMPLE, "4").unwrap_err();
+        assert_eq!(e.to_string(), "invalid selector `4`, expected `all` or a region from 1 to 3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_both_parses_once() -> Result<()> {
+        crate::PARSES.with(|parses| parses.set(0));
+        crate::PASSES.with(|passes| passes.set(0));
+        assert_eq!(crate::Day3.solve_both(SAMPLE)?, ("161".to_string(), "48".to_string()));
+        assert_eq!(crate::PARSES.with(|parses| parses.get()), 1);
+        assert_eq!(crate::PASSES.with(|passes| passes.get()), 1);
+        // the separate parts scan and walk the instructions once each
+        crate::Day3.part1(SAMPLE)?;
+        crate::Day3.part2(SAMPLE)?;
+        assert_eq!(crate::PARSES.with(|parses| parses.get()), 3);
+        assert_eq!(crate::PASSES.with(|passes| passes.get()), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate_matches_parts() -> Result<()> {
+        for text in [SAMPLE, "don't()mul(2,3)", "mul(4,5)don't()do()mul(1,1)", ""] {
+            let input = crate::Day3Input::try_from(text)?;
+            assert_eq!(crate::evaluate(&input), (crate::part1(&input), crate::part2(&input)), "{}", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_try_from() -> Result<()> {
+        use crate::Instruction::*;
+        let input = crate::Day3Input::try_from(SAMPLE)?;
+        assert_eq!(input.0, vec![Mul(2, 4), Dont, Mul(5, 5), Mul(11, 8), Do, Mul(8, 5)]);
+        assert_eq!(crate::Day3Input::try_from("mul(1,2")?, crate::Day3Input::default());
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<()> {
+        let input = crate::Day3Input::try_from("mul(2,4)don't()do()")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"[{"mul":[2,4]},"dont","do"]"#);
+        assert_eq!(serde_json::from_str::<crate::Day3Input>(&json)?, input);
+        let regions = crate::toggle_regions(SAMPLE)?;
+        let json = serde_json::to_string(&regions)?;
+        assert_eq!(serde_json::from_str::<Vec<crate::Region>>(&json)?, regions);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part2_sample() -> Result<()> {
+        assert_eq!(crate::Day3.part2(SAMPLE)?, "48");
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(crate::Day3.part1(&input)?, crate::SAMPLE_ANSWERS[0]);
+        assert_eq!(crate::Day3.part2(&input)?, crate::SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day3(seed, 300);
+            assert_eq!(crate::Day3.part1(&generated.input)?, generated.part1);
+            assert_eq!(crate::Day3.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_with_progress() -> Result<()> {
+        let generated = aoc_testing::day3(1, 300);
+        for (part, answer) in [(1, &generated.part1), (2, &generated.part2)] {
+            let progress = aoc_testing::RecordingProgress::default();
+            assert_eq!(&crate::Day3.solve_with_progress(part, &generated.input, &progress)?, answer);
+            progress.assert_complete();
+            let total = generated.input.len() as u64;
+            assert_eq!(progress.updates().last(), Some(&(total, Some(total))));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_with_cancel() {
+        let input = aoc_testing::day3(1, 1_000_000).input;
+        let (result, latency) = aoc_testing::cancel_after(std::time::Duration::from_millis(50), |cancel| {
+            crate::scan_instructions_with_cancel(&input, cancel)
+        });
+        assert!(matches!(result, Err(Day3Error::Cancelled(_))), "{:?}", result.map(|v| v.len()));
+        assert!(latency < std::time::Duration::from_millis(200), "took {:?} to stop", latency);
+    }
 
     #[test]
     fn test_filter_disabled_success() -> Result<()> {
@@ -170,6 +735,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
     fn test_filter_corrupted_success() -> Result<()> {
         let file = crate::read_file(std::path::Path::new("./data/input_test_48.txt"))?;
@@ -178,6 +744,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_success() -> Result<()> {
         let temp_file = assert_fs::NamedTempFile::new("sample.txt")?;
@@ -191,10 +758,31 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "fs")]
     #[test]
     fn test_read_file_negative() -> Result<()> {
         let result = crate::read_file(std::path::Path::new("sample.txt"));
         assert!(result.is_err_and(|e| e.to_string().contains("could not read file")));
         Ok(())
     }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_file_io_variant() {
+        let result = crate::read_file(std::path::Path::new("sample.txt"));
+        assert!(matches!(result, Err(Day3Error::Io { path, .. }) if path == std::path::Path::new("sample.txt")));
+    }
+
+    #[test]
+    fn test_filter_corrupted_read_line_variant() {
+        let invalid_utf8: &[u8] = &[b'm', 0xff, 0xfe];
+        let result = crate::filter_corrupted(invalid_utf8);
+        assert!(matches!(result, Err(Day3Error::ReadLine(_))));
+    }
+
+    #[test]
+    fn test_solution_error_keeps_day_error() {
+        let result = crate::filter_corrupted(&[0xffu8][..]).map_err(aoc_utils::SolutionError::from);
+        assert!(result.is_err_and(|e| matches!(e.day_error::<Day3Error>(), Some(Day3Error::ReadLine(_)))));
+    }
 }
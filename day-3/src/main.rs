@@ -1,11 +1,25 @@
-use std::path::Path;
-use anyhow::Result;
+use std::process::ExitCode;
+use aoc_prelude::*;
 
-fn main() -> Result<()>{
-    let f = day_3::read_file(Path::new("./data/input.txt"))?;
-    let v = day_3::filter_corrupted(f)?;
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+
+    let v = day_3::filter_corrupted(input.reader()?)?;
     let instructions = day_3::filter_disabled(&v)?;
     let result = day_3::multiply(&instructions)?;
     println!("{}", result);
     Ok(())
 }
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_3::Day3Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,13 @@
+//! Replays the crashers saved from the `day_3` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_3", |data| {
+        if let Ok(instructions) = day_3::filter_corrupted(data) {
+            let _ = day_3::multiply(&instructions);
+            if let Ok(enabled) = day_3::filter_disabled(&instructions) {
+                let _ = day_3::multiply(&enabled);
+            }
+        }
+    });
+}
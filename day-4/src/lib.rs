@@ -5,120 +5,258 @@
 //! 
 //! [`Read more`](../../../README.md)
 
-use std::{fs::File, io::{BufRead, BufReader, Lines}};
-use anyhow::{Context, Error, Ok};
+#[cfg(feature = "fs")]
+use std::fs::File;
+use aoc_prelude::*;
 
-/// Form a word from a string vector
-/// 
-/// Form a 3 letter word in a required direction
-/// 
-/// # Parameters
-/// 
-/// * `lines_vec` - A reference to a vector of strings. At least 3
-/// lines are requered to form 3 letter vertical / diagonal words.
-/// * `idx` - An index of the starting symbol in a line / string.
-/// * `dir` - Direction to form word `0` (vertical), `-1` (left diagonal), `1` (right diagonal).
-/// 
-/// # Returns
-/// 
-/// * `String` - Formed word in a chosen direction.
-fn form_word(lines_vec: &Vec<String>, idx: usize, dir: i32) -> String {
-    let mut s = String::new();
-    if ![0, -1, 1].contains(&dir) {
-        return s;
-    }
-    if lines_vec.len() < 3 {
-        return s;
-    }
-    for i in 0..3 {
-        let mut chars = lines_vec[(2 - i) as usize].chars();
-        let new_idx = idx as isize + i as isize * dir as isize;
-        if new_idx < 0 {
-            return s;
+/// Errors returned by the day 4 functions
+#[derive(Debug, Error)]
+pub enum Day4Error {
+    /// Input file could not be opened
+    #[cfg(feature = "fs")]
+    #[error("failed to open file {}", path.display())]
+    Io {
+        /// Path of the file
+        path: std::path::PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// Lines of the word search have different lengths
+    #[error("malformed word search")]
+    Grid(#[from] GridError),
+    /// Counting was stopped with a [`CancelToken`]
+    #[error(transparent)]
+    Cancelled(#[from] Cancelled),
+}
+
+impl From<Day4Error> for SolutionError {
+    fn from(e: Day4Error) -> Self {
+        match e {
+            Day4Error::Cancelled(_) => SolutionError::Cancelled,
+            e => SolutionError::Day(Box::new(e)),
         }
-        let opt = chars.nth(new_idx as usize);
-        match opt {
-            Some(c) => s.push(c),
-            None => return s
+    }
+}
+
+impl Day4Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day4Error::Grid(e) => e.location(),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "fs")]
+            Day4Error::Io { source, .. } => ErrorKind::io(source),
+            Day4Error::ReadLine(source) => ErrorKind::io(source),
+            Day4Error::Grid(_) => ErrorKind::Parse,
+            Day4Error::Cancelled(_) => ErrorKind::Timeout,
         }
     }
-    return s;
 }
 
-/// Count the amount of X-shaped MAS words in 3 lines string vector
-/// 
-/// # Parameters
-/// 
-/// * `lines_vec` - A reference to a vector of strings. At least 3
-/// lines are requered to form 3 letter vertical / diagonal words.
+/// Result type returned by the day 4 functions
+pub type Result<T, E = Day4Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["18", "9"];
+
+#[cfg(test)]
+thread_local! {
+    /// Inputs parsed on this thread, the tests check that a part parses its input once
+    static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Letter grid of the word search, parsed once for the two parts
 /// 
-/// # Returns
+/// Lines of different lengths are reported as [`Day4Error::Grid`]
+/// with the row that does not match the first one
 /// 
-/// * `usize` - Number of X-shaped `MAS` in provided vector.
-fn count_verticals(lines_vec: &Vec<String>) -> usize {
-    let mut count = 0;
-    if lines_vec.len() < 3 {
-        return count;
-    }
-    for i in 0..lines_vec[2].len() - 2 {
-        let s1 = form_word(lines_vec, i, 1);
-        let s2 = form_word(lines_vec, i + 2, -1);
-        if (s1.contains("MAS") || s1.contains("SAM")) &&
-        (s2.contains("MAS") || s2.contains("SAM")) {
-            count += 1;
+/// # Examples
+/// ```
+/// fn main() -> day_4::Result<()> {
+///     let input = day_4::Day4Input::try_from("M.S\n.A.\nM.S\n")?;
+///     assert_eq!((input.0.width(), input.0.height()), (3, 3));
+///     assert_eq!(input.0[(1, 1)], 'A');
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day4Input(pub Grid<char>);
+
+impl Day4Input {
+    /// Read the grid line by line, checking `cancel` before every line
+    fn read<B: BufRead>(reader: B, cancel: Option<&CancelToken>) -> Result<Self> {
+        #[cfg(test)]
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        let check = || cancel.map_or(Ok(()), CancelToken::check);
+        // rows stop at the first failure, which is returned once the grid is built
+        let mut failure = None;
+        let rows = reader.lines().map_while(|line| {
+            match check().map_err(Day4Error::from).and_then(|_| line.map_err(Day4Error::ReadLine)) {
+                Ok(line) => Some(line.chars().collect::<Vec<char>>()),
+                Err(e) => {
+                    failure = Some(e);
+                    None
+                },
+            }
+        });
+        let grid = Grid::from_rows(rows)?;
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(Day4Input(grid)),
         }
     }
-    return count;
 }
 
-/// Shift vector by 1 iteration
-/// 
-/// Shift vector by 1 line, removing the first line and
-/// adding a new line from the iterator to the end of the vector.
-/// Adds an empty string if the end of the iterator is reached.
+impl TryFrom<&str> for Day4Input {
+    type Error = Day4Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        Day4Input::read(text.as_bytes(), None)
+    }
+}
+
+/// Number of X-shaped `MAS` words, the answer of part 2
 /// 
-/// # Parameters
+/// # Examples
+/// ```
+/// fn main() -> day_4::Result<()> {
+///     let input = day_4::Day4Input::try_from(day_4::SAMPLE)?;
+///     assert_eq!(day_4::part2(&input)?, 9);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day4Input) -> Result<usize> {
+    count_x_mas(input, None, None)
+}
+
+/// Day 4 solution used by the `aoc` runner
 /// 
-/// * `v` - A mutable reference to a vector of strings.
-/// * `iter` - A mutable reference to an iterator over lines in a buffered reader.
+/// Part 1 (`XMAS` word search) is not solved yet,
+/// part 2 counts X-shaped `MAS` words in the [`Day4Input`] grid
 /// 
-/// # Returns
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
 /// 
-/// * `Result<Vec<String>, Error>` - The updated vector or an error.
-fn vec_update<B: BufRead>(v: &mut Vec<String>, iter: &mut Lines<B>) -> Result<Vec<String>, Error> {
-    v[0] = v[1].clone();
-    v[1] = v[2].clone();
-    let opt = iter.next();
-    match opt {
-        Some(new) => v[2] = new.with_context(|| "failed reading line")?,
-        None => v[2] = String::new()
+/// fn main() -> Result<()> {
+///     let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+///     XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n";
+///     assert!(day_4::Day4.part1(input).is_err());
+///     assert_eq!(day_4::Day4.part2(input)?, "9");
+///     Ok(())
+/// }
+/// ```
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, _input: &str) -> SolutionResult<String> {
+        Err(SolutionError::NotImplemented { day: 4, part: 1 })
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day4Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day4Input::try_from(input)?;
+        Ok((input.solve(1)?, input.solve(2)?))
     }
-    Ok(v.to_vec())
 
+    fn solve_with_cancel(&self, part: u8, input: &str, progress: &dyn Progress, 
+        cancel: &CancelToken) -> SolutionResult<String> {
+        match part {
+            1 => self.part1(input),
+            _ => Ok(count_from_reader(input.as_bytes(), Some(progress), Some(cancel))?.to_string()),
+        }
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day4Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
 }
 
-/// Initialize a 3-line vector from a [BufReader] iterator.
+impl Parsed for Day4Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Err(SolutionError::NotImplemented { day: 4, part: 1 }),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a rectangular grid of `XMAS` letters
+fn format_problem(input: &str) -> Option<String> {
+    let grid: Grid<char> = match input.parse() {
+        Ok(grid) => grid,
+        Err(e) => return Some(error_chain(&Day4Error::Grid(e))),
+    };
+    let problem = grid.iter()
+    .find(|(_, letter)| !"XMAS".contains(**letter))
+    .map(|((row, col), letter)| format!("unexpected letter {} on row {}, column {}", letter, row + 1, col + 1));
+    problem
+}
+
+/// Day 4 input with a `size` x `size` letter grid, generated from `seed`, and its expected answers
 /// 
-/// Reads up to 3 lines from the provided iterator and initializes a vector with these lines.
-/// If fewer than 3 lines are available, the remaining entries in the vector are filled with empty strings.
+/// # Examples
+/// ```
+/// let (input, answers) = day_4::generate(50, 7);
+/// assert_eq!(day_4::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day4(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+/// Check whether an X-shaped `MAS` is centered at the position
 /// 
 /// # Parameters
 /// 
-/// * `lines_iter` - A mutable reference to an iterator over lines in a buffered reader.
+/// * `grid` - A reference to the word search grid.
+/// * `pos` - Position of the middle `A` letter.
 /// 
 /// # Returns
 /// 
-/// * `Result<Vec<String>, Error>` - A vector containing up to 3 lines read from the iterator, or an error.
-fn vec_init<B: BufRead>(lines_iter: &mut Lines<B>) -> Result<Vec<String>, Error> {
-    let mut v = Vec::new();
-    for _ in 0..3 {
-        let opt = lines_iter.next();
-        match opt {
-            Some(res) => v.push(res.with_context(|| "failed reading line")?),
-            None => v.push(String::new())
-        }
+/// * `bool` - `true` when both diagonals through `pos` spell `MAS` or `SAM`.
+fn is_x_mas(grid: &Grid<char>, pos: Pos) -> bool {
+    if grid[pos] != 'A' {
+        return false;
     }
-    Ok(v)
+    let corner = |d| grid.offset(pos, d).map(|(_, &c)| c);
+    let is_mas = |a, b| matches!((corner(a), corner(b)), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+    is_mas((-1, -1), (1, 1)) && is_mas((-1, 1), (1, -1))
 }
 
 /// Count XMAS matches in a file
@@ -131,87 +269,263 @@ fn vec_init<B: BufRead>(lines_iter: &mut Lines<B>) -> Result<Vec<String>, Error>
 /// 
 /// # Returns
 /// 
-/// * `Result<usize, Error>` - The count of XMAS matches or an error.
+/// * `Result<usize, Day4Error>` - The count of XMAS matches or an error.
 /// 
 /// # Examples
 /// ```
+/// use assert_fs::prelude::*;
 /// use anyhow::Result;
 /// 
 /// fn main() -> Result<()> {
-///     let c = day_4::xmas_count(std::path::Path::new("./data/input_test_9.txt"))?;
+///     let temp_file = assert_fs::NamedTempFile::new("input.txt")?;
+///     temp_file.write_str(day_4::SAMPLE)?;
+///     let c = day_4::xmas_count(temp_file.path())?;
 ///     assert_eq!(c, 9);
 ///     Ok(())
 /// }
 /// ```
-pub fn xmas_count(input_path: &std::path::Path) -> Result<usize, Error> {
-    let mut count = 0;
-
+#[cfg(feature = "fs")]
+pub fn xmas_count(input_path: &std::path::Path) -> Result<usize> {
     let file = File::open(input_path)
-    .with_context(|| format!("failed to open file {}", input_path.display()))?;
-    let reader = BufReader::new(file);
+    .map_err(|source| Day4Error::Io { path: input_path.to_path_buf(), source })?;
+    let reader = NormalizingReader::new(BufReader::new(file));
 
-    let mut lines_iter = reader.lines();
+    xmas_count_from_reader(reader)
+}
 
-    let mut lines_vec = vec_init(&mut lines_iter)?;
+/// Count XMAS matches read from a [BufRead]
+/// 
+/// Count X-shaped `MAS` words in the lines provided by the reader
+/// 
+/// # Parameters
+/// 
+/// * `reader` - A buffered reader providing the word search lines.
+/// 
+/// # Returns
+/// 
+/// * `Result<usize, Day4Error>` - The count of XMAS matches or an error.
+/// 
+/// # Examples
+/// ```
+/// use anyhow::Result;
+/// use std::io::Cursor;
+/// 
+/// fn main() -> Result<()> {
+///     let data = "M.S\n.A.\nM.S";
+///     let c = day_4::xmas_count_from_reader(Cursor::new(data))?;
+///     assert_eq!(c, 1);
+///     Ok(())
+/// }
+/// ```
+pub fn xmas_count_from_reader<B: BufRead>(reader: B) -> Result<usize> {
+    xmas_count_from_reader_with_progress(reader, None)
+}
 
-    while !lines_vec[2].is_empty() {
-        // count vertical and diagonal matches
-        count += count_verticals(&lines_vec);
+/// [`xmas_count_from_reader`] reporting the rows counted so far
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::NoProgress;
+/// 
+/// fn main() -> day_4::Result<()> {
+///     let c = day_4::xmas_count_from_reader_with_progress("M.S\n.A.\nM.S".as_bytes(), Some(&NoProgress))?;
+///     assert_eq!(c, 1);
+///     Ok(())
+/// }
+/// ```
+pub fn xmas_count_from_reader_with_progress<B: BufRead>(reader: B, progress: Option<&dyn Progress>) -> Result<usize> {
+    count_from_reader(reader, progress, None)
+}
 
-        // update lines vector
-        lines_vec = vec_update(&mut lines_vec, &mut lines_iter)?;
-    }
+/// [`xmas_count_from_reader`] stopping with [`Day4Error::Cancelled`] once `cancel` is cancelled
+/// 
+/// The token is checked before every row of the grid
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::CancelToken;
+/// 
+/// let cancel = CancelToken::new();
+/// assert_eq!(day_4::xmas_count_from_reader_with_cancel("M.S\n.A.\nM.S".as_bytes(), &cancel).unwrap(), 1);
+/// cancel.cancel();
+/// let result = day_4::xmas_count_from_reader_with_cancel("M.S\n.A.\nM.S".as_bytes(), &cancel);
+/// assert!(matches!(result, Err(day_4::Day4Error::Cancelled(_))));
+/// ```
+pub fn xmas_count_from_reader_with_cancel<B: BufRead>(reader: B, cancel: &CancelToken) -> Result<usize> {
+    count_from_reader(reader, None, Some(cancel))
+}
+
+fn count_from_reader<B: BufRead>(reader: B, progress: Option<&dyn Progress>, 
+    cancel: Option<&CancelToken>) -> Result<usize> {
+    count_x_mas(&Day4Input::read(reader, cancel)?, progress, cancel)
+}
 
+/// Count X-shaped `MAS` words, reporting the rows counted so far
+fn count_x_mas(input: &Day4Input, progress: Option<&dyn Progress>, cancel: Option<&CancelToken>) -> Result<usize> {
+    let grid = &input.0;
+    let ticker = Ticker::new(progress, Some(grid.height() as u64));
+
+    #[cfg(feature = "parallel")]
+    let count = count_x_mas_parallel(grid, &ticker, cancel)?;
+    #[cfg(not(feature = "parallel"))]
+    let count = count_x_mas_serial(grid, &ticker, cancel)?;
+    ticker.finish();
     Ok(count)
 }
 
+/// X-shaped `MAS` words centered in one row of the grid
+fn count_x_mas_row(grid: &Grid<char>, row: usize, ticker: &Ticker, cancel: Option<&CancelToken>) -> Result<usize> {
+    cancel.map_or(Ok(()), CancelToken::check)?;
+    let count = (0..grid.width()).filter(|&col| is_x_mas(grid, (row, col))).count();
+    ticker.tick(1);
+    Ok(count)
+}
+
+/// Count X-shaped `MAS` words one row at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn count_x_mas_serial(grid: &Grid<char>, ticker: &Ticker, cancel: Option<&CancelToken>) -> Result<usize> {
+    (0..grid.height())
+    .map(|row| count_x_mas_row(grid, row, ticker, cancel))
+    .sum()
+}
+
+/// Count X-shaped `MAS` words splitting the rows between the rayon threads
+#[cfg(feature = "parallel")]
+fn count_x_mas_parallel(grid: &Grid<char>, ticker: &Ticker, cancel: Option<&CancelToken>) -> Result<usize> {
+    use rayon::prelude::*;
+
+    (0..grid.height()).into_par_iter()
+    .map(|row| count_x_mas_row(grid, row, ticker, cancel))
+    .try_reduce(|| 0, |a, b| Ok(a + b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::{BufRead, BufReader, Cursor};
+    use std::io::Cursor;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
     use anyhow::{Ok, Result};
 
     #[test]
-    fn test_count_verticals() -> Result<()> {
-        let data = vec![
-            "AMXSXMAAMM".to_string(),
-            "MSAMASMSMX".to_string(),
-            "XMASAMXAMM".to_string()];
-        let count = count_verticals(&data);
-        assert_eq!(count, 2);
+    fn test_solution_part1_not_implemented() {
+        let result = Day4.part1(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: 4, part: 1 })));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_xmas_count_io_variant() {
+        let result = xmas_count(std::path::Path::new("missing.txt"));
+        assert!(matches!(result, Err(Day4Error::Io { path, .. }) if path == std::path::Path::new("missing.txt")));
+    }
+
+    #[test]
+    fn test_xmas_count_read_line_variant() {
+        let invalid_utf8: &[u8] = &[b'M', b'\n', 0xff, b'\n', b'S'];
+        let result = xmas_count_from_reader(invalid_utf8);
+        assert!(matches!(result, Err(Day4Error::ReadLine(_))));
+    }
+
+    #[test]
+    fn test_solution_part2_sample() -> Result<()> {
+        assert_eq!(Day4.part2(SAMPLE)?, "9");
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_parses_once() -> Result<()> {
+        PARSES.with(|parses| parses.set(0));
+        assert_eq!(Day4.part2(SAMPLE)?, "9");
+        assert_eq!(Day4.solve_with_progress(2, SAMPLE, &aoc_utils::NoProgress)?, "9");
+        assert_eq!(PARSES.with(|parses| parses.get()), 2);
         Ok(())
     }
 
     #[test]
-    fn test_form_word_right_diag() {
-        let data = vec![
-            "MMMSXXMASM".to_string(),
-            "MSAMXMSMSA".to_string(),
-            "AMXSXMAAMM".to_string()];
-        let s = form_word(&data, 5, 1);
-        assert_eq!(s, "MSA");
+    fn test_solve_both_parses_once() {
+        PARSES.with(|parses| parses.set(0));
+        let result = Day4.solve_both(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: 4, part: 1 })));
+        assert_eq!(PARSES.with(|parses| parses.get()), 1);
+    }
+
+    #[test]
+    fn test_input_try_from_ragged() {
+        let result = Day4Input::try_from("XMAS\nXMA\n");
+        assert!(matches!(&result, Err(Day4Error::Grid(GridError::Ragged { row: 1, .. }))));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_form_word_left_diag() {
-        let data = vec![
-            "MMMSXXMASM".to_string(),
-            "MSAMXMSMSA".to_string(),
-            "AMXSXMAAMM".to_string()];
-        let s = form_word(&data, 5, -1);
-        assert_eq!(s, "MXS");
-    }
-    
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day4Input::try_from("XM\nAS\n")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"[["X","M"],["A","S"]]"#);
+        assert_eq!(serde_json::from_str::<Day4Input>(&json)?, input);
+        assert!(serde_json::from_str::<Day4Input>(r#"[["X","M"],["A"]]"#).is_err());
+        Ok(())
+    }
+
     #[test]
-    fn test_form_word_vertical() {
-        let data = vec![
-            "MMMSXXMASM".to_string(),
-            "MSAMXMSMSA".to_string(),
-            "AMXSXMAAMM".to_string()];
-        let s = form_word(&data, 5, 0);
-        assert_eq!(s, "MMX");
+    fn test_describe() {
+        assert_eq!(Day4.describe(SAMPLE).problem, None);
+        assert_eq!(Day4.describe("XMAS\nXMA\n").problem.as_deref(),
+        Some("malformed word search: row 1 has 3 cells, expected 4"));
+        assert_eq!(Day4.describe("XMAS\nXMAZ\n").problem.as_deref(), Some("unexpected letter Z on row 2, column 4"));
     }
 
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day4.part2(&input)?, SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part2_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day4(seed, 60);
+            assert_eq!(Day4.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_solution_part2_matches_oracle(grid in aoc_testing::grid_strategy(1..12, 1..12, &['X', 'M', 'A', 'S'])) {
+            let expected = aoc_testing::oracle::x_mas_count(&grid).to_string();
+            proptest::prop_assert_eq!(Day4.part2(&grid.to_string()).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_is_x_mas_success() -> Result<()> {
+        let grid: Grid<char> = "M.S\n.A.\nM.S".parse()?;
+        assert!(is_x_mas(&grid, (1, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_x_mas_negative() -> Result<()> {
+        let grid: Grid<char> = "M.M\n.A.\nM.S".parse()?;
+        assert!(!is_x_mas(&grid, (1, 1)));
+        assert!(!is_x_mas(&grid, (0, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_x_mas_edge() -> Result<()> {
+        let grid: Grid<char> = "A.S\n.A.\nM.S".parse()?;
+        assert!(!is_x_mas(&grid, (0, 0)));
+        Ok(())
+    }
+
+    #[cfg(feature = "fs")]
     #[test]
     fn test_xmas_count() -> Result<()> {
         let c = xmas_count(std::path::Path::new("./data/input_test_9.txt"))?;
@@ -220,44 +534,50 @@ mod tests {
     }
 
     #[test]
-    fn test_vec_update() -> Result<()> {
-        let data = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM";
-        let cursor = Cursor::new(data);
-        let reader = BufReader::new(cursor);
-        
-        let mut lines_iter = reader.lines();
-        lines_iter.nth(2); // Skip the first 2 lines
-    
-        let mut lines_vec = vec![
-            "MMMSXXMASM".to_string(),
-            "MSAMXMSMSA".to_string(),
-            "AMXSXMAAMM".to_string()
-        ];
-    
-        lines_vec = vec_update(&mut lines_vec, &mut lines_iter)?;
-    
-        assert_eq!(lines_vec, vec![
-            "MSAMXMSMSA".to_string(),
-            "AMXSXMAAMM".to_string(),
-            "MSAMASMSMX".to_string()
-        ]);
+    fn test_solve_with_progress() -> Result<()> {
+        let progress = aoc_testing::RecordingProgress::default();
+        let input = aoc_testing::day4(1, 300).input;
+        assert_eq!(Day4.solve_with_progress(2, &input, &progress)?, Day4.part2(&input)?);
+        progress.assert_complete();
+        assert!(progress.updates().len() > 10);
+        assert_eq!(progress.updates().last(), Some(&(300, Some(300))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_with_cancel() {
+        let tile = aoc_testing::day4(1, 500).input;
+        let input = tile.lines().map(|line| line.repeat(8) + "\n").collect::<String>().repeat(8);
+        let (result, latency) = aoc_testing::cancel_after(std::time::Duration::from_millis(50), |cancel| {
+            Day4.solve_with_cancel(2, &input, &aoc_utils::NoProgress, cancel)
+        });
+        assert!(matches!(result, Err(SolutionError::Cancelled)), "{:?}", result);
+        assert!(latency < std::time::Duration::from_millis(200), "took {:?} to stop", latency);
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_xmas_count_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        assert_eq!(xmas_count(file.path())?, 9);
         Ok(())
     }
 
     #[test]
-    fn test_vec_init() -> Result<()> {
-        let data = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nMSAMASMSMX";
-        let cursor = Cursor::new(data);
-        let reader = BufReader::new(cursor);
-    
-        let mut lines_iter = reader.lines();
-
-        let lines_vec = vec_init(&mut lines_iter)?;
-
-        assert_eq!(lines_vec, vec![
-        "MMMSXXMASM".to_string(),
-        "MSAMXMSMSA".to_string(),
-        "AMXSXMAAMM".to_string()]);
+    #[cfg(feature = "parallel")]
+    fn test_count_x_mas_parallel_matches_serial() -> Result<()> {
+        let grid: Grid<char> = SAMPLE.parse()?;
+        assert_eq!(count_x_mas_parallel(&grid, &Ticker::new(None, None), None)?, count_x_mas_serial(&grid, &Ticker::new(None, None), None)?);
+
+        let grid: Grid<char> = aoc_testing::day4(0x2024_0004, 200).input.parse()?;
+        assert_eq!(count_x_mas_parallel(&grid, &Ticker::new(None, None), None)?, count_x_mas_serial(&grid, &Ticker::new(None, None), None)?);
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_xmas_count_grid_variant() {
+        let result = xmas_count_from_reader(Cursor::new("MAS\nMA"));
+        assert!(matches!(result, Err(Day4Error::Grid(GridError::Ragged { row: 1, .. }))));
+    }
+}
@@ -1,11 +1,25 @@
-use anyhow::{Ok, Result};
+use std::process::ExitCode;
+use aoc_prelude::*;
 
-fn main() -> Result<()>{
-    let input = std::path::Path::new("./data/input.txt");
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
     
-    let xmas_count = day_4::xmas_count(input)?;
+    let xmas_count = day_4::xmas_count_from_reader(input.reader()?)?;
 
     println!("X-MAS_COUNT: {}", xmas_count);
 
     Ok(())
 }
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_4::Day4Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,8 @@
+//! Replays the crashers saved from the `day_4` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_4", |data| {
+        let _ = day_4::xmas_count_from_reader(data);
+    });
+}
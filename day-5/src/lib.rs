@@ -5,8 +5,383 @@
 //! 
 //! [`Read more`](../../../README.md)
 
-use std::{collections::HashMap, io::BufRead};
-use anyhow::{Context, Error, Result};
+use aoc_prelude::*;
+
+/// Errors returned by the day 5 functions
+#[derive(Debug, Error)]
+pub enum Day5Error {
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// A rule does not have the `X|Y` format with numeric pages
+    #[error("malformed rule {rule} on line {line}")]
+    MalformedRule {
+        /// Line number of the rule (starting from 1)
+        line: usize,
+        /// Text of the rule
+        rule: String,
+    },
+    /// An update contains a page that is not a number
+    #[error("malformed update {update}")]
+    MalformedUpdate {
+        /// Text of the update
+        update: String,
+    },
+    /// Checking the updates was stopped with a [`CancelToken`]
+    #[error(transparent)]
+    Cancelled(#[from] Cancelled),
+}
+
+impl From<Day5Error> for SolutionError {
+    fn from(e: Day5Error) -> Self {
+        match e {
+            Day5Error::Cancelled(_) => SolutionError::Cancelled,
+            e => SolutionError::Day(Box::new(e)),
+        }
+    }
+}
+
+impl Day5Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day5Error::MalformedRule { line, .. } => Some(Location::Line(*line)),
+            Day5Error::MalformedUpdate { update } => Some(Location::Token(update.clone())),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day5Error::ReadLine(source) => ErrorKind::io(source),
+            Day5Error::MalformedRule { .. } | Day5Error::MalformedUpdate { .. } => ErrorKind::Parse,
+            Day5Error::Cancelled(_) => ErrorKind::Timeout,
+        }
+    }
+}
+
+/// Result type returned by the day 5 functions
+pub type Result<T, E = Day5Error> = std::result::Result<T, E>;
+
+/// Check that a page is a number
+fn is_page(page: &str) -> bool {
+    page.parse::<i32>().is_ok()
+}
+
+/// Page ordering rules, each `X|Y` rule puts page `X` before page `Y`
+/// 
+/// # Examples
+/// ```
+/// let mut rules = day_5::Rules::default();
+/// rules.insert(47, 53);
+/// assert!(rules.orders(47, 53));
+/// assert!(!rules.orders(53, 47));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rules(HashMap<u32, HashSet<u32>>);
+
+impl Rules {
+    /// Add the rule putting `before` ahead of `after`
+    pub fn insert(&mut self, before: u32, after: u32) {
+        self.0.entry(before).or_default().insert(after);
+    }
+
+    /// Whether a rule puts `before` ahead of `after`
+    pub fn orders(&self, before: u32, after: u32) -> bool {
+        self.0.get(&before).is_some_and(|pages| pages.contains(&after))
+    }
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Inputs parsed on this thread, the tests check that both parts share one parse
+    static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Ordering rules and the updates to check, parsed once for the two parts
+/// 
+/// Rules end at the first line without a `|`, which separates them from
+/// the updates. Blank lines between the updates are skipped.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_5::Result<()> {
+///     let input = day_5::Day5Input::try_from("47|53\n97|13\n\n47,53\n97,13,61\n")?;
+///     assert!(input.rules.orders(97, 13));
+///     assert_eq!(input.updates, vec![vec![47, 53], vec![97, 13, 61]]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day5Input {
+    /// Page ordering rules
+    pub rules: Rules,
+    /// Pages of every update
+    pub updates: Vec<Vec<u32>>,
+}
+
+impl Day5Input {
+    /// Parse the input, polling `cancel` between the updates
+    fn parse(text: &str, cancel: Option<&CancelToken>) -> Result<Self> {
+        #[cfg(test)]
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        let mut lines = text.lines().enumerate();
+        let mut rules = Rules::default();
+        for (i, line) in lines.by_ref() {
+            let Some((before, after)) = line.split_once('|') else {
+                break;
+            };
+            match (before.parse(), after.parse()) {
+                (Ok(before), Ok(after)) => rules.insert(before, after),
+                _ => return Err(Day5Error::MalformedRule { line: i + 1, rule: line.to_string() }),
+            }
+        }
+
+        let mut updates = Vec::new();
+        for (index, (_, update)) in lines.filter(|(_, line)| !line.trim().is_empty()).enumerate() {
+            poll(cancel, index)?;
+            let pages = update.split(',')
+            .map(str::parse)
+            .collect::<std::result::Result<Vec<u32>, _>>()
+            .map_err(|_| Day5Error::MalformedUpdate { update: update.to_string() })?;
+            updates.push(pages);
+        }
+        Ok(Day5Input { rules, updates })
+    }
+}
+
+impl TryFrom<&str> for Day5Input {
+    type Error = Day5Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        Day5Input::parse(text, None)
+    }
+}
+
+/// Sum of the middle pages of the correctly ordered updates, the answer of part 1
+/// 
+/// Like [`correctly_ordered_sum`], pages without a rule ordering them
+/// count as out of order
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_5::Result<()> {
+///     let input = day_5::Day5Input::try_from(day_5::SAMPLE)?;
+///     assert_eq!(day_5::part1(&input)?, 143);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day5Input) -> Result<u64> {
+    middle_page_sum(input, None, None)
+}
+
+/// Whether every page of the update is ordered before all the pages after it
+fn is_ordered(update: &[u32], rules: &Rules) -> bool {
+    update.iter().enumerate()
+    .all(|(i, &before)| update[i + 1..].iter().all(|&after| rules.orders(before, after)))
+}
+
+/// [`part1`] reporting the updates checked so far and polling `cancel`
+fn middle_page_sum(input: &Day5Input, progress: Option<&dyn Progress>, cancel: Option<&CancelToken>) -> Result<u64> {
+    let ticker = Ticker::new(progress, Some(input.updates.len() as u64));
+    let middle_page = |(index, update): (usize, &Vec<u32>)| -> Result<u64> {
+        poll(cancel, index)?;
+        let page = match is_ordered(update, &input.rules) {
+            true => update.get(update.len() / 2).copied().map_or(0, u64::from),
+            false => 0,
+        };
+        ticker.tick(1);
+        Ok(page)
+    };
+
+    #[cfg(feature = "parallel")]
+    let sum = {
+        use rayon::prelude::*;
+        input.updates.par_iter().enumerate()
+        .map(middle_page)
+        .try_reduce(|| 0, |a, b| Ok(a + b))?
+    };
+    #[cfg(not(feature = "parallel"))]
+    let sum = input.updates.iter().enumerate()
+    .map(middle_page)
+    .sum::<Result<u64>>()?;
+    ticker.finish();
+    Ok(sum)
+}
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n\
+53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n\
+75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["143", "123"];
+
+/// Day 5 solution used by the `aoc` runner
+/// 
+/// Part 1 sums the middle pages of correctly ordered updates,
+/// part 2 (reordering the incorrect updates) is not solved yet.
+/// The input is parsed into a [`Day5Input`].
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     let input = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n\
+///     53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n\
+///     75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47\n";
+///     assert_eq!(day_5::Day5.part1(input)?, "143");
+///     assert!(day_5::Day5.part2(input).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day5Input::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, _input: &str) -> SolutionResult<String> {
+        Err(SolutionError::NotImplemented { day: 5, part: 2 })
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day5Input::try_from(input)?;
+        Ok((input.solve(1)?, input.solve(2)?))
+    }
+
+    fn solve_with_cancel(&self, part: u8, input: &str, progress: &dyn Progress, 
+        cancel: &CancelToken) -> SolutionResult<String> {
+        if part != 1 {
+            return self.part2(input);
+        }
+        let input = Day5Input::parse(input, Some(cancel))?;
+        Ok(middle_page_sum(&input, Some(progress), Some(cancel))?.to_string())
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day5Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+
+    fn explain(&self, input: &str, selector: &str) -> SolutionResult<String> {
+        let rules = read_rules(&mut Cursor::new(input))?;
+        let lines: Vec<&str> = input.lines().collect();
+        // updates start after the blank line following the rules
+        let first_update = lines.iter().position(|line| !line.contains('|')).map_or(lines.len() + 1, |i| i + 2);
+        let line = selector.trim().parse::<usize>().ok()
+        .filter(|line| (first_update..=lines.len()).contains(line))
+        .ok_or_else(|| SolutionError::InvalidSelector {
+            selector: selector.to_string(),
+            expected: format!("an update line from {} to {}", first_update, lines.len()),
+        })?;
+        let update = lines[line - 1];
+        Ok(format!("Update on line {}: {}\n{}", line, update, explain_update(update, &rules)?))
+    }
+}
+
+impl Parsed for Day5Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Err(SolutionError::NotImplemented { day: 5, part: 2 }),
+        }
+    }
+}
+
+/// Rules an update breaks, or its middle page when it is correctly ordered
+/// 
+/// Like [`correctly_ordered_sum`], pages without a rule ordering them
+/// count as out of order
+/// 
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// 
+/// fn main() -> day_5::Result<()> {
+///     let rules = HashMap::from([("47".to_string(), vec!["53".to_string()])]);
+///     assert_eq!(day_5::explain_update("47,53", &rules)?, "Correctly ordered, middle page 53");
+///     assert_eq!(day_5::explain_update("53,47", &rules)?, "Breaks 47|53: 53 comes before 47\nNot correctly ordered");
+///     Ok(())
+/// }
+/// ```
+pub fn explain_update(update: &str, rules: &HashMap<String, Vec<String>>) -> Result<String> {
+    let pages: Vec<&str> = update.split(',').collect();
+    if !pages.iter().all(|page| is_page(page)) {
+        return Err(Day5Error::MalformedUpdate { update: update.to_string() });
+    }
+    let orders = |before: &str, after: &str| rules.get(before).is_some_and(|pages| pages.iter().any(|page| page == after));
+
+    let mut lines = Vec::new();
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            if orders(before, after) {
+                continue;
+            }
+            match orders(after, before) {
+                true => lines.push(format!("Breaks {}|{}: {} comes before {}", after, before, before, after)),
+                false => lines.push(format!("No rule puts {} before {}", before, after)),
+            }
+        }
+    }
+    match lines.is_empty() {
+        true => lines.push(format!("Correctly ordered, middle page {}", pages[pages.len() / 2])),
+        false => lines.push("Not correctly ordered".to_string()),
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Why the input does not look like rules, a blank line and the updates
+fn format_problem(input: &str) -> Option<String> {
+    let mut lines = input.lines().enumerate();
+    match lines.by_ref().find(|(_, line)| !line.contains('|')) {
+        None => return Some("no updates after the rules".to_string()),
+        Some((i, line)) if !line.trim().is_empty() => {
+            return Some(format!("missing the blank line between the rules and the updates on line {}", i + 1));
+        },
+        Some(_) => {},
+    }
+    if let Err(e) = read_rules(&mut Cursor::new(input)) {
+        return Some(e.to_string());
+    }
+    lines.map(|(_, line)| line)
+    .find(|update| !update.split(',').all(is_page))
+    .map(|update| Day5Error::MalformedUpdate { update: update.to_string() }.to_string())
+}
+
+/// Day 5 input with `size` updates, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_5::generate(50, 7);
+/// assert_eq!(day_5::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day5(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
 
 /// Read rules and add them to a hash map
 /// 
@@ -14,6 +389,8 @@ use anyhow::{Context, Error, Result};
 /// to all the numbers that are greater
 /// The map keys are the numbers provided in the rules.
 /// The map values are lists of numbers greater (in order) than the key.
+/// Reading stops at the first line without a `|` separator.
+/// A rule whose pages are not numbers is reported as [`Day5Error::MalformedRule`].
 /// 
 /// # Examples
 /// ```
@@ -57,10 +434,10 @@ use anyhow::{Context, Error, Result};
 ///     Ok(())
 /// }
 /// ```
-pub fn read_rules<B: BufRead>(reader: &mut B) -> Result<HashMap<String, Vec<String>>, Error> {
+pub fn read_rules<B: BufRead>(reader: &mut B) -> Result<HashMap<String, Vec<String>>> {
     let mut map: HashMap<String, Vec<String>> = HashMap::new();
-    for line in reader.lines() {
-        let l = line.with_context(|| "failed reading line")?;
+    for (i, line) in reader.lines().enumerate() {
+        let l = line.map_err(Day5Error::ReadLine)?;
         let opt = l.split_once("|");
         let key;
         let val;
@@ -71,6 +448,9 @@ pub fn read_rules<B: BufRead>(reader: &mut B) -> Result<HashMap<String, Vec<Stri
             },
             None => return Ok(map)
         }
+        if !is_page(key) || !is_page(val) {
+            return Err(Day5Error::MalformedRule { line: i + 1, rule: l });
+        }
         if map.contains_key(key) {
             map.entry(key.to_string()).and_modify(|vec| vec.push(val.to_string()));
         } 
@@ -88,11 +468,10 @@ pub fn read_rules<B: BufRead>(reader: &mut B) -> Result<HashMap<String, Vec<Stri
 fn is_correctly_ordered(rules: &HashMap<String, Vec<String>>, update_vec: &Vec<&str>) -> bool {
     for i in 0..update_vec.len()-1 {
         let curr = update_vec.get(i).unwrap_or(&"").to_string();
-        let curr_greater;
-        match rules.get(&curr) {
-            Some(val) => curr_greater = val,
+        let curr_greater = match rules.get(&curr) {
+            Some(val) => val,
             None => return false
-        }
+        };
         for j in i+1..update_vec.len() {
             let next = update_vec.get(j).unwrap_or(&"").to_string();
             if !curr_greater.contains(&next) {
@@ -106,7 +485,8 @@ fn is_correctly_ordered(rules: &HashMap<String, Vec<String>>, update_vec: &Vec<&
 /// Sum middle page numbers of correctly ordered updates
 /// 
 /// Find all the correctly ordered updates and add
-/// all middle page numbers to get the sum
+/// all middle page numbers to get the sum.
+/// A middle page that is not a number is reported as [`Day5Error::MalformedUpdate`].
 /// 
 /// # Examples
 /// ```
@@ -153,21 +533,108 @@ fn is_correctly_ordered(rules: &HashMap<String, Vec<String>>, update_vec: &Vec<&
 /// }
 /// ```
 pub fn correctly_ordered_sum<B: BufRead>(reader: &mut B, 
-    rules: &HashMap<String, Vec<String>>) -> Result<i32, Error> {
+    rules: &HashMap<String, Vec<String>>) -> Result<i32> {
+    correctly_ordered_sum_with_progress(reader, rules, None)
+}
+
+/// [`correctly_ordered_sum`] reporting the number of updates checked so far
+/// 
+/// The total is only known up front when the updates are checked in parallel
+pub fn correctly_ordered_sum_with_progress<B: BufRead>(reader: &mut B, 
+    rules: &HashMap<String, Vec<String>>, progress: Option<&dyn Progress>) -> Result<i32> {
+    ordered_sum(reader, rules, progress, None)
+}
+
+/// [`correctly_ordered_sum`] stopping with [`Day5Error::Cancelled`] once `cancel` is cancelled
+/// 
+/// # Examples
+/// ```
+/// use std::{collections::HashMap, io::Cursor};
+/// use aoc_utils::CancelToken;
+/// 
+/// let rules = HashMap::from([
+///     ("47".to_string(), vec!["53".to_string(), "61".to_string()]),
+///     ("53".to_string(), vec!["61".to_string()]),
+/// ]);
+/// let cancel = CancelToken::new();
+/// assert_eq!(day_5::correctly_ordered_sum_with_cancel(&mut Cursor::new("47,53,61\n"), &rules, &cancel).unwrap(), 53);
+/// cancel.cancel();
+/// let result = day_5::correctly_ordered_sum_with_cancel(&mut Cursor::new("47,53,61\n"), &rules, &cancel);
+/// assert!(matches!(result, Err(day_5::Day5Error::Cancelled(_))));
+/// ```
+pub fn correctly_ordered_sum_with_cancel<B: BufRead>(reader: &mut B, 
+    rules: &HashMap<String, Vec<String>>, cancel: &CancelToken) -> Result<i32> {
+    ordered_sum(reader, rules, None, Some(cancel))
+}
+
+fn ordered_sum<B: BufRead>(reader: &mut B, rules: &HashMap<String, Vec<String>>, 
+    progress: Option<&dyn Progress>, cancel: Option<&CancelToken>) -> Result<i32> {
+    #[cfg(feature = "parallel")]
+    return correctly_ordered_sum_parallel(reader, rules, progress, cancel);
+    #[cfg(not(feature = "parallel"))]
+    return correctly_ordered_sum_serial(reader, rules, progress, cancel);
+}
+
+/// [`CancelToken::poll`] for the update with the index, when there is a token
+fn poll(cancel: Option<&CancelToken>, index: usize) -> Result<()> {
+    Ok(cancel.map_or(Ok(()), |cancel| cancel.poll(index))?)
+}
+
+/// Middle page of an update if it is correctly ordered, `0` otherwise
+fn ordered_middle_page(update: &str, rules: &HashMap<String, Vec<String>>) -> Result<i32> {
+    let update_vec = update.split(",").collect();
+    if !is_correctly_ordered(rules, &update_vec) {
+        return Ok(0);
+    }
+    update_vec.get(update_vec.len()/2)
+    .unwrap_or(&"")
+    .parse::<i32>()
+    .map_err(|_| Day5Error::MalformedUpdate { update: update.to_string() })
+}
+
+/// [`correctly_ordered_sum`] checking one update at a time as it is read
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn correctly_ordered_sum_serial<B: BufRead>(reader: &mut B, 
+    rules: &HashMap<String, Vec<String>>, progress: Option<&dyn Progress>, 
+    cancel: Option<&CancelToken>) -> Result<i32> {
+    let ticker = Ticker::new(progress, None);
     let mut sum = 0;
 
-    for line in reader.lines() {
-        let update = line.with_context(|| "failed to read line")?;
-        let update_vec = update.split(",").collect();
-        if !is_correctly_ordered(rules, &update_vec) {
-            continue;
-        }
-        sum += update_vec.get(update_vec.len()/2)
-        .unwrap_or(&"")
-        .parse::<i32>()
-        .unwrap_or(0);
+    for (index, line) in reader.lines().enumerate() {
+        poll(cancel, index)?;
+        let update = line.map_err(Day5Error::ReadLine)?;
+        sum += ordered_middle_page(&update, rules)?;
+        ticker.tick(1);
+    }
+
+    ticker.finish();
+    Ok(sum)
+}
+
+/// [`correctly_ordered_sum`] reading every update first and
+/// splitting them between the rayon threads
+#[cfg(feature = "parallel")]
+fn correctly_ordered_sum_parallel<B: BufRead>(reader: &mut B, 
+    rules: &HashMap<String, Vec<String>>, progress: Option<&dyn Progress>, 
+    cancel: Option<&CancelToken>) -> Result<i32> {
+    use rayon::prelude::*;
+
+    let mut updates = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        poll(cancel, index)?;
+        updates.push(line.map_err(Day5Error::ReadLine)?);
     }
+    let ticker = Ticker::new(progress, Some(updates.len() as u64));
 
+    let sum = updates.par_iter().enumerate()
+    .map(|(index, update)| {
+        poll(cancel, index)?;
+        let page = ordered_middle_page(update, rules);
+        ticker.tick(1);
+        page
+    })
+    .try_reduce(|| 0, |a, b| Ok(a + b))?;
+    ticker.finish();
     Ok(sum)
 }
 
@@ -175,8 +642,198 @@ pub fn correctly_ordered_sum<B: BufRead>(reader: &mut B,
 mod tests {
     use super::*;
     use std::io::{BufReader, Cursor};
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
     use anyhow::{Ok, Result};
 
+    #[test]
+    fn test_solution_part1_sample() -> Result<()> {
+        assert_eq!(Day5.part1(SAMPLE)?, "143");
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_both_parses_once() {
+        PARSES.with(|parses| parses.set(0));
+        let result = Day5.solve_both(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: 5, part: 2 })));
+        assert_eq!(PARSES.with(|parses| parses.get()), 1);
+    }
+
+    #[test]
+    fn test_input_try_from() -> Result<()> {
+        let input = Day5Input::try_from("47|53\n\n47,53\n\n53\n")?;
+        assert_eq!(input.updates, vec![vec![47, 53], vec![53]]);
+        assert_eq!(part1(&input)?, 53 + 53);
+        let e = Day5Input::try_from("47|53\n97|ab\n").unwrap_err();
+        assert!(matches!(e, Day5Error::MalformedRule { line: 2, .. }));
+        let e = Day5Input::try_from("47|53\n\n47,x\n").unwrap_err();
+        assert_eq!(e.location(), Some(Location::Token("47,x".to_string())));
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day5Input::try_from("47|53\n\n47,53\n")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"{"rules":{"47":[53]},"updates":[[47,53]]}"#);
+        assert_eq!(serde_json::from_str::<Day5Input>(&json)?, input);
+        let input = Day5Input::try_from(SAMPLE)?;
+        assert_eq!(serde_json::from_str::<Day5Input>(&serde_json::to_string(&input)?)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_sample() -> Result<()> {
+        assert_eq!(Day5.explain(SAMPLE, "23")?, "Update on line 23: 75,47,61,53,29\nCorrectly ordered, middle page 61");
+        assert_eq!(Day5.explain(SAMPLE, "26")?, "Update on line 26: 75,97,47,61,53\n\
+        Breaks 97|75: 75 comes before 97\nNot correctly ordered");
+        assert_eq!(Day5.explain(SAMPLE, "28")?, concat!(
+            "Update on line 28: 97,13,75,29,47\n",
+            "Breaks 75|13: 13 comes before 75\n",
+            "Breaks 29|13: 13 comes before 29\n",
+            "Breaks 47|13: 13 comes before 47\n",
+            "Breaks 47|29: 29 comes before 47\n",
+            "Not correctly ordered",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_invalid_selector() {
+        for selector in ["5", "22", "29", "last"] {
+            let e = Day5.explain(SAMPLE, selector).unwrap_err();
+            assert_eq!(e.to_string(), format!("invalid selector `{}`, expected an update line from 23 to 28", selector));
+        }
+        let rules = HashMap::from([("47".to_string(), vec!["53".to_string()])]);
+        assert_eq!(explain_update("47,61", &rules).unwrap(), "No rule puts 47 before 61\nNot correctly ordered");
+        assert!(matches!(explain_update("47,x", &rules), Err(Day5Error::MalformedUpdate { .. })));
+    }
+
+    #[test]
+    fn test_describe_sample() {
+        let stats = Day5.describe(SAMPLE);
+        assert_eq!(stats.lines, 28);
+        assert_eq!((stats.min, stats.max), (Some(13), Some(97)));
+        assert_eq!(stats.problem, None);
+    }
+
+    #[test]
+    fn test_describe_missing_blank_line() {
+        let input = SAMPLE.replace("\n\n", "\n");
+        assert_eq!(Day5.describe(&input).problem.as_deref(),
+        Some("missing the blank line between the rules and the updates on line 22"));
+        assert_eq!(Day5.describe("47|53\n").problem.as_deref(), Some("no updates after the rules"));
+    }
+
+    #[test]
+    fn test_describe_malformed_update() {
+        let input = "47|53\n\n47,x,53\n";
+        assert_eq!(Day5.describe(input).problem.as_deref(), Some("malformed update 47,x,53"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day5.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part1_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day5(seed, 200);
+            assert_eq!(Day5.part1(&generated.input)?, generated.part1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_with_progress() -> Result<()> {
+        let progress = aoc_testing::RecordingProgress::default();
+        let generated = aoc_testing::day5(1, 5000);
+        assert_eq!(Day5.solve_with_progress(1, &generated.input, &progress)?, generated.part1);
+        progress.assert_complete();
+        assert_eq!(progress.updates().last(), Some(&(5000, Some(5000))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_with_cancel() {
+        let generated = aoc_testing::day5(1, 2000).input;
+        let (rules, updates) = generated.split_once("\n\n").unwrap();
+        let input = format!("{}\n\n{}", rules, updates.repeat(50));
+        let (result, latency) = aoc_testing::cancel_after(std::time::Duration::from_millis(50), |cancel| {
+            Day5.solve_with_cancel(1, &input, &aoc_utils::NoProgress, cancel)
+        });
+        assert!(matches!(result, Err(SolutionError::Cancelled)), "{:?}", result);
+        assert!(latency < std::time::Duration::from_millis(200), "took {:?} to stop", latency);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_solution_part1_sorted_update(rules in aoc_testing::consistent_rules_strategy(1..30), seed in proptest::prelude::any::<u64>()) {
+            let mut rng = aoc_testing::Rng::new(seed);
+            let mut update = rules.order.clone();
+            rng.shuffle(&mut update);
+            update.truncate(rng.range(0, (rules.order.len() as u64).div_ceil(2)) as usize * 2 + 1);
+            let update = rules.sorted(&update);
+            let pages: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+            let input = format!("{}\n{}\n", rules.text(), pages.join(","));
+            proptest::prop_assert_eq!(Day5.part1(&input).unwrap(), update[update.len() / 2].to_string());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_correctly_ordered_sum_parallel_matches_serial() -> Result<()> {
+        let sum = |text: &str, parallel: bool| {
+            let mut reader = Cursor::new(text);
+            let rules = read_rules(&mut reader)?;
+            match parallel {
+                true => correctly_ordered_sum_parallel(&mut reader, &rules, None, None),
+                false => correctly_ordered_sum_serial(&mut reader, &rules, None, None),
+            }
+        };
+        assert_eq!(sum(SAMPLE, true)?, sum(SAMPLE, false)?);
+
+        let text = aoc_testing::day5(0x2024_0005, 1000).input;
+        assert_eq!(sum(&text, true)?, sum(&text, false)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_part2_not_implemented() {
+        let result = Day5.part2(SAMPLE);
+        assert!(matches!(result, Err(SolutionError::NotImplemented { day: 5, part: 2 })));
+    }
+
+    #[test]
+    fn test_read_rules_malformed_rule_variant() {
+        let mut reader = Cursor::new("47|53\n97|ab\n");
+        let result = read_rules(&mut reader);
+        assert!(matches!(result, Err(Day5Error::MalformedRule { line: 2, rule }) if rule == "97|ab"));
+    }
+
+    #[test]
+    fn test_correctly_ordered_sum_malformed_update_variant() {
+        let rules = HashMap::from([("1".to_string(), vec!["x".to_string()])]);
+        let mut reader = Cursor::new("1,x");
+        let result = correctly_ordered_sum(&mut reader, &rules);
+        assert!(matches!(result, Err(Day5Error::MalformedUpdate { update }) if update == "1,x"));
+    }
+
+    #[test]
+    fn test_read_rules_read_line_variant() {
+        let mut reader = Cursor::new(vec![b'4', b'7', b'|', 0xff]);
+        let result = read_rules(&mut reader);
+        assert!(matches!(result, Err(Day5Error::ReadLine(_))));
+    }
+
     #[test]
     fn test_sum() -> Result<()> {
         let data = "75,47,61,53,29\n97,61,53,29,13\n\
@@ -279,9 +936,7 @@ mod tests {
 
     #[test]
     fn test_read_rules() -> Result<()> {
-        let data = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n\
-        53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13";
-        let cursor = Cursor::new(data);
+        let cursor = Cursor::new(SAMPLE);
         let mut reader = BufReader::new(cursor);
 
         let real_map = read_rules(&mut reader)?;
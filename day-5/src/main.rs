@@ -1,12 +1,12 @@
-use std::{fs::File, io::BufReader, path::Path};
+use std::process::ExitCode;
+use aoc_prelude::*;
 
-use anyhow::{Result, Context};
-
-fn main() -> Result<()> {
-    let input_path = Path::new("./data/input.txt");
-    let file = File::open(input_path)
-    .with_context(|| format!("Failed reading file from path {}", input_path.display()))?;
-    let mut reader = BufReader::new(file);
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    let mut reader = input.reader()?;
 
     let rules = day_5::read_rules(&mut reader)?;
 
@@ -16,3 +16,13 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_5::Day5Error::kind)).into()
+        },
+    }
+}
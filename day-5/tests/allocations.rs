@@ -0,0 +1,19 @@
+//! Upper bound on the allocations of the solve, guarding the page
+//! strings of the rules and updates against extra copies
+
+use aoc_prelude::Solution;
+use aoc_testing::{AllocationGuard, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_allocations_10k_updates() -> anyhow::Result<()> {
+    let input = aoc_testing::day5(7, 10_000).input;
+    let updates = input.lines().skip_while(|line| !line.is_empty()).skip(1).count() as u64;
+    let guard = AllocationGuard::new();
+    day_5::Day5.part1(&input)?;
+    // about 66 allocations per update, mostly page strings compared with the rules
+    assert!(guard.allocations() < 70 * updates, "part 1 allocated {} times for {} updates", guard.allocations(), updates);
+    Ok(())
+}
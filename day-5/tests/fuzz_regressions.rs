@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_5` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_5", |data| {
+        let mut reader = data;
+        if let Ok(rules) = day_5::read_rules(&mut reader) {
+            let _ = day_5::correctly_ordered_sum(&mut reader, &rules);
+        }
+    });
+}
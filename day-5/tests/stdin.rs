@@ -0,0 +1,16 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-5"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-5");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-5");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "SUM:: 143\n");
+}
@@ -0,0 +1,626 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 6 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 6 functions
+#[derive(Debug, Error)]
+pub enum Day6Error {
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// Lines of the map have different lengths
+    #[error("malformed lab map")]
+    Grid(#[from] GridError),
+    /// A cell is neither empty, an obstruction nor the guard
+    #[error("unexpected {cell} on line {line}, column {column}")]
+    UnknownCell {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        cell: char,
+    },
+    /// No guard (`^`, `>`, `v` or `<`) on the map
+    #[error("the map has no guard")]
+    MissingGuard,
+    /// More than one guard on the map
+    #[error("second guard on line {line}, column {column}")]
+    ExtraGuard {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+    },
+    /// The guard walks in a loop and never leaves the map
+    #[error("the guard never leaves the map")]
+    Loop,
+}
+
+impl From<Day6Error> for SolutionError {
+    fn from(e: Day6Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day6Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day6Error::Grid(e) => e.location(),
+            Day6Error::UnknownCell { line, .. } | Day6Error::ExtraGuard { line, .. } => Some(Location::Line(*line)),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day6Error::ReadLine(source) => ErrorKind::io(source),
+            Day6Error::Grid(_) | Day6Error::UnknownCell { .. } | Day6Error::MissingGuard
+            | Day6Error::ExtraGuard { .. } => ErrorKind::Parse,
+            Day6Error::Loop => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 6 functions
+pub type Result<T, E = Day6Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+..........\n.#..^.....\n........#.\n#.........\n......#...\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["41", "6"];
+
+/// Direction the guard is facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Towards the first row, drawn as `^`
+    Up,
+    /// Towards the last column, drawn as `>`
+    Right,
+    /// Towards the last row, drawn as `v`
+    Down,
+    /// Towards the first column, drawn as `<`
+    Left,
+}
+
+impl Direction {
+    /// Direction of a guard drawn as `c`, `None` for any other character
+    /// 
+    /// # Examples
+    /// ```
+    /// use day_6::Direction;
+    /// 
+    /// assert_eq!(Direction::from_guard('v'), Some(Direction::Down));
+    /// assert_eq!(Direction::from_guard('#'), None);
+    /// ```
+    pub fn from_guard(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// Direction after turning right by 90 degrees
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Row and column offset of one step forward
+    pub fn step(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    /// Bit marking the direction in the patrol states of a cell
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Lab map with the obstructions and the starting guard, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// use day_6::Direction;
+/// 
+/// fn main() -> day_6::Result<()> {
+///     let map = day_6::parse_map(".#.\n.>.\n")?;
+///     assert_eq!((map.obstacles.width(), map.obstacles.height()), (3, 2));
+///     assert!(map.obstacles[(0, 1)]);
+///     assert_eq!((map.guard, map.facing), ((1, 1), Direction::Right));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LabMap {
+    /// Cells of the lab, `true` where there is an obstruction
+    pub obstacles: Grid<bool>,
+    /// Starting position of the guard
+    pub guard: Pos,
+    /// Direction the guard is facing at the start
+    pub facing: Direction,
+}
+
+impl TryFrom<&str> for LabMap {
+    type Error = Day6Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        read_map(text.as_bytes())
+    }
+}
+
+/// Parse the lab map from text
+/// 
+/// Empty cells are `.`, obstructions `#` and the guard one of `^`, `>`,
+/// `v` or `<`, facing the way it points. The map needs exactly one guard.
+/// 
+/// # Examples
+/// ```
+/// let map = day_6::parse_map(day_6::SAMPLE).unwrap();
+/// assert_eq!(map.guard, (6, 4));
+/// assert!(matches!(day_6::parse_map("..\n.."), Err(day_6::Day6Error::MissingGuard)));
+/// ```
+pub fn parse_map(text: &str) -> Result<LabMap> {
+    LabMap::try_from(text)
+}
+
+/// Read the lab map line by line from a [BufRead]
+/// 
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// 
+/// fn main() -> day_6::Result<()> {
+///     let map = day_6::read_map(Cursor::new("..#\n^..\n"))?;
+///     assert_eq!(map.guard, (1, 0));
+///     Ok(())
+/// }
+/// ```
+pub fn read_map<B: BufRead>(reader: B) -> Result<LabMap> {
+    let mut guard = None;
+    // rows stop at the first failure, which is returned once the grid is built
+    let mut failure = None;
+    let rows = reader.lines().enumerate().map_while(|(row, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                failure = Some(Day6Error::ReadLine(e));
+                return None;
+            },
+        };
+        let mut cells = Vec::with_capacity(line.len());
+        for (col, cell) in line.chars().enumerate() {
+            let (line, column) = (row + 1, col + 1);
+            match cell {
+                '.' => cells.push(false),
+                '#' => cells.push(true),
+                _ => match Direction::from_guard(cell) {
+                    Some(_) if guard.is_some() => {
+                        failure = Some(Day6Error::ExtraGuard { line, column });
+                        return None;
+                    },
+                    Some(facing) => {
+                        guard = Some(((row, col), facing));
+                        cells.push(false);
+                    },
+                    None => {
+                        failure = Some(Day6Error::UnknownCell { line, column, cell });
+                        return None;
+                    },
+                },
+            }
+        }
+        Some(cells)
+    });
+    let obstacles = Grid::from_rows(rows)?;
+    if let Some(e) = failure {
+        return Err(e);
+    }
+    let (guard, facing) = guard.ok_or(Day6Error::MissingGuard)?;
+    Ok(LabMap { obstacles, guard, facing })
+}
+
+/// Directions the guard faced on each cell of its patrol and whether it ended in a loop
+/// 
+/// The guard steps forward until an obstruction is in front of it, then
+/// turns right. The patrol ends when the guard steps off the map or is
+/// back on a cell facing a direction it already faced there.
+/// `extra` is one more obstruction that is not on the map.
+fn patrol(map: &LabMap, extra: Option<Pos>) -> (Grid<u8>, bool) {
+    let obstacles = &map.obstacles;
+    let mut states = Grid::new(obstacles.height(), obstacles.width(), 0u8);
+    let (mut pos, mut facing) = (map.guard, map.facing);
+    loop {
+        if states[pos] & facing.bit() != 0 {
+            return (states, true);
+        }
+        states[pos] |= facing.bit();
+        match obstacles.offset(pos, facing.step()) {
+            None => return (states, false),
+            Some((next, &blocked)) if blocked || Some(next) == extra => facing = facing.turn_right(),
+            Some((next, _)) => pos = next,
+        }
+    }
+}
+
+/// Number of distinct positions the guard visits, including its start
+/// 
+/// When the guard walks in a loop this is the number of positions on
+/// the loop and the way to it, [`part1`] reports it as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_6::Result<()> {
+///     let map = day_6::parse_map(day_6::SAMPLE)?;
+///     assert_eq!(day_6::visited_positions(&map), 41);
+///     Ok(())
+/// }
+/// ```
+pub fn visited_positions(map: &LabMap) -> usize {
+    let (states, _) = patrol(map, None);
+    states.iter().filter(|(_, &state)| state != 0).count()
+}
+
+/// Number of distinct positions the guard visits before leaving the map, the answer of part 1
+/// 
+/// Fails with [`Day6Error::Loop`] when the guard never leaves
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_6::Result<()> {
+///     assert_eq!(day_6::part1(&day_6::parse_map(day_6::SAMPLE)?)?, 41);
+///     let trapped = day_6::parse_map(".#.\n#^#\n.#.\n")?;
+///     assert!(matches!(day_6::part1(&trapped), Err(day_6::Day6Error::Loop)));
+///     Ok(())
+/// }
+/// ```
+pub fn part1(map: &LabMap) -> Result<usize> {
+    match patrol(map, None) {
+        (_, true) => Err(Day6Error::Loop),
+        (states, false) => Ok(states.iter().filter(|(_, &state)| state != 0).count()),
+    }
+}
+
+/// Number of positions where a single new obstruction makes the guard walk in a loop
+/// 
+/// The starting position of the guard is never a candidate. Only the
+/// positions of the original patrol are tried, an obstruction anywhere
+/// else is never reached. With the `parallel` feature the candidates are
+/// split between the rayon threads.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_6::Result<()> {
+///     let map = day_6::parse_map(day_6::SAMPLE)?;
+///     assert_eq!(day_6::loop_obstructions(&map), 6);
+///     Ok(())
+/// }
+/// ```
+pub fn loop_obstructions(map: &LabMap) -> usize {
+    loop_candidates(map).len()
+}
+
+/// Number of positions where a new obstruction traps the guard, the answer of part 2
+/// 
+/// Fails with [`Day6Error::Loop`] when the guard never leaves even without one
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_6::Result<()> {
+///     assert_eq!(day_6::part2(&day_6::parse_map(day_6::SAMPLE)?)?, 6);
+///     let trapped = day_6::parse_map(".#.\n#^#\n.#.\n")?;
+///     assert!(matches!(day_6::part2(&trapped), Err(day_6::Day6Error::Loop)));
+///     Ok(())
+/// }
+/// ```
+pub fn part2(map: &LabMap) -> Result<usize> {
+    match patrol(map, None) {
+        (_, true) => Err(Day6Error::Loop),
+        (_, false) => Ok(loop_obstructions(map)),
+    }
+}
+
+/// Positions on the original patrol, except the start, where a new obstruction makes a loop
+fn loop_candidates(map: &LabMap) -> Vec<Pos> {
+    let (states, _) = patrol(map, None);
+    let path: Vec<Pos> = states.iter()
+    .filter(|&(pos, &state)| state != 0 && pos != map.guard)
+    .map(|(pos, _)| pos)
+    .collect();
+
+    #[cfg(feature = "parallel")]
+    let candidates = loop_candidates_parallel(map, &path);
+    #[cfg(not(feature = "parallel"))]
+    let candidates = loop_candidates_serial(map, &path);
+    candidates
+}
+
+/// Check whether an obstruction at `pos` makes the guard walk in a loop
+fn makes_loop(map: &LabMap, pos: Pos) -> bool {
+    let (_, looped) = patrol(map, Some(pos));
+    looped
+}
+
+/// Try the path positions one at a time
+#[cfg_attr(feature = "parallel", allow(dead_code))]
+fn loop_candidates_serial(map: &LabMap, path: &[Pos]) -> Vec<Pos> {
+    path.iter().copied().filter(|&pos| makes_loop(map, pos)).collect()
+}
+
+/// Try the path positions splitting them between the rayon threads
+#[cfg(feature = "parallel")]
+fn loop_candidates_parallel(map: &LabMap, path: &[Pos]) -> Vec<Pos> {
+    use rayon::prelude::*;
+
+    path.par_iter().copied().filter(|&pos| makes_loop(map, pos)).collect()
+}
+
+/// Day 6 solution used by the `aoc` runner
+/// 
+/// Part 1 counts the positions the guard visits on the [`LabMap`],
+/// part 2 the positions where a new obstruction traps it in a loop
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_6::Day6.part1(day_6::SAMPLE)?, "41");
+///     assert_eq!(day_6::Day6.part2(day_6::SAMPLE)?, "6");
+///     Ok(())
+/// }
+/// ```
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&LabMap::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&LabMap::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let map = LabMap::try_from(input)?;
+        Ok((map.solve(1)?, map.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(LabMap::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for LabMap {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a lab map with one guard
+fn format_problem(input: &str) -> Option<String> {
+    parse_map(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 6 input with a `size` x `size` lab map, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_6::generate(50, 7);
+/// assert_eq!(day_6::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day6(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_visited_positions_sample() -> Result<()> {
+        assert_eq!(visited_positions(&parse_map(SAMPLE)?), 41);
+        assert_eq!(Day6.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visited_positions_immediate_exit() -> Result<()> {
+        // the guard faces the edge it stands on
+        assert_eq!(visited_positions(&parse_map("^..\n...\n")?), 1);
+        assert_eq!(visited_positions(&parse_map(".>\n..\n")?), 1);
+        assert_eq!(visited_positions(&parse_map("^")?), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visited_positions_guard_on_edge() -> Result<()> {
+        // the guard starts on the bottom edge and walks up through the whole column
+        assert_eq!(visited_positions(&parse_map("...\n...\n.^.\n")?), 3);
+        // turning at the obstruction takes it along the top row to the right edge
+        assert_eq!(visited_positions(&parse_map("#...\n....\n^...\n")?), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visited_positions_turns_in_place() -> Result<()> {
+        // obstructions on three sides turn the guard around
+        assert_eq!(visited_positions(&parse_map(".#.\n#^#\n...\n")?), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1_loop() -> Result<()> {
+        let map = parse_map(".#..\n...#\n#^..\n..#.\n")?;
+        assert!(matches!(part1(&map), Err(Day6Error::Loop)));
+        assert_eq!(visited_positions(&map), 4);
+        assert_eq!(Day6Error::Loop.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_map_errors() {
+        assert!(matches!(parse_map("..\n.."), Err(Day6Error::MissingGuard)));
+        assert!(matches!(parse_map(""), Err(Day6Error::MissingGuard)));
+        let result = parse_map("^.\n.>\n");
+        assert!(matches!(result, Err(Day6Error::ExtraGuard { line: 2, column: 2 })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_map("^.\n.x\n");
+        assert!(matches!(result, Err(Day6Error::UnknownCell { line: 2, column: 2, cell: 'x' })));
+        let result = parse_map("^..\n..\n");
+        assert!(matches!(result, Err(Day6Error::Grid(GridError::Ragged { row: 1, .. }))));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+    }
+
+    #[test]
+    fn test_read_map_read_line_variant() {
+        let invalid_utf8: &[u8] = &[b'^', b'\n', 0xff, b'\n', b'.'];
+        assert!(matches!(read_map(invalid_utf8), Err(Day6Error::ReadLine(_))));
+    }
+
+    #[test]
+    fn test_loop_obstructions_sample() -> Result<()> {
+        assert_eq!(loop_obstructions(&parse_map(SAMPLE)?), 6);
+        assert_eq!(Day6.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day6.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_loop_obstructions_away_from_start() -> Result<()> {
+        // only an obstruction at (0, 2) traps the guard, between (1, 2) and (2, 2)
+        let map = parse_map("#....\n...#.\n.#.#.\n^.###\n#.##.\n")?;
+        assert_eq!(loop_candidates(&map), vec![(0, 2)]);
+        assert_eq!(loop_obstructions(&map), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_loop_obstructions_excludes_start() -> Result<()> {
+        // the guard walks back over its start, an obstruction there would box it in
+        let map = parse_map("###.\n.#.#\n..^.\n#.#.\n")?;
+        assert!(makes_loop(&map, map.guard));
+        assert_eq!(loop_candidates(&map), vec![(2, 1)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_loop() -> Result<()> {
+        let map = parse_map(".#..\n...#\n#^..\n..#.\n")?;
+        assert!(matches!(part2(&map), Err(Day6Error::Loop)));
+        Ok(())
+    }
+
+    /// Every empty position except the start where a new obstruction makes a loop
+    fn brute_force_candidates(map: &LabMap) -> Vec<Pos> {
+        map.obstacles.iter()
+        .filter(|&(pos, &blocked)| !blocked && pos != map.guard && makes_loop(map, pos))
+        .map(|(pos, _)| pos)
+        .collect()
+    }
+
+    #[test]
+    fn test_loop_candidates_match_brute_force() -> Result<()> {
+        let mut maps = vec![parse_map(SAMPLE)?, parse_map("#....\n...#.\n.#.#.\n^.###\n#.##.\n")?];
+        maps.extend((1..=10).map(|seed| parse_map(&aoc_testing::day6(seed, 25).input)).collect::<Result<Vec<_>, _>>()?);
+        for map in &maps {
+            assert_eq!(loop_candidates(map), brute_force_candidates(map), "{:?}", map);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_loop_candidates_parallel_matches_serial() -> Result<()> {
+        let map = parse_map(&aoc_testing::day6(0x2024_0006, 130).input)?;
+        let (states, _) = patrol(&map, None);
+        let path: Vec<Pos> = states.iter().filter(|&(_, &state)| state != 0).map(|(pos, _)| pos).collect();
+        assert_eq!(loop_candidates_parallel(&map, &path), loop_candidates_serial(&map, &path));
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_map_serde_round_trip() -> Result<()> {
+        let map = parse_map("#.\n.v\n")?;
+        let json = serde_json::to_string(&map)?;
+        assert_eq!(json, r#"{"obstacles":[[true,false],[false,false]],"guard":[1,1],"facing":"Down"}"#);
+        assert_eq!(serde_json::from_str::<LabMap>(&json)?, map);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day6.describe(SAMPLE).problem, None);
+        assert_eq!(Day6.describe("..\n.*\n").problem.as_deref(), Some("unexpected * on line 2, column 2"));
+        assert_eq!(Day6.describe("..\n..\n").problem.as_deref(), Some("the map has no guard"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day6.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day6(seed, 60);
+            assert_eq!(Day6.part1(&generated.input)?, generated.part1);
+            assert_eq!(Day6.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
+}
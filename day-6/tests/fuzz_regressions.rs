@@ -0,0 +1,10 @@
+//! Replays the crashers saved from the `day_6` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_6", |data| {
+        if let Ok(map) = day_6::read_map(data) {
+            let _ = day_6::part1(&map);
+        }
+    });
+}
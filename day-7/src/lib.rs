@@ -0,0 +1,658 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 7 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::num::ParseIntError;
+
+/// Errors returned by the day 7 functions
+#[derive(Debug, Error)]
+pub enum Day7Error {
+    /// A line could not be read from the input
+    #[error("failed reading line")]
+    ReadLine(#[source] std::io::Error),
+    /// A line does not have the `target: operands` format
+    #[error("malformed equation {equation} on line {line}")]
+    MalformedEquation {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text of the line
+        equation: String,
+    },
+    /// The target or an operand is not a number that fits `u64`
+    #[error("failed parsing {token} on line {line}")]
+    Number {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Text that failed to parse
+        token: String,
+        /// Underlying parse error
+        source: ParseIntError,
+    },
+    /// An equation has a target but no operands
+    #[error("equation on line {line} has no operands")]
+    MissingOperands {
+        /// Line number (starting from 1)
+        line: usize,
+    },
+    /// The sum of the targets does not fit `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day7Error> for SolutionError {
+    fn from(e: Day7Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day7Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day7Error::MalformedEquation { line, .. } | Day7Error::MissingOperands { line } => Some(Location::Line(*line)),
+            Day7Error::Number { token, .. } => Some(Location::Token(token.clone())),
+            _ => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day7Error::ReadLine(source) => ErrorKind::io(source),
+            Day7Error::MalformedEquation { .. } | Day7Error::Number { .. }
+            | Day7Error::MissingOperands { .. } => ErrorKind::Parse,
+            Day7Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 7 functions
+pub type Result<T, E = Day7Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n\
+161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["3749", "11387"];
+
+/// Calibration equation with its operators missing
+/// 
+/// # Examples
+/// ```
+/// let equation = day_7::Equation { target: 190, operands: vec![10, 19] };
+/// assert!(equation.is_solvable());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equation {
+    /// Value the operands have to make
+    pub target: u64,
+    /// Numbers combined left to right, never empty
+    pub operands: Vec<u64>,
+}
+
+impl Equation {
+    /// Whether `+` and `*` between the operands, evaluated left to right, make the target
+    /// 
+    /// A depth first search over the operators, dropping a branch once its
+    /// value is over the target since adding or multiplying can only grow it
+    /// (unless a zero operand is still to come)
+    /// 
+    /// # Examples
+    /// ```
+    /// let equation = day_7::Equation { target: 3267, operands: vec![81, 40, 27] };
+    /// assert!(equation.is_solvable());
+    /// let equation = day_7::Equation { target: 83, operands: vec![17, 5] };
+    /// assert!(!equation.is_solvable());
+    /// ```
+    pub fn is_solvable(&self) -> bool {
+        match self.operands.split_first() {
+            Some((&first, rest)) => solvable_from(self.target, Some(first), rest),
+            None => false,
+        }
+    }
+
+    /// Whether `+`, `*` and `||` between the operands, evaluated left to right, make the target
+    /// 
+    /// `||` concatenates the digits, `12 || 345` is `12345`. The search goes
+    /// backwards from the last operand and only undoes the operators that
+    /// fit: subtracting it must not go below zero, dividing by it must leave
+    /// no remainder and the target must end with its digits to split them off.
+    /// 
+    /// # Examples
+    /// ```
+    /// let equation = day_7::Equation { target: 7290, operands: vec![6, 8, 6, 15] };
+    /// assert!(!equation.is_solvable());
+    /// assert!(equation.is_solvable_with_concat());
+    /// ```
+    pub fn is_solvable_with_concat(&self) -> bool {
+        solvable_to(self.target, &self.operands)
+    }
+
+    /// [`is_solvable`](Equation::is_solvable) or, with `concat`,
+    /// [`is_solvable_with_concat`](Equation::is_solvable_with_concat) trying
+    /// every combination of the operators one after another
+    /// 
+    /// Slower than the pruned searches, it is kept to check and benchmark them
+    /// 
+    /// # Panics
+    /// 
+    /// When the combinations do not fit `u64`, with more than 40 operands
+    /// 
+    /// # Examples
+    /// ```
+    /// let equation = day_7::Equation { target: 156, operands: vec![15, 6] };
+    /// assert!(!equation.is_solvable_naive(false));
+    /// assert!(equation.is_solvable_naive(true));
+    /// ```
+    #[allow(clippy::manual_try_fold)]
+    pub fn is_solvable_naive(&self, concat: bool) -> bool {
+        let operators: u64 = if concat { 3 } else { 2 };
+        let Some((&first, rest)) = self.operands.split_first() else {
+            return false;
+        };
+        let combinations = operators.checked_pow(rest.len() as u32).expect("few enough operands to try every combination");
+        (0..combinations).any(|mut choice| {
+            // an overflowed value still becomes zero when multiplied by zero, so no `try_fold`
+            let value = rest.iter().fold(Some(first), |value, &operand| {
+                let operator = choice % operators;
+                choice /= operators;
+                match (operator, operand) {
+                    (0, _) => value?.checked_add(operand),
+                    (1, 0) => Some(0),
+                    (1, _) => value?.checked_mul(operand),
+                    _ => self::concat(value?, operand),
+                }
+            });
+            value == Some(self.target)
+        })
+    }
+}
+
+/// Digits of `right` appended to `left`, `None` when the result does not fit `u64`
+/// 
+/// # Examples
+/// ```
+/// assert_eq!(day_7::concat(12, 345), Some(12345));
+/// assert_eq!(day_7::concat(7, 0), Some(70));
+/// assert_eq!(day_7::concat(u64::MAX, 1), None);
+/// ```
+pub fn concat(left: u64, right: u64) -> Option<u64> {
+    match shift(right) {
+        Some(shift) => left.checked_mul(shift)?.checked_add(right),
+        // only a zero in front leaves room for the widest numbers
+        None => (left == 0).then_some(right),
+    }
+}
+
+/// Power of ten moving a number left by the digits of `operand`, `None` when it does not fit `u64`
+fn shift(operand: u64) -> Option<u64> {
+    10u64.checked_pow(operand.checked_ilog10().unwrap_or(0) + 1)
+}
+
+/// Whether the operators between the `operands`, including `||`, make the target
+/// 
+/// Works backwards from the last operand, the values before it are never
+/// over the target unless the last operand is a zero multiplying them
+fn solvable_to(target: u64, operands: &[u64]) -> bool {
+    let Some((&last, rest)) = operands.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return target == last;
+    }
+    if target >= last && solvable_to(target - last, rest) {
+        return true;
+    }
+    let multiplied = match last {
+        // anything times zero is zero
+        0 => target == 0,
+        _ => target.is_multiple_of(last) && solvable_to(target / last, rest),
+    };
+    if multiplied {
+        return true;
+    }
+    // a shift too large for u64 leaves nothing but zero in front of `last`
+    match shift(last) {
+        Some(shift) => target % shift == last && solvable_to(target / shift, rest),
+        None => target == last && solvable_to(0, rest),
+    }
+}
+
+/// Whether the operators between `value` and the `rest` of the operands make the target
+/// 
+/// `None` is a value that does not fit `u64`, it can only come back by
+/// multiplying with zero
+fn solvable_from(target: u64, value: Option<u64>, rest: &[u64]) -> bool {
+    let Some((&operand, rest)) = rest.split_first() else {
+        return value == Some(target);
+    };
+    if value.is_none_or(|value| value > target) && !rest.contains(&0) && operand != 0 {
+        return false;
+    }
+    let product = match operand {
+        0 => Some(0),
+        _ => value.and_then(|value| value.checked_mul(operand)),
+    };
+    solvable_from(target, value.and_then(|value| value.checked_add(operand)), rest)
+    || solvable_from(target, product, rest)
+}
+
+/// Equations of the calibration, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_7::Result<()> {
+///     let input = day_7::Day7Input::try_from("190: 10 19\n83: 17 5\n")?;
+///     assert_eq!(input.0[1], day_7::Equation { target: 83, operands: vec![17, 5] });
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Day7Input(pub Vec<Equation>);
+
+impl TryFrom<&str> for Day7Input {
+    type Error = Day7Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        Ok(Day7Input(parse_equations(text.as_bytes())?))
+    }
+}
+
+/// Parse one `target: operands` line
+fn parse_equation(line: usize, text: &str) -> Result<Equation> {
+    let number = |token: &str| token.parse::<u64>()
+    .map_err(|source| Day7Error::Number { line, token: token.to_string(), source });
+    let (target, operands) = text.split_once(':')
+    .ok_or_else(|| Day7Error::MalformedEquation { line, equation: text.to_string() })?;
+    let operands = operands.split_whitespace().map(number).collect::<Result<Vec<u64>>>()?;
+    if operands.is_empty() {
+        return Err(Day7Error::MissingOperands { line });
+    }
+    Ok(Equation { target: number(target.trim())?, operands })
+}
+
+/// Read the equations line by line from a [BufRead]
+/// 
+/// Every line is a target, a colon and the whitespace separated operands.
+/// Blank lines are skipped.
+/// 
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// 
+/// fn main() -> day_7::Result<()> {
+///     let equations = day_7::parse_equations(Cursor::new("190: 10 19\n\n3267: 81 40 27\n"))?;
+///     assert_eq!(equations.len(), 2);
+///     assert_eq!(equations[1].operands, vec![81, 40, 27]);
+///     Ok(())
+/// }
+/// ```
+pub fn parse_equations<B: BufRead>(reader: B) -> Result<Vec<Equation>> {
+    let mut equations = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(Day7Error::ReadLine)?;
+        if !line.trim().is_empty() {
+            equations.push(parse_equation(i + 1, &line)?);
+        }
+    }
+    Ok(equations)
+}
+
+/// Sum of the targets of the equations solvable with `+` and `*`
+/// 
+/// Saturates at [`u64::MAX`], [`part1`] reports the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_7::Result<()> {
+///     let equations = day_7::parse_equations(day_7::SAMPLE.as_bytes())?;
+///     assert_eq!(day_7::calibration_result(&equations), 3749);
+///     Ok(())
+/// }
+/// ```
+pub fn calibration_result(equations: &[Equation]) -> u64 {
+    total(equations, 1).unwrap_or(u64::MAX)
+}
+
+/// Sum of the targets of the equations solvable with `+` and `*`, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_7::Result<()> {
+///     let input = day_7::Day7Input::try_from(day_7::SAMPLE)?;
+///     assert_eq!(day_7::part1(&input)?, 3749);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(input: &Day7Input) -> Result<u64> {
+    total(&input.0, 1)
+}
+
+/// Sum of the targets of the equations solvable with `+`, `*` and `||`
+/// 
+/// Saturates at [`u64::MAX`], [`part2`] reports the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_7::Result<()> {
+///     let equations = day_7::parse_equations(day_7::SAMPLE.as_bytes())?;
+///     assert_eq!(day_7::calibration_result_with_concat(&equations), 11387);
+///     Ok(())
+/// }
+/// ```
+pub fn calibration_result_with_concat(equations: &[Equation]) -> u64 {
+    total(equations, 2).unwrap_or(u64::MAX)
+}
+
+/// Sum of the targets of the equations solvable with `+`, `*` and `||`, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_7::Result<()> {
+///     let input = day_7::Day7Input::try_from(day_7::SAMPLE)?;
+///     assert_eq!(day_7::part2(&input)?, 11387);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(input: &Day7Input) -> Result<u64> {
+    total(&input.0, 2)
+}
+
+/// Sum of the targets of the equations solvable in the `part`, failing with [`Day7Error::Overflow`]
+fn total(equations: &[Equation], part: u8) -> Result<u64> {
+    equations.iter()
+    .filter(|equation| match part {
+        1 => equation.is_solvable(),
+        _ => equation.is_solvable_with_concat(),
+    })
+    .try_fold(0u64, |sum, equation| sum.checked_add(equation.target))
+    .ok_or(Day7Error::Overflow { part })
+}
+
+/// Day 7 solution used by the `aoc` runner
+/// 
+/// Part 1 sums the targets of the equations solvable with `+` and `*`,
+/// part 2 the ones solvable with concatenation too
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_7::Day7.part1(day_7::SAMPLE)?, "3749");
+///     assert_eq!(day_7::Day7.part2(day_7::SAMPLE)?, "11387");
+///     Ok(())
+/// }
+/// ```
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Day7Input::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Day7Input::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let input = Day7Input::try_from(input)?;
+        Ok((input.solve(1)?, input.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Day7Input::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Day7Input {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a list of calibration equations
+fn format_problem(input: &str) -> Option<String> {
+    Day7Input::try_from(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 7 input with `size` equations, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_7::generate(50, 7);
+/// assert_eq!(input.lines().count(), 50);
+/// assert_eq!(day_7::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day7(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_calibration_result_sample() -> Result<()> {
+        let equations = parse_equations(SAMPLE.as_bytes())?;
+        assert_eq!(equations.len(), 9);
+        let solvable: Vec<u64> = equations.iter().filter(|e| e.is_solvable()).map(|e| e.target).collect();
+        assert_eq!(solvable, vec![190, 3267, 292]);
+        assert_eq!(calibration_result(&equations), 3749);
+        assert_eq!(Day7.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_solvable_single_operand() {
+        assert!(Equation { target: 7, operands: vec![7] }.is_solvable());
+        assert!(!Equation { target: 7, operands: vec![8] }.is_solvable());
+        assert!(!Equation { target: 0, operands: vec![] }.is_solvable());
+    }
+
+    #[test]
+    fn test_is_solvable_zero_operand() {
+        // the product is over the target until the zero brings it back
+        assert!(Equation { target: 5, operands: vec![90, 90, 0, 5] }.is_solvable());
+        assert!(Equation { target: 0, operands: vec![3, 0] }.is_solvable());
+    }
+
+    #[test]
+    fn test_is_solvable_overflow() {
+        let big = u64::MAX / 2 + 1;
+        // big * 2 does not fit, big + big - 1 is not reachable
+        assert!(!Equation { target: u64::MAX, operands: vec![big, 2] }.is_solvable());
+        assert!(Equation { target: u64::MAX, operands: vec![big, big - 1] }.is_solvable());
+        // an overflowed product times zero is zero again
+        assert!(Equation { target: 1, operands: vec![big, 4, 0, 1] }.is_solvable());
+        assert!(Equation { target: 1, operands: vec![big, 4, 0, 1] }.is_solvable_naive(false));
+        assert!(Equation { target: 1, operands: vec![big, 4, 0, 1] }.is_solvable_with_concat());
+    }
+
+    #[test]
+    fn test_part1_overflow() -> Result<()> {
+        let input = Day7Input::try_from("18446744073709551615: 18446744073709551615\n2: 1 1\n")?;
+        assert!(matches!(part1(&input), Err(Day7Error::Overflow { part: 1 })));
+        assert_eq!(calibration_result(&input.0), u64::MAX);
+        assert_eq!(Day7Error::Overflow { part: 1 }.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_equations_errors() {
+        let result = parse_equations("190: 10 19\n83 17 5\n".as_bytes());
+        assert!(matches!(&result, Err(Day7Error::MalformedEquation { line: 2, .. })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_equations("190: 10 x\n".as_bytes());
+        assert!(matches!(&result, Err(Day7Error::Number { line: 1, token, .. }) if token == "x"));
+        let result = parse_equations("18446744073709551616: 1\n".as_bytes());
+        assert!(matches!(&result, Err(Day7Error::Number { line: 1, .. })));
+        assert!(matches!(parse_equations("\n190:\n".as_bytes()), Err(Day7Error::MissingOperands { line: 2 })));
+    }
+
+    #[test]
+    fn test_parse_equations_read_line_variant() {
+        let invalid_utf8: &[u8] = &[b'1', b':', b' ', b'1', b'\n', 0xff, b'\n'];
+        assert!(matches!(parse_equations(invalid_utf8), Err(Day7Error::ReadLine(_))));
+    }
+
+    #[test]
+    fn test_calibration_result_with_concat_sample() -> Result<()> {
+        let equations = parse_equations(SAMPLE.as_bytes())?;
+        let solvable: Vec<u64> = equations.iter().filter(|e| e.is_solvable_with_concat()).map(|e| e.target).collect();
+        assert_eq!(solvable, vec![190, 3267, 156, 7290, 192, 292]);
+        assert_eq!(calibration_result_with_concat(&equations), 11387);
+        assert_eq!(Day7.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day7.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(15, 6), Some(156));
+        assert_eq!(concat(0, 0), Some(0));
+        assert_eq!(concat(1, 10), Some(110));
+        assert_eq!(concat(1, u64::MAX), None);
+        assert_eq!(concat(0, u64::MAX), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_is_solvable_with_concat_edges() {
+        // a zero operand concatenates as a single digit
+        assert!(Equation { target: 50, operands: vec![5, 0] }.is_solvable_with_concat());
+        assert!(Equation { target: 0, operands: vec![123, 4, 0] }.is_solvable_with_concat());
+        assert!(Equation { target: u64::MAX, operands: vec![0, u64::MAX] }.is_solvable_with_concat());
+        assert!(!Equation { target: u64::MAX, operands: vec![2, u64::MAX] }.is_solvable_with_concat());
+        assert!(Equation { target: 7, operands: vec![7] }.is_solvable_with_concat());
+        assert!(!Equation { target: 0, operands: vec![] }.is_solvable_with_concat());
+    }
+
+    #[test]
+    fn test_is_solvable_with_concat_twelve_operands() {
+        let operands = vec![7, 49, 3, 12, 8, 95, 6, 2, 71, 4, 30, 5];
+        let target = operands[1..].iter().enumerate()
+        .try_fold(operands[0], |value, (i, &operand)| match i % 3 {
+            0 => concat(value, operand),
+            1 => value.checked_add(operand),
+            _ => value.checked_mul(operand),
+        })
+        .expect("fits u64");
+        let equation = Equation { target, operands };
+        assert!(equation.is_solvable_with_concat());
+        assert!(equation.is_solvable_naive(true));
+        let equation = Equation { target: target + 1, ..equation };
+        assert_eq!(equation.is_solvable_with_concat(), equation.is_solvable_naive(true));
+    }
+
+    #[test]
+    fn test_part2_overflow() -> Result<()> {
+        let input = Day7Input::try_from("18446744073709551615: 18446744073709551615\n12: 1 2\n")?;
+        assert!(matches!(part2(&input), Err(Day7Error::Overflow { part: 2 })));
+        assert_eq!(calibration_result_with_concat(&input.0), u64::MAX);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_input_serde_round_trip() -> Result<()> {
+        let input = Day7Input::try_from("190: 10 19\n")?;
+        let json = serde_json::to_string(&input)?;
+        assert_eq!(json, r#"[{"target":190,"operands":[10,19]}]"#);
+        assert_eq!(serde_json::from_str::<Day7Input>(&json)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day7.describe(SAMPLE).problem, None);
+        assert_eq!(Day7.describe("190: 10 19\n83 17 5\n").problem.as_deref(), Some("malformed equation 83 17 5 on line 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day7.part1(&input)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day7(seed, 100);
+            assert_eq!(Day7.part1(&generated.input)?, generated.part1);
+            assert_eq!(Day7.part2(&generated.input)?, generated.part2);
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_is_solvable_matches_oracle(target in 0u64..2000, operands in proptest::collection::vec(0u64..20, 1..7)) {
+            let equation = Equation { target, operands: operands.clone() };
+            proptest::prop_assert_eq!(equation.is_solvable(), aoc_testing::oracle::calibrates(target, &operands, false));
+            proptest::prop_assert_eq!(equation.is_solvable_with_concat(), aoc_testing::oracle::calibrates(target, &operands, true));
+        }
+
+        #[test]
+        fn test_is_solvable_naive_matches_pruned(target in 0u64..5000, operands in proptest::collection::vec(0u64..30, 1..8)) {
+            let equation = Equation { target, operands };
+            proptest::prop_assert_eq!(equation.is_solvable_naive(false), equation.is_solvable());
+            proptest::prop_assert_eq!(equation.is_solvable_naive(true), equation.is_solvable_with_concat());
+        }
+
+        #[test]
+        fn test_solvable_stays_solvable_with_concat(operands in proptest::collection::vec(0u64..1000, 1..10), choices: u64) {
+            // a target made with + and * only, concatenation adds options
+            #[allow(clippy::manual_try_fold)]
+            let target = operands[1..].iter().enumerate().fold(Some(operands[0]), |value, (i, &operand)| {
+                match (choices >> i & 1, operand) {
+                    (0, _) => value?.checked_add(operand),
+                    (_, 0) => Some(0),
+                    _ => value?.checked_mul(operand),
+                }
+            });
+            proptest::prop_assume!(target.is_some());
+            let equation = Equation { target: target.unwrap(), operands };
+            proptest::prop_assert!(equation.is_solvable());
+            proptest::prop_assert!(equation.is_solvable_with_concat());
+        }
+    }
+}
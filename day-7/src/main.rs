@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let input = day_7::Day7Input(day_7::parse_equations(input.reader()?)?);
+
+    println!("CALIBRATION_RESULT: {}", day_7::part1(&input)?);
+    println!("CALIBRATION_RESULT_WITH_CONCAT: {}", day_7::part2(&input)?);
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_7::Day7Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_7` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_7", |data| {
+        if let Ok(equations) = day_7::parse_equations(data) {
+            let _ = day_7::calibration_result(&equations);
+            let _ = day_7::calibration_result_with_concat(&equations);
+        }
+    });
+}
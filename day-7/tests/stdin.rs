@@ -0,0 +1,36 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-7"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-7");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_3749.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-7");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "CALIBRATION_RESULT: 3749\nCALIBRATION_RESULT_WITH_CONCAT: 11387\n");
+}
+
+/// Exit code of day-7 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-7"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-7");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-7").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("190: 10 19\n"), Some(0));
+    assert_eq!(exit_code("190: 10 x\n"), Some(3));
+    assert_eq!(exit_code("18446744073709551615: 18446744073709551615\n2: 1 1\n"), Some(4));
+}
@@ -0,0 +1,444 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 8 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+
+/// Errors returned by the day 8 functions
+#[derive(Debug, Error)]
+pub enum Day8Error {
+    /// Lines of the map have different lengths
+    #[error("malformed antenna map")]
+    Grid(#[from] GridError),
+    /// A cell is neither empty nor an antenna
+    #[error("unexpected {cell} on line {line}, column {column}")]
+    UnknownCell {
+        /// Line number (starting from 1)
+        line: usize,
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        cell: char,
+    },
+}
+
+impl From<Day8Error> for SolutionError {
+    fn from(e: Day8Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day8Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Day8Error::Grid(e) => e.location(),
+            Day8Error::UnknownCell { line, .. } => Some(Location::Line(*line)),
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day8Error::Grid(_) | Day8Error::UnknownCell { .. } => ErrorKind::Parse,
+        }
+    }
+}
+
+/// Result type returned by the day 8 functions
+pub type Result<T, E = Day8Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "............\n........0...\n.....0......\n.......0....\n....0.......\n......A.....\n\
+............\n............\n........A...\n.........A..\n............\n............\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["14", "34"];
+
+/// Row and column of a position on the map
+pub type Point = Pos;
+
+/// Antenna map with the positions of the antennas of every frequency, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_8::Result<()> {
+///     let map = day_8::parse_antennas("a..\n..A\na..\n")?;
+///     assert_eq!((map.grid.width(), map.grid.height()), (3, 3));
+///     assert_eq!(map.antennas[&'a'], vec![(0, 0), (2, 0)]);
+///     assert_eq!(map.antennas[&'A'], vec![(1, 2)]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AntennaMap {
+    /// Cells of the map, `.` or the frequency of an antenna
+    pub grid: Grid<char>,
+    /// Positions of the antennas of every frequency, in reading order
+    pub antennas: HashMap<char, Vec<Point>>,
+}
+
+impl AntennaMap {
+    /// Pairs of antennas with the same frequency
+    fn pairs(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.antennas.values().flat_map(|points| {
+            points.iter().enumerate()
+            .flat_map(move |(i, &a)| points[i + 1..].iter().map(move |&b| (a, b)))
+        })
+    }
+}
+
+impl TryFrom<&str> for AntennaMap {
+    type Error = Day8Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let grid: Grid<char> = text.parse()?;
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+        for ((row, col), &cell) in grid.iter() {
+            match cell {
+                '.' => {},
+                _ if cell.is_ascii_alphanumeric() => antennas.entry(cell).or_default().push((row, col)),
+                _ => return Err(Day8Error::UnknownCell { line: row + 1, column: col + 1, cell }),
+            }
+        }
+        Ok(AntennaMap { grid, antennas })
+    }
+}
+
+/// Parse the antenna map from text
+/// 
+/// Empty cells are `.` and antennas are a lowercase letter, an uppercase
+/// letter or a digit naming their frequency
+/// 
+/// # Examples
+/// ```
+/// let map = day_8::parse_antennas(day_8::SAMPLE).unwrap();
+/// assert_eq!(map.antennas[&'0'].len(), 4);
+/// assert!(matches!(day_8::parse_antennas("..\n.#\n"), Err(day_8::Day8Error::UnknownCell { line: 2, column: 2, cell: '#' })));
+/// ```
+pub fn parse_antennas(text: &str) -> Result<AntennaMap> {
+    AntennaMap::try_from(text)
+}
+
+/// Row and column steps from `from` to `to`
+fn direction((from_row, from_col): Point, (to_row, to_col): Point) -> (isize, isize) {
+    (to_row as isize - from_row as isize, to_col as isize - from_col as isize)
+}
+
+/// Positions on the map in line with two antennas of the same frequency,
+/// with one of them twice as far away as the other
+fn antinodes(map: &AntennaMap) -> HashSet<Point> {
+    map.pairs()
+    .flat_map(|(a, b)| {
+        let (d_row, d_col) = direction(a, b);
+        [map.grid.offset(b, (d_row, d_col)), map.grid.offset(a, (-d_row, -d_col))]
+    })
+    .flatten()
+    .map(|(point, _)| point)
+    .collect()
+}
+
+/// Number of distinct positions on the map with an antinode, the answer of part 1
+/// 
+/// Antinodes outside of the map are not counted, the ones on an antenna are
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_8::Result<()> {
+///     let map = day_8::parse_antennas(day_8::SAMPLE)?;
+///     assert_eq!(day_8::antinode_count(&map), 14);
+///     Ok(())
+/// }
+/// ```
+pub fn antinode_count(map: &AntennaMap) -> usize {
+    antinodes(map).len()
+}
+
+/// Greatest common divisor of `a` and `b`
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Positions on the map exactly in line with two antennas of the same frequency,
+/// the antennas included
+/// 
+/// Steps from one antenna of every pair by the direction to the other one,
+/// divided by their gcd so no position on the line is skipped
+fn resonant_antinodes(map: &AntennaMap) -> HashSet<Point> {
+    let mut found = HashSet::new();
+    for (a, b) in map.pairs() {
+        let (d_row, d_col) = direction(a, b);
+        let divisor = gcd(d_row.unsigned_abs(), d_col.unsigned_abs()) as isize;
+        let (d_row, d_col) = (d_row / divisor, d_col / divisor);
+        found.insert(a);
+        for step in [(d_row, d_col), (-d_row, -d_col)] {
+            let mut point = a;
+            while let Some((next, _)) = map.grid.offset(point, step) {
+                found.insert(next);
+                point = next;
+            }
+        }
+    }
+    found
+}
+
+/// Number of distinct positions on the map in line with at least two antennas
+/// of the same frequency, the answer of part 2
+/// 
+/// Every antenna sharing its frequency with another one is an antinode too
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_8::Result<()> {
+///     let map = day_8::parse_antennas(day_8::SAMPLE)?;
+///     assert_eq!(day_8::resonant_antinode_count(&map), 34);
+///     Ok(())
+/// }
+/// ```
+pub fn resonant_antinode_count(map: &AntennaMap) -> usize {
+    resonant_antinodes(map).len()
+}
+
+/// Day 8 solution used by the `aoc` runner
+/// 
+/// Part 1 counts the antinodes of the [`AntennaMap`],
+/// part 2 the antinodes along the whole lines of the antenna pairs
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_8::Day8.part1(day_8::SAMPLE)?, "14");
+///     assert_eq!(day_8::Day8.part2(day_8::SAMPLE)?, "34");
+///     Ok(())
+/// }
+/// ```
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(antinode_count(&AntennaMap::try_from(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(resonant_antinode_count(&AntennaMap::try_from(input)?).to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let map = AntennaMap::try_from(input)?;
+        Ok((map.solve(1)?, map.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(AntennaMap::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for AntennaMap {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(antinode_count(self).to_string()),
+            _ => Ok(resonant_antinode_count(self).to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like an antenna map
+fn format_problem(input: &str) -> Option<String> {
+    parse_antennas(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 8 input with a `size` x `size` antenna map, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_8::generate(50, 7);
+/// assert_eq!(input.lines().count(), 50);
+/// assert_eq!(day_8::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day8(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    #[test]
+    fn test_antinode_count_sample() -> Result<()> {
+        assert_eq!(antinode_count(&parse_antennas(SAMPLE)?), 14);
+        assert_eq!(Day8.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_antinode_count_single_antenna() -> Result<()> {
+        assert_eq!(antinode_count(&parse_antennas("....\n.a..\n....\n")?), 0);
+        // antennas of different frequencies do not make antinodes together
+        assert_eq!(antinode_count(&parse_antennas("....\n.aA.\n....\n")?), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_antinode_count_on_antenna() -> Result<()> {
+        // the `b` pair puts an antinode on the `a` antenna, which still counts
+        let map = parse_antennas("a.b.b\n.....\n")?;
+        assert_eq!(antinodes(&map), HashSet::from([(0, 0)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_antinode_count_out_of_bounds() -> Result<()> {
+        // both antinodes of the pair fall outside, one row above and below the map
+        assert_eq!(antinode_count(&parse_antennas("a\na\n")?), 0);
+        // one antinode is inside the map, the other one left of it
+        let map = parse_antennas(".a.a...\n")?;
+        assert_eq!(antinodes(&map), HashSet::from([(0, 5)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_antinode_count_deduplicated() -> Result<()> {
+        // the `a` and `b` pairs share their antinode below the middle
+        let map = parse_antennas("a...b\n.a.b.\n.....\n")?;
+        assert_eq!(antinodes(&map), HashSet::from([(2, 2)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_antennas_errors() {
+        let result = parse_antennas("a..\n..\n");
+        assert!(matches!(&result, Err(Day8Error::Grid(GridError::Ragged { row: 1, .. }))));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(2)));
+        let result = parse_antennas("a..\n.#.\n");
+        assert!(matches!(&result, Err(Day8Error::UnknownCell { line: 2, column: 2, cell: '#' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn test_resonant_antinode_count_sample() -> Result<()> {
+        let map = parse_antennas(SAMPLE)?;
+        assert_eq!(resonant_antinode_count(&map), 34);
+        assert_eq!(Day8.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day8.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resonant_antinodes_superset_of_antinodes() -> Result<()> {
+        let map = parse_antennas(SAMPLE)?;
+        assert!(resonant_antinodes(&map).is_superset(&antinodes(&map)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resonant_antinodes_straight_lines() -> Result<()> {
+        // a vertical pair fills its whole column
+        let map = parse_antennas("...
+.a.
+...
+.a.
+")?;
+        assert_eq!(resonant_antinodes(&map), HashSet::from([(0, 1), (1, 1), (2, 1), (3, 1)]));
+        // a horizontal pair fills its whole row
+        let map = parse_antennas("....
+A..A
+")?;
+        assert_eq!(resonant_antinodes(&map), HashSet::from([(1, 0), (1, 1), (1, 2), (1, 3)]));
+        // a lone antenna is not an antinode
+        assert_eq!(resonant_antinode_count(&parse_antennas("a..
+...
+..b
+")?), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resonant_antinodes_reduced_step() -> Result<()> {
+        // the pair is two steps apart, the position between them is in line too
+        let map = parse_antennas("a....
+.....
+....a
+")?;
+        assert_eq!(resonant_antinodes(&map), HashSet::from([(0, 0), (1, 2), (2, 4)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resonant_antinodes_three_collinear() -> Result<()> {
+        // the three pairs of the diagonal all mark the same line
+        let map = parse_antennas("T.....
+.T....
+......
+...T..
+......
+......
+")?;
+        let diagonal: HashSet<Point> = (0..6).map(|i| (i, i)).collect();
+        assert_eq!(resonant_antinodes(&map), diagonal);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_map_serde_round_trip() -> Result<()> {
+        let map = parse_antennas("a.\n.a\n")?;
+        let json = serde_json::to_string(&map)?;
+        assert_eq!(json, r#"{"grid":[["a","."],[".","a"]],"antennas":{"a":[[0,0],[1,1]]}}"#);
+        assert_eq!(serde_json::from_str::<AntennaMap>(&json)?, map);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day8.describe(SAMPLE).problem, None);
+        assert_eq!(Day8.describe("a..\n.#.\n").problem.as_deref(), Some("unexpected # on line 2, column 2"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day8.part1(&input)?, SAMPLE_ANSWERS[0]);
+        assert_eq!(Day8.part2(&input)?, SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day8(seed, 60);
+            assert_eq!(Day8.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use std::process::ExitCode;
+use aoc_prelude::*;
+
+fn run() -> Result<()> {
+    // first argument is the input file, `-` reads the standard input
+    let input: InputSource = std::env::args().nth(1)
+    .unwrap_or_else(|| "./data/input.txt".to_string())
+    .parse()?;
+    
+    let map = day_8::parse_antennas(&input.read_to_string()?)?;
+
+    println!("ANTINODES: {}", day_8::antinode_count(&map));
+    println!("RESONANT_ANTINODES: {}", day_8::resonant_antinode_count(&map));
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ErrorKind::of(e.as_ref(), |e| e.downcast_ref().map(day_8::Day8Error::kind)).into()
+        },
+    }
+}
@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_8` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_8", |data| {
+        if let Ok(map) = day_8::parse_antennas(&String::from_utf8_lossy(data)) {
+            let _ = day_8::antinode_count(&map);
+            let _ = day_8::resonant_antinode_count(&map);
+        }
+    });
+}
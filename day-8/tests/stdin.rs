@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-8"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-8");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_14.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-8");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ANTINODES: 14\nRESONANT_ANTINODES: 34\n");
+}
+
+/// Exit code of day-8 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-8"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-8");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-8").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("a.\n.a\n"), Some(0));
+    assert_eq!(exit_code("a.\n.#\n"), Some(3));
+}
@@ -0,0 +1,576 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 Day 9 Challenge
+//! 
+//! Functions to complete the task for advent of code 2024
+//! 
+//! [`Read more`](../../../README.md)
+
+use aoc_prelude::*;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Errors returned by the day 9 functions
+#[derive(Debug, Error)]
+pub enum Day9Error {
+    /// A character of the disk map is not a digit
+    #[error("unexpected {character:?} at column {column}")]
+    InvalidDigit {
+        /// Column number (starting from 1)
+        column: usize,
+        /// The unexpected character
+        character: char,
+    },
+    /// The checksum does not fit `u64`
+    #[error("part {part} overflowed")]
+    Overflow {
+        /// Part of the puzzle
+        part: u8,
+    },
+}
+
+impl From<Day9Error> for SolutionError {
+    fn from(e: Day9Error) -> Self {
+        SolutionError::Day(Box::new(e))
+    }
+}
+
+impl Day9Error {
+    /// Part of the input the error points to
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            // the disk map is a single line
+            Day9Error::InvalidDigit { .. } => Some(Location::Line(1)),
+            Day9Error::Overflow { .. } => None,
+        }
+    }
+
+    /// Category of the error, deciding the exit code of the binaries
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Day9Error::InvalidDigit { .. } => ErrorKind::Parse,
+            Day9Error::Overflow { .. } => ErrorKind::Solver,
+        }
+    }
+}
+
+/// Result type returned by the day 9 functions
+pub type Result<T, E = Day9Error> = std::result::Result<T, E>;
+
+/// Example input from the puzzle description
+pub const SAMPLE: &str = "2333133121414131402\n";
+
+/// Expected answers of both parts for [`SAMPLE`]
+pub const SAMPLE_ANSWERS: [&str; 2] = ["1928", "2858"];
+
+/// Id of a file, its position among the files of the disk map
+pub type FileId = usize;
+
+/// Blocks of the disk, parsed once for the two parts
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let disk = day_9::parse_disk("12345")?;
+///     assert_eq!(disk.blocks.len(), 15);
+///     assert_eq!(disk.blocks[..4], [Some(0), None, None, Some(1)]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Disk {
+    /// Every block of the disk, the id of the file stored in it or `None` when free
+    pub blocks: Vec<Option<FileId>>,
+}
+
+impl TryFrom<&str> for Disk {
+    type Error = Day9Error;
+
+    fn try_from(text: &str) -> Result<Self> {
+        let mut blocks = Vec::new();
+        for (i, character) in text.trim_end().chars().enumerate() {
+            let length = character.to_digit(10).ok_or(Day9Error::InvalidDigit { column: i + 1, character })?;
+            // files and free space alternate, starting with a file
+            let block = if i % 2 == 0 { Some(i / 2) } else { None };
+            blocks.extend(std::iter::repeat_n(block, length as usize));
+        }
+        Ok(Disk { blocks })
+    }
+}
+
+/// Parse the dense disk map into its blocks
+/// 
+/// Digits alternate between the length of a file and the length of the
+/// free space after it. Files get their ids in order, starting from 0.
+/// The map is a single line, trailing whitespace is ignored.
+/// 
+/// # Examples
+/// ```
+/// let disk = day_9::parse_disk("2333133121414131402\n").unwrap();
+/// assert_eq!(disk.blocks.len(), 42);
+/// assert!(matches!(day_9::parse_disk("12x45"), Err(day_9::Day9Error::InvalidDigit { column: 3, character: 'x' })));
+/// ```
+pub fn parse_disk(text: &str) -> Result<Disk> {
+    Disk::try_from(text)
+}
+
+/// Move file blocks one at a time from the end of the disk into the leftmost
+/// free block, until the free space is all at the end
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let mut disk = day_9::parse_disk("12345")?;
+///     day_9::compact_blocks(&mut disk);
+///     assert_eq!(disk.blocks[..9], [0, 2, 2, 1, 1, 1, 2, 2, 2].map(Some));
+///     assert!(disk.blocks[9..].iter().all(Option::is_none));
+///     Ok(())
+/// }
+/// ```
+pub fn compact_blocks(disk: &mut Disk) {
+    let blocks = &mut disk.blocks;
+    let mut free = 0;
+    let mut last = blocks.len();
+    loop {
+        while free < last && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > free && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if free == last {
+            return;
+        }
+        blocks.swap(free, last - 1);
+    }
+}
+
+/// Runs of equal blocks as the block, its start and its length
+fn runs(blocks: &[Option<FileId>]) -> Vec<(Option<FileId>, usize, usize)> {
+    let mut runs: Vec<(Option<FileId>, usize, usize)> = Vec::new();
+    for (pos, &block) in blocks.iter().enumerate() {
+        match runs.last_mut() {
+            Some((last, _, len)) if *last == block => *len += 1,
+            _ => runs.push((block, pos, 1)),
+        }
+    }
+    runs
+}
+
+/// Files of the disk as their id, start and length, highest id first
+fn files(blocks: &[Option<FileId>]) -> Vec<(FileId, usize, usize)> {
+    let mut files: Vec<(FileId, usize, usize)> = runs(blocks).into_iter()
+    .filter_map(|(block, start, len)| block.map(|id| (id, start, len)))
+    .collect();
+    files.sort_by_key(|&(id, start, _)| Reverse((id, start)));
+    files
+}
+
+/// Move the file of `len` blocks from `from` to the free space at `to`
+fn move_file(blocks: &mut [Option<FileId>], id: FileId, from: usize, to: usize, len: usize) {
+    blocks[to..to + len].fill(Some(id));
+    blocks[from..from + len].fill(None);
+}
+
+/// Move whole files, highest id first, into the leftmost span of free blocks
+/// before them that is long enough
+/// 
+/// Every file is tried once, a file with no span to its left stays where it
+/// is. Free spans are kept in a min-heap of their starts for every length,
+/// so finding the leftmost span that fits only looks at the front of the
+/// heaps at least as long as the file. A partly filled span goes back to the
+/// heap of its remaining length. The space freed by a moved file is not
+/// tracked since, with the ids in disk order, no file left to move is right
+/// of it.
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let mut disk = day_9::parse_disk("12202")?;
+///     day_9::compact_files(&mut disk);
+///     assert_eq!(disk.blocks, [Some(0), Some(2), Some(2), Some(1), Some(1), None, None]);
+///     Ok(())
+/// }
+/// ```
+pub fn compact_files(disk: &mut Disk) {
+    let blocks = &mut disk.blocks;
+    let free: Vec<(usize, usize)> = runs(blocks).into_iter()
+    .filter_map(|(block, start, len)| block.is_none().then_some((start, len)))
+    .collect();
+    let longest = free.iter().map(|&(_, len)| len).max().unwrap_or(0);
+    let mut spans: Vec<BinaryHeap<Reverse<usize>>> = vec![BinaryHeap::new(); longest + 1];
+    for (start, len) in free {
+        spans[len].push(Reverse(start));
+    }
+
+    for (id, from, len) in files(blocks) {
+        let leftmost = (len..spans.len())
+        .filter_map(|size| spans[size].peek().map(|&Reverse(start)| (start, size)))
+        .min();
+        let Some((to, size)) = leftmost.filter(|&(to, _)| to < from) else {
+            continue;
+        };
+        spans[size].pop();
+        move_file(blocks, id, from, to, len);
+        if size > len {
+            spans[size - len].push(Reverse(to + len));
+        }
+    }
+}
+
+/// [`compact_files`] scanning the blocks from the start for the free span of every file
+/// 
+/// Quadratic in the size of the disk, it is kept to check and benchmark [`compact_files`]
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let mut disk = day_9::parse_disk(day_9::SAMPLE)?;
+///     let mut naive = disk.clone();
+///     day_9::compact_files(&mut disk);
+///     day_9::compact_files_naive(&mut naive);
+///     assert_eq!(disk, naive);
+///     Ok(())
+/// }
+/// ```
+pub fn compact_files_naive(disk: &mut Disk) {
+    let blocks = &mut disk.blocks;
+    for (id, from, len) in files(blocks) {
+        let to = (0..from).find(|&to| to + len <= from && blocks[to..to + len].iter().all(Option::is_none));
+        if let Some(to) = to {
+            move_file(blocks, id, from, to, len);
+        }
+    }
+}
+
+/// Sum of every block position multiplied by the id of the file in it,
+/// `None` when it does not fit `u64`
+fn try_checksum(disk: &Disk) -> Option<u64> {
+    disk.blocks.iter().enumerate()
+    .filter_map(|(pos, block)| block.map(|id| (pos as u64).checked_mul(id as u64)))
+    .try_fold(0u64, |sum, product| sum.checked_add(product?))
+}
+
+/// Filesystem checksum, the sum of every block position multiplied by the id of the file in it
+/// 
+/// Saturates at [`u64::MAX`], [`part1`] and [`part2`] report the overflow as an error instead
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let mut disk = day_9::parse_disk(day_9::SAMPLE)?;
+///     day_9::compact_blocks(&mut disk);
+///     assert_eq!(day_9::checksum(&disk), 1928);
+///     Ok(())
+/// }
+/// ```
+pub fn checksum(disk: &Disk) -> u64 {
+    try_checksum(disk).unwrap_or(u64::MAX)
+}
+
+/// Checksum of the disk compacted one block at a time, the answer of part 1
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let disk = day_9::parse_disk(day_9::SAMPLE)?;
+///     assert_eq!(day_9::part1(&disk)?, 1928);
+///     Ok(())
+/// }
+/// ```
+pub fn part1(disk: &Disk) -> Result<u64> {
+    let mut disk = disk.clone();
+    compact_blocks(&mut disk);
+    try_checksum(&disk).ok_or(Day9Error::Overflow { part: 1 })
+}
+
+/// Checksum of the disk compacted one whole file at a time, the answer of part 2
+/// 
+/// # Examples
+/// ```
+/// fn main() -> day_9::Result<()> {
+///     let disk = day_9::parse_disk(day_9::SAMPLE)?;
+///     assert_eq!(day_9::part2(&disk)?, 2858);
+///     Ok(())
+/// }
+/// ```
+pub fn part2(disk: &Disk) -> Result<u64> {
+    let mut disk = disk.clone();
+    compact_files(&mut disk);
+    try_checksum(&disk).ok_or(Day9Error::Overflow { part: 2 })
+}
+
+/// Day 9 solution used by the `aoc` runner
+/// 
+/// Part 1 compacts the [`Disk`] one block at a time and takes its checksum,
+/// part 2 moves whole files instead
+/// 
+/// # Examples
+/// ```
+/// use aoc_utils::{Result, Solution};
+/// 
+/// fn main() -> Result<()> {
+///     assert_eq!(day_9::Day9.part1(day_9::SAMPLE)?, "1928");
+///     assert_eq!(day_9::Day9.part2(day_9::SAMPLE)?, "2858");
+///     Ok(())
+/// }
+/// ```
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part1(&self, input: &str) -> SolutionResult<String> {
+        Ok(part1(&Disk::try_from(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> SolutionResult<String> {
+        Ok(part2(&Disk::try_from(input)?)?.to_string())
+    }
+
+    fn solve_both(&self, input: &str) -> SolutionResult<(String, String)> {
+        let disk = Disk::try_from(input)?;
+        Ok((disk.solve(1)?, disk.solve(2)?))
+    }
+
+    fn sample(&self) -> Option<Sample> {
+        Some(Sample { input: SAMPLE, answers: SAMPLE_ANSWERS })
+    }
+
+    fn parse(&self, input: &str) -> SolutionResult<Box<dyn Parsed>> {
+        Ok(Box::new(Disk::try_from(input)?))
+    }
+
+    #[cfg(feature = "generate")]
+    fn generate(&self, size: usize, seed: u64) -> Option<(String, ExpectedAnswers)> {
+        Some(generate(size, seed))
+    }
+
+    fn describe(&self, input: &str) -> InputStats {
+        InputStats::generic(input).with_problem(format_problem(input))
+    }
+}
+
+impl Parsed for Disk {
+    fn solve(&self, part: u8) -> SolutionResult<String> {
+        match part {
+            1 => Ok(part1(self)?.to_string()),
+            _ => Ok(part2(self)?.to_string()),
+        }
+    }
+}
+
+/// Why the input does not look like a dense disk map
+fn format_problem(input: &str) -> Option<String> {
+    parse_disk(input).err().map(|e| error_chain(&e))
+}
+
+/// Day 9 input with a disk map of `size` files, generated from `seed`, and its expected answers
+/// 
+/// # Examples
+/// ```
+/// let (input, answers) = day_9::generate(50, 7);
+/// assert_eq!(input.trim_end().len(), 99);
+/// assert_eq!(day_9::generate(50, 7), (input, answers));
+/// ```
+#[cfg(feature = "generate")]
+pub fn generate(size: usize, seed: u64) -> (String, ExpectedAnswers) {
+    let generated = aoc_testing::day9(seed, size);
+    (generated.input, [generated.part1, generated.part2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "fs")]
+    use assert_fs::prelude::*;
+    use anyhow::{Ok, Result};
+
+    /// Blocks of the disk in the puzzle notation, the file id digit or `.` for free space
+    fn layout(disk: &Disk) -> String {
+        disk.blocks.iter().map(|block| match block {
+            Some(id) => char::from_digit(*id as u32, 10).expect("single digit file id"),
+            None => '.',
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_parse_disk_mini() -> Result<()> {
+        let disk = parse_disk("12345")?;
+        assert_eq!(layout(&disk), "0..111....22222");
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_blocks_mini() -> Result<()> {
+        let mut disk = parse_disk("12345")?;
+        compact_blocks(&mut disk);
+        assert_eq!(layout(&disk), "022111222......");
+        assert_eq!(checksum(&disk), 60);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_blocks_sample() -> Result<()> {
+        let mut disk = parse_disk(SAMPLE)?;
+        assert_eq!(layout(&disk), "00...111...2...333.44.5555.6666.777.888899");
+        compact_blocks(&mut disk);
+        assert_eq!(layout(&disk), "0099811188827773336446555566..............");
+        assert_eq!(checksum(&disk), 1928);
+        assert_eq!(Day9.part1(SAMPLE)?, SAMPLE_ANSWERS[0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_blocks_edges() -> Result<()> {
+        // already compact, with and without trailing free space
+        for map in ["", "9", "90", "1200"] {
+            let mut disk = parse_disk(map)?;
+            let before = disk.clone();
+            compact_blocks(&mut disk);
+            assert_eq!(disk, before, "{}", map);
+        }
+        // empty files take no blocks but still use up an id
+        let mut disk = parse_disk("10002")?;
+        assert_eq!(layout(&disk), "022");
+        compact_blocks(&mut disk);
+        assert_eq!(checksum(&disk), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum_overflow() -> Result<()> {
+        let disk = Disk { blocks: vec![None, Some(usize::MAX)] };
+        assert_eq!(checksum(&disk), u64::MAX);
+        // compacting moves the file to position 0, where it adds nothing
+        assert_eq!(part1(&disk)?, 0);
+        let disk = Disk { blocks: vec![Some(0), Some(usize::MAX), Some(usize::MAX)] };
+        assert!(matches!(part1(&disk), Err(Day9Error::Overflow { part: 1 })));
+        assert_eq!(Day9Error::Overflow { part: 1 }.kind(), ErrorKind::Solver);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_disk_errors() {
+        let result = parse_disk("12 45");
+        assert!(matches!(&result, Err(Day9Error::InvalidDigit { column: 3, character: ' ' })));
+        assert_eq!(result.unwrap_err().location(), Some(Location::Line(1)));
+        let result = parse_disk("123\n45\n");
+        assert!(matches!(&result, Err(Day9Error::InvalidDigit { column: 4, character: '\n' })));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Parse);
+        // trailing whitespace is not part of the map
+        assert!(parse_disk("12345 \r\n").is_ok());
+    }
+
+    #[test]
+    fn test_compact_files_sample() -> Result<()> {
+        let mut disk = parse_disk(SAMPLE)?;
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "00992111777.44.333....5555.6666.....8888..");
+        assert_eq!(checksum(&disk), 2858);
+        assert_eq!(Day9.part2(SAMPLE)?, SAMPLE_ANSWERS[1]);
+        assert_eq!(Day9.solve_both(SAMPLE)?, (SAMPLE_ANSWERS[0].to_string(), SAMPLE_ANSWERS[1].to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_files_cannot_move() -> Result<()> {
+        // no free span is long enough for file 1
+        let mut disk = parse_disk("1133")?;
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "0.111...");
+        // file 0 is already leftmost
+        let mut disk = parse_disk("21")?;
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "00.");
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_files_only_left() -> Result<()> {
+        // file 2 moves left of file 1, file 1 does not follow it into the freed span on its right
+        let mut disk = parse_disk("12202")?;
+        assert_eq!(layout(&disk), "0..1122");
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "02211..");
+        // the remainder of a partly filled span is still used by later files
+        let mut disk = parse_disk("1311221")?;
+        assert_eq!(layout(&disk), "0...1.22..3");
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "03221......");
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_files_merged_free_space() -> Result<()> {
+        // the empty files 1 and 2 join the free space around them into one span of four
+        let mut disk = parse_disk("1202004")?;
+        assert_eq!(layout(&disk), "0....3333");
+        compact_files(&mut disk);
+        assert_eq!(layout(&disk), "03333....");
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2_overflow() {
+        let disk = Disk { blocks: vec![Some(0), Some(usize::MAX), Some(usize::MAX)] };
+        assert!(matches!(part2(&disk), Err(Day9Error::Overflow { part: 2 })));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_disk_serde_round_trip() -> Result<()> {
+        let disk = parse_disk("121")?;
+        let json = serde_json::to_string(&disk)?;
+        assert_eq!(json, r#"{"blocks":[0,null,null,1]}"#);
+        assert_eq!(serde_json::from_str::<Disk>(&json)?, disk);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(Day9.describe(SAMPLE).problem, None);
+        assert_eq!(Day9.describe("12x45\n").problem.as_deref(), Some("unexpected 'x' at column 3"));
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_solution_crlf_bom() -> Result<()> {
+        let file = assert_fs::NamedTempFile::new("input.txt")?;
+        file.write_str(&aoc_testing::crlf_bom(SAMPLE))?;
+        let input = aoc_utils::InputSource::Path(file.path().to_path_buf()).read_to_string()?;
+        assert_eq!(Day9.part1(&input)?, SAMPLE_ANSWERS[0]);
+        assert_eq!(Day9.part2(&input)?, SAMPLE_ANSWERS[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_solution_generated() -> Result<()> {
+        for seed in 1..=5 {
+            let generated = aoc_testing::day9(seed, 200);
+            assert_eq!(Day9.solve_both(&generated.input)?, (generated.part1, generated.part2));
+        }
+        Ok(())
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_compact_files_matches_brute_force(map in "[0-9]{0,40}") {
+            let mut disk = parse_disk(&map)?;
+            let mut naive = disk.clone();
+            compact_files(&mut disk);
+            compact_files_naive(&mut naive);
+            proptest::prop_assert_eq!(&disk, &naive);
+            proptest::prop_assert_eq!(checksum(&disk), aoc_testing::oracle::file_compacted_checksum(&map));
+        }
+
+        #[test]
+        fn test_compact_blocks_matches_brute_force(map in "[0-9]{0,40}") {
+            let mut disk = parse_disk(&map)?;
+            compact_blocks(&mut disk);
+            proptest::prop_assert_eq!(checksum(&disk), aoc_testing::oracle::block_compacted_checksum(&map));
+        }
+    }
+}
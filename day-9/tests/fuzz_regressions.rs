@@ -0,0 +1,11 @@
+//! Replays the crashers saved from the `day_9` fuzz target
+
+#[test]
+fn test_fuzz_regressions() {
+    aoc_testing::replay_regressions("day_9", |data| {
+        if let Ok(disk) = day_9::parse_disk(&String::from_utf8_lossy(data)) {
+            let _ = day_9::part1(&disk);
+            let _ = day_9::part2(&disk);
+        }
+    });
+}
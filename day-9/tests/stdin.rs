@@ -0,0 +1,35 @@
+use std::{io::Write, process::{Command, Stdio}};
+
+#[test]
+fn test_stdin_sample() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-9"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("failed to start day-9");
+    child.stdin.take().unwrap().write_all(include_bytes!("../data/input_test_1928.txt")).unwrap();
+    let output = child.wait_with_output().expect("failed to wait for day-9");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "CHECKSUM: 1928\nFILE_CHECKSUM: 2858\n");
+}
+
+/// Exit code of day-9 solving the input piped to stdin
+fn exit_code(input: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day-9"))
+    .arg("-")
+    .stdin(Stdio::piped())
+    .stdout(Stdio::null())
+    .stderr(Stdio::null())
+    .spawn()
+    .expect("failed to start day-9");
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait().expect("failed to wait for day-9").code()
+}
+
+#[test]
+fn test_exit_codes() {
+    assert_eq!(exit_code("12345\n"), Some(0));
+    assert_eq!(exit_code("12x45\n"), Some(3));
+}
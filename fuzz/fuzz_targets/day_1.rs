@@ -0,0 +1,15 @@
+#![no_main]
+//! Day 1 list parsing, distance and similarity score
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok((list1, list2)) = day_1::get_lists(text) {
+        let _ = day_1::distance(&list1, &list2);
+        let _ = day_1::similarity_score(&list1, &list2);
+    }
+    let _ = day_1::get_number_lists::<i32>(text);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 10 topographic map parsing, trailhead scores and ratings
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(map) = day_10::parse_map(&String::from_utf8_lossy(data)) {
+        let _ = day_10::trailhead_scores(&map);
+        let _ = day_10::trailhead_ratings(&map);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 11 stone parsing and the stone counts after 25 and 75 blinks
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(stones) = day_11::parse_stones(&String::from_utf8_lossy(data)) {
+        let _ = day_11::part1(&stones);
+        let _ = day_11::part2(&stones);
+    }
+});
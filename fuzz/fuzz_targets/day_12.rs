@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 12 garden map parsing and both fence prices
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(garden) = day_12::parse_garden(&String::from_utf8_lossy(data)) {
+        let _ = day_12::total_price(&garden.plots);
+        let _ = day_12::discounted_price(&garden.plots);
+    }
+});
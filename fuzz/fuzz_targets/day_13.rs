@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 13 claw machine parsing and the tokens of both parts
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(arcade) = day_13::parse_machines(&String::from_utf8_lossy(data)) {
+        let _ = day_13::part1(&arcade);
+        let _ = day_13::part2(&arcade);
+    }
+});
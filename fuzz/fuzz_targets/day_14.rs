@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 14 robot parsing, the safety factor and the Easter egg search
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(robots) = day_14::parse_robots(&String::from_utf8_lossy(data)) {
+        let _ = day_14::part1(&robots, day_14::FLOOR);
+        let _ = day_14::part2(&robots, day_14::FLOOR);
+    }
+});
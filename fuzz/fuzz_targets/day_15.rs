@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 15 warehouse parsing and the robot pushing boxes in both warehouses
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = day_15::Day15Input::try_from(String::from_utf8_lossy(data).as_ref()) {
+        let _ = day_15::part1(&input);
+        let _ = day_15::part2(&input);
+    }
+});
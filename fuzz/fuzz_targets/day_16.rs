@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 16 maze parsing, the lowest score and the tiles on the best paths
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(maze) = day_16::parse_maze(&String::from_utf8_lossy(data)) {
+        let _ = day_16::part1(&maze);
+        let _ = day_16::part2(&maze);
+    }
+});
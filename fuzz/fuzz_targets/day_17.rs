@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 17 program parsing, running the program and the quine search
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = day_17::Day17Input::try_from(String::from_utf8_lossy(data).as_ref()) {
+        let _ = day_17::part1(&input);
+        let _ = day_17::part2(&input);
+    }
+});
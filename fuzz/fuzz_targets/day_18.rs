@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 18 byte parsing, the shortest path and the first blocking byte
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(bytes) = day_18::parse_bytes(&String::from_utf8_lossy(data)) {
+        let _ = day_18::part1(&bytes, day_18::SPACE);
+        let _ = day_18::part2(&bytes, day_18::SPACE);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 19 towel parsing and counting the arrangements of the designs
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(towels) = day_19::parse_towels(&String::from_utf8_lossy(data)) {
+        let _ = day_19::part1(&towels);
+        let _ = day_19::part2(&towels);
+    }
+});
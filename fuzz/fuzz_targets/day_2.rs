@@ -0,0 +1,13 @@
+#![no_main]
+//! Day 2 report parsing, with and without the problem dampener
+
+use aoc_utils::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let _ = day_2::Day2.part1(text);
+    let _ = day_2::Day2.part2(text);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 20 racetrack parsing and counting the cheats
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(track) = day_20::parse_track(&String::from_utf8_lossy(data)) {
+        let _ = day_20::part1(&track, day_20::MIN_SAVING);
+        let _ = day_20::part2(&track, day_20::MIN_SAVING);
+    }
+});
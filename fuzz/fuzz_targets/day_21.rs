@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 21 code parsing and the presses through the chains of robots
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(codes) = day_21::parse_codes(&String::from_utf8_lossy(data)) {
+        let _ = day_21::part1(&codes);
+        let _ = day_21::part2(&codes);
+    }
+});
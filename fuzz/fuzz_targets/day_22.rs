@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 22 secret number parsing and the search for the best sequence of price changes
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(buyers) = day_22::parse_buyers(&String::from_utf8_lossy(data)) {
+        let _ = day_22::part1(&buyers);
+        let _ = day_22::part2(&buyers);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 23 network parsing, the triangle count and the largest clique
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(network) = day_23::parse_network(&String::from_utf8_lossy(data)) {
+        let _ = day_23::part1(&network);
+        let _ = day_23::part2(&network);
+    }
+});
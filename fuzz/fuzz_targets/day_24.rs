@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 24 circuit parsing, its evaluation and the swapped outputs
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(circuit) = day_24::parse_circuit(&String::from_utf8_lossy(data)) {
+        let _ = day_24::part1(&circuit);
+        let _ = day_24::part2(&circuit);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+//! Day 25 schematic parsing and the fitting lock and key pairs
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok((locks, keys)) = day_25::parse_schematics(data) {
+        let _ = day_25::fitting_pairs(&locks, &keys);
+    }
+});
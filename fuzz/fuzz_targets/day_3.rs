@@ -0,0 +1,13 @@
+#![no_main]
+//! Day 3 instruction scanning over arbitrary bytes
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(instructions) = day_3::filter_corrupted(data) {
+        let _ = day_3::multiply(&instructions);
+        if let Ok(enabled) = day_3::filter_disabled(&instructions) {
+            let _ = day_3::multiply(&enabled);
+        }
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+//! Day 4 grid loading and `X-MAS` counting
+
+use libfuzzer_sys::fuzz_target;
+
+/// Larger grids only slow the fuzzer down without reaching new code
+const MAX_INPUT: usize = 64 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT {
+        return;
+    }
+    let _ = day_4::xmas_count_from_reader(data);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 5 rule parsing and update classification
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut reader = data;
+    if let Ok(rules) = day_5::read_rules(&mut reader) {
+        let _ = day_5::correctly_ordered_sum(&mut reader, &rules);
+    }
+});
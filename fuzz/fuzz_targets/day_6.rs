@@ -0,0 +1,16 @@
+#![no_main]
+//! Day 6 map parsing and the guard patrol
+
+use libfuzzer_sys::fuzz_target;
+
+/// Larger maps only slow the fuzzer down without reaching new code
+const MAX_INPUT: usize = 64 * 1024;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() > MAX_INPUT {
+        return;
+    }
+    if let Ok(map) = day_6::read_map(data) {
+        let _ = day_6::part1(&map);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 7 equation parsing and the operator searches
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(equations) = day_7::parse_equations(data) {
+        let _ = day_7::calibration_result(&equations);
+        let _ = day_7::calibration_result_with_concat(&equations);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 8 antenna map parsing and both antinode counts
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(map) = day_8::parse_antennas(&String::from_utf8_lossy(data)) {
+        let _ = day_8::antinode_count(&map);
+        let _ = day_8::resonant_antinode_count(&map);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+//! Day 9 disk map parsing and both compactions
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(disk) = day_9::parse_disk(&String::from_utf8_lossy(data)) {
+        let _ = day_9::part1(&disk);
+        let _ = day_9::part2(&disk);
+    }
+});
@@ -0,0 +1,43 @@
+#![warn(missing_docs)]
+//! Advent of code 2024 integration tests
+//! 
+//! Owns the `fixtures` tree with the sample input of every day. The tests
+//! in `tests/` solve the fixtures with the public entry points of each day
+//! crate and compare the answers with the published ones. Fixture paths are
+//! resolved from the crate directory, so the tests pass from any working
+//! directory.
+//! 
+//! [`Read more`](../../../README.md)
+
+use std::path::PathBuf;
+
+/// Path of the sample input fixture of a day
+/// 
+/// # Examples
+/// ```
+/// let path = tests_integration::fixture_path(1);
+/// assert!(path.ends_with("fixtures/day-1/sample.txt"));
+/// assert!(path.is_file());
+/// ```
+pub fn fixture_path(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("fixtures")
+    .join(format!("day-{}", day))
+    .join("sample.txt")
+}
+
+/// Sample input fixture of a day
+/// 
+/// # Panics
+/// 
+/// When the fixture of the day is missing
+/// 
+/// # Examples
+/// ```
+/// assert!(tests_integration::fixture(4).starts_with("MMMSXXMASM"));
+/// ```
+pub fn fixture(day: u8) -> String {
+    let path = fixture_path(day);
+    std::fs::read_to_string(&path)
+    .unwrap_or_else(|e| panic!("could not read fixture {}: {}", path.display(), e))
+}
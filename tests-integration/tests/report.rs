@@ -0,0 +1,40 @@
+use std::time::Duration;
+use anyhow::Result;
+use aoc::report::{render, Format};
+use aoc_utils::{SolutionError, SolveResult};
+use tests_integration::fixture;
+
+/// Results of every implemented part of every day, like `aoc run --all`
+/// 
+/// The fixtures are the samples, solved with the parameters of the sample
+/// and only for the parts the sample has an answer for. Durations are cut to whole microseconds, the precision of the report
+fn run_all() -> Result<Vec<SolveResult>> {
+    let mut results = Vec::new();
+    for solution in aoc::solutions() {
+        let input = fixture(solution.day());
+        let sample_solution = solution.sample_solution();
+        let solver = sample_solution.as_deref().unwrap_or(solution.as_ref());
+        let answers = solution.sample().map(|sample| sample.answers).unwrap_or_default();
+        for (part, _) in [1, 2].into_iter().zip(answers).filter(|(_, expected)| !expected.is_empty()) {
+            match aoc::solve_part(solver, part, &input) {
+                Ok(mut result) => {
+                    result.duration = Duration::from_micros(result.duration_us());
+                    results.push(result);
+                },
+                Err(SolutionError::NotImplemented { .. }) => {},
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+    Ok(results)
+}
+
+#[test]
+fn test_json_report_round_trip() -> Result<()> {
+    let results = run_all()?;
+    assert_eq!(results.len(), 45);
+    let json = render(&results, Format::Json)?;
+    let parsed: Vec<SolveResult> = serde_json::from_str(&json)?;
+    assert_eq!(parsed, results);
+    Ok(())
+}
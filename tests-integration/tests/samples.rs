@@ -0,0 +1,252 @@
+use std::io::BufReader;
+use anyhow::Result;
+use aoc_utils::{Solution, SolutionError};
+use tests_integration::{fixture, fixture_path};
+
+/// Solve every part of the fixture with the [`Solution`] of the day
+/// 
+/// Parts that are not implemented yet or have no sample answer are skipped
+fn assert_solution(solution: &dyn Solution, answers: [&str; 2]) {
+    let input = fixture(solution.day());
+    for (part, expected) in [1, 2].into_iter().zip(answers).filter(|(_, expected)| !expected.is_empty()) {
+        let answer = match part {
+            1 => solution.part1(&input),
+            _ => solution.part2(&input),
+        };
+        match answer {
+            Ok(answer) => assert_eq!(answer, expected, "day {} part {}", solution.day(), part),
+            Err(SolutionError::NotImplemented { .. }) => {},
+            Err(e) => panic!("day {} part {}: {}", solution.day(), part, e),
+        }
+    }
+}
+
+#[test]
+fn test_fixtures_match_embedded_samples() {
+    let samples = [day_1::SAMPLE, day_2::SAMPLE, day_3::SAMPLE, day_4::SAMPLE, day_5::SAMPLE, day_6::SAMPLE, day_7::SAMPLE, day_8::SAMPLE, day_9::SAMPLE, day_10::SAMPLE, day_11::SAMPLE, day_12::SAMPLE, day_13::SAMPLE, day_14::SAMPLE, day_15::SAMPLE, day_16::SAMPLE, day_17::SAMPLE, day_18::SAMPLE, day_19::SAMPLE, day_20::SAMPLE, day_21::SAMPLE, day_22::SAMPLE, day_23::SAMPLE, day_24::SAMPLE, day_25::SAMPLE];
+    for (day, sample) in (1..).zip(samples) {
+        assert_eq!(fixture(day).trim_end(), sample.trim_end(), "day {}", day);
+    }
+}
+
+#[test]
+fn test_day_1_sample() -> Result<()> {
+    let text = day_1::read_file(&fixture_path(1))?;
+    let (left, right) = day_1::get_lists(&text)?;
+    assert_eq!(day_1::distance(&left, &right)?, 11);
+    assert_eq!(day_1::similarity_score(&left, &right)?, 31);
+    assert_solution(&day_1::Day1, day_1::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_2_sample() -> Result<()> {
+    assert_eq!(day_2::safe_reports_number_from_path(&fixture_path(2))?, 4);
+    assert_solution(&day_2::Day2, day_2::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_3_sample() -> Result<()> {
+    let file = day_3::read_file(&fixture_path(3))?;
+    let instructions = day_3::filter_corrupted(file)?;
+    let enabled = day_3::filter_disabled(&instructions)?;
+    assert_eq!(day_3::multiply(&enabled)?, 48);
+    assert_solution(&day_3::Day3, day_3::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_4_sample() -> Result<()> {
+    assert_eq!(day_4::xmas_count(&fixture_path(4))?, 9);
+    assert_solution(&day_4::Day4, day_4::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_5_sample() -> Result<()> {
+    let mut reader = BufReader::new(std::fs::File::open(fixture_path(5))?);
+    let rules = day_5::read_rules(&mut reader)?;
+    assert_eq!(day_5::correctly_ordered_sum(&mut reader, &rules)?, 143);
+    assert_solution(&day_5::Day5, day_5::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_6_sample() -> Result<()> {
+    let map = day_6::read_map(BufReader::new(std::fs::File::open(fixture_path(6))?))?;
+    assert_eq!(day_6::visited_positions(&map), 41);
+    assert_eq!(day_6::loop_obstructions(&map), 6);
+    assert_solution(&day_6::Day6, day_6::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_7_sample() -> Result<()> {
+    let equations = day_7::parse_equations(BufReader::new(std::fs::File::open(fixture_path(7))?))?;
+    assert_eq!(day_7::calibration_result(&equations), 3749);
+    assert_eq!(day_7::calibration_result_with_concat(&equations), 11387);
+    assert_solution(&day_7::Day7, day_7::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_8_sample() -> Result<()> {
+    let map = day_8::parse_antennas(&fixture(8))?;
+    assert_eq!(day_8::antinode_count(&map), 14);
+    assert_eq!(day_8::resonant_antinode_count(&map), 34);
+    assert_solution(&day_8::Day8, day_8::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_9_sample() -> Result<()> {
+    let disk = day_9::parse_disk(&fixture(9))?;
+    assert_eq!(day_9::part1(&disk)?, 1928);
+    assert_eq!(day_9::part2(&disk)?, 2858);
+    assert_solution(&day_9::Day9, day_9::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_10_sample() -> Result<()> {
+    let map = day_10::parse_map(&fixture(10))?;
+    assert_eq!(day_10::trailhead_scores(&map), 36);
+    assert_eq!(day_10::trailhead_ratings(&map), 81);
+    assert_solution(&day_10::Day10, day_10::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_11_sample() -> Result<()> {
+    let stones = day_11::parse_stones(&fixture(11))?;
+    assert_eq!(day_11::part1(&stones)?, 55312);
+    assert_eq!(day_11::part2(&stones)?, 65601038650482);
+    assert_solution(&day_11::Day11, day_11::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_12_sample() -> Result<()> {
+    let garden = day_12::parse_garden(&fixture(12))?;
+    assert_eq!(day_12::total_price(&garden.plots), 1930);
+    assert_eq!(day_12::discounted_price(&garden.plots), 1206);
+    assert_solution(&day_12::Day12, day_12::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_13_sample() -> Result<()> {
+    let arcade = day_13::parse_machines(&fixture(13))?;
+    assert_eq!(day_13::fewest_tokens(&arcade.0), 480);
+    assert_eq!(day_13::fewest_tokens_far(&arcade.0), 875318608908);
+    assert_solution(&day_13::Day13, day_13::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_14_sample() -> Result<()> {
+    let robots = day_14::parse_robots(&fixture(14))?;
+    let positions = day_14::positions_after(&robots.0, 100, 11, 7);
+    assert_eq!(day_14::safety_factor(&positions, 11, 7), 12);
+    assert_solution(&day_14::Day14 { floor: day_14::SAMPLE_FLOOR }, day_14::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_15_sample() -> Result<()> {
+    let (warehouse, moves) = day_15::parse(&fixture(15))?;
+    assert_eq!(day_15::gps_sum(&day_15::simulate(&warehouse, &moves)), 10092);
+    assert_eq!(day_15::gps_sum(&day_15::simulate(&warehouse.widen(), &moves)), 9021);
+    assert_solution(&day_15::Day15, day_15::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_16_sample() -> Result<()> {
+    let maze = day_16::parse_maze(&fixture(16))?;
+    assert_eq!(day_16::lowest_score(&maze), Some(7036));
+    assert_eq!(day_16::best_path_tiles(&maze).map(|tiles| tiles.len()), Some(45));
+    assert_solution(&day_16::Day16, day_16::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_17_sample() -> Result<()> {
+    let (machine, program) = day_17::parse(&fixture(17))?;
+    assert_eq!(day_17::run_program(&machine, &program)?, "5,7,3,0");
+    assert_eq!(day_17::find_quine_a(&program), Some(117440));
+    assert_solution(&day_17::Day17, day_17::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_18_sample() -> Result<()> {
+    let bytes = day_18::parse_bytes(&fixture(18))?;
+    assert_eq!(day_18::shortest_path(&bytes.0[..12], 7), Some(22));
+    assert_eq!(day_18::first_blocking_byte(&bytes.0, 7), Some((6, 1)));
+    assert_solution(&day_18::Day18 { space: day_18::SAMPLE_SPACE }, day_18::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_19_sample() -> Result<()> {
+    let towels = day_19::parse_towels(&fixture(19))?;
+    assert_eq!(day_19::design_counts(&towels)?, [2, 1, 4, 6, 0, 1, 2, 0]);
+    assert_eq!(day_19::solve(&towels)?, (6, 16));
+    assert_solution(&day_19::Day19, day_19::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_20_sample() -> Result<()> {
+    let track = day_20::parse_track(&fixture(20))?;
+    assert_eq!(day_20::count_cheats(&track, 2, 64), Some(1));
+    assert_eq!(day_20::count_cheats(&track, 20, 50), Some(285));
+    assert_solution(&day_20::Day20 { min_saving: day_20::SAMPLE_MIN_SAVING }, day_20::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_21_sample() -> Result<()> {
+    let codes = day_21::parse_codes(&fixture(21))?;
+    assert_eq!(day_21::complexity(&codes.0, 2), Some(126384));
+    assert_eq!(day_21::RobotChain::new(2).presses("029A"), Some(68));
+    assert_solution(&day_21::Day21, day_21::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_22_sample() -> Result<()> {
+    let buyers = day_22::parse_buyers(&fixture(22))?;
+    assert_eq!(day_22::part1(&buyers), 37327623);
+    assert_eq!(day_22::best_sequence(&[1, 2, 3, 2024]), Some(([-2, 1, -1, 3], 23)));
+    assert_solution(&day_22::Day22, day_22::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_23_sample() -> Result<()> {
+    let network = day_23::parse_network(&fixture(23))?;
+    assert_eq!(network.triangles().len(), 12);
+    assert_eq!(network.password(&network.max_clique()), "co,de,ka,ta");
+    assert_solution(&day_23::Day23, day_23::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_24_sample() -> Result<()> {
+    let circuit = day_24::parse_circuit(&fixture(24))?;
+    assert_eq!(day_24::part1(&circuit)?, 2024);
+    assert_solution(&day_24::Day24, day_24::SAMPLE_ANSWERS);
+    Ok(())
+}
+
+#[test]
+fn test_day_25_sample() -> Result<()> {
+    let (locks, keys) = day_25::parse_schematics(fixture(25).as_bytes())?;
+    assert_eq!((locks.len(), keys.len()), (2, 3));
+    assert_eq!(day_25::fitting_pairs(&locks, &keys), 3);
+    assert_solution(&day_25::Day25, day_25::SAMPLE_ANSWERS);
+    Ok(())
+}